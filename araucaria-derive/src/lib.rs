@@ -0,0 +1,182 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    Attribute, Data, DeriveInput, Expr, Fields, GenericArgument, Ident, Lit, LitStr, PathArguments, Token, Type, ext::IdentExt, parenthesized,
+    parse_macro_input, punctuated::Punctuated, spanned::Spanned,
+};
+
+#[derive(Default)]
+struct Options {
+    rename: Option<String>,
+    kind: Option<Ident>,
+    calls: Vec<TokenStream2>,
+}
+
+const KINDS: [&str; 4] = ["email", "date", "time", "date_time"];
+
+fn call_arg(arg: Expr) -> TokenStream2 {
+    match arg {
+        Expr::Lit(lit) if matches!(lit.lit, Lit::Str(_)) => quote! { #lit.into() },
+        arg => quote! { #arg },
+    }
+}
+
+fn parse_options(attrs: &[Attribute]) -> syn::Result<Options> {
+    let mut options = Options::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("araucaria")) {
+        attr.parse_nested_meta(|meta| {
+            let ident = meta.path.require_ident()?.clone();
+            if ident == "rename" {
+                options.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if ident == "pattern" {
+                let content;
+                parenthesized!(content in meta.input);
+                let source = content.parse::<LitStr>()?;
+                if let Err(err) = regex::Regex::new(&source.value()) {
+                    return Err(syn::Error::new(source.span(), format!("invalid pattern: {}", err)));
+                }
+                options.calls.push(quote! { .pattern(::araucaria::schema::Pattern::new(#source).expect("invalid pattern")) });
+            } else if KINDS.iter().any(|kind| ident == kind) {
+                options.kind = Some(ident);
+            } else if meta.input.peek(syn::token::Paren) {
+                let content;
+                parenthesized!(content in meta.input);
+                let args = Punctuated::<Expr, Token![,]>::parse_terminated(&content)?.into_iter().map(call_arg);
+                options.calls.push(quote! { .#ident(#(#args),*) });
+            } else {
+                options.calls.push(quote! { .#ident() });
+            }
+            Ok(())
+        })?;
+    }
+    Ok(options)
+}
+
+fn generic_arg(ty: &Type, index: usize) -> Option<&Type> {
+    let Type::Path(type_path) = ty else { return None };
+    let PathArguments::AngleBracketed(arguments) = &type_path.path.segments.last()?.arguments else { return None };
+    match arguments.args.iter().filter(|arg| matches!(arg, GenericArgument::Type(_))).nth(index)? {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    }
+}
+
+fn type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path.path.segments.last().map(|segment| segment.ident.to_string()),
+        Type::Reference(reference) => type_name(&reference.elem),
+        _ => None,
+    }
+}
+
+fn leaf_schema(ty: &Type, kind: Option<&Ident>, calls: &[TokenStream2]) -> syn::Result<TokenStream2> {
+    let name = type_name(ty).unwrap_or_default();
+    let schema = match (name.as_str(), kind.map(|kind| kind.to_string()).as_deref()) {
+        ("String" | "str", Some("email")) => quote! { ::araucaria::schema::EmailSchema },
+        ("String" | "str", Some("date")) => quote! { ::araucaria::schema::DateSchema },
+        ("String" | "str", Some("time")) => quote! { ::araucaria::schema::TimeSchema },
+        ("String" | "str", Some("date_time")) => quote! { ::araucaria::schema::DateTimeSchema },
+        (_, Some(_)) => return Err(syn::Error::new(ty.span(), "email, date, time and date_time require a String field")),
+        ("String" | "str", None) => quote! { ::araucaria::schema::StrSchema },
+        ("u8" | "u16" | "u32" | "u64", None) => quote! { ::araucaria::schema::U64Schema },
+        ("i8" | "i16" | "i32" | "i64", None) => quote! { ::araucaria::schema::I64Schema },
+        ("f32" | "f64", None) => quote! { ::araucaria::schema::F64Schema },
+        ("usize", None) => quote! { ::araucaria::schema::USizeSchema },
+        ("isize", None) => quote! { ::araucaria::schema::ISizeSchema },
+        ("bool", None) => quote! { ::araucaria::schema::BoolSchema },
+        _ => {
+            if let Some(call) = calls.first() {
+                return Err(syn::Error::new(call.span(), "validation attributes are not supported on nested structs"));
+            }
+            return Ok(quote! { <#ty>::schema() });
+        }
+    };
+    Ok(quote! { ::araucaria::schema::Schema::from(#schema::default() #(#calls)*) })
+}
+
+fn field_schema(ty: &Type, kind: Option<&Ident>, calls: &[TokenStream2]) -> syn::Result<TokenStream2> {
+    match type_name(ty).as_deref() {
+        Some("Option") => {
+            let inner = field_schema(generic_arg(ty, 0).unwrap_or(ty), kind, calls)?;
+            Ok(quote! { #inner.optional() })
+        }
+        Some("Vec") => {
+            let item = field_schema(generic_arg(ty, 0).unwrap_or(ty), kind, &[])?;
+            Ok(quote! { ::araucaria::schema::Schema::from(::araucaria::schema::ArrSchema::from(#item) #(#calls)*) })
+        }
+        Some("BTreeMap" | "HashMap") => {
+            let value = field_schema(generic_arg(ty, 1).unwrap_or(ty), kind, &[])?;
+            Ok(quote! { ::araucaria::schema::Schema::from(::araucaria::schema::MapSchema::from(#value) #(#calls)*) })
+        }
+        _ => leaf_schema(ty, kind, calls),
+    }
+}
+
+fn derive_schema(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(syn::Error::new(input.ident.span(), "AraucariaSchema requires a struct with named fields")),
+        },
+        _ => return Err(syn::Error::new(input.ident.span(), "AraucariaSchema requires a struct with named fields")),
+    };
+    let struct_options = parse_options(&input.attrs)?;
+    let mut entries = vec![];
+    for field in fields {
+        let options = parse_options(&field.attrs)?;
+        let key = options.rename.clone().unwrap_or_else(|| field.ident.as_ref().map(|ident| ident.unraw().to_string()).unwrap_or_default());
+        let schema = field_schema(&field.ty, options.kind.as_ref(), &options.calls)?;
+        entries.push(quote! { (::std::string::String::from(#key), #schema) });
+    }
+    let struct_calls = &struct_options.calls;
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            pub fn schema() -> ::araucaria::schema::Schema {
+                ::araucaria::schema::Schema::from(
+                    ::araucaria::schema::ObjSchema::from(::std::collections::BTreeMap::from([#(#entries),*])) #(#struct_calls)*
+                )
+            }
+        }
+    })
+}
+
+#[proc_macro_derive(AraucariaSchema, attributes(araucaria))]
+pub fn araucaria_schema(input: TokenStream) -> TokenStream {
+    derive_schema(parse_macro_input!(input as DeriveInput)).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::derive_schema;
+
+    fn derive_err(input: syn::DeriveInput) -> String {
+        derive_schema(input).err().map(|err| err.to_string()).unwrap_or_default()
+    }
+
+    #[test]
+    fn derive_schema_err() {
+        assert_eq!(derive_err(parse_quote! { struct Id(u64); }), "AraucariaSchema requires a struct with named fields");
+        assert_eq!(derive_err(parse_quote! { enum Role { Admin } }), "AraucariaSchema requires a struct with named fields");
+        assert_eq!(
+            derive_err(parse_quote! { struct User { #[araucaria(email)] age: u64 } }),
+            "email, date, time and date_time require a String field"
+        );
+        assert_eq!(
+            derive_err(parse_quote! { struct User { #[araucaria(len_le(3))] address: Address } }),
+            "validation attributes are not supported on nested structs"
+        );
+        assert_eq!(derive_err(parse_quote! { struct User { #[araucaria(rename)] name: String } }), "expected `=`");
+        assert!(derive_err(parse_quote! { struct User { #[araucaria(pattern("[a-z"))] name: String } }).starts_with("invalid pattern: "));
+    }
+
+    #[test]
+    fn derive_schema_raw_ident() {
+        let tokens = derive_schema(parse_quote! { struct Item { r#type: String } }).unwrap().to_string();
+        assert!(tokens.contains("\"type\""));
+    }
+}
@@ -0,0 +1,69 @@
+use std::collections::BTreeMap;
+
+use araucaria::schema::{
+    ArrSchema, BoolSchema, DateSchema, EmailSchema, F64Schema, I64Schema, MapSchema, ObjSchema, Pattern, Schema, StrSchema, U64Schema, UnknownKeys,
+};
+use araucaria_derive::AraucariaSchema;
+
+#[allow(dead_code)]
+#[derive(AraucariaSchema)]
+struct Address {
+    #[araucaria(chars_len_btwn(1, 256))]
+    street: String,
+    number: Option<u32>,
+    #[araucaria(pattern("^[0-9]{5}-[0-9]{3}$"))]
+    zip_code: String,
+}
+
+#[allow(dead_code)]
+#[derive(AraucariaSchema)]
+#[araucaria(unknown_keys(UnknownKeys::Reject))]
+struct CreateUser {
+    #[araucaria(chars_len_btwn(1, 256))]
+    first_name: String,
+    #[araucaria(email)]
+    email: String,
+    #[araucaria(date, unix_epoch)]
+    birthdate: String,
+    #[araucaria(rename = "isActive")]
+    active: Option<bool>,
+    #[araucaria(ge(0.0), le(10.0))]
+    score: f64,
+    balance: i64,
+    #[araucaria(len_le(3))]
+    tags: Vec<String>,
+    #[araucaria(ne("ADMIN"))]
+    role: Option<String>,
+    address: Address,
+    addresses: Option<Vec<Address>>,
+    counters: BTreeMap<String, u64>,
+}
+
+#[test]
+fn derive_schema() {
+    let address = Schema::from(ObjSchema::from([
+        ("street".into(), Schema::from(StrSchema::default().chars_len_btwn(1, 256))),
+        ("number".into(), Schema::from(U64Schema::default().optional())),
+        ("zip_code".into(), Schema::from(StrSchema::default().pattern(Pattern::new("^[0-9]{5}-[0-9]{3}$").unwrap()))),
+    ]));
+    assert_eq!(Address::schema(), address);
+    assert_eq!(
+        CreateUser::schema(),
+        Schema::from(
+            ObjSchema::from([
+                ("first_name".into(), Schema::from(StrSchema::default().chars_len_btwn(1, 256))),
+                ("email".into(), Schema::from(EmailSchema::default())),
+                ("birthdate".into(), Schema::from(DateSchema::default().unix_epoch())),
+                ("isActive".into(), Schema::from(BoolSchema::default().optional())),
+                ("score".into(), Schema::from(F64Schema::default().ge(0.0).le(10.0))),
+                ("balance".into(), Schema::from(I64Schema::default())),
+                ("tags".into(), Schema::from(ArrSchema::from(Schema::from(StrSchema::default())).len_le(3))),
+                ("role".into(), Schema::from(StrSchema::default().ne("ADMIN".into()).optional())),
+                ("address".into(), address.clone()),
+                ("addresses".into(), Schema::from(ArrSchema::from(address).optional())),
+                ("counters".into(), Schema::from(MapSchema::from(Schema::from(U64Schema::default())))),
+            ])
+            .unknown_keys(UnknownKeys::Reject)
+        )
+    );
+}
@@ -1,6 +1,9 @@
 use std::collections::BTreeMap;
 
-use crate::{operation::Operation, schema::EnumValues};
+use crate::{
+    operation::{OperandValue, Operation},
+    schema::{Charset, EnumValues, Normalization},
+};
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum ValidationErr {
@@ -16,6 +19,10 @@ pub enum ValidationErr {
     Date,
     Time,
     DateTime,
+    Arr,
+    Obj,
+    Map,
+    UnknownKey,
     Operation(Operation),
     BytesLen(Operation),
     CharsLen(Operation),
@@ -24,7 +31,18 @@ pub enum ValidationErr {
     UppercaseLen(Operation),
     NumbersLen(Operation),
     SymbolsLen(Operation),
+    Len(Operation),
     Enumerated(EnumValues),
+    Pattern(String),
+    StartsWith(String),
+    EndsWith(String),
+    Contains(String),
+    NotContains(String),
+    OnlyChars(Charset),
+    Trimmed,
+    NoControlChars,
+    Normalized(Normalization),
+    Custom { code: String, params: BTreeMap<String, OperandValue> },
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -32,6 +50,10 @@ pub enum SchemaErr {
     Validation(Vec<ValidationErr>),
     Arr(Vec<SchemaErr>),
     Obj(BTreeMap<String, SchemaErr>),
+    Union(Vec<SchemaErr>),
+    Tagged(String, Box<SchemaErr>),
+    MapKey { key: Box<SchemaErr>, value: Option<Box<SchemaErr>> },
+    Collection { errors: Vec<ValidationErr>, items: Box<SchemaErr> },
 }
 
 impl<const N: usize> From<[ValidationErr; N]> for SchemaErr {
@@ -52,13 +74,80 @@ impl<const N: usize> From<[(String, SchemaErr); N]> for SchemaErr {
     }
 }
 
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FlatErr<T> {
+    pub pointer: String,
+    pub path: String,
+    pub key: bool,
+    pub errors: Vec<T>,
+}
+
+pub(crate) fn pointer_child(pointer: &str, key: &str) -> String {
+    format!("{}/{}", pointer, key.replace('~', "~0").replace('/', "~1"))
+}
+
+pub(crate) fn path_child(path: &str, key: &str) -> String {
+    if path.is_empty() { key.into() } else { format!("{}.{}", path, key) }
+}
+
+pub(crate) fn push_flat_err<T>(flat: &mut Vec<FlatErr<T>>, pointer: &str, path: &str, key: bool, errors: Vec<T>) {
+    if errors.is_empty() {
+        return;
+    }
+    match flat.iter_mut().find(|item| item.pointer == pointer && item.key == key) {
+        Some(item) => item.errors.extend(errors),
+        None => flat.push(FlatErr { pointer: pointer.into(), path: path.into(), key, errors }),
+    }
+}
+
+fn flatten_schema_err(err: &SchemaErr, pointer: &str, path: &str, key: bool, flat: &mut Vec<FlatErr<ValidationErr>>) {
+    match err {
+        SchemaErr::Validation(errors) => push_flat_err(flat, pointer, path, key, errors.clone()),
+        SchemaErr::Arr(arr) => {
+            for (index, item) in arr.iter().enumerate() {
+                flatten_schema_err(item, &pointer_child(pointer, &index.to_string()), &path_child(path, &index.to_string()), key, flat);
+            }
+        }
+        SchemaErr::Obj(obj) => {
+            for (item_key, item) in obj {
+                flatten_schema_err(item, &pointer_child(pointer, item_key), &path_child(path, item_key), key, flat);
+            }
+        }
+        SchemaErr::Union(union) => {
+            for item in union {
+                flatten_schema_err(item, pointer, path, key, flat);
+            }
+        }
+        SchemaErr::Tagged(_, err) => flatten_schema_err(err, pointer, path, key, flat),
+        SchemaErr::MapKey { key: key_err, value } => {
+            flatten_schema_err(key_err, pointer, path, true, flat);
+            if let Some(value) = value {
+                flatten_schema_err(value, pointer, path, key, flat);
+            }
+        }
+        SchemaErr::Collection { errors, items } => {
+            push_flat_err(flat, pointer, path, key, errors.clone());
+            flatten_schema_err(items, pointer, path, key, flat);
+        }
+    }
+}
+
+impl SchemaErr {
+    pub fn flatten(&self) -> Vec<FlatErr<ValidationErr>> {
+        let mut flat = vec![];
+        flatten_schema_err(self, "", "", false, &mut flat);
+        flat
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
 
     use crate::operation::{Operand, OperandValue, Operation};
 
-    use super::{SchemaErr, ValidationErr};
+    use super::{FlatErr, SchemaErr, ValidationErr};
 
     const REQUIRED: ValidationErr = ValidationErr::Required;
     const U64: ValidationErr = ValidationErr::U64;
@@ -106,4 +195,48 @@ mod tests {
             )]))
         );
     }
+
+    #[test]
+    fn schema_err_flatten() {
+        let err = SchemaErr::from([
+            ("id".into(), SchemaErr::from([REQUIRED, U64])),
+            (
+                "users".into(),
+                SchemaErr::from([
+                    SchemaErr::from([("email".into(), SchemaErr::from([REQUIRED]))]),
+                    SchemaErr::from([("a/b~c".into(), SchemaErr::from([F64]))]),
+                ]),
+            ),
+            ("value".into(), SchemaErr::Union(vec![SchemaErr::from([U64]), SchemaErr::from([I64])])),
+            ("event".into(), SchemaErr::Tagged("click".into(), Box::new(SchemaErr::from([("x".into(), SchemaErr::from([OPERATION_U64]))])))),
+        ]);
+        assert_eq!(
+            err.flatten(),
+            vec![
+                FlatErr { pointer: "/event/x".into(), path: "event.x".into(), key: false, errors: vec![OPERATION_U64] },
+                FlatErr { pointer: "/id".into(), path: "id".into(), key: false, errors: vec![REQUIRED, U64] },
+                FlatErr { pointer: "/users/0/email".into(), path: "users.0.email".into(), key: false, errors: vec![REQUIRED] },
+                FlatErr { pointer: "/users/1/a~1b~0c".into(), path: "users.1.a/b~c".into(), key: false, errors: vec![F64] },
+                FlatErr { pointer: "/value".into(), path: "value".into(), key: false, errors: vec![U64, I64] },
+            ]
+        );
+        assert_eq!(SchemaErr::from([REQUIRED]).flatten(), vec![FlatErr { pointer: "".into(), path: "".into(), key: false, errors: vec![REQUIRED] }]);
+        assert_eq!(SchemaErr::Obj(BTreeMap::new()).flatten(), vec![]);
+    }
+
+    #[test]
+    fn schema_err_flatten_valid_items() {
+        let err = SchemaErr::from([(
+            "ids".into(),
+            SchemaErr::from([SchemaErr::Validation(vec![]), SchemaErr::from([U64]), SchemaErr::Validation(vec![]), SchemaErr::from([REQUIRED, U64])]),
+        )]);
+        assert_eq!(
+            err.flatten(),
+            vec![
+                FlatErr { pointer: "/ids/1".into(), path: "ids.1".into(), key: false, errors: vec![U64] },
+                FlatErr { pointer: "/ids/3".into(), path: "ids.3".into(), key: false, errors: vec![REQUIRED, U64] },
+            ]
+        );
+        assert_eq!(SchemaErr::from([SchemaErr::Validation(vec![]), SchemaErr::Validation(vec![])]).flatten(), vec![]);
+    }
 }
@@ -2,4 +2,8 @@ pub mod error;
 pub mod locale;
 pub mod operation;
 pub mod schema;
+pub mod validation;
 pub mod value;
+
+#[cfg(feature = "derive")]
+pub use araucaria_derive::AraucariaSchema;
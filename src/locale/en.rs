@@ -0,0 +1,177 @@
+use std::collections::BTreeMap;
+
+use super::{Locale, plural_en};
+
+impl Locale {
+    pub fn en() -> Self {
+        Locale {
+            required: "is required".into(),
+            u64: "must be an unsigned integer".into(),
+            i64: "must be an integer".into(),
+            f64: "must be a float".into(),
+            usize: "must be an unsigned integer".into(),
+            isize: "must be an integer".into(),
+            bool: "must be a boolean".into(),
+            str: "must be a string".into(),
+            email: "must be an email".into(),
+            date: "must be a date".into(),
+            time: "must be a time".into(),
+            date_time: "must be a date and time".into(),
+            arr: "must be a list".into(),
+            obj: "must be an object".into(),
+            map: "must be an object".into(),
+            unknown_key: "is not allowed".into(),
+            eq: "must be equal to %value%".into(),
+            ne: "must be different from %value%".into(),
+            gt: "must be greater than %value%".into(),
+            ge: "must be greater than or equal to %value%".into(),
+            lt: "must be smaller than %value%".into(),
+            le: "must be smaller than or equal to %value%".into(),
+            btwn: "must be between %value_a% and %value_b%".into(),
+            eq_field: "must be equal to the field %value%".into(),
+            ne_field: "must be different from the field %value%".into(),
+            gt_field: "must be greater than the field %value%".into(),
+            lt_field: "must be smaller than the field %value%".into(),
+            ge_field: "must be greater than or equal to the field %value%".into(),
+            le_field: "must be smaller than or equal to the field %value%".into(),
+            today: "today".into(),
+            days_ago: "{value, plural, one {# day ago} other {# days ago}}".into(),
+            days_from_now: "{value, plural, one {# day from now} other {# days from now}}".into(),
+            months_ago: "{value, plural, one {# month ago} other {# months ago}}".into(),
+            months_from_now: "{value, plural, one {# month from now} other {# months from now}}".into(),
+            years_ago: "{value, plural, one {# year ago} other {# years ago}}".into(),
+            years_from_now: "{value, plural, one {# year from now} other {# years from now}}".into(),
+            le_today: "must not be in the future".into(),
+            ge_today: "must not be in the past".into(),
+            age_ge: "must be at least {value, plural, one {# year} other {# years}} ago".into(),
+            within_days: "must be within the next {value, plural, one {# day} other {# days}}".into(),
+            bytes_len_eq: "must have exactly {value, plural, one {# byte} other {# bytes}}".into(),
+            bytes_len_ne: "must not have exactly {value, plural, one {# byte} other {# bytes}}".into(),
+            bytes_len_gt: "must have more than {value, plural, one {# byte} other {# bytes}}".into(),
+            bytes_len_ge: "must have at least {value, plural, one {# byte} other {# bytes}}".into(),
+            bytes_len_lt: "must have fewer than {value, plural, one {# byte} other {# bytes}}".into(),
+            bytes_len_le: "must have at most {value, plural, one {# byte} other {# bytes}}".into(),
+            bytes_len_btwn: "must have between {value_a} and {value_b, plural, one {# byte} other {# bytes}}".into(),
+            chars_len_eq: "must have exactly {value, plural, one {# character} other {# characters}}".into(),
+            chars_len_ne: "must not have exactly {value, plural, one {# character} other {# characters}}".into(),
+            chars_len_gt: "must have more than {value, plural, one {# character} other {# characters}}".into(),
+            chars_len_ge: "must have at least {value, plural, one {# character} other {# characters}}".into(),
+            chars_len_lt: "must have fewer than {value, plural, one {# character} other {# characters}}".into(),
+            chars_len_le: "must have at most {value, plural, one {# character} other {# characters}}".into(),
+            chars_len_btwn: "must have between {value_a} and {value_b, plural, one {# character} other {# characters}}".into(),
+            graphemes_len_eq: "must have exactly {value, plural, one {# grapheme} other {# graphemes}}".into(),
+            graphemes_len_ne: "must not have exactly {value, plural, one {# grapheme} other {# graphemes}}".into(),
+            graphemes_len_gt: "must have more than {value, plural, one {# grapheme} other {# graphemes}}".into(),
+            graphemes_len_ge: "must have at least {value, plural, one {# grapheme} other {# graphemes}}".into(),
+            graphemes_len_lt: "must have fewer than {value, plural, one {# grapheme} other {# graphemes}}".into(),
+            graphemes_len_le: "must have at most {value, plural, one {# grapheme} other {# graphemes}}".into(),
+            graphemes_len_btwn: "must have between {value_a} and {value_b, plural, one {# grapheme} other {# graphemes}}".into(),
+            lowercase_len_eq: "must have exactly {value, plural, one {# lowercase character} other {# lowercase characters}}".into(),
+            lowercase_len_ne: "must not have exactly {value, plural, one {# lowercase character} other {# lowercase characters}}".into(),
+            lowercase_len_gt: "must have more than {value, plural, one {# lowercase character} other {# lowercase characters}}".into(),
+            lowercase_len_ge: "must have at least {value, plural, one {# lowercase character} other {# lowercase characters}}".into(),
+            lowercase_len_lt: "must have fewer than {value, plural, one {# lowercase character} other {# lowercase characters}}".into(),
+            lowercase_len_le: "must have at most {value, plural, one {# lowercase character} other {# lowercase characters}}".into(),
+            lowercase_len_btwn: "must have between {value_a} and {value_b, plural, one {# lowercase character} other {# lowercase characters}}"
+                .into(),
+            uppercase_len_eq: "must have exactly {value, plural, one {# uppercase character} other {# uppercase characters}}".into(),
+            uppercase_len_ne: "must not have exactly {value, plural, one {# uppercase character} other {# uppercase characters}}".into(),
+            uppercase_len_gt: "must have more than {value, plural, one {# uppercase character} other {# uppercase characters}}".into(),
+            uppercase_len_ge: "must have at least {value, plural, one {# uppercase character} other {# uppercase characters}}".into(),
+            uppercase_len_lt: "must have fewer than {value, plural, one {# uppercase character} other {# uppercase characters}}".into(),
+            uppercase_len_le: "must have at most {value, plural, one {# uppercase character} other {# uppercase characters}}".into(),
+            uppercase_len_btwn: "must have between {value_a} and {value_b, plural, one {# uppercase character} other {# uppercase characters}}"
+                .into(),
+            number_len_eq: "must have exactly {value, plural, one {# digit} other {# digits}}".into(),
+            number_len_ne: "must not have exactly {value, plural, one {# digit} other {# digits}}".into(),
+            number_len_gt: "must have more than {value, plural, one {# digit} other {# digits}}".into(),
+            number_len_ge: "must have at least {value, plural, one {# digit} other {# digits}}".into(),
+            number_len_lt: "must have fewer than {value, plural, one {# digit} other {# digits}}".into(),
+            number_len_le: "must have at most {value, plural, one {# digit} other {# digits}}".into(),
+            number_len_btwn: "must have between {value_a} and {value_b, plural, one {# digit} other {# digits}}".into(),
+            symbols_eq: "must have exactly {value, plural, one {# symbol} other {# symbols}}".into(),
+            symbols_ne: "must not have exactly {value, plural, one {# symbol} other {# symbols}}".into(),
+            symbols_gt: "must have more than {value, plural, one {# symbol} other {# symbols}}".into(),
+            symbols_ge: "must have at least {value, plural, one {# symbol} other {# symbols}}".into(),
+            symbols_lt: "must have fewer than {value, plural, one {# symbol} other {# symbols}}".into(),
+            symbols_le: "must have at most {value, plural, one {# symbol} other {# symbols}}".into(),
+            symbols_btwn: "must have between {value_a} and {value_b, plural, one {# symbol} other {# symbols}}".into(),
+            len_eq: "must have exactly {value, plural, one {# item} other {# items}}".into(),
+            len_ne: "must not have exactly {value, plural, one {# item} other {# items}}".into(),
+            len_gt: "must have more than {value, plural, one {# item} other {# items}}".into(),
+            len_ge: "must have at least {value, plural, one {# item} other {# items}}".into(),
+            len_lt: "must have fewer than {value, plural, one {# item} other {# items}}".into(),
+            len_le: "must have at most {value, plural, one {# item} other {# items}}".into(),
+            len_btwn: "must have between {value_a} and {value_b, plural, one {# item} other {# items}}".into(),
+            enumerated: "must be one of %value%".into(),
+            pattern: "must match the pattern %value%".into(),
+            starts_with: "must start with %value%".into(),
+            ends_with: "must end with %value%".into(),
+            contains: "must contain %value%".into(),
+            not_contains: "must not contain %value%".into(),
+            only_chars: "{value, select, ascii_alphanumeric {must contain only letters and digits} hex {must contain only hexadecimal digits} base64_url {must contain only letters, digits, - and _} other {must contain only the characters {value}}}".into(),
+            trimmed: "must not start or end with spaces".into(),
+            no_control_chars: "must not contain control or invisible characters".into(),
+            normalized: "must be in Unicode %value% form".into(),
+            custom: BTreeMap::new(),
+            plural: plural_en,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::ValidationErr,
+        locale::{Locale, localize_validation_err},
+        operation::{Operand, OperandValue, Operation, Relative},
+        schema::{Charset, EnumValues, Normalization},
+    };
+
+    #[test]
+    fn locale_en() {
+        let locale = Locale::en();
+        let chars_len_btwn =
+            ValidationErr::CharsLen(Operation::Btwn(Operand::Value(OperandValue::USize(1)), Operand::Value(OperandValue::USize(64))));
+        let gt_field = ValidationErr::Operation(Operation::Gt(Operand::FieldPath("min".into())));
+        let enumerated = ValidationErr::Enumerated(EnumValues::from(["ADMIN", "USER"]));
+        assert_eq!(localize_validation_err(&ValidationErr::Required, &locale), "is required".to_string());
+        assert_eq!(localize_validation_err(&chars_len_btwn, &locale), "must have between 1 and 64 characters".to_string());
+        let chars_len_ge_one = ValidationErr::CharsLen(Operation::Ge(Operand::Value(OperandValue::USize(1))));
+        let chars_len_ge_five = ValidationErr::CharsLen(Operation::Ge(Operand::Value(OperandValue::USize(5))));
+        assert_eq!(localize_validation_err(&chars_len_ge_one, &locale), "must have at least 1 character".to_string());
+        assert_eq!(localize_validation_err(&chars_len_ge_five, &locale), "must have at least 5 characters".to_string());
+        assert_eq!(localize_validation_err(&gt_field, &locale), r#"must be greater than the field "min""#.to_string());
+        assert_eq!(localize_validation_err(&enumerated, &locale), r#"must be one of [ "ADMIN", "USER" ]"#.to_string());
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Pattern("^[A-Z]{3}-[0-9]{4}$".into()), &locale),
+            r#"must match the pattern "^[A-Z]{3}-[0-9]{4}$""#.to_string()
+        );
+        assert_eq!(localize_validation_err(&ValidationErr::StartsWith("sk_".into()), &locale), r#"must start with "sk_""#.to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::NotContains("..".into()), &locale), r#"must not contain "..""#.to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::OnlyChars(Charset::Hex), &locale), "must contain only hexadecimal digits".to_string());
+        assert_eq!(
+            localize_validation_err(&ValidationErr::OnlyChars(Charset::Custom("ACGT".into())), &locale),
+            r#"must contain only the characters "ACGT""#.to_string()
+        );
+        assert_eq!(localize_validation_err(&ValidationErr::Trimmed, &locale), "must not start or end with spaces".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::Normalized(Normalization::Nfkc), &locale), "must be in Unicode NFKC form".to_string());
+    }
+
+    #[test]
+    fn locale_en_relative() {
+        let locale = Locale::en();
+        let le_today = ValidationErr::Operation(Operation::Le(Operand::Now(Relative::Days(0))));
+        let age_ge = |years: i64| ValidationErr::Operation(Operation::Le(Operand::Now(Relative::Years(-years))));
+        let within_days = |days: i64| ValidationErr::Operation(Operation::Btwn(Operand::Now(Relative::Days(0)), Operand::Now(Relative::Days(days))));
+        let ge_months_ago = ValidationErr::Operation(Operation::Ge(Operand::Now(Relative::Months(-6))));
+        let lt_month_from_now = ValidationErr::Operation(Operation::Lt(Operand::Now(Relative::Months(1))));
+        assert_eq!(localize_validation_err(&le_today, &locale), "must not be in the future".to_string());
+        assert_eq!(localize_validation_err(&age_ge(18), &locale), "must be at least 18 years ago".to_string());
+        assert_eq!(localize_validation_err(&age_ge(1), &locale), "must be at least 1 year ago".to_string());
+        assert_eq!(localize_validation_err(&within_days(30), &locale), "must be within the next 30 days".to_string());
+        assert_eq!(localize_validation_err(&within_days(1), &locale), "must be within the next 1 day".to_string());
+        assert_eq!(localize_validation_err(&ge_months_ago, &locale), "must be greater than or equal to 6 months ago".to_string());
+        assert_eq!(localize_validation_err(&lt_month_from_now, &locale), "must be smaller than 1 month from now".to_string());
+    }
+}
@@ -0,0 +1,162 @@
+use std::collections::BTreeMap;
+
+use super::{Locale, plural_es};
+
+impl Locale {
+    pub fn es() -> Self {
+        Locale {
+            required: "es obligatorio".into(),
+            u64: "debe ser un entero sin signo".into(),
+            i64: "debe ser un entero".into(),
+            f64: "debe ser un número decimal".into(),
+            usize: "debe ser un entero sin signo".into(),
+            isize: "debe ser un entero".into(),
+            bool: "debe ser un booleano".into(),
+            str: "debe ser un texto".into(),
+            email: "debe ser un correo electrónico".into(),
+            date: "debe ser una fecha".into(),
+            time: "debe ser una hora".into(),
+            date_time: "debe ser una fecha y hora".into(),
+            arr: "debe ser una lista".into(),
+            obj: "debe ser un objeto".into(),
+            map: "debe ser un objeto".into(),
+            unknown_key: "no está permitido".into(),
+            eq: "debe ser igual a %value%".into(),
+            ne: "debe ser diferente de %value%".into(),
+            gt: "debe ser mayor que %value%".into(),
+            ge: "debe ser mayor o igual a %value%".into(),
+            lt: "debe ser menor que %value%".into(),
+            le: "debe ser menor o igual a %value%".into(),
+            btwn: "debe estar entre %value_a% y %value_b%".into(),
+            eq_field: "debe ser igual al campo %value%".into(),
+            ne_field: "debe ser diferente del campo %value%".into(),
+            gt_field: "debe ser mayor que el campo %value%".into(),
+            lt_field: "debe ser menor que el campo %value%".into(),
+            ge_field: "debe ser mayor o igual al campo %value%".into(),
+            le_field: "debe ser menor o igual al campo %value%".into(),
+            today: "hoy".into(),
+            days_ago: "{value, plural, one {hace # día} other {hace # días}}".into(),
+            days_from_now: "{value, plural, one {dentro de # día} other {dentro de # días}}".into(),
+            months_ago: "{value, plural, one {hace # mes} other {hace # meses}}".into(),
+            months_from_now: "{value, plural, one {dentro de # mes} other {dentro de # meses}}".into(),
+            years_ago: "{value, plural, one {hace # año} other {hace # años}}".into(),
+            years_from_now: "{value, plural, one {dentro de # año} other {dentro de # años}}".into(),
+            le_today: "no debe estar en el futuro".into(),
+            ge_today: "no debe estar en el pasado".into(),
+            age_ge: "debe ser de hace al menos {value, plural, one {# año} other {# años}}".into(),
+            within_days: "debe estar {value, plural, one {en el próximo # día} other {en los próximos # días}}".into(),
+            bytes_len_eq: "debe tener exactamente {value, plural, one {# byte} other {# bytes}}".into(),
+            bytes_len_ne: "no debe tener exactamente {value, plural, one {# byte} other {# bytes}}".into(),
+            bytes_len_gt: "debe tener más de {value, plural, one {# byte} other {# bytes}}".into(),
+            bytes_len_ge: "debe tener al menos {value, plural, one {# byte} other {# bytes}}".into(),
+            bytes_len_lt: "debe tener menos de {value, plural, one {# byte} other {# bytes}}".into(),
+            bytes_len_le: "debe tener como máximo {value, plural, one {# byte} other {# bytes}}".into(),
+            bytes_len_btwn: "debe tener entre {value_a} y {value_b, plural, one {# byte} other {# bytes}}".into(),
+            chars_len_eq: "debe tener exactamente {value, plural, one {# carácter} other {# caracteres}}".into(),
+            chars_len_ne: "no debe tener exactamente {value, plural, one {# carácter} other {# caracteres}}".into(),
+            chars_len_gt: "debe tener más de {value, plural, one {# carácter} other {# caracteres}}".into(),
+            chars_len_ge: "debe tener al menos {value, plural, one {# carácter} other {# caracteres}}".into(),
+            chars_len_lt: "debe tener menos de {value, plural, one {# carácter} other {# caracteres}}".into(),
+            chars_len_le: "debe tener como máximo {value, plural, one {# carácter} other {# caracteres}}".into(),
+            chars_len_btwn: "debe tener entre {value_a} y {value_b, plural, one {# carácter} other {# caracteres}}".into(),
+            graphemes_len_eq: "debe tener exactamente {value, plural, one {# grafema} other {# grafemas}}".into(),
+            graphemes_len_ne: "no debe tener exactamente {value, plural, one {# grafema} other {# grafemas}}".into(),
+            graphemes_len_gt: "debe tener más de {value, plural, one {# grafema} other {# grafemas}}".into(),
+            graphemes_len_ge: "debe tener al menos {value, plural, one {# grafema} other {# grafemas}}".into(),
+            graphemes_len_lt: "debe tener menos de {value, plural, one {# grafema} other {# grafemas}}".into(),
+            graphemes_len_le: "debe tener como máximo {value, plural, one {# grafema} other {# grafemas}}".into(),
+            graphemes_len_btwn: "debe tener entre {value_a} y {value_b, plural, one {# grafema} other {# grafemas}}".into(),
+            lowercase_len_eq: "debe tener exactamente {value, plural, one {# letra minúscula} other {# letras minúsculas}}".into(),
+            lowercase_len_ne: "no debe tener exactamente {value, plural, one {# letra minúscula} other {# letras minúsculas}}".into(),
+            lowercase_len_gt: "debe tener más de {value, plural, one {# letra minúscula} other {# letras minúsculas}}".into(),
+            lowercase_len_ge: "debe tener al menos {value, plural, one {# letra minúscula} other {# letras minúsculas}}".into(),
+            lowercase_len_lt: "debe tener menos de {value, plural, one {# letra minúscula} other {# letras minúsculas}}".into(),
+            lowercase_len_le: "debe tener como máximo {value, plural, one {# letra minúscula} other {# letras minúsculas}}".into(),
+            lowercase_len_btwn: "debe tener entre {value_a} y {value_b, plural, one {# letra minúscula} other {# letras minúsculas}}".into(),
+            uppercase_len_eq: "debe tener exactamente {value, plural, one {# letra mayúscula} other {# letras mayúsculas}}".into(),
+            uppercase_len_ne: "no debe tener exactamente {value, plural, one {# letra mayúscula} other {# letras mayúsculas}}".into(),
+            uppercase_len_gt: "debe tener más de {value, plural, one {# letra mayúscula} other {# letras mayúsculas}}".into(),
+            uppercase_len_ge: "debe tener al menos {value, plural, one {# letra mayúscula} other {# letras mayúsculas}}".into(),
+            uppercase_len_lt: "debe tener menos de {value, plural, one {# letra mayúscula} other {# letras mayúsculas}}".into(),
+            uppercase_len_le: "debe tener como máximo {value, plural, one {# letra mayúscula} other {# letras mayúsculas}}".into(),
+            uppercase_len_btwn: "debe tener entre {value_a} y {value_b, plural, one {# letra mayúscula} other {# letras mayúsculas}}".into(),
+            number_len_eq: "debe tener exactamente {value, plural, one {# número} other {# números}}".into(),
+            number_len_ne: "no debe tener exactamente {value, plural, one {# número} other {# números}}".into(),
+            number_len_gt: "debe tener más de {value, plural, one {# número} other {# números}}".into(),
+            number_len_ge: "debe tener al menos {value, plural, one {# número} other {# números}}".into(),
+            number_len_lt: "debe tener menos de {value, plural, one {# número} other {# números}}".into(),
+            number_len_le: "debe tener como máximo {value, plural, one {# número} other {# números}}".into(),
+            number_len_btwn: "debe tener entre {value_a} y {value_b, plural, one {# número} other {# números}}".into(),
+            symbols_eq: "debe tener exactamente {value, plural, one {# símbolo} other {# símbolos}}".into(),
+            symbols_ne: "no debe tener exactamente {value, plural, one {# símbolo} other {# símbolos}}".into(),
+            symbols_gt: "debe tener más de {value, plural, one {# símbolo} other {# símbolos}}".into(),
+            symbols_ge: "debe tener al menos {value, plural, one {# símbolo} other {# símbolos}}".into(),
+            symbols_lt: "debe tener menos de {value, plural, one {# símbolo} other {# símbolos}}".into(),
+            symbols_le: "debe tener como máximo {value, plural, one {# símbolo} other {# símbolos}}".into(),
+            symbols_btwn: "debe tener entre {value_a} y {value_b, plural, one {# símbolo} other {# símbolos}}".into(),
+            len_eq: "debe tener exactamente {value, plural, one {# elemento} other {# elementos}}".into(),
+            len_ne: "no debe tener exactamente {value, plural, one {# elemento} other {# elementos}}".into(),
+            len_gt: "debe tener más de {value, plural, one {# elemento} other {# elementos}}".into(),
+            len_ge: "debe tener al menos {value, plural, one {# elemento} other {# elementos}}".into(),
+            len_lt: "debe tener menos de {value, plural, one {# elemento} other {# elementos}}".into(),
+            len_le: "debe tener como máximo {value, plural, one {# elemento} other {# elementos}}".into(),
+            len_btwn: "debe tener entre {value_a} y {value_b, plural, one {# elemento} other {# elementos}}".into(),
+            enumerated: "debe ser uno de %value%".into(),
+            pattern: "debe coincidir con el patrón %value%".into(),
+            starts_with: "debe empezar con %value%".into(),
+            ends_with: "debe terminar con %value%".into(),
+            contains: "debe contener %value%".into(),
+            not_contains: "no debe contener %value%".into(),
+            only_chars: "{value, select, ascii_alphanumeric {debe contener solo letras y dígitos} hex {debe contener solo dígitos hexadecimales} base64_url {debe contener solo letras, dígitos, - y _} other {debe contener solo los caracteres {value}}}".into(),
+            trimmed: "no debe empezar ni terminar con espacios".into(),
+            no_control_chars: "no debe contener caracteres de control o invisibles".into(),
+            normalized: "debe estar en la forma Unicode %value%".into(),
+            custom: BTreeMap::new(),
+            plural: plural_es,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::ValidationErr,
+        locale::{Locale, localize_validation_err},
+        operation::{Operand, OperandValue, Operation, Relative},
+        schema::EnumValues,
+    };
+
+    #[test]
+    fn locale_es() {
+        let locale = Locale::es();
+        let chars_len_btwn =
+            ValidationErr::CharsLen(Operation::Btwn(Operand::Value(OperandValue::USize(1)), Operand::Value(OperandValue::USize(64))));
+        let gt_field = ValidationErr::Operation(Operation::Gt(Operand::FieldPath("min".into())));
+        let enumerated = ValidationErr::Enumerated(EnumValues::from(["ADMIN", "USER"]));
+        assert_eq!(localize_validation_err(&ValidationErr::Required, &locale), "es obligatorio".to_string());
+        assert_eq!(localize_validation_err(&chars_len_btwn, &locale), "debe tener entre 1 y 64 caracteres".to_string());
+        let chars_len_ge_one = ValidationErr::CharsLen(Operation::Ge(Operand::Value(OperandValue::USize(1))));
+        let chars_len_ge_five = ValidationErr::CharsLen(Operation::Ge(Operand::Value(OperandValue::USize(5))));
+        assert_eq!(localize_validation_err(&chars_len_ge_one, &locale), "debe tener al menos 1 carácter".to_string());
+        assert_eq!(localize_validation_err(&chars_len_ge_five, &locale), "debe tener al menos 5 caracteres".to_string());
+        assert_eq!(localize_validation_err(&gt_field, &locale), r#"debe ser mayor que el campo "min""#.to_string());
+        assert_eq!(localize_validation_err(&enumerated, &locale), r#"debe ser uno de [ "ADMIN", "USER" ]"#.to_string());
+    }
+
+    #[test]
+    fn locale_es_relative() {
+        let locale = Locale::es();
+        let le_today = ValidationErr::Operation(Operation::Le(Operand::Now(Relative::Days(0))));
+        let age_ge = |years: i64| ValidationErr::Operation(Operation::Le(Operand::Now(Relative::Years(-years))));
+        let within_days = |days: i64| ValidationErr::Operation(Operation::Btwn(Operand::Now(Relative::Days(0)), Operand::Now(Relative::Days(days))));
+        let ge_months_ago = ValidationErr::Operation(Operation::Ge(Operand::Now(Relative::Months(-6))));
+        let lt_month_from_now = ValidationErr::Operation(Operation::Lt(Operand::Now(Relative::Months(1))));
+        assert_eq!(localize_validation_err(&le_today, &locale), "no debe estar en el futuro".to_string());
+        assert_eq!(localize_validation_err(&age_ge(18), &locale), "debe ser de hace al menos 18 años".to_string());
+        assert_eq!(localize_validation_err(&age_ge(1), &locale), "debe ser de hace al menos 1 año".to_string());
+        assert_eq!(localize_validation_err(&within_days(30), &locale), "debe estar en los próximos 30 días".to_string());
+        assert_eq!(localize_validation_err(&within_days(1), &locale), "debe estar en el próximo 1 día".to_string());
+        assert_eq!(localize_validation_err(&ge_months_ago, &locale), "debe ser mayor o igual a hace 6 meses".to_string());
+        assert_eq!(localize_validation_err(&lt_month_from_now, &locale), "debe ser menor que dentro de 1 mes".to_string());
+    }
+}
@@ -0,0 +1,276 @@
+use super::Plural;
+
+pub struct MessageArg {
+    pub display: String,
+    pub select: String,
+    pub number: Option<f64>,
+}
+
+struct Formatter<'a> {
+    chars: Vec<char>,
+    pos: usize,
+    args: &'a [(&'a str, MessageArg)],
+    plural: fn(f64) -> Plural,
+}
+
+fn plural_keyword(plural: Plural) -> &'static str {
+    match plural {
+        Plural::Zero => "zero",
+        Plural::One => "one",
+        Plural::Two => "two",
+        Plural::Few => "few",
+        Plural::Many => "many",
+        Plural::Other => "other",
+    }
+}
+
+impl Formatter<'_> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn arg(&self, name: &str) -> Option<&MessageArg> {
+        self.args.iter().find(|(arg_name, _)| *arg_name == name).map(|(_, arg)| arg)
+    }
+
+    fn read_until(&mut self, stop: &[char]) -> String {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| !stop.contains(&c)) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect::<String>().trim().to_string()
+    }
+
+    fn quoted(&mut self, hash: Option<&str>) -> Option<String> {
+        match self.chars.get(self.pos + 1) {
+            Some('\'') => {
+                self.pos += 2;
+                return Some("'".into());
+            }
+            Some('{' | '}') => {}
+            Some('#') if hash.is_some() => {}
+            _ => return None,
+        }
+        self.pos += 1;
+        let mut text = String::new();
+        while let Some(c) = self.peek() {
+            self.pos += 1;
+            if c == '\'' {
+                if self.peek() == Some('\'') {
+                    self.pos += 1;
+                    text.push('\'');
+                } else {
+                    break;
+                }
+            } else {
+                text.push(c);
+            }
+        }
+        Some(text)
+    }
+
+    fn legacy_placeholder(&mut self) -> Option<String> {
+        let rest: String = self.chars[self.pos..].iter().collect();
+        for (name, arg) in self.args {
+            let placeholder = format!("%{}%", name);
+            if rest.starts_with(&placeholder) {
+                self.pos += placeholder.chars().count();
+                return Some(arg.display.clone());
+            }
+        }
+        None
+    }
+
+    fn text(&mut self, hash: Option<&str>, in_branch: bool) -> String {
+        let mut text = String::new();
+        while let Some(c) = self.peek() {
+            match c {
+                '}' if in_branch => break,
+                '\'' => match self.quoted(hash) {
+                    Some(quoted) => text.push_str(&quoted),
+                    None => {
+                        self.pos += 1;
+                        text.push(c);
+                    }
+                },
+                '{' => text.push_str(&self.placeholder(hash)),
+                '#' if hash.is_some() => {
+                    self.pos += 1;
+                    text.push_str(hash.unwrap_or_default());
+                }
+                '%' => match self.legacy_placeholder() {
+                    Some(display) => text.push_str(&display),
+                    None => {
+                        self.pos += 1;
+                        text.push(c);
+                    }
+                },
+                c => {
+                    self.pos += 1;
+                    text.push(c);
+                }
+            }
+        }
+        text
+    }
+
+    fn branches(&mut self, hash: Option<&str>) -> Option<Vec<(String, String)>> {
+        let mut branches = vec![];
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('}') => {
+                    self.pos += 1;
+                    return Some(branches);
+                }
+                None => return None,
+                _ => {}
+            }
+            let selector = self.read_until(&['{', '}']);
+            if self.peek() != Some('{') {
+                continue;
+            }
+            self.pos += 1;
+            let text = self.text(hash, true);
+            if self.peek() != Some('}') {
+                return None;
+            }
+            self.pos += 1;
+            branches.push((selector, text));
+        }
+    }
+
+    fn literal(&mut self, start: usize) -> String {
+        self.pos = self.chars.len();
+        self.chars[start..].iter().collect()
+    }
+
+    fn placeholder(&mut self, hash: Option<&str>) -> String {
+        let start = self.pos;
+        self.pos += 1;
+        let name = self.read_until(&[',', '}']);
+        match self.peek() {
+            Some('}') => {
+                self.pos += 1;
+                return match self.arg(&name) {
+                    Some(arg) => arg.display.clone(),
+                    None => self.chars[start..self.pos].iter().collect(),
+                };
+            }
+            None => return self.literal(start),
+            _ => {}
+        }
+        self.pos += 1;
+        let kind = self.read_until(&[',', '}']);
+        if self.peek() == Some(',') {
+            self.pos += 1;
+        }
+        let (display, select, number) = match self.arg(&name) {
+            Some(arg) => (arg.display.clone(), arg.select.clone(), arg.number),
+            None => (String::new(), String::new(), None),
+        };
+        let branches = if kind == "plural" { self.branches(Some(&display)) } else { self.branches(hash) };
+        let Some(branches) = branches else { return self.literal(start) };
+        let branch = |selector: &str| branches.iter().find(|(branch_selector, _)| branch_selector == selector).map(|(_, text)| text.clone());
+        let chosen = match (kind.as_str(), number) {
+            ("plural", Some(number)) => branches
+                .iter()
+                .find(|(selector, _)| selector.strip_prefix('=').and_then(|exact| exact.parse::<f64>().ok()) == Some(number))
+                .map(|(_, text)| text.clone())
+                .or_else(|| branch(plural_keyword((self.plural)(number)))),
+            ("select", _) => branch(&select),
+            _ => None,
+        };
+        chosen.or_else(|| branch("other")).unwrap_or_default()
+    }
+}
+
+pub fn format_message(template: &str, args: &[(&str, MessageArg)], plural: fn(f64) -> Plural) -> String {
+    let mut formatter = Formatter { chars: template.chars().collect(), pos: 0, args, plural };
+    formatter.text(None, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::locale::{plural_en, plural_pt};
+
+    use super::{MessageArg, format_message};
+
+    fn number(value: u64) -> MessageArg {
+        MessageArg { display: value.to_string(), select: value.to_string(), number: Some(value as f64) }
+    }
+
+    fn text(value: &str) -> MessageArg {
+        MessageArg { display: format!("\"{}\"", value), select: value.into(), number: None }
+    }
+
+    const CHARACTERS: &str = "at least {value, plural, =0 {no characters} one {# character} other {# characters}}";
+
+    #[test]
+    fn format_message_legacy() {
+        assert_eq!(format_message("== %value%", &[("value", number(34))], plural_en), "== 34");
+        assert_eq!(format_message("%value_a% <= <= %value_b%", &[("value_a", number(1)), ("value_b", number(9))], plural_en), "1 <= <= 9");
+        assert_eq!(format_message("100% %other%", &[("value", number(34))], plural_en), "100% %other%");
+    }
+
+    #[test]
+    fn format_message_argument() {
+        assert_eq!(format_message("== {value}", &[("value", number(34))], plural_en), "== 34");
+        assert_eq!(format_message("== { value }", &[("value", text("Paul"))], plural_en), "== \"Paul\"");
+        assert_eq!(format_message("== {other}", &[("value", number(34))], plural_en), "== {other}");
+    }
+
+    #[test]
+    fn format_message_plural() {
+        assert_eq!(format_message(CHARACTERS, &[("value", number(0))], plural_en), "at least no characters");
+        assert_eq!(format_message(CHARACTERS, &[("value", number(1))], plural_en), "at least 1 character");
+        assert_eq!(format_message(CHARACTERS, &[("value", number(5))], plural_en), "at least 5 characters");
+        assert_eq!(format_message(CHARACTERS, &[("value", text("min"))], plural_en), "at least \"min\" characters");
+        let template = "{value, plural, one {# caractere} other {# caracteres}}";
+        assert_eq!(format_message(template, &[("value", number(0))], plural_pt), "0 caractere");
+        assert_eq!(format_message(template, &[("value", number(2))], plural_pt), "2 caracteres");
+        let template = "{value_a} to {value_b, plural, one {# item} other {# items}}";
+        assert_eq!(format_message(template, &[("value_a", number(0)), ("value_b", number(1))], plural_en), "0 to 1 item");
+    }
+
+    #[test]
+    fn format_message_select() {
+        let template = "{value, select, true {must be checked} false {must not be checked} other {must be {value}}}";
+        assert_eq!(
+            format_message(template, &[("value", MessageArg { display: "true".into(), select: "true".into(), number: None })], plural_en),
+            "must be checked"
+        );
+        assert_eq!(format_message(template, &[("value", text("on"))], plural_en), "must be \"on\"");
+        let template = "{value, select, min {{count, plural, one {# minute} other {# minutes}}} other {#}}";
+        assert_eq!(format_message(template, &[("value", text("min")), ("count", number(3))], plural_en), "3 minutes");
+        assert_eq!(format_message(template, &[("value", text("max")), ("count", number(3))], plural_en), "#");
+    }
+
+    #[test]
+    fn format_message_quote() {
+        assert_eq!(format_message("can't be '{value}'", &[("value", number(1))], plural_en), "can't be {value}");
+        assert_eq!(format_message("it''s {value}", &[("value", number(1))], plural_en), "it's 1");
+        assert_eq!(format_message("{value, plural, other {'#' #}}", &[("value", number(2))], plural_en), "# 2");
+    }
+
+    #[test]
+    fn format_message_unterminated() {
+        assert_eq!(format_message("{field", &[("field", text("name"))], plural_en), "{field");
+        assert_eq!(format_message("must be {", &[("value", number(2))], plural_en), "must be {");
+        assert_eq!(format_message("{value,", &[("value", number(2))], plural_en), "{value,");
+        assert_eq!(format_message("{value, plural", &[("value", number(2))], plural_en), "{value, plural");
+        assert_eq!(format_message("{value, plural, one {#", &[("value", number(1))], plural_en), "{value, plural, one {#");
+        assert_eq!(format_message("{value, plural, other {#", &[("value", number(2))], plural_en), "{value, plural, other {#");
+        assert_eq!(format_message("{value, plural, other {# items}", &[("value", number(2))], plural_en), "{value, plural, other {# items}");
+        assert_eq!(
+            format_message("{value} of {count, select, min {{value}} other {x}", &[("value", number(2)), ("count", text("min"))], plural_en),
+            "2 of {count, select, min {{value}} other {x}"
+        );
+    }
+}
@@ -0,0 +1,1532 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    error::{FlatErr, SchemaErr, ValidationErr, path_child, pointer_child, push_flat_err},
+    operation::{Operand, OperandValue, Operation, Relative},
+    schema::{Charset, EnumValues, Schema},
+};
+
+use message_format::{MessageArg, format_message};
+
+pub use plural::{Plural, plural_en, plural_es, plural_pt};
+
+mod en;
+mod es;
+mod message_format;
+mod plural;
+mod pt_br;
+
+pub struct Locale {
+    pub required: String,
+    pub u64: String,
+    pub i64: String,
+    pub f64: String,
+    pub usize: String,
+    pub isize: String,
+    pub bool: String,
+    pub str: String,
+    pub email: String,
+    pub date: String,
+    pub time: String,
+    pub date_time: String,
+    pub arr: String,
+    pub obj: String,
+    pub map: String,
+    pub unknown_key: String,
+    pub eq: String,
+    pub ne: String,
+    pub gt: String,
+    pub lt: String,
+    pub ge: String,
+    pub le: String,
+    pub btwn: String,
+    pub eq_field: String,
+    pub ne_field: String,
+    pub gt_field: String,
+    pub lt_field: String,
+    pub ge_field: String,
+    pub le_field: String,
+    pub today: String,
+    pub days_ago: String,
+    pub days_from_now: String,
+    pub months_ago: String,
+    pub months_from_now: String,
+    pub years_ago: String,
+    pub years_from_now: String,
+    pub le_today: String,
+    pub ge_today: String,
+    pub age_ge: String,
+    pub within_days: String,
+    pub bytes_len_eq: String,
+    pub bytes_len_ne: String,
+    pub bytes_len_gt: String,
+    pub bytes_len_ge: String,
+    pub bytes_len_lt: String,
+    pub bytes_len_le: String,
+    pub bytes_len_btwn: String,
+    pub chars_len_eq: String,
+    pub chars_len_ne: String,
+    pub chars_len_gt: String,
+    pub chars_len_ge: String,
+    pub chars_len_lt: String,
+    pub chars_len_le: String,
+    pub chars_len_btwn: String,
+    pub graphemes_len_eq: String,
+    pub graphemes_len_ne: String,
+    pub graphemes_len_gt: String,
+    pub graphemes_len_ge: String,
+    pub graphemes_len_lt: String,
+    pub graphemes_len_le: String,
+    pub graphemes_len_btwn: String,
+    pub lowercase_len_eq: String,
+    pub lowercase_len_ne: String,
+    pub lowercase_len_gt: String,
+    pub lowercase_len_ge: String,
+    pub lowercase_len_lt: String,
+    pub lowercase_len_le: String,
+    pub lowercase_len_btwn: String,
+    pub uppercase_len_eq: String,
+    pub uppercase_len_ne: String,
+    pub uppercase_len_gt: String,
+    pub uppercase_len_ge: String,
+    pub uppercase_len_lt: String,
+    pub uppercase_len_le: String,
+    pub uppercase_len_btwn: String,
+    pub number_len_eq: String,
+    pub number_len_ne: String,
+    pub number_len_gt: String,
+    pub number_len_ge: String,
+    pub number_len_lt: String,
+    pub number_len_le: String,
+    pub number_len_btwn: String,
+    pub symbols_eq: String,
+    pub symbols_ne: String,
+    pub symbols_gt: String,
+    pub symbols_ge: String,
+    pub symbols_lt: String,
+    pub symbols_le: String,
+    pub symbols_btwn: String,
+    pub len_eq: String,
+    pub len_ne: String,
+    pub len_gt: String,
+    pub len_ge: String,
+    pub len_lt: String,
+    pub len_le: String,
+    pub len_btwn: String,
+    pub enumerated: String,
+    pub pattern: String,
+    pub starts_with: String,
+    pub ends_with: String,
+    pub contains: String,
+    pub not_contains: String,
+    pub only_chars: String,
+    pub trimmed: String,
+    pub no_control_chars: String,
+    pub normalized: String,
+    pub custom: BTreeMap<String, String>,
+    pub plural: fn(f64) -> Plural,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum SchemaErrLocale {
+    Validation(Vec<String>),
+    Arr(Vec<SchemaErrLocale>),
+    Obj(BTreeMap<String, SchemaErrLocale>),
+    Union(Vec<SchemaErrLocale>),
+    Tagged(String, Box<SchemaErrLocale>),
+    MapKey { key: Box<SchemaErrLocale>, value: Option<Box<SchemaErrLocale>> },
+    Collection { errors: Vec<String>, items: Box<SchemaErrLocale> },
+}
+
+impl<const N: usize> From<[String; N]> for SchemaErrLocale {
+    fn from(value: [String; N]) -> Self {
+        SchemaErrLocale::Validation(value.to_vec())
+    }
+}
+
+impl<const N: usize> From<[SchemaErrLocale; N]> for SchemaErrLocale {
+    fn from(value: [SchemaErrLocale; N]) -> Self {
+        SchemaErrLocale::Arr(value.to_vec())
+    }
+}
+
+impl<const N: usize> From<[(String, SchemaErrLocale); N]> for SchemaErrLocale {
+    fn from(value: [(String, SchemaErrLocale); N]) -> Self {
+        SchemaErrLocale::Obj(BTreeMap::from(value))
+    }
+}
+
+fn flatten_schema_err_locale(err: &SchemaErrLocale, pointer: &str, path: &str, key: bool, flat: &mut Vec<FlatErr<String>>) {
+    match err {
+        SchemaErrLocale::Validation(errors) => push_flat_err(flat, pointer, path, key, errors.clone()),
+        SchemaErrLocale::Arr(arr) => {
+            for (index, item) in arr.iter().enumerate() {
+                flatten_schema_err_locale(item, &pointer_child(pointer, &index.to_string()), &path_child(path, &index.to_string()), key, flat);
+            }
+        }
+        SchemaErrLocale::Obj(obj) => {
+            for (item_key, item) in obj {
+                flatten_schema_err_locale(item, &pointer_child(pointer, item_key), &path_child(path, item_key), key, flat);
+            }
+        }
+        SchemaErrLocale::Union(union) => {
+            for item in union {
+                flatten_schema_err_locale(item, pointer, path, key, flat);
+            }
+        }
+        SchemaErrLocale::Tagged(_, err) => flatten_schema_err_locale(err, pointer, path, key, flat),
+        SchemaErrLocale::MapKey { key: key_err, value } => {
+            flatten_schema_err_locale(key_err, pointer, path, true, flat);
+            if let Some(value) = value {
+                flatten_schema_err_locale(value, pointer, path, key, flat);
+            }
+        }
+        SchemaErrLocale::Collection { errors, items } => {
+            push_flat_err(flat, pointer, path, key, errors.clone());
+            flatten_schema_err_locale(items, pointer, path, key, flat);
+        }
+    }
+}
+
+impl SchemaErrLocale {
+    pub fn flatten(&self) -> Vec<FlatErr<String>> {
+        let mut flat = vec![];
+        flatten_schema_err_locale(self, "", "", false, &mut flat);
+        flat
+    }
+}
+
+fn count_arg(count: u64) -> MessageArg {
+    MessageArg { display: count.to_string(), select: count.to_string(), number: Some(count as f64) }
+}
+
+fn localize_relative(relative: &Relative, locale: &Locale) -> String {
+    let (past, future) = match relative {
+        Relative::Days(_) => (&locale.days_ago, &locale.days_from_now),
+        Relative::Months(_) => (&locale.months_ago, &locale.months_from_now),
+        Relative::Years(_) => (&locale.years_ago, &locale.years_from_now),
+    };
+    let amount = relative.amount();
+    let template = match amount {
+        0 => &locale.today,
+        amount if amount < 0 => past,
+        _ => future,
+    };
+    format_message(template, &[("value", count_arg(amount.unsigned_abs()))], locale.plural)
+}
+
+fn operand_arg(operand: &Operand, locale: &Locale) -> MessageArg {
+    let display = match operand {
+        Operand::Now(relative) => localize_relative(relative, locale),
+        _ => operand.to_string(),
+    };
+    let (select, number) = match operand {
+        Operand::Value(OperandValue::U64(value)) => (value.to_string(), Some(*value as f64)),
+        Operand::Value(OperandValue::I64(value)) => (value.to_string(), Some(*value as f64)),
+        Operand::Value(OperandValue::F64(value)) => (value.to_string(), Some(*value)),
+        Operand::Value(OperandValue::USize(value)) => (value.to_string(), Some(*value as f64)),
+        Operand::Value(OperandValue::ISize(value)) => (value.to_string(), Some(*value as f64)),
+        Operand::Value(OperandValue::Bool(value)) => (value.to_string(), None),
+        Operand::Value(OperandValue::Str(value)) => (value.clone(), None),
+        Operand::Value(OperandValue::Date(value)) => (value.to_string(), None),
+        Operand::Value(OperandValue::Time(value)) => (value.to_string(), None),
+        Operand::Value(OperandValue::DateTime(value)) => (value.to_string(), None),
+        Operand::FieldPath(field) => (field.clone(), None),
+        Operand::Now(_) => (display.clone(), None),
+    };
+    MessageArg { display, select, number }
+}
+
+fn field_arg(field: &str) -> MessageArg {
+    MessageArg { display: field.into(), select: field.into(), number: None }
+}
+
+fn localize_message(template: &str, field: &str, locale: &Locale) -> String {
+    format_message(template, &[("field", field_arg(field))], locale.plural)
+}
+
+fn localize_operand(template: &str, operand: &Operand, field: &str, locale: &Locale) -> String {
+    format_message(template, &[("value", operand_arg(operand, locale)), ("field", field_arg(field))], locale.plural)
+}
+
+fn localize_operands(template: &str, operand_a: &Operand, operand_b: &Operand, field: &str, locale: &Locale) -> String {
+    format_message(
+        template,
+        &[("value_a", operand_arg(operand_a, locale)), ("value_b", operand_arg(operand_b, locale)), ("field", field_arg(field))],
+        locale.plural,
+    )
+}
+
+fn localize_count(template: &str, count: u64, field: &str, locale: &Locale) -> String {
+    format_message(template, &[("value", count_arg(count)), ("field", field_arg(field))], locale.plural)
+}
+
+fn localize_relative_operation(operation: &Operation, field: &str, locale: &Locale) -> Option<String> {
+    match operation {
+        Operation::Le(Operand::Now(relative)) if relative.amount() == 0 => Some(localize_message(&locale.le_today, field, locale)),
+        Operation::Ge(Operand::Now(relative)) if relative.amount() == 0 => Some(localize_message(&locale.ge_today, field, locale)),
+        Operation::Le(Operand::Now(Relative::Years(years))) if *years < 0 => {
+            Some(localize_count(&locale.age_ge, years.unsigned_abs(), field, locale))
+        }
+        Operation::Btwn(Operand::Now(start), Operand::Now(Relative::Days(days))) if start.amount() == 0 && *days > 0 => {
+            Some(localize_count(&locale.within_days, days.unsigned_abs(), field, locale))
+        }
+        _ => None,
+    }
+}
+
+pub fn localize_validation_err(error: &ValidationErr, locale: &Locale) -> String {
+    localize_field_validation_err(error, "", locale)
+}
+
+pub fn localize_field_validation_err(error: &ValidationErr, field: &str, locale: &Locale) -> String {
+    match error {
+        ValidationErr::Required => localize_message(&locale.required, field, locale),
+        ValidationErr::U64 => localize_message(&locale.u64, field, locale),
+        ValidationErr::I64 => localize_message(&locale.i64, field, locale),
+        ValidationErr::F64 => localize_message(&locale.f64, field, locale),
+        ValidationErr::USize => localize_message(&locale.usize, field, locale),
+        ValidationErr::ISize => localize_message(&locale.isize, field, locale),
+        ValidationErr::Bool => localize_message(&locale.bool, field, locale),
+        ValidationErr::Str => localize_message(&locale.str, field, locale),
+        ValidationErr::Email => localize_message(&locale.email, field, locale),
+        ValidationErr::Date => localize_message(&locale.date, field, locale),
+        ValidationErr::Time => localize_message(&locale.time, field, locale),
+        ValidationErr::DateTime => localize_message(&locale.date_time, field, locale),
+        ValidationErr::Arr => localize_message(&locale.arr, field, locale),
+        ValidationErr::Obj => localize_message(&locale.obj, field, locale),
+        ValidationErr::Map => localize_message(&locale.map, field, locale),
+        ValidationErr::UnknownKey => localize_message(&locale.unknown_key, field, locale),
+        ValidationErr::Operation(operation) => localize_relative_operation(operation, field, locale).unwrap_or_else(|| match operation {
+            Operation::Eq(operand) => match operand {
+                Operand::FieldPath(_) => localize_operand(&locale.eq_field, operand, field, locale),
+                _ => localize_operand(&locale.eq, operand, field, locale),
+            },
+            Operation::Ne(operand) => match operand {
+                Operand::FieldPath(_) => localize_operand(&locale.ne_field, operand, field, locale),
+                _ => localize_operand(&locale.ne, operand, field, locale),
+            },
+            Operation::Gt(operand) => match operand {
+                Operand::FieldPath(_) => localize_operand(&locale.gt_field, operand, field, locale),
+                _ => localize_operand(&locale.gt, operand, field, locale),
+            },
+            Operation::Ge(operand) => match operand {
+                Operand::FieldPath(_) => localize_operand(&locale.ge_field, operand, field, locale),
+                _ => localize_operand(&locale.ge, operand, field, locale),
+            },
+            Operation::Lt(operand) => match operand {
+                Operand::FieldPath(_) => localize_operand(&locale.lt_field, operand, field, locale),
+                _ => localize_operand(&locale.lt, operand, field, locale),
+            },
+            Operation::Le(operand) => match operand {
+                Operand::FieldPath(_) => localize_operand(&locale.le_field, operand, field, locale),
+                _ => localize_operand(&locale.le, operand, field, locale),
+            },
+            Operation::Btwn(a, b) => localize_operands(&locale.btwn, a, b, field, locale),
+        }),
+        ValidationErr::BytesLen(operation) => match operation {
+            Operation::Eq(v) => localize_operand(&locale.bytes_len_eq, v, field, locale),
+            Operation::Ne(v) => localize_operand(&locale.bytes_len_ne, v, field, locale),
+            Operation::Gt(v) => localize_operand(&locale.bytes_len_gt, v, field, locale),
+            Operation::Ge(v) => localize_operand(&locale.bytes_len_ge, v, field, locale),
+            Operation::Lt(v) => localize_operand(&locale.bytes_len_lt, v, field, locale),
+            Operation::Le(v) => localize_operand(&locale.bytes_len_le, v, field, locale),
+            Operation::Btwn(a, b) => localize_operands(&locale.bytes_len_btwn, a, b, field, locale),
+        },
+        ValidationErr::CharsLen(operation) => match operation {
+            Operation::Eq(v) => localize_operand(&locale.chars_len_eq, v, field, locale),
+            Operation::Ne(v) => localize_operand(&locale.chars_len_ne, v, field, locale),
+            Operation::Gt(v) => localize_operand(&locale.chars_len_gt, v, field, locale),
+            Operation::Ge(v) => localize_operand(&locale.chars_len_ge, v, field, locale),
+            Operation::Lt(v) => localize_operand(&locale.chars_len_lt, v, field, locale),
+            Operation::Le(v) => localize_operand(&locale.chars_len_le, v, field, locale),
+            Operation::Btwn(a, b) => localize_operands(&locale.chars_len_btwn, a, b, field, locale),
+        },
+        ValidationErr::GraphemesLen(operation) => match operation {
+            Operation::Eq(v) => localize_operand(&locale.graphemes_len_eq, v, field, locale),
+            Operation::Ne(v) => localize_operand(&locale.graphemes_len_ne, v, field, locale),
+            Operation::Gt(v) => localize_operand(&locale.graphemes_len_gt, v, field, locale),
+            Operation::Ge(v) => localize_operand(&locale.graphemes_len_ge, v, field, locale),
+            Operation::Lt(v) => localize_operand(&locale.graphemes_len_lt, v, field, locale),
+            Operation::Le(v) => localize_operand(&locale.graphemes_len_le, v, field, locale),
+            Operation::Btwn(a, b) => localize_operands(&locale.graphemes_len_btwn, a, b, field, locale),
+        },
+        ValidationErr::LowercaseLen(operation) => match operation {
+            Operation::Eq(v) => localize_operand(&locale.lowercase_len_eq, v, field, locale),
+            Operation::Ne(v) => localize_operand(&locale.lowercase_len_ne, v, field, locale),
+            Operation::Gt(v) => localize_operand(&locale.lowercase_len_gt, v, field, locale),
+            Operation::Ge(v) => localize_operand(&locale.lowercase_len_ge, v, field, locale),
+            Operation::Lt(v) => localize_operand(&locale.lowercase_len_lt, v, field, locale),
+            Operation::Le(v) => localize_operand(&locale.lowercase_len_le, v, field, locale),
+            Operation::Btwn(a, b) => localize_operands(&locale.lowercase_len_btwn, a, b, field, locale),
+        },
+        ValidationErr::UppercaseLen(operation) => match operation {
+            Operation::Eq(v) => localize_operand(&locale.uppercase_len_eq, v, field, locale),
+            Operation::Ne(v) => localize_operand(&locale.uppercase_len_ne, v, field, locale),
+            Operation::Gt(v) => localize_operand(&locale.uppercase_len_gt, v, field, locale),
+            Operation::Ge(v) => localize_operand(&locale.uppercase_len_ge, v, field, locale),
+            Operation::Lt(v) => localize_operand(&locale.uppercase_len_lt, v, field, locale),
+            Operation::Le(v) => localize_operand(&locale.uppercase_len_le, v, field, locale),
+            Operation::Btwn(a, b) => localize_operands(&locale.uppercase_len_btwn, a, b, field, locale),
+        },
+        ValidationErr::NumbersLen(operation) => match operation {
+            Operation::Eq(v) => localize_operand(&locale.number_len_eq, v, field, locale),
+            Operation::Ne(v) => localize_operand(&locale.number_len_ne, v, field, locale),
+            Operation::Gt(v) => localize_operand(&locale.number_len_gt, v, field, locale),
+            Operation::Ge(v) => localize_operand(&locale.number_len_ge, v, field, locale),
+            Operation::Lt(v) => localize_operand(&locale.number_len_lt, v, field, locale),
+            Operation::Le(v) => localize_operand(&locale.number_len_le, v, field, locale),
+            Operation::Btwn(a, b) => localize_operands(&locale.number_len_btwn, a, b, field, locale),
+        },
+        ValidationErr::SymbolsLen(operation) => match operation {
+            Operation::Eq(v) => localize_operand(&locale.symbols_eq, v, field, locale),
+            Operation::Ne(v) => localize_operand(&locale.symbols_ne, v, field, locale),
+            Operation::Gt(v) => localize_operand(&locale.symbols_gt, v, field, locale),
+            Operation::Ge(v) => localize_operand(&locale.symbols_ge, v, field, locale),
+            Operation::Lt(v) => localize_operand(&locale.symbols_lt, v, field, locale),
+            Operation::Le(v) => localize_operand(&locale.symbols_le, v, field, locale),
+            Operation::Btwn(a, b) => localize_operands(&locale.symbols_btwn, a, b, field, locale),
+        },
+        ValidationErr::Len(operation) => match operation {
+            Operation::Eq(v) => localize_operand(&locale.len_eq, v, field, locale),
+            Operation::Ne(v) => localize_operand(&locale.len_ne, v, field, locale),
+            Operation::Gt(v) => localize_operand(&locale.len_gt, v, field, locale),
+            Operation::Ge(v) => localize_operand(&locale.len_ge, v, field, locale),
+            Operation::Lt(v) => localize_operand(&locale.len_lt, v, field, locale),
+            Operation::Le(v) => localize_operand(&locale.len_le, v, field, locale),
+            Operation::Btwn(a, b) => localize_operands(&locale.len_btwn, a, b, field, locale),
+        },
+        ValidationErr::Enumerated(values) => {
+            let len = match values {
+                EnumValues::USize(values) => values.len(),
+                EnumValues::ISize(values) => values.len(),
+                EnumValues::Str(values) => values.len(),
+            };
+            let arg = MessageArg { display: values.to_string(), select: values.to_string(), number: Some(len as f64) };
+            format_message(&locale.enumerated, &[("value", arg), ("field", field_arg(field))], locale.plural)
+        }
+        ValidationErr::Pattern(source) => {
+            let arg = MessageArg { display: format!("\"{}\"", source), select: source.clone(), number: None };
+            format_message(&locale.pattern, &[("value", arg), ("field", field_arg(field))], locale.plural)
+        }
+        ValidationErr::StartsWith(prefix) => localize_operand(&locale.starts_with, &Operand::Value(OperandValue::Str(prefix.clone())), field, locale),
+        ValidationErr::EndsWith(suffix) => localize_operand(&locale.ends_with, &Operand::Value(OperandValue::Str(suffix.clone())), field, locale),
+        ValidationErr::Contains(part) => localize_operand(&locale.contains, &Operand::Value(OperandValue::Str(part.clone())), field, locale),
+        ValidationErr::NotContains(part) => localize_operand(&locale.not_contains, &Operand::Value(OperandValue::Str(part.clone())), field, locale),
+        ValidationErr::OnlyChars(charset) => {
+            let arg = match charset {
+                Charset::AsciiAlphanumeric => MessageArg { display: "ascii_alphanumeric".into(), select: "ascii_alphanumeric".into(), number: None },
+                Charset::Hex => MessageArg { display: "hex".into(), select: "hex".into(), number: None },
+                Charset::Base64Url => MessageArg { display: "base64_url".into(), select: "base64_url".into(), number: None },
+                Charset::Custom(chars) => MessageArg { display: format!("\"{}\"", chars), select: "custom".into(), number: None },
+            };
+            format_message(&locale.only_chars, &[("value", arg), ("field", field_arg(field))], locale.plural)
+        }
+        ValidationErr::Trimmed => localize_message(&locale.trimmed, field, locale),
+        ValidationErr::NoControlChars => localize_message(&locale.no_control_chars, field, locale),
+        ValidationErr::Normalized(normalization) => {
+            let arg = MessageArg { display: normalization.to_string(), select: normalization.to_string(), number: None };
+            format_message(&locale.normalized, &[("value", arg), ("field", field_arg(field))], locale.plural)
+        }
+        ValidationErr::Custom { code, params } => {
+            let template = locale.custom.get(code).unwrap_or(code);
+            let mut args: Vec<(&str, MessageArg)> =
+                params.iter().map(|(name, value)| (name.as_str(), operand_arg(&Operand::Value(value.clone()), locale))).collect();
+            args.push(("field", field_arg(field)));
+            format_message(template, &args, locale.plural)
+        }
+    }
+}
+
+fn localize_map_key(
+    key_err: &SchemaErr,
+    value_err: &Option<Box<SchemaErr>>,
+    path: &str,
+    key_schema: Option<&Schema>,
+    value_schema: Option<&Schema>,
+    locale: &Locale,
+) -> SchemaErrLocale {
+    SchemaErrLocale::MapKey {
+        key: Box::new(localize_schema_err_at(key_err, path, key_schema, locale)),
+        value: value_err.as_ref().map(|value_err| Box::new(localize_schema_err_at(value_err, path, value_schema, locale))),
+    }
+}
+
+fn localize_schema_err_at(err: &SchemaErr, path: &str, schema: Option<&Schema>, locale: &Locale) -> SchemaErrLocale {
+    if let Some(Schema::Custom(schema)) = schema {
+        return localize_schema_err_at(err, path, Some(&schema.schema), locale);
+    }
+    match err {
+        SchemaErr::Validation(v) => {
+            let field = schema.and_then(Schema::get_label).unwrap_or(path);
+            SchemaErrLocale::Validation(v.iter().map(|item| localize_field_validation_err(item, field, locale)).collect())
+        }
+        SchemaErr::Arr(arr) => {
+            let item_schema = match schema {
+                Some(Schema::Arr(schema)) => Some(schema.item.as_ref()),
+                _ => None,
+            };
+            let mut result: Vec<SchemaErrLocale> = Vec::new();
+            for (index, item) in arr.iter().enumerate() {
+                result.push(localize_schema_err_at(item, &path_child(path, &index.to_string()), item_schema, locale));
+            }
+            SchemaErrLocale::Arr(result)
+        }
+        SchemaErr::Obj(obj) => {
+            let mut result: BTreeMap<String, SchemaErrLocale> = BTreeMap::new();
+            for (key, item) in obj {
+                let item_path = path_child(path, key);
+                let localized = match (item, schema) {
+                    (SchemaErr::MapKey { key: key_err, value }, Some(Schema::Map(schema))) => {
+                        localize_map_key(key_err, value, &item_path, Some(&schema.key), Some(&schema.value), locale)
+                    }
+                    _ => {
+                        let item_schema = match schema {
+                            Some(Schema::Obj(schema)) => schema.validation.get(key),
+                            Some(Schema::Map(schema)) => Some(schema.value.as_ref()),
+                            _ => None,
+                        };
+                        localize_schema_err_at(item, &item_path, item_schema, locale)
+                    }
+                };
+                result.insert(key.clone(), localized);
+            }
+            SchemaErrLocale::Obj(result)
+        }
+        SchemaErr::Union(union) => {
+            let mut result: Vec<SchemaErrLocale> = Vec::new();
+            for (index, item) in union.iter().enumerate() {
+                let variant_schema = match schema {
+                    Some(Schema::Union(schema)) => schema.variants.get(index),
+                    _ => None,
+                };
+                result.push(localize_schema_err_at(item, path, variant_schema, locale));
+            }
+            SchemaErrLocale::Union(result)
+        }
+        SchemaErr::Tagged(tag_value, err) => {
+            let variant_schema = match schema {
+                Some(Schema::Tagged(schema)) => schema.variants.get(tag_value).cloned().map(Schema::Obj),
+                _ => None,
+            };
+            SchemaErrLocale::Tagged(tag_value.clone(), Box::new(localize_schema_err_at(err, path, variant_schema.as_ref(), locale)))
+        }
+        SchemaErr::MapKey { key, value } => localize_map_key(key, value, path, None, schema, locale),
+        SchemaErr::Collection { errors, items } => {
+            let field = schema.and_then(Schema::get_label).unwrap_or(path);
+            SchemaErrLocale::Collection {
+                errors: errors.iter().map(|item| localize_field_validation_err(item, field, locale)).collect(),
+                items: Box::new(localize_schema_err_at(items, path, schema, locale)),
+            }
+        }
+    }
+}
+
+pub fn localize_schema_err(err: &SchemaErr, locale: &Locale) -> SchemaErrLocale {
+    localize_schema_err_at(err, "", None, locale)
+}
+
+pub fn localize_schema_err_with_schema(err: &SchemaErr, schema: &Schema, locale: &Locale) -> SchemaErrLocale {
+    localize_schema_err_at(err, "", Some(schema), locale)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{BTreeMap, BTreeSet},
+        sync::LazyLock,
+    };
+
+    use crate::{
+        error::{FlatErr, SchemaErr, ValidationErr},
+        operation::{Operand, OperandValue, Operation, Relative},
+        schema::{
+            ArrSchema, Charset, EnumSchema, EnumValues, MapSchema, Normalization, ObjSchema, Schema, StrSchema, TaggedSchema, U64Schema, UnionSchema,
+        },
+        validation::validate,
+        value::Value,
+    };
+
+    use super::{
+        Locale, SchemaErrLocale, localize_field_validation_err, localize_schema_err, localize_schema_err_with_schema, localize_validation_err,
+        plural_en,
+    };
+
+    const STR_VALUES: [&str; 3] = ["APPLE", "GRAPE", "PEAR"];
+    const USIZE_VALUES: [usize; 6] = [0, 1, 2, 3, 4, 5];
+    const ISIZE_VALUES: [isize; 5] = [-2, -1, 0, 1, 2];
+
+    const U64_VALUE: Operand = Operand::Value(OperandValue::U64(34));
+    const U64_VALUE_B: Operand = Operand::Value(OperandValue::U64(43));
+    const I64_VALUE: Operand = Operand::Value(OperandValue::I64(-4));
+    const I64_VALUE_B: Operand = Operand::Value(OperandValue::I64(4));
+    const F64_VALUE: Operand = Operand::Value(OperandValue::F64(-4.6));
+    const F64_VALUE_B: Operand = Operand::Value(OperandValue::F64(-2.4));
+    const USIZE_VALUE: Operand = Operand::Value(OperandValue::USize(27));
+    const USIZE_VALUE_B: Operand = Operand::Value(OperandValue::USize(39));
+    const ISIZE_VALUE: Operand = Operand::Value(OperandValue::ISize(-93));
+    const ISIZE_VALUE_B: Operand = Operand::Value(OperandValue::ISize(-72));
+    const BOOL_VALUE: Operand = Operand::Value(OperandValue::Bool(false));
+    const BOOL_VALUE_B: Operand = Operand::Value(OperandValue::Bool(true));
+    static STR_VALUE: LazyLock<Operand> = LazyLock::new(|| Operand::Value(OperandValue::from("aurorae")));
+    static STR_VALUE_B: LazyLock<Operand> = LazyLock::new(|| Operand::Value(OperandValue::from("crespúculum")));
+    static FIELD_PATH: LazyLock<Operand> = LazyLock::new(|| Operand::FieldPath("user.account.info.details.user_name".into()));
+
+    const REQUIRED: ValidationErr = ValidationErr::Required;
+    const U64: ValidationErr = ValidationErr::U64;
+    const I64: ValidationErr = ValidationErr::I64;
+    const F64: ValidationErr = ValidationErr::F64;
+    const USIZE: ValidationErr = ValidationErr::USize;
+    const ISIZE: ValidationErr = ValidationErr::ISize;
+    const BOOL: ValidationErr = ValidationErr::Bool;
+    const STR: ValidationErr = ValidationErr::Str;
+    const EMAIL: ValidationErr = ValidationErr::Email;
+    const DATE: ValidationErr = ValidationErr::Date;
+    const TIME: ValidationErr = ValidationErr::Time;
+    const DATE_TIME: ValidationErr = ValidationErr::DateTime;
+    const ARR: ValidationErr = ValidationErr::Arr;
+    const OBJ: ValidationErr = ValidationErr::Obj;
+    const MAP: ValidationErr = ValidationErr::Map;
+    const UNKNOWN_KEY: ValidationErr = ValidationErr::UnknownKey;
+
+    const OPERATION_U64_EQ: ValidationErr = ValidationErr::Operation(Operation::Eq(U64_VALUE));
+    const OPERATION_U64_NE: ValidationErr = ValidationErr::Operation(Operation::Ne(U64_VALUE));
+    const OPERATION_U64_GT: ValidationErr = ValidationErr::Operation(Operation::Gt(U64_VALUE));
+    const OPERATION_U64_GE: ValidationErr = ValidationErr::Operation(Operation::Ge(U64_VALUE));
+    const OPERATION_U64_LT: ValidationErr = ValidationErr::Operation(Operation::Lt(U64_VALUE));
+    const OPERATION_U64_LE: ValidationErr = ValidationErr::Operation(Operation::Le(U64_VALUE));
+    const OPERATION_U64_BTWN: ValidationErr = ValidationErr::Operation(Operation::Btwn(U64_VALUE, U64_VALUE_B));
+
+    const OPERATION_I64_EQ: ValidationErr = ValidationErr::Operation(Operation::Eq(I64_VALUE));
+    const OPERATION_I64_NE: ValidationErr = ValidationErr::Operation(Operation::Ne(I64_VALUE));
+    const OPERATION_I64_GT: ValidationErr = ValidationErr::Operation(Operation::Gt(I64_VALUE));
+    const OPERATION_I64_GE: ValidationErr = ValidationErr::Operation(Operation::Ge(I64_VALUE));
+    const OPERATION_I64_LT: ValidationErr = ValidationErr::Operation(Operation::Lt(I64_VALUE));
+    const OPERATION_I64_LE: ValidationErr = ValidationErr::Operation(Operation::Le(I64_VALUE));
+    const OPERATION_I64_BTWN: ValidationErr = ValidationErr::Operation(Operation::Btwn(I64_VALUE, I64_VALUE_B));
+
+    const OPERATION_F64_EQ: ValidationErr = ValidationErr::Operation(Operation::Eq(F64_VALUE));
+    const OPERATION_F64_NE: ValidationErr = ValidationErr::Operation(Operation::Ne(F64_VALUE));
+    const OPERATION_F64_GT: ValidationErr = ValidationErr::Operation(Operation::Gt(F64_VALUE));
+    const OPERATION_F64_GE: ValidationErr = ValidationErr::Operation(Operation::Ge(F64_VALUE));
+    const OPERATION_F64_LT: ValidationErr = ValidationErr::Operation(Operation::Lt(F64_VALUE));
+    const OPERATION_F64_LE: ValidationErr = ValidationErr::Operation(Operation::Le(F64_VALUE));
+    const OPERATION_F64_BTWN: ValidationErr = ValidationErr::Operation(Operation::Btwn(F64_VALUE, F64_VALUE_B));
+
+    const OPERATION_USIZE_EQ: ValidationErr = ValidationErr::Operation(Operation::Eq(USIZE_VALUE));
+    const OPERATION_USIZE_NE: ValidationErr = ValidationErr::Operation(Operation::Ne(USIZE_VALUE));
+    const OPERATION_USIZE_GT: ValidationErr = ValidationErr::Operation(Operation::Gt(USIZE_VALUE));
+    const OPERATION_USIZE_GE: ValidationErr = ValidationErr::Operation(Operation::Ge(USIZE_VALUE));
+    const OPERATION_USIZE_LT: ValidationErr = ValidationErr::Operation(Operation::Lt(USIZE_VALUE));
+    const OPERATION_USIZE_LE: ValidationErr = ValidationErr::Operation(Operation::Le(USIZE_VALUE));
+    const OPERATION_USIZE_BTWN: ValidationErr = ValidationErr::Operation(Operation::Btwn(USIZE_VALUE, USIZE_VALUE_B));
+
+    const OPERATION_ISIZE_EQ: ValidationErr = ValidationErr::Operation(Operation::Eq(ISIZE_VALUE));
+    const OPERATION_ISIZE_NE: ValidationErr = ValidationErr::Operation(Operation::Ne(ISIZE_VALUE));
+    const OPERATION_ISIZE_GT: ValidationErr = ValidationErr::Operation(Operation::Gt(ISIZE_VALUE));
+    const OPERATION_ISIZE_GE: ValidationErr = ValidationErr::Operation(Operation::Ge(ISIZE_VALUE));
+    const OPERATION_ISIZE_LT: ValidationErr = ValidationErr::Operation(Operation::Lt(ISIZE_VALUE));
+    const OPERATION_ISIZE_LE: ValidationErr = ValidationErr::Operation(Operation::Le(ISIZE_VALUE));
+    const OPERATION_ISIZE_BTWN: ValidationErr = ValidationErr::Operation(Operation::Btwn(ISIZE_VALUE, ISIZE_VALUE_B));
+
+    const OPERATION_BOOL_EQ: ValidationErr = ValidationErr::Operation(Operation::Eq(BOOL_VALUE));
+    const OPERATION_BOOL_NE: ValidationErr = ValidationErr::Operation(Operation::Ne(BOOL_VALUE));
+    const OPERATION_BOOL_GT: ValidationErr = ValidationErr::Operation(Operation::Gt(BOOL_VALUE));
+    const OPERATION_BOOL_GE: ValidationErr = ValidationErr::Operation(Operation::Ge(BOOL_VALUE));
+    const OPERATION_BOOL_LT: ValidationErr = ValidationErr::Operation(Operation::Lt(BOOL_VALUE));
+    const OPERATION_BOOL_LE: ValidationErr = ValidationErr::Operation(Operation::Le(BOOL_VALUE));
+    const OPERATION_BOOL_BTWN: ValidationErr = ValidationErr::Operation(Operation::Btwn(BOOL_VALUE, BOOL_VALUE_B));
+
+    static OPERATION_STR_EQ: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Operation(Operation::Eq(STR_VALUE.clone())));
+    static OPERATION_STR_NE: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Operation(Operation::Ne(STR_VALUE.clone())));
+    static OPERATION_STR_GT: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Operation(Operation::Gt(STR_VALUE.clone())));
+    static OPERATION_STR_GE: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Operation(Operation::Ge(STR_VALUE.clone())));
+    static OPERATION_STR_LT: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Operation(Operation::Lt(STR_VALUE.clone())));
+    static OPERATION_STR_LE: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Operation(Operation::Le(STR_VALUE.clone())));
+    static OPERATION_STR_BTWN: LazyLock<ValidationErr> =
+        LazyLock::new(|| ValidationErr::Operation(Operation::Btwn(STR_VALUE.clone(), STR_VALUE_B.clone())));
+
+    static OPERATION_FIELD_EQ: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Operation(Operation::Eq(FIELD_PATH.clone())));
+    static OPERATION_FIELD_NE: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Operation(Operation::Ne(FIELD_PATH.clone())));
+    static OPERATION_FIELD_GT: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Operation(Operation::Gt(FIELD_PATH.clone())));
+    static OPERATION_FIELD_GE: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Operation(Operation::Ge(FIELD_PATH.clone())));
+    static OPERATION_FIELD_LT: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Operation(Operation::Lt(FIELD_PATH.clone())));
+    static OPERATION_FIELD_LE: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Operation(Operation::Le(FIELD_PATH.clone())));
+
+    static OPERATION_NOW_LE: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Operation(Operation::Le(Operand::Now(Relative::Days(0)))));
+    static OPERATION_NOW_GE: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Operation(Operation::Ge(Operand::Now(Relative::Years(-18)))));
+    static OPERATION_NOW_BTWN: LazyLock<ValidationErr> =
+        LazyLock::new(|| ValidationErr::Operation(Operation::Btwn(Operand::Now(Relative::Days(0)), Operand::Now(Relative::Days(30)))));
+
+    const BYTES_LEN_EQ: ValidationErr = ValidationErr::BytesLen(Operation::Eq(USIZE_VALUE));
+    const BYTES_LEN_NE: ValidationErr = ValidationErr::BytesLen(Operation::Ne(USIZE_VALUE));
+    const BYTES_LEN_GT: ValidationErr = ValidationErr::BytesLen(Operation::Gt(USIZE_VALUE));
+    const BYTES_LEN_GE: ValidationErr = ValidationErr::BytesLen(Operation::Ge(USIZE_VALUE));
+    const BYTES_LEN_LT: ValidationErr = ValidationErr::BytesLen(Operation::Lt(USIZE_VALUE));
+    const BYTES_LEN_LE: ValidationErr = ValidationErr::BytesLen(Operation::Le(USIZE_VALUE));
+    const BYTES_LEN_BTWN: ValidationErr = ValidationErr::BytesLen(Operation::Btwn(USIZE_VALUE, USIZE_VALUE_B));
+
+    const CHARS_LEN_EQ: ValidationErr = ValidationErr::CharsLen(Operation::Eq(USIZE_VALUE));
+    const CHARS_LEN_NE: ValidationErr = ValidationErr::CharsLen(Operation::Ne(USIZE_VALUE));
+    const CHARS_LEN_GT: ValidationErr = ValidationErr::CharsLen(Operation::Gt(USIZE_VALUE));
+    const CHARS_LEN_GE: ValidationErr = ValidationErr::CharsLen(Operation::Ge(USIZE_VALUE));
+    const CHARS_LEN_LT: ValidationErr = ValidationErr::CharsLen(Operation::Lt(USIZE_VALUE));
+    const CHARS_LEN_LE: ValidationErr = ValidationErr::CharsLen(Operation::Le(USIZE_VALUE));
+    const CHARS_LEN_BTWN: ValidationErr = ValidationErr::CharsLen(Operation::Btwn(USIZE_VALUE, USIZE_VALUE_B));
+
+    const GRAPHEMES_LEN_EQ: ValidationErr = ValidationErr::GraphemesLen(Operation::Eq(USIZE_VALUE));
+    const GRAPHEMES_LEN_NE: ValidationErr = ValidationErr::GraphemesLen(Operation::Ne(USIZE_VALUE));
+    const GRAPHEMES_LEN_GT: ValidationErr = ValidationErr::GraphemesLen(Operation::Gt(USIZE_VALUE));
+    const GRAPHEMES_LEN_GE: ValidationErr = ValidationErr::GraphemesLen(Operation::Ge(USIZE_VALUE));
+    const GRAPHEMES_LEN_LT: ValidationErr = ValidationErr::GraphemesLen(Operation::Lt(USIZE_VALUE));
+    const GRAPHEMES_LEN_LE: ValidationErr = ValidationErr::GraphemesLen(Operation::Le(USIZE_VALUE));
+    const GRAPHEMES_LEN_BTWN: ValidationErr = ValidationErr::GraphemesLen(Operation::Btwn(USIZE_VALUE, USIZE_VALUE_B));
+
+    const LOWER_LEN_EQ: ValidationErr = ValidationErr::LowercaseLen(Operation::Eq(USIZE_VALUE));
+    const LOWER_LEN_NE: ValidationErr = ValidationErr::LowercaseLen(Operation::Ne(USIZE_VALUE));
+    const LOWER_LEN_GT: ValidationErr = ValidationErr::LowercaseLen(Operation::Gt(USIZE_VALUE));
+    const LOWER_LEN_GE: ValidationErr = ValidationErr::LowercaseLen(Operation::Ge(USIZE_VALUE));
+    const LOWER_LEN_LT: ValidationErr = ValidationErr::LowercaseLen(Operation::Lt(USIZE_VALUE));
+    const LOWER_LEN_LE: ValidationErr = ValidationErr::LowercaseLen(Operation::Le(USIZE_VALUE));
+    const LOWER_LEN_BTWN: ValidationErr = ValidationErr::LowercaseLen(Operation::Btwn(USIZE_VALUE, USIZE_VALUE_B));
+
+    const UPPER_LEN_EQ: ValidationErr = ValidationErr::UppercaseLen(Operation::Eq(USIZE_VALUE));
+    const UPPER_LEN_NE: ValidationErr = ValidationErr::UppercaseLen(Operation::Ne(USIZE_VALUE));
+    const UPPER_LEN_GT: ValidationErr = ValidationErr::UppercaseLen(Operation::Gt(USIZE_VALUE));
+    const UPPER_LEN_GE: ValidationErr = ValidationErr::UppercaseLen(Operation::Ge(USIZE_VALUE));
+    const UPPER_LEN_LT: ValidationErr = ValidationErr::UppercaseLen(Operation::Lt(USIZE_VALUE));
+    const UPPER_LEN_LE: ValidationErr = ValidationErr::UppercaseLen(Operation::Le(USIZE_VALUE));
+    const UPPER_LEN_BTWN: ValidationErr = ValidationErr::UppercaseLen(Operation::Btwn(USIZE_VALUE, USIZE_VALUE_B));
+
+    const NUMBERS_LEN_EQ: ValidationErr = ValidationErr::NumbersLen(Operation::Eq(USIZE_VALUE));
+    const NUMBERS_LEN_NE: ValidationErr = ValidationErr::NumbersLen(Operation::Ne(USIZE_VALUE));
+    const NUMBERS_LEN_GT: ValidationErr = ValidationErr::NumbersLen(Operation::Gt(USIZE_VALUE));
+    const NUMBERS_LEN_GE: ValidationErr = ValidationErr::NumbersLen(Operation::Ge(USIZE_VALUE));
+    const NUMBERS_LEN_LT: ValidationErr = ValidationErr::NumbersLen(Operation::Lt(USIZE_VALUE));
+    const NUMBERS_LEN_LE: ValidationErr = ValidationErr::NumbersLen(Operation::Le(USIZE_VALUE));
+    const NUMBERS_LEN_BTWN: ValidationErr = ValidationErr::NumbersLen(Operation::Btwn(USIZE_VALUE, USIZE_VALUE_B));
+
+    const SYMBOLS_LEN_EQ: ValidationErr = ValidationErr::SymbolsLen(Operation::Eq(USIZE_VALUE));
+    const SYMBOLS_LEN_NE: ValidationErr = ValidationErr::SymbolsLen(Operation::Ne(USIZE_VALUE));
+    const SYMBOLS_LEN_GT: ValidationErr = ValidationErr::SymbolsLen(Operation::Gt(USIZE_VALUE));
+    const SYMBOLS_LEN_GE: ValidationErr = ValidationErr::SymbolsLen(Operation::Ge(USIZE_VALUE));
+    const SYMBOLS_LEN_LT: ValidationErr = ValidationErr::SymbolsLen(Operation::Lt(USIZE_VALUE));
+    const SYMBOLS_LEN_LE: ValidationErr = ValidationErr::SymbolsLen(Operation::Le(USIZE_VALUE));
+    const SYMBOLS_LEN_BTWN: ValidationErr = ValidationErr::SymbolsLen(Operation::Btwn(USIZE_VALUE, USIZE_VALUE_B));
+
+    const LEN_EQ: ValidationErr = ValidationErr::Len(Operation::Eq(USIZE_VALUE));
+    const LEN_NE: ValidationErr = ValidationErr::Len(Operation::Ne(USIZE_VALUE));
+    const LEN_GT: ValidationErr = ValidationErr::Len(Operation::Gt(USIZE_VALUE));
+    const LEN_GE: ValidationErr = ValidationErr::Len(Operation::Ge(USIZE_VALUE));
+    const LEN_LT: ValidationErr = ValidationErr::Len(Operation::Lt(USIZE_VALUE));
+    const LEN_LE: ValidationErr = ValidationErr::Len(Operation::Le(USIZE_VALUE));
+    const LEN_BTWN: ValidationErr = ValidationErr::Len(Operation::Btwn(USIZE_VALUE, USIZE_VALUE_B));
+
+    static ENUM_USIZE: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Enumerated(EnumValues::from(USIZE_VALUES)));
+    static ENUM_ISIZE: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Enumerated(EnumValues::from(ISIZE_VALUES)));
+    static ENUM_STR: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Enumerated(EnumValues::from(STR_VALUES)));
+
+    static NAME: LazyLock<Operand> = LazyLock::new(|| Operand::Value(OperandValue::from("Paul McCartney")));
+    static BIRTHDATE: LazyLock<Operand> = LazyLock::new(|| Operand::Value(OperandValue::from("1942-06-18")));
+    static ALIVE: LazyLock<Operand> = LazyLock::new(|| Operand::Value(OperandValue::Bool(true)));
+    static BANDS: LazyLock<Operand> = LazyLock::new(|| Operand::Value(OperandValue::from("The Beatles")));
+
+    static OPERATION_NAME: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Operation(Operation::Eq(NAME.clone())));
+    static OPERATION_BIRTHDATE: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Operation(Operation::Eq(BIRTHDATE.clone())));
+    static OPERATION_ALIVE: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Operation(Operation::Eq(ALIVE.clone())));
+    static OPERATION_BANDS: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Operation(Operation::Eq(BANDS.clone())));
+
+    fn mock_locale() -> Locale {
+        Locale {
+            required: "required".into(),
+            u64: "u64".into(),
+            i64: "i64".into(),
+            f64: "f64".into(),
+            usize: "usize".into(),
+            isize: "isize".into(),
+            bool: "bool".into(),
+            str: "str".into(),
+            email: "email".into(),
+            date: "date".into(),
+            time: "time".into(),
+            date_time: "date_time".into(),
+            arr: "arr".into(),
+            obj: "obj".into(),
+            map: "map".into(),
+            unknown_key: "unknown_key".into(),
+            eq: "== %value%".into(),
+            ne: "!= %value%".into(),
+            gt: "> %value%".into(),
+            ge: ">= %value%".into(),
+            lt: "< %value%".into(),
+            le: "<= %value%".into(),
+            btwn: "%value_a% <= <= %value_b%".into(),
+            eq_field: "== field %value%".into(),
+            ne_field: "!= field %value%".into(),
+            gt_field: "> field %value%".into(),
+            ge_field: ">= field %value%".into(),
+            lt_field: "< field %value%".into(),
+            le_field: "<= field %value%".into(),
+            today: "today".into(),
+            days_ago: "-%value%d".into(),
+            days_from_now: "+%value%d".into(),
+            months_ago: "-%value%m".into(),
+            months_from_now: "+%value%m".into(),
+            years_ago: "-%value%y".into(),
+            years_from_now: "+%value%y".into(),
+            le_today: "<= today".into(),
+            ge_today: ">= today".into(),
+            age_ge: "age >= %value%".into(),
+            within_days: "within %value% days".into(),
+            bytes_len_eq: "bytes_len == %value%".into(),
+            bytes_len_ne: "bytes_len != %value%".into(),
+            bytes_len_gt: "bytes_len > %value%".into(),
+            bytes_len_ge: "bytes_len >= %value%".into(),
+            bytes_len_lt: "bytes_len < %value%".into(),
+            bytes_len_le: "bytes_len <= %value%".into(),
+            bytes_len_btwn: "%value_a% <= bytes_len <= %value_b%".into(),
+            chars_len_eq: "chars_len == %value%".into(),
+            chars_len_ne: "chars_len != %value%".into(),
+            chars_len_gt: "chars_len > %value%".into(),
+            chars_len_ge: "chars_len >= %value%".into(),
+            chars_len_lt: "chars_len < %value%".into(),
+            chars_len_le: "chars_len <= %value%".into(),
+            chars_len_btwn: "%value_a% <= chars_len <= %value_b%".into(),
+            graphemes_len_eq: "graphemes_len == %value%".into(),
+            graphemes_len_ne: "graphemes_len != %value%".into(),
+            graphemes_len_gt: "graphemes_len > %value%".into(),
+            graphemes_len_ge: "graphemes_len >= %value%".into(),
+            graphemes_len_lt: "graphemes_len < %value%".into(),
+            graphemes_len_le: "graphemes_len <= %value%".into(),
+            graphemes_len_btwn: "%value_a% <= graphemes_len <= %value_b%".into(),
+            lowercase_len_eq: "lowercase_len == %value%".into(),
+            lowercase_len_ne: "lowercase_len != %value%".into(),
+            lowercase_len_gt: "lowercase_len > %value%".into(),
+            lowercase_len_ge: "lowercase_len >= %value%".into(),
+            lowercase_len_lt: "lowercase_len < %value%".into(),
+            lowercase_len_le: "lowercase_len <= %value%".into(),
+            lowercase_len_btwn: "%value_a% <= lowercase_len <= %value_b%".into(),
+            uppercase_len_eq: "uppercase_len == %value%".into(),
+            uppercase_len_ne: "uppercase_len != %value%".into(),
+            uppercase_len_gt: "uppercase_len > %value%".into(),
+            uppercase_len_ge: "uppercase_len >= %value%".into(),
+            uppercase_len_lt: "uppercase_len < %value%".into(),
+            uppercase_len_le: "uppercase_len <= %value%".into(),
+            uppercase_len_btwn: "%value_a% <= uppercase_len <= %value_b%".into(),
+            number_len_eq: "number_len == %value%".into(),
+            number_len_ne: "number_len != %value%".into(),
+            number_len_gt: "number_len > %value%".into(),
+            number_len_ge: "number_len >= %value%".into(),
+            number_len_lt: "number_len < %value%".into(),
+            number_len_le: "number_len <= %value%".into(),
+            number_len_btwn: "%value_a% <= number_len <= %value_b%".into(),
+            symbols_eq: "symbols == %value%".into(),
+            symbols_ne: "symbols != %value%".into(),
+            symbols_gt: "symbols > %value%".into(),
+            symbols_ge: "symbols >= %value%".into(),
+            symbols_lt: "symbols < %value%".into(),
+            symbols_le: "symbols <= %value%".into(),
+            symbols_btwn: "%value_a% <= symbols <= %value_b%".into(),
+            len_eq: "len == %value%".into(),
+            len_ne: "len != %value%".into(),
+            len_gt: "len > %value%".into(),
+            len_ge: "len >= %value%".into(),
+            len_lt: "len < %value%".into(),
+            len_le: "len <= %value%".into(),
+            len_btwn: "%value_a% <= len <= %value_b%".into(),
+            enumerated: "enum %value%".into(),
+            pattern: "pattern %value%".into(),
+            starts_with: "starts with %value%".into(),
+            ends_with: "ends with %value%".into(),
+            contains: "contains %value%".into(),
+            not_contains: "not contains %value%".into(),
+            only_chars: "only chars %value%".into(),
+            trimmed: "trimmed".into(),
+            no_control_chars: "no control chars".into(),
+            normalized: "normalized %value%".into(),
+            custom: BTreeMap::new(),
+            plural: plural_en,
+        }
+    }
+
+    const VALIDATION_ERR_VARIANTS: usize = 36;
+
+    fn validation_err_variant(err: &ValidationErr) -> usize {
+        match err {
+            ValidationErr::Required => 0,
+            ValidationErr::U64 => 1,
+            ValidationErr::I64 => 2,
+            ValidationErr::F64 => 3,
+            ValidationErr::USize => 4,
+            ValidationErr::ISize => 5,
+            ValidationErr::Bool => 6,
+            ValidationErr::Str => 7,
+            ValidationErr::Email => 8,
+            ValidationErr::Date => 9,
+            ValidationErr::Time => 10,
+            ValidationErr::DateTime => 11,
+            ValidationErr::Arr => 12,
+            ValidationErr::Obj => 13,
+            ValidationErr::Map => 14,
+            ValidationErr::UnknownKey => 15,
+            ValidationErr::Operation(_) => 16,
+            ValidationErr::BytesLen(_) => 17,
+            ValidationErr::CharsLen(_) => 18,
+            ValidationErr::GraphemesLen(_) => 19,
+            ValidationErr::LowercaseLen(_) => 20,
+            ValidationErr::UppercaseLen(_) => 21,
+            ValidationErr::NumbersLen(_) => 22,
+            ValidationErr::SymbolsLen(_) => 23,
+            ValidationErr::Len(_) => 24,
+            ValidationErr::Enumerated(_) => 25,
+            ValidationErr::Pattern(_) => 26,
+            ValidationErr::Custom { .. } => 27,
+            ValidationErr::StartsWith(_) => 28,
+            ValidationErr::EndsWith(_) => 29,
+            ValidationErr::Contains(_) => 30,
+            ValidationErr::NotContains(_) => 31,
+            ValidationErr::OnlyChars(_) => 32,
+            ValidationErr::Trimmed => 33,
+            ValidationErr::NoControlChars => 34,
+            ValidationErr::Normalized(_) => 35,
+        }
+    }
+
+    fn every_validation_err() -> Vec<ValidationErr> {
+        vec![
+            REQUIRED,
+            U64,
+            I64,
+            F64,
+            USIZE,
+            ISIZE,
+            BOOL,
+            STR,
+            EMAIL,
+            DATE,
+            TIME,
+            DATE_TIME,
+            ARR,
+            OBJ,
+            MAP,
+            UNKNOWN_KEY,
+            OPERATION_U64_EQ,
+            OPERATION_U64_NE,
+            OPERATION_U64_GT,
+            OPERATION_U64_GE,
+            OPERATION_U64_LT,
+            OPERATION_U64_LE,
+            OPERATION_U64_BTWN,
+            OPERATION_FIELD_EQ.clone(),
+            OPERATION_FIELD_NE.clone(),
+            OPERATION_FIELD_GT.clone(),
+            OPERATION_FIELD_GE.clone(),
+            OPERATION_FIELD_LT.clone(),
+            OPERATION_FIELD_LE.clone(),
+            OPERATION_NOW_LE.clone(),
+            OPERATION_NOW_GE.clone(),
+            OPERATION_NOW_BTWN.clone(),
+            BYTES_LEN_EQ,
+            BYTES_LEN_NE,
+            BYTES_LEN_GT,
+            BYTES_LEN_GE,
+            BYTES_LEN_LT,
+            BYTES_LEN_LE,
+            BYTES_LEN_BTWN,
+            CHARS_LEN_EQ,
+            CHARS_LEN_NE,
+            CHARS_LEN_GT,
+            CHARS_LEN_GE,
+            CHARS_LEN_LT,
+            CHARS_LEN_LE,
+            CHARS_LEN_BTWN,
+            GRAPHEMES_LEN_EQ,
+            GRAPHEMES_LEN_NE,
+            GRAPHEMES_LEN_GT,
+            GRAPHEMES_LEN_GE,
+            GRAPHEMES_LEN_LT,
+            GRAPHEMES_LEN_LE,
+            GRAPHEMES_LEN_BTWN,
+            LOWER_LEN_EQ,
+            LOWER_LEN_NE,
+            LOWER_LEN_GT,
+            LOWER_LEN_GE,
+            LOWER_LEN_LT,
+            LOWER_LEN_LE,
+            LOWER_LEN_BTWN,
+            UPPER_LEN_EQ,
+            UPPER_LEN_NE,
+            UPPER_LEN_GT,
+            UPPER_LEN_GE,
+            UPPER_LEN_LT,
+            UPPER_LEN_LE,
+            UPPER_LEN_BTWN,
+            NUMBERS_LEN_EQ,
+            NUMBERS_LEN_NE,
+            NUMBERS_LEN_GT,
+            NUMBERS_LEN_GE,
+            NUMBERS_LEN_LT,
+            NUMBERS_LEN_LE,
+            NUMBERS_LEN_BTWN,
+            SYMBOLS_LEN_EQ,
+            SYMBOLS_LEN_NE,
+            SYMBOLS_LEN_GT,
+            SYMBOLS_LEN_GE,
+            SYMBOLS_LEN_LT,
+            SYMBOLS_LEN_LE,
+            SYMBOLS_LEN_BTWN,
+            LEN_EQ,
+            LEN_NE,
+            LEN_GT,
+            LEN_GE,
+            LEN_LT,
+            LEN_LE,
+            LEN_BTWN,
+            ENUM_STR.clone(),
+            ValidationErr::Pattern("^[a-z0-9-]+$".into()),
+            ValidationErr::Custom { code: "sku_exists".into(), params: BTreeMap::new() },
+            ValidationErr::StartsWith("sk_".into()),
+            ValidationErr::EndsWith("_live".into()),
+            ValidationErr::Contains("@".into()),
+            ValidationErr::NotContains("..".into()),
+            ValidationErr::OnlyChars(Charset::Base64Url),
+            ValidationErr::OnlyChars(Charset::Custom("ACGT".into())),
+            ValidationErr::Trimmed,
+            ValidationErr::NoControlChars,
+            ValidationErr::Normalized(Normalization::Nfc),
+        ]
+    }
+
+    #[test]
+    fn built_in_locales() {
+        let errs = every_validation_err();
+        let variants: BTreeSet<usize> = errs.iter().map(validation_err_variant).collect();
+        assert_eq!(variants, (0..VALIDATION_ERR_VARIANTS).collect());
+        for locale in [Locale::en(), Locale::pt_br(), Locale::es()] {
+            for err in &errs {
+                let localized = localize_validation_err(err, &locale);
+                assert!(!localized.is_empty() && !localized.contains('%') && !localized.contains('{'), "{:?} is localized as {:?}", err, localized);
+            }
+        }
+    }
+
+    #[test]
+    fn localize_validation_err_message_format() {
+        let locale = Locale {
+            eq: "{value, select, true {must be checked} other {must be %value%}}".into(),
+            chars_len_le: "at most {value, plural, one {# character} other {# characters}}".into(),
+            enumerated: "{value, plural, one {must be %value%} other {must be one of %value%}}".into(),
+            ..mock_locale()
+        };
+        let chars_len_le = ValidationErr::CharsLen(Operation::Le(Operand::Value(OperandValue::USize(1))));
+        assert_eq!(localize_validation_err(&OPERATION_BOOL_EQ, &locale), "must be false".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::Operation(Operation::Eq(BOOL_VALUE_B)), &locale), "must be checked".to_string());
+        assert_eq!(localize_validation_err(&chars_len_le, &locale), "at most 1 character".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_LE, &locale), "at most 27 characters".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::Enumerated(EnumValues::from(["ADMIN"])), &locale), r#"must be [ "ADMIN" ]"#.to_string());
+        assert_eq!(localize_validation_err(&ENUM_STR, &locale), r#"must be one of [ "APPLE", "GRAPE", "PEAR" ]"#.to_string());
+    }
+
+    #[test]
+    fn localize_validation_err_malformed() {
+        let locale = Locale {
+            required: "{field".into(),
+            chars_len_le: "at most {value, plural, one {#".into(),
+            eq: "{value, select, true {must be checked} other {must be {value}".into(),
+            ..mock_locale()
+        };
+        assert_eq!(localize_field_validation_err(&REQUIRED, "Name", &locale), "{field");
+        assert_eq!(localize_validation_err(&CHARS_LEN_LE, &locale), "at most {value, plural, one {#".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_BOOL_EQ, &locale), "{value, select, true {must be checked} other {must be {value}".to_string());
+    }
+
+    #[test]
+    fn localize_validation_err_custom() {
+        let locale = Locale {
+            custom: BTreeMap::from([
+                ("username_not_reserved".into(), "{field} can't be {username}".into()),
+                ("lt_stock".into(), "only {stock, plural, one {# item} other {# items}} in stock".into()),
+            ]),
+            ..mock_locale()
+        };
+        let reserved =
+            ValidationErr::Custom { code: "username_not_reserved".into(), params: BTreeMap::from([("username".into(), OperandValue::from("root"))]) };
+        let stock = ValidationErr::Custom { code: "lt_stock".into(), params: BTreeMap::from([("stock".into(), OperandValue::U64(1))]) };
+        let unknown = ValidationErr::Custom { code: "sku_exists".into(), params: BTreeMap::new() };
+        assert_eq!(localize_field_validation_err(&reserved, "Username", &locale), r#"Username can't be "root""#.to_string());
+        assert_eq!(localize_validation_err(&stock, &locale), "only 1 item in stock".to_string());
+        assert_eq!(localize_validation_err(&unknown, &locale), "sku_exists".to_string());
+    }
+
+    #[test]
+    fn test_localize_validation_err() {
+        let l = mock_locale();
+
+        assert_eq!(localize_validation_err(&REQUIRED, &l), "required".to_string());
+        assert_eq!(localize_validation_err(&U64, &l), "u64".to_string());
+        assert_eq!(localize_validation_err(&I64, &l), "i64".to_string());
+        assert_eq!(localize_validation_err(&F64, &l), "f64".to_string());
+        assert_eq!(localize_validation_err(&USIZE, &l), "usize".to_string());
+        assert_eq!(localize_validation_err(&ISIZE, &l), "isize".to_string());
+        assert_eq!(localize_validation_err(&BOOL, &l), "bool".to_string());
+        assert_eq!(localize_validation_err(&STR, &l), "str".to_string());
+        assert_eq!(localize_validation_err(&EMAIL, &l), "email".to_string());
+        assert_eq!(localize_validation_err(&DATE, &l), "date".to_string());
+        assert_eq!(localize_validation_err(&TIME, &l), "time".to_string());
+        assert_eq!(localize_validation_err(&DATE_TIME, &l), "date_time".to_string());
+        assert_eq!(localize_validation_err(&ARR, &l), "arr".to_string());
+        assert_eq!(localize_validation_err(&OBJ, &l), "obj".to_string());
+        assert_eq!(localize_validation_err(&MAP, &l), "map".to_string());
+        assert_eq!(localize_validation_err(&UNKNOWN_KEY, &l), "unknown_key".to_string());
+
+        assert_eq!(localize_validation_err(&OPERATION_U64_EQ, &l), "== 34".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_U64_NE, &l), "!= 34".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_U64_GT, &l), "> 34".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_U64_GE, &l), ">= 34".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_U64_LT, &l), "< 34".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_U64_LE, &l), "<= 34".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_U64_BTWN, &l), "34 <= <= 43".to_string());
+
+        assert_eq!(localize_validation_err(&OPERATION_I64_EQ, &l), "== -4".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_I64_NE, &l), "!= -4".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_I64_GT, &l), "> -4".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_I64_GE, &l), ">= -4".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_I64_LT, &l), "< -4".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_I64_LE, &l), "<= -4".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_I64_BTWN, &l), "-4 <= <= 4".to_string());
+
+        assert_eq!(localize_validation_err(&OPERATION_F64_EQ, &l), "== -4.6".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_F64_NE, &l), "!= -4.6".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_F64_GT, &l), "> -4.6".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_F64_GE, &l), ">= -4.6".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_F64_LT, &l), "< -4.6".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_F64_LE, &l), "<= -4.6".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_F64_BTWN, &l), "-4.6 <= <= -2.4".to_string());
+
+        assert_eq!(localize_validation_err(&OPERATION_USIZE_EQ, &l), "== 27".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_USIZE_NE, &l), "!= 27".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_USIZE_GT, &l), "> 27".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_USIZE_GE, &l), ">= 27".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_USIZE_LT, &l), "< 27".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_USIZE_LE, &l), "<= 27".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_USIZE_BTWN, &l), "27 <= <= 39".to_string());
+
+        assert_eq!(localize_validation_err(&OPERATION_ISIZE_EQ, &l), "== -93".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_ISIZE_NE, &l), "!= -93".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_ISIZE_GT, &l), "> -93".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_ISIZE_GE, &l), ">= -93".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_ISIZE_LT, &l), "< -93".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_ISIZE_LE, &l), "<= -93".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_ISIZE_BTWN, &l), "-93 <= <= -72".to_string());
+
+        assert_eq!(localize_validation_err(&OPERATION_BOOL_EQ, &l), "== false".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_BOOL_NE, &l), "!= false".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_BOOL_GT, &l), "> false".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_BOOL_GE, &l), ">= false".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_BOOL_LT, &l), "< false".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_BOOL_LE, &l), "<= false".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_BOOL_BTWN, &l), "false <= <= true".to_string());
+
+        assert_eq!(localize_validation_err(&OPERATION_STR_EQ, &l), r#"== "aurorae""#.to_string());
+        assert_eq!(localize_validation_err(&OPERATION_STR_NE, &l), r#"!= "aurorae""#.to_string());
+        assert_eq!(localize_validation_err(&OPERATION_STR_GT, &l), r#"> "aurorae""#.to_string());
+        assert_eq!(localize_validation_err(&OPERATION_STR_GE, &l), r#">= "aurorae""#.to_string());
+        assert_eq!(localize_validation_err(&OPERATION_STR_LT, &l), r#"< "aurorae""#.to_string());
+        assert_eq!(localize_validation_err(&OPERATION_STR_LE, &l), r#"<= "aurorae""#.to_string());
+        assert_eq!(localize_validation_err(&OPERATION_STR_BTWN, &l), r#""aurorae" <= <= "crespúculum""#.to_string());
+
+        assert_eq!(localize_validation_err(&OPERATION_FIELD_EQ, &l), r#"== field "user.account.info.details.user_name""#.to_string());
+        assert_eq!(localize_validation_err(&OPERATION_FIELD_NE, &l), r#"!= field "user.account.info.details.user_name""#.to_string());
+        assert_eq!(localize_validation_err(&OPERATION_FIELD_GT, &l), r#"> field "user.account.info.details.user_name""#.to_string());
+        assert_eq!(localize_validation_err(&OPERATION_FIELD_GE, &l), r#">= field "user.account.info.details.user_name""#.to_string());
+        assert_eq!(localize_validation_err(&OPERATION_FIELD_LT, &l), r#"< field "user.account.info.details.user_name""#.to_string());
+        assert_eq!(localize_validation_err(&OPERATION_FIELD_LE, &l), r#"<= field "user.account.info.details.user_name""#.to_string());
+
+        assert_eq!(localize_validation_err(&OPERATION_NOW_LE, &l), "<= today".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_NOW_GE, &l), ">= -18y".to_string());
+        assert_eq!(localize_validation_err(&OPERATION_NOW_BTWN, &l), "within 30 days".to_string());
+        let age_ge = ValidationErr::Operation(Operation::Le(Operand::Now(Relative::Years(-18))));
+        let btwn_months = ValidationErr::Operation(Operation::Btwn(Operand::Now(Relative::Months(-1)), Operand::Now(Relative::Months(6))));
+        assert_eq!(localize_validation_err(&age_ge, &l), "age >= 18".to_string());
+        assert_eq!(localize_validation_err(&btwn_months, &l), "-1m <= <= +6m".to_string());
+
+        assert_eq!(localize_validation_err(&BYTES_LEN_EQ, &l), "bytes_len == 27".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_NE, &l), "bytes_len != 27".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_GT, &l), "bytes_len > 27".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_GE, &l), "bytes_len >= 27".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_LT, &l), "bytes_len < 27".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_LE, &l), "bytes_len <= 27".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_BTWN, &l), "27 <= bytes_len <= 39".to_string());
+
+        assert_eq!(localize_validation_err(&CHARS_LEN_EQ, &l), "chars_len == 27".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_NE, &l), "chars_len != 27".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_GT, &l), "chars_len > 27".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_GE, &l), "chars_len >= 27".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_LT, &l), "chars_len < 27".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_LE, &l), "chars_len <= 27".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_BTWN, &l), "27 <= chars_len <= 39".to_string());
+
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_EQ, &l), "graphemes_len == 27".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_NE, &l), "graphemes_len != 27".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_GT, &l), "graphemes_len > 27".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_GE, &l), "graphemes_len >= 27".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_LT, &l), "graphemes_len < 27".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_LE, &l), "graphemes_len <= 27".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_BTWN, &l), "27 <= graphemes_len <= 39".to_string());
+
+        assert_eq!(localize_validation_err(&LOWER_LEN_EQ, &l), "lowercase_len == 27".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_NE, &l), "lowercase_len != 27".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_GT, &l), "lowercase_len > 27".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_GE, &l), "lowercase_len >= 27".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_LT, &l), "lowercase_len < 27".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_LE, &l), "lowercase_len <= 27".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_BTWN, &l), "27 <= lowercase_len <= 39".to_string());
+
+        assert_eq!(localize_validation_err(&UPPER_LEN_EQ, &l), "uppercase_len == 27".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_NE, &l), "uppercase_len != 27".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_GT, &l), "uppercase_len > 27".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_GE, &l), "uppercase_len >= 27".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_LT, &l), "uppercase_len < 27".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_LE, &l), "uppercase_len <= 27".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_BTWN, &l), "27 <= uppercase_len <= 39".to_string());
+
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_EQ, &l), "number_len == 27".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_NE, &l), "number_len != 27".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_GT, &l), "number_len > 27".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_GE, &l), "number_len >= 27".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_LT, &l), "number_len < 27".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_LE, &l), "number_len <= 27".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_BTWN, &l), "27 <= number_len <= 39".to_string());
+
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_EQ, &l), "symbols == 27".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_NE, &l), "symbols != 27".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_GT, &l), "symbols > 27".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_GE, &l), "symbols >= 27".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_LT, &l), "symbols < 27".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_LE, &l), "symbols <= 27".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_BTWN, &l), "27 <= symbols <= 39".to_string());
+
+        assert_eq!(localize_validation_err(&LEN_EQ, &l), "len == 27".to_string());
+        assert_eq!(localize_validation_err(&LEN_NE, &l), "len != 27".to_string());
+        assert_eq!(localize_validation_err(&LEN_GT, &l), "len > 27".to_string());
+        assert_eq!(localize_validation_err(&LEN_GE, &l), "len >= 27".to_string());
+        assert_eq!(localize_validation_err(&LEN_LT, &l), "len < 27".to_string());
+        assert_eq!(localize_validation_err(&LEN_LE, &l), "len <= 27".to_string());
+        assert_eq!(localize_validation_err(&LEN_BTWN, &l), "27 <= len <= 39".to_string());
+
+        assert_eq!(localize_validation_err(&ENUM_USIZE, &l), "enum [ 0, 1, 2, 3, 4, 5 ]".to_string());
+        assert_eq!(localize_validation_err(&ENUM_ISIZE, &l), "enum [ -2, -1, 0, 1, 2 ]".to_string());
+        assert_eq!(localize_validation_err(&ENUM_STR, &l), r#"enum [ "APPLE", "GRAPE", "PEAR" ]"#.to_string());
+    }
+
+    #[test]
+    fn schema_err_locale_validation() {
+        assert_eq!(
+            SchemaErrLocale::from(["required".to_string(), "u64".to_string()]),
+            SchemaErrLocale::Validation(vec!["required".into(), "u64".into()])
+        );
+    }
+
+    #[test]
+    fn schema_err_locale_arr() {
+        assert_eq!(
+            SchemaErrLocale::from([SchemaErrLocale::from(["required".to_string(), "i64".to_string()])]),
+            SchemaErrLocale::Arr(vec![SchemaErrLocale::Validation(vec!["required".into(), "i64".into()])])
+        );
+    }
+
+    #[test]
+    fn schema_err_locale_obj() {
+        assert_eq!(
+            SchemaErrLocale::from([("f64".into(), SchemaErrLocale::from(["required".to_string(), "f64".to_string()]))]),
+            SchemaErrLocale::Obj(BTreeMap::from([("f64".into(), SchemaErrLocale::Validation(vec!["required".into(), "f64".into()]))]))
+        );
+    }
+
+    #[test]
+    fn schema_err_locale_nested() {
+        assert_eq!(
+            SchemaErrLocale::from([(
+                "user".into(),
+                SchemaErrLocale::from([
+                    SchemaErrLocale::from(["required".to_string()]),
+                    SchemaErrLocale::from([("i64".into(), SchemaErrLocale::from(["required".to_string(), "i64".to_string()]))]),
+                ]),
+            )]),
+            SchemaErrLocale::Obj(BTreeMap::from([(
+                "user".into(),
+                SchemaErrLocale::Arr(vec![
+                    SchemaErrLocale::Validation(vec!["required".into()]),
+                    SchemaErrLocale::Obj(BTreeMap::from([("i64".into(), SchemaErrLocale::Validation(vec!["required".into(), "i64".into()]))])),
+                ]),
+            )]))
+        );
+    }
+
+    #[test]
+    fn localize_schema_err_validation() {
+        let locale = mock_locale();
+        let err = SchemaErr::from([REQUIRED, BOOL, ValidationErr::Operation(Operation::Eq(Operand::Value(OperandValue::Bool(true))))]);
+        let localized_err = SchemaErrLocale::Validation(vec!["required".into(), "bool".into(), "== true".into()]);
+        assert_eq!(localize_schema_err(&err, &locale), localized_err);
+    }
+
+    #[test]
+    fn localize_schema_err_arr() {
+        let locale = mock_locale();
+        let err = SchemaErr::from([
+            SchemaErr::from([REQUIRED, STR, OPERATION_NAME.clone()]),
+            SchemaErr::from([REQUIRED, STR, OPERATION_BIRTHDATE.clone()]),
+            SchemaErr::from([REQUIRED, BOOL, OPERATION_ALIVE.clone()]),
+            SchemaErr::from([REQUIRED, STR, OPERATION_BANDS.clone()]),
+        ]);
+        let localized_err = SchemaErrLocale::Arr(vec![
+            SchemaErrLocale::Validation(vec!["required".into(), "str".into(), r#"== "Paul McCartney""#.into()]),
+            SchemaErrLocale::Validation(vec!["required".into(), "str".into(), r#"== "1942-06-18""#.into()]),
+            SchemaErrLocale::Validation(vec!["required".into(), "bool".into(), "== true".into()]),
+            SchemaErrLocale::Validation(vec!["required".into(), "str".into(), r#"== "The Beatles""#.into()]),
+        ]);
+        assert_eq!(localize_schema_err(&err, &locale), localized_err);
+    }
+
+    #[test]
+    fn localize_schema_err_obj() {
+        let locale = mock_locale();
+        let err = SchemaErr::from([
+            ("name".into(), SchemaErr::from([REQUIRED, STR, OPERATION_NAME.clone()])),
+            ("birthdate".into(), SchemaErr::from([REQUIRED, STR, OPERATION_BIRTHDATE.clone()])),
+            ("alive".into(), SchemaErr::from([REQUIRED, BOOL, OPERATION_ALIVE.clone()])),
+            ("bands".into(), SchemaErr::from([REQUIRED, STR, OPERATION_BANDS.clone()])),
+        ]);
+        let localized_err = SchemaErrLocale::Obj(BTreeMap::from([
+            ("name".into(), SchemaErrLocale::Validation(vec!["required".into(), "str".into(), r#"== "Paul McCartney""#.into()])),
+            ("birthdate".into(), SchemaErrLocale::Validation(vec!["required".into(), "str".into(), r#"== "1942-06-18""#.into()])),
+            ("alive".into(), SchemaErrLocale::Validation(vec!["required".into(), "bool".into(), "== true".into()])),
+            ("bands".into(), SchemaErrLocale::Validation(vec!["required".into(), "str".into(), r#"== "The Beatles""#.into()])),
+        ]));
+        assert_eq!(localize_schema_err(&err, &locale), localized_err);
+    }
+
+    #[test]
+    fn localize_field_validation_err_field() {
+        let locale = Locale { required: "%field% is required".into(), gt: "{field} must be greater than {value}".into(), ..mock_locale() };
+        assert_eq!(localize_field_validation_err(&REQUIRED, "First name", &locale), "First name is required");
+        assert_eq!(localize_field_validation_err(&ValidationErr::Operation(Operation::Gt(U64_VALUE)), "age", &locale), "age must be greater than 34");
+        assert_eq!(localize_validation_err(&REQUIRED, &locale), " is required");
+        assert_eq!(localize_field_validation_err(&STR, "First name", &locale), "str");
+    }
+
+    #[test]
+    fn localize_schema_err_field() {
+        let locale = Locale { required: "%field% is required".into(), str: "{field} must be a string".into(), ..mock_locale() };
+        let err = SchemaErr::from([
+            ("first_name".into(), SchemaErr::from([REQUIRED, STR])),
+            ("tags".into(), SchemaErr::Arr(vec![SchemaErr::from([STR])])),
+            ("address".into(), SchemaErr::from([("city".into(), SchemaErr::from([REQUIRED]))])),
+        ]);
+        let schema = Schema::from(ObjSchema::from([
+            ("first_name".into(), Schema::from(StrSchema::default().label("First name"))),
+            ("tags".into(), Schema::from(ArrSchema::from(Schema::from(StrSchema::default())))),
+            ("address".into(), Schema::from(ObjSchema::from([("city".into(), Schema::from(StrSchema::default()))]))),
+        ]));
+        assert_eq!(
+            localize_schema_err(&err, &locale),
+            SchemaErrLocale::from([
+                ("first_name".into(), SchemaErrLocale::Validation(vec!["first_name is required".into(), "first_name must be a string".into()])),
+                ("tags".into(), SchemaErrLocale::Arr(vec![SchemaErrLocale::Validation(vec!["tags.0 must be a string".into()])])),
+                ("address".into(), SchemaErrLocale::from([("city".into(), SchemaErrLocale::Validation(vec!["address.city is required".into()]))])),
+            ])
+        );
+        assert_eq!(
+            localize_schema_err_with_schema(&err, &schema, &locale),
+            SchemaErrLocale::from([
+                ("first_name".into(), SchemaErrLocale::Validation(vec!["First name is required".into(), "First name must be a string".into()])),
+                ("tags".into(), SchemaErrLocale::Arr(vec![SchemaErrLocale::Validation(vec!["tags.0 must be a string".into()])])),
+                ("address".into(), SchemaErrLocale::from([("city".into(), SchemaErrLocale::Validation(vec!["address.city is required".into()]))])),
+            ])
+        );
+    }
+
+    #[test]
+    fn localize_schema_err_field_union_tagged() {
+        let locale = Locale { required: "%field% is required".into(), ..mock_locale() };
+        let schema = Schema::from(UnionSchema::from([
+            Schema::from(U64Schema::default().label("Amount")),
+            Schema::from(StrSchema::default().label("Description")),
+        ]));
+        assert_eq!(
+            localize_schema_err_with_schema(&SchemaErr::Union(vec![SchemaErr::from([REQUIRED]), SchemaErr::from([REQUIRED])]), &schema, &locale),
+            SchemaErrLocale::Union(vec![
+                SchemaErrLocale::Validation(vec!["Amount is required".into()]),
+                SchemaErrLocale::Validation(vec!["Description is required".into()])
+            ])
+        );
+        let schema = Schema::from(
+            TaggedSchema::from("kind".to_string())
+                .variant("user".into(), ObjSchema::from([("name".into(), Schema::from(StrSchema::default().label("Name")))])),
+        );
+        let err = SchemaErr::Tagged("user".into(), Box::new(SchemaErr::from([("name".into(), SchemaErr::from([REQUIRED]))])));
+        assert_eq!(
+            localize_schema_err_with_schema(&err, &schema, &locale),
+            SchemaErrLocale::Tagged(
+                "user".into(),
+                Box::new(SchemaErrLocale::from([("name".into(), SchemaErrLocale::Validation(vec!["Name is required".into()]))]))
+            )
+        );
+    }
+
+    #[test]
+    fn localize_schema_err_map_key() {
+        let locale = Locale { enumerated: "%field% must be one of %value%".into(), ..Locale::en() };
+        let schema = Schema::from(
+            MapSchema::from(Schema::from(StrSchema::default().chars_len_ge(1))).key(Schema::from(EnumSchema::from(["en", "pt"]).label("Language"))),
+        );
+        let value = Value::from([("de".into(), Value::from("Hallo")), ("fr".into(), Value::from("")), ("pt".into(), Value::from(""))]);
+        let err = validate(&schema, &value).unwrap_err();
+        let localized = localize_schema_err_with_schema(&err, &schema, &locale);
+        assert_eq!(
+            localized,
+            SchemaErrLocale::from([
+                (
+                    "de".into(),
+                    SchemaErrLocale::MapKey {
+                        key: Box::new(SchemaErrLocale::Validation(vec![r#"Language must be one of [ "en", "pt" ]"#.into()])),
+                        value: None
+                    }
+                ),
+                (
+                    "fr".into(),
+                    SchemaErrLocale::MapKey {
+                        key: Box::new(SchemaErrLocale::Validation(vec![r#"Language must be one of [ "en", "pt" ]"#.into()])),
+                        value: Some(Box::new(SchemaErrLocale::Validation(vec!["must have at least 1 character".into()])))
+                    }
+                ),
+                ("pt".into(), SchemaErrLocale::Validation(vec!["must have at least 1 character".into()])),
+            ])
+        );
+        assert_eq!(
+            localized.flatten(),
+            vec![
+                FlatErr {
+                    pointer: "/de".into(),
+                    path: "de".into(),
+                    key: true,
+                    errors: vec![r#"Language must be one of [ "en", "pt" ]"#.to_string()]
+                },
+                FlatErr {
+                    pointer: "/fr".into(),
+                    path: "fr".into(),
+                    key: true,
+                    errors: vec![r#"Language must be one of [ "en", "pt" ]"#.to_string()]
+                },
+                FlatErr { pointer: "/fr".into(), path: "fr".into(), key: false, errors: vec!["must have at least 1 character".to_string()] },
+                FlatErr { pointer: "/pt".into(), path: "pt".into(), key: false, errors: vec!["must have at least 1 character".to_string()] },
+            ]
+        );
+    }
+
+    #[test]
+    fn localize_schema_err_collection() {
+        let locale = Locale { len_le: "%field% must have at most {value, plural, one {# item} other {# items}}".into(), ..Locale::en() };
+        let schema = Schema::from(ArrSchema::from(Schema::from(U64Schema::default().label("Tag id"))).len_le(2).label("Tags"));
+        let value = Value::from([Value::U64(1), Value::from("two"), Value::U64(3)]);
+        let localized = localize_schema_err_with_schema(&validate(&schema, &value).unwrap_err(), &schema, &locale);
+        assert_eq!(
+            localized,
+            SchemaErrLocale::Collection {
+                errors: vec!["Tags must have at most 2 items".into()],
+                items: Box::new(SchemaErrLocale::Arr(vec![
+                    SchemaErrLocale::Validation(vec![]),
+                    SchemaErrLocale::Validation(vec!["must be an unsigned integer".into()]),
+                    SchemaErrLocale::Validation(vec![]),
+                ]))
+            }
+        );
+        assert_eq!(
+            localized.flatten(),
+            vec![
+                FlatErr { pointer: "".into(), path: "".into(), key: false, errors: vec!["Tags must have at most 2 items".to_string()] },
+                FlatErr { pointer: "/1".into(), path: "1".into(), key: false, errors: vec!["must be an unsigned integer".to_string()] },
+            ]
+        );
+    }
+
+    #[test]
+    fn schema_err_locale_flatten() {
+        let err = SchemaErrLocale::from([
+            ("name".into(), SchemaErrLocale::Validation(vec!["required".into()])),
+            (
+                "users".into(),
+                SchemaErrLocale::Arr(vec![SchemaErrLocale::from([("email".into(), SchemaErrLocale::Validation(vec!["email".into()]))])]),
+            ),
+            (
+                "value".into(),
+                SchemaErrLocale::Union(vec![SchemaErrLocale::Validation(vec!["u64".into()]), SchemaErrLocale::Validation(vec!["str".into()])]),
+            ),
+        ]);
+        assert_eq!(
+            err.flatten(),
+            vec![
+                FlatErr { pointer: "/name".into(), path: "name".into(), key: false, errors: vec!["required".to_string()] },
+                FlatErr { pointer: "/users/0/email".into(), path: "users.0.email".into(), key: false, errors: vec!["email".to_string()] },
+                FlatErr { pointer: "/value".into(), path: "value".into(), key: false, errors: vec!["u64".to_string(), "str".to_string()] },
+            ]
+        );
+        let err = SchemaErrLocale::from([
+            SchemaErrLocale::Validation(vec![]),
+            SchemaErrLocale::from(["u64".to_string()]),
+            SchemaErrLocale::Validation(vec![]),
+        ]);
+        assert_eq!(err.flatten(), vec![FlatErr { pointer: "/1".into(), path: "1".into(), key: false, errors: vec!["u64".to_string()] }]);
+    }
+
+    #[test]
+    fn localize_schema_err_union() {
+        let locale = mock_locale();
+        let err = SchemaErr::Union(vec![SchemaErr::from([STR]), SchemaErr::from([BOOL])]);
+        let localized_err =
+            SchemaErrLocale::Union(vec![SchemaErrLocale::Validation(vec!["str".into()]), SchemaErrLocale::Validation(vec!["bool".into()])]);
+        assert_eq!(localize_schema_err(&err, &locale), localized_err);
+    }
+
+    #[test]
+    fn localize_schema_err_tagged() {
+        let locale = mock_locale();
+        let err = SchemaErr::Tagged("user".into(), Box::new(SchemaErr::from([("name".into(), SchemaErr::from([REQUIRED, STR]))])));
+        let localized_err = SchemaErrLocale::Tagged(
+            "user".into(),
+            Box::new(SchemaErrLocale::Obj(BTreeMap::from([("name".into(), SchemaErrLocale::Validation(vec!["required".into(), "str".into()]))]))),
+        );
+        assert_eq!(localize_schema_err(&err, &locale), localized_err);
+    }
+
+    #[test]
+    fn localize_schema_err_nested() {
+        let locale = mock_locale();
+        let err = SchemaErr::from([(
+            "user".into(),
+            SchemaErr::from([
+                SchemaErr::from([REQUIRED]),
+                SchemaErr::from([
+                    ("name".into(), SchemaErr::from([REQUIRED, STR, OPERATION_NAME.clone()])),
+                    ("birthdate".into(), SchemaErr::from([REQUIRED, STR, OPERATION_BIRTHDATE.clone()])),
+                    ("alive".into(), SchemaErr::from([REQUIRED, BOOL, OPERATION_ALIVE.clone()])),
+                    ("bands".into(), SchemaErr::from([REQUIRED, STR, OPERATION_BANDS.clone()])),
+                ]),
+            ]),
+        )]);
+        let localized_err = SchemaErrLocale::Obj(BTreeMap::from([(
+            "user".into(),
+            SchemaErrLocale::Arr(vec![
+                SchemaErrLocale::Validation(vec!["required".into()]),
+                SchemaErrLocale::Obj(BTreeMap::from([
+                    ("name".into(), SchemaErrLocale::Validation(vec!["required".into(), "str".into(), r#"== "Paul McCartney""#.into()])),
+                    ("birthdate".into(), SchemaErrLocale::Validation(vec!["required".into(), "str".into(), r#"== "1942-06-18""#.into()])),
+                    ("alive".into(), SchemaErrLocale::Validation(vec!["required".into(), "bool".into(), "== true".into()])),
+                    ("bands".into(), SchemaErrLocale::Validation(vec!["required".into(), "str".into(), r#"== "The Beatles""#.into()])),
+                ])),
+            ]),
+        )]));
+        assert_eq!(localize_schema_err(&err, &locale), localized_err);
+    }
+}
@@ -0,0 +1,41 @@
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Plural {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+pub fn plural_en(value: f64) -> Plural {
+    if value == 1.0 { Plural::One } else { Plural::Other }
+}
+
+pub fn plural_pt(value: f64) -> Plural {
+    if (0.0..2.0).contains(&value) { Plural::One } else { Plural::Other }
+}
+
+pub fn plural_es(value: f64) -> Plural {
+    if value == 1.0 { Plural::One } else { Plural::Other }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Plural, plural_en, plural_es, plural_pt};
+
+    #[test]
+    fn plural() {
+        assert_eq!(plural_en(0.0), Plural::Other);
+        assert_eq!(plural_en(1.0), Plural::One);
+        assert_eq!(plural_en(1.5), Plural::Other);
+        assert_eq!(plural_en(2.0), Plural::Other);
+        assert_eq!(plural_pt(0.0), Plural::One);
+        assert_eq!(plural_pt(1.0), Plural::One);
+        assert_eq!(plural_pt(1.5), Plural::One);
+        assert_eq!(plural_pt(2.0), Plural::Other);
+        assert_eq!(plural_es(0.0), Plural::Other);
+        assert_eq!(plural_es(1.0), Plural::One);
+        assert_eq!(plural_es(2.0), Plural::Other);
+    }
+}
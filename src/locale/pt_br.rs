@@ -0,0 +1,162 @@
+use std::collections::BTreeMap;
+
+use super::{Locale, plural_pt};
+
+impl Locale {
+    pub fn pt_br() -> Self {
+        Locale {
+            required: "é obrigatório".into(),
+            u64: "deve ser um inteiro sem sinal".into(),
+            i64: "deve ser um inteiro".into(),
+            f64: "deve ser um número decimal".into(),
+            usize: "deve ser um inteiro sem sinal".into(),
+            isize: "deve ser um inteiro".into(),
+            bool: "deve ser um booleano".into(),
+            str: "deve ser um texto".into(),
+            email: "deve ser um e-mail".into(),
+            date: "deve ser uma data".into(),
+            time: "deve ser um horário".into(),
+            date_time: "deve ser uma data e hora".into(),
+            arr: "deve ser uma lista".into(),
+            obj: "deve ser um objeto".into(),
+            map: "deve ser um objeto".into(),
+            unknown_key: "não é permitido".into(),
+            eq: "deve ser igual a %value%".into(),
+            ne: "deve ser diferente de %value%".into(),
+            gt: "deve ser maior que %value%".into(),
+            ge: "deve ser maior ou igual a %value%".into(),
+            lt: "deve ser menor que %value%".into(),
+            le: "deve ser menor ou igual a %value%".into(),
+            btwn: "deve estar entre %value_a% e %value_b%".into(),
+            eq_field: "deve ser igual ao campo %value%".into(),
+            ne_field: "deve ser diferente do campo %value%".into(),
+            gt_field: "deve ser maior que o campo %value%".into(),
+            lt_field: "deve ser menor que o campo %value%".into(),
+            ge_field: "deve ser maior ou igual ao campo %value%".into(),
+            le_field: "deve ser menor ou igual ao campo %value%".into(),
+            today: "hoje".into(),
+            days_ago: "{value, plural, one {há # dia} other {há # dias}}".into(),
+            days_from_now: "{value, plural, one {daqui a # dia} other {daqui a # dias}}".into(),
+            months_ago: "{value, plural, one {há # mês} other {há # meses}}".into(),
+            months_from_now: "{value, plural, one {daqui a # mês} other {daqui a # meses}}".into(),
+            years_ago: "{value, plural, one {há # ano} other {há # anos}}".into(),
+            years_from_now: "{value, plural, one {daqui a # ano} other {daqui a # anos}}".into(),
+            le_today: "não deve estar no futuro".into(),
+            ge_today: "não deve estar no passado".into(),
+            age_ge: "deve ser de pelo menos {value, plural, one {# ano} other {# anos}} atrás".into(),
+            within_days: "deve estar {value, plural, one {no próximo # dia} other {nos próximos # dias}}".into(),
+            bytes_len_eq: "deve ter exatamente {value, plural, one {# byte} other {# bytes}}".into(),
+            bytes_len_ne: "não deve ter exatamente {value, plural, one {# byte} other {# bytes}}".into(),
+            bytes_len_gt: "deve ter mais de {value, plural, one {# byte} other {# bytes}}".into(),
+            bytes_len_ge: "deve ter pelo menos {value, plural, one {# byte} other {# bytes}}".into(),
+            bytes_len_lt: "deve ter menos de {value, plural, one {# byte} other {# bytes}}".into(),
+            bytes_len_le: "deve ter no máximo {value, plural, one {# byte} other {# bytes}}".into(),
+            bytes_len_btwn: "deve ter entre {value_a} e {value_b, plural, one {# byte} other {# bytes}}".into(),
+            chars_len_eq: "deve ter exatamente {value, plural, one {# caractere} other {# caracteres}}".into(),
+            chars_len_ne: "não deve ter exatamente {value, plural, one {# caractere} other {# caracteres}}".into(),
+            chars_len_gt: "deve ter mais de {value, plural, one {# caractere} other {# caracteres}}".into(),
+            chars_len_ge: "deve ter pelo menos {value, plural, one {# caractere} other {# caracteres}}".into(),
+            chars_len_lt: "deve ter menos de {value, plural, one {# caractere} other {# caracteres}}".into(),
+            chars_len_le: "deve ter no máximo {value, plural, one {# caractere} other {# caracteres}}".into(),
+            chars_len_btwn: "deve ter entre {value_a} e {value_b, plural, one {# caractere} other {# caracteres}}".into(),
+            graphemes_len_eq: "deve ter exatamente {value, plural, one {# grafema} other {# grafemas}}".into(),
+            graphemes_len_ne: "não deve ter exatamente {value, plural, one {# grafema} other {# grafemas}}".into(),
+            graphemes_len_gt: "deve ter mais de {value, plural, one {# grafema} other {# grafemas}}".into(),
+            graphemes_len_ge: "deve ter pelo menos {value, plural, one {# grafema} other {# grafemas}}".into(),
+            graphemes_len_lt: "deve ter menos de {value, plural, one {# grafema} other {# grafemas}}".into(),
+            graphemes_len_le: "deve ter no máximo {value, plural, one {# grafema} other {# grafemas}}".into(),
+            graphemes_len_btwn: "deve ter entre {value_a} e {value_b, plural, one {# grafema} other {# grafemas}}".into(),
+            lowercase_len_eq: "deve ter exatamente {value, plural, one {# letra minúscula} other {# letras minúsculas}}".into(),
+            lowercase_len_ne: "não deve ter exatamente {value, plural, one {# letra minúscula} other {# letras minúsculas}}".into(),
+            lowercase_len_gt: "deve ter mais de {value, plural, one {# letra minúscula} other {# letras minúsculas}}".into(),
+            lowercase_len_ge: "deve ter pelo menos {value, plural, one {# letra minúscula} other {# letras minúsculas}}".into(),
+            lowercase_len_lt: "deve ter menos de {value, plural, one {# letra minúscula} other {# letras minúsculas}}".into(),
+            lowercase_len_le: "deve ter no máximo {value, plural, one {# letra minúscula} other {# letras minúsculas}}".into(),
+            lowercase_len_btwn: "deve ter entre {value_a} e {value_b, plural, one {# letra minúscula} other {# letras minúsculas}}".into(),
+            uppercase_len_eq: "deve ter exatamente {value, plural, one {# letra maiúscula} other {# letras maiúsculas}}".into(),
+            uppercase_len_ne: "não deve ter exatamente {value, plural, one {# letra maiúscula} other {# letras maiúsculas}}".into(),
+            uppercase_len_gt: "deve ter mais de {value, plural, one {# letra maiúscula} other {# letras maiúsculas}}".into(),
+            uppercase_len_ge: "deve ter pelo menos {value, plural, one {# letra maiúscula} other {# letras maiúsculas}}".into(),
+            uppercase_len_lt: "deve ter menos de {value, plural, one {# letra maiúscula} other {# letras maiúsculas}}".into(),
+            uppercase_len_le: "deve ter no máximo {value, plural, one {# letra maiúscula} other {# letras maiúsculas}}".into(),
+            uppercase_len_btwn: "deve ter entre {value_a} e {value_b, plural, one {# letra maiúscula} other {# letras maiúsculas}}".into(),
+            number_len_eq: "deve ter exatamente {value, plural, one {# número} other {# números}}".into(),
+            number_len_ne: "não deve ter exatamente {value, plural, one {# número} other {# números}}".into(),
+            number_len_gt: "deve ter mais de {value, plural, one {# número} other {# números}}".into(),
+            number_len_ge: "deve ter pelo menos {value, plural, one {# número} other {# números}}".into(),
+            number_len_lt: "deve ter menos de {value, plural, one {# número} other {# números}}".into(),
+            number_len_le: "deve ter no máximo {value, plural, one {# número} other {# números}}".into(),
+            number_len_btwn: "deve ter entre {value_a} e {value_b, plural, one {# número} other {# números}}".into(),
+            symbols_eq: "deve ter exatamente {value, plural, one {# símbolo} other {# símbolos}}".into(),
+            symbols_ne: "não deve ter exatamente {value, plural, one {# símbolo} other {# símbolos}}".into(),
+            symbols_gt: "deve ter mais de {value, plural, one {# símbolo} other {# símbolos}}".into(),
+            symbols_ge: "deve ter pelo menos {value, plural, one {# símbolo} other {# símbolos}}".into(),
+            symbols_lt: "deve ter menos de {value, plural, one {# símbolo} other {# símbolos}}".into(),
+            symbols_le: "deve ter no máximo {value, plural, one {# símbolo} other {# símbolos}}".into(),
+            symbols_btwn: "deve ter entre {value_a} e {value_b, plural, one {# símbolo} other {# símbolos}}".into(),
+            len_eq: "deve ter exatamente {value, plural, one {# item} other {# itens}}".into(),
+            len_ne: "não deve ter exatamente {value, plural, one {# item} other {# itens}}".into(),
+            len_gt: "deve ter mais de {value, plural, one {# item} other {# itens}}".into(),
+            len_ge: "deve ter pelo menos {value, plural, one {# item} other {# itens}}".into(),
+            len_lt: "deve ter menos de {value, plural, one {# item} other {# itens}}".into(),
+            len_le: "deve ter no máximo {value, plural, one {# item} other {# itens}}".into(),
+            len_btwn: "deve ter entre {value_a} e {value_b, plural, one {# item} other {# itens}}".into(),
+            enumerated: "deve ser um de %value%".into(),
+            pattern: "deve corresponder ao padrão %value%".into(),
+            starts_with: "deve começar com %value%".into(),
+            ends_with: "deve terminar com %value%".into(),
+            contains: "deve conter %value%".into(),
+            not_contains: "não deve conter %value%".into(),
+            only_chars: "{value, select, ascii_alphanumeric {deve conter apenas letras e dígitos} hex {deve conter apenas dígitos hexadecimais} base64_url {deve conter apenas letras, dígitos, - e _} other {deve conter apenas os caracteres {value}}}".into(),
+            trimmed: "não deve começar nem terminar com espaços".into(),
+            no_control_chars: "não deve conter caracteres de controle ou invisíveis".into(),
+            normalized: "deve estar na forma Unicode %value%".into(),
+            custom: BTreeMap::new(),
+            plural: plural_pt,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::ValidationErr,
+        locale::{Locale, localize_validation_err},
+        operation::{Operand, OperandValue, Operation, Relative},
+        schema::EnumValues,
+    };
+
+    #[test]
+    fn locale_pt_br() {
+        let locale = Locale::pt_br();
+        let chars_len_btwn =
+            ValidationErr::CharsLen(Operation::Btwn(Operand::Value(OperandValue::USize(1)), Operand::Value(OperandValue::USize(64))));
+        let gt_field = ValidationErr::Operation(Operation::Gt(Operand::FieldPath("min".into())));
+        let enumerated = ValidationErr::Enumerated(EnumValues::from(["ADMIN", "USER"]));
+        assert_eq!(localize_validation_err(&ValidationErr::Required, &locale), "é obrigatório".to_string());
+        assert_eq!(localize_validation_err(&chars_len_btwn, &locale), "deve ter entre 1 e 64 caracteres".to_string());
+        let chars_len_ge_one = ValidationErr::CharsLen(Operation::Ge(Operand::Value(OperandValue::USize(1))));
+        let chars_len_ge_five = ValidationErr::CharsLen(Operation::Ge(Operand::Value(OperandValue::USize(5))));
+        assert_eq!(localize_validation_err(&chars_len_ge_one, &locale), "deve ter pelo menos 1 caractere".to_string());
+        assert_eq!(localize_validation_err(&chars_len_ge_five, &locale), "deve ter pelo menos 5 caracteres".to_string());
+        assert_eq!(localize_validation_err(&gt_field, &locale), r#"deve ser maior que o campo "min""#.to_string());
+        assert_eq!(localize_validation_err(&enumerated, &locale), r#"deve ser um de [ "ADMIN", "USER" ]"#.to_string());
+    }
+
+    #[test]
+    fn locale_pt_br_relative() {
+        let locale = Locale::pt_br();
+        let le_today = ValidationErr::Operation(Operation::Le(Operand::Now(Relative::Days(0))));
+        let age_ge = |years: i64| ValidationErr::Operation(Operation::Le(Operand::Now(Relative::Years(-years))));
+        let within_days = |days: i64| ValidationErr::Operation(Operation::Btwn(Operand::Now(Relative::Days(0)), Operand::Now(Relative::Days(days))));
+        let ge_months_ago = ValidationErr::Operation(Operation::Ge(Operand::Now(Relative::Months(-6))));
+        let lt_month_from_now = ValidationErr::Operation(Operation::Lt(Operand::Now(Relative::Months(1))));
+        assert_eq!(localize_validation_err(&le_today, &locale), "não deve estar no futuro".to_string());
+        assert_eq!(localize_validation_err(&age_ge(18), &locale), "deve ser de pelo menos 18 anos atrás".to_string());
+        assert_eq!(localize_validation_err(&age_ge(1), &locale), "deve ser de pelo menos 1 ano atrás".to_string());
+        assert_eq!(localize_validation_err(&within_days(30), &locale), "deve estar nos próximos 30 dias".to_string());
+        assert_eq!(localize_validation_err(&within_days(1), &locale), "deve estar no próximo 1 dia".to_string());
+        assert_eq!(localize_validation_err(&ge_months_ago, &locale), "deve ser maior ou igual a há 6 meses".to_string());
+        assert_eq!(localize_validation_err(&lt_month_from_now, &locale), "deve ser menor que daqui a 1 mês".to_string());
+    }
+}
@@ -0,0 +1,368 @@
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct Date {
+    pub year: u32,
+    pub month: u32,
+    pub day: u32,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct Time {
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+    pub nanosecond: u32,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct DateTime {
+    pub date: Date,
+    pub time: Time,
+}
+
+fn parse_digits(value: &str) -> Option<u32> {
+    if value.is_empty() || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    value.parse::<u32>().ok()
+}
+
+fn days_in_month(year: u32, month: u32) -> Option<u32> {
+    let leap = (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400);
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+        4 | 6 | 9 | 11 => Some(30),
+        2 if leap => Some(29),
+        2 => Some(28),
+        _ => None,
+    }
+}
+
+fn days_from_civil(date: &Date) -> i64 {
+    let year = date.year as i64 - if date.month <= 2 { 1 } else { 0 };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = date.month as i64;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + date.day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+fn civil_from_days(days: i64) -> Option<Date> {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    if !(0..=9999).contains(&year) {
+        return None;
+    }
+    Some(Date { year: year as u32, month: month as u32, day: day as u32 })
+}
+
+pub fn date_from_unix_days(days: i64) -> Option<Date> {
+    civil_from_days(days)
+}
+
+pub fn add_days(date: &Date, days: i64) -> Option<Date> {
+    civil_from_days(days_from_civil(date) + days)
+}
+
+pub fn add_months(date: &Date, months: i64) -> Option<Date> {
+    let total = date.year as i64 * 12 + date.month as i64 - 1 + months;
+    if !(0..=9999 * 12 + 11).contains(&total) {
+        return None;
+    }
+    let year = (total / 12) as u32;
+    let month = (total % 12) as u32 + 1;
+    let day = date.day.min(days_in_month(year, month)?);
+    Some(Date { year, month, day })
+}
+
+pub fn parse_date(value: &str) -> Option<Date> {
+    if !value.is_ascii() {
+        return None;
+    }
+    let bytes = value.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+    let year = parse_digits(&value[0..4])?;
+    let month = parse_digits(&value[5..7])?;
+    let day = parse_digits(&value[8..10])?;
+    if day < 1 || day > days_in_month(year, month)? {
+        return None;
+    }
+    Some(Date { year, month, day })
+}
+
+pub fn parse_time(value: &str) -> Option<Time> {
+    if !value.is_ascii() {
+        return None;
+    }
+    let bytes = value.as_bytes();
+    if bytes.len() < 5 || bytes[2] != b':' {
+        return None;
+    }
+    let hour = parse_digits(&value[0..2])?;
+    let minute = parse_digits(&value[3..5])?;
+    let mut second = 0;
+    let mut nanosecond = 0;
+    if bytes.len() > 5 {
+        if bytes.len() < 8 || bytes[5] != b':' {
+            return None;
+        }
+        second = parse_digits(&value[6..8])?;
+        if bytes.len() > 8 {
+            let fraction = &value[9..];
+            if bytes[8] != b'.' || fraction.len() > 9 {
+                return None;
+            }
+            nanosecond = parse_digits(fraction)? * 10_u32.pow(9 - fraction.len() as u32);
+        }
+    }
+    if hour == 24 && (minute != 0 || second != 0 || nanosecond != 0) {
+        return None;
+    }
+    if hour > 24 || minute > 59 || second > 59 {
+        return None;
+    }
+    Some(Time { hour, minute, second, nanosecond })
+}
+
+pub fn parse_date_time(value: &str) -> Option<DateTime> {
+    if !value.is_ascii() {
+        return None;
+    }
+    let bytes = value.as_bytes();
+    if bytes.len() < 17 || !matches!(bytes[10], b'T' | b't') {
+        return None;
+    }
+    let date = parse_date(&value[0..10])?;
+    let rest = &value[11..];
+    let (time, offset) = if let Some(time) = rest.strip_suffix(['Z', 'z']) {
+        (time, 0)
+    } else {
+        if rest.len() < 6 {
+            return None;
+        }
+        let (time, offset) = rest.split_at(rest.len() - 6);
+        let offset_bytes = offset.as_bytes();
+        if offset_bytes[3] != b':' {
+            return None;
+        }
+        let offset_hour = parse_digits(&offset[1..3])? as i64;
+        let offset_minute = parse_digits(&offset[4..6])? as i64;
+        if offset_hour > 23 || offset_minute > 59 {
+            return None;
+        }
+        match offset_bytes[0] {
+            b'+' => (time, offset_hour * 60 + offset_minute),
+            b'-' => (time, -(offset_hour * 60 + offset_minute)),
+            _ => return None,
+        }
+    };
+    let time = parse_time(time)?;
+    let minutes = days_from_civil(&date) * 1440 + time.hour as i64 * 60 + time.minute as i64 - offset;
+    let date = civil_from_days(minutes.div_euclid(1440))?;
+    let minute_of_day = minutes.rem_euclid(1440);
+    let time = Time { hour: (minute_of_day / 60) as u32, minute: (minute_of_day % 60) as u32, ..time };
+    Some(DateTime { date, time })
+}
+
+impl std::fmt::Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl std::fmt::Display for Time {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)?;
+        if self.second != 0 || self.nanosecond != 0 {
+            write!(f, ":{:02}", self.second)?;
+        }
+        if self.nanosecond != 0 {
+            write!(f, ".{}", format!("{:09}", self.nanosecond).trim_end_matches('0'))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}T{}Z", self.date, self.time)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Date {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Date {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        parse_date(&value).ok_or_else(|| serde::de::Error::custom("invalid ISO-8601 date"))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Time {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Time {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        parse_time(&value).ok_or_else(|| serde::de::Error::custom("invalid ISO-8601 time"))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for DateTime {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DateTime {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        parse_date_time(&value).ok_or_else(|| serde::de::Error::custom("invalid ISO-8601 date-time"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Date, DateTime, Time, add_days, add_months, date_from_unix_days, parse_date, parse_date_time, parse_time};
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(parse_date("2026-10-18"), Some(Date { year: 2026, month: 10, day: 18 }));
+        assert_eq!(parse_date("2024-02-29"), Some(Date { year: 2024, month: 2, day: 29 }));
+        assert_eq!(parse_date("0000-01-01"), Some(Date { year: 0, month: 1, day: 1 }));
+        assert_eq!(parse_date("2025-02-29"), None);
+        assert_eq!(parse_date("2026-13-18"), None);
+        assert_eq!(parse_date("2026-00-18"), None);
+        assert_eq!(parse_date("2026-10-00"), None);
+        assert_eq!(parse_date("2026-10-32"), None);
+        assert_eq!(parse_date("2026-1-18"), None);
+        assert_eq!(parse_date("2026/10/18"), None);
+        assert_eq!(parse_date("2026-+1-18"), None);
+        assert_eq!(parse_date("2026-10-18T10:00Z"), None);
+        assert_eq!(parse_date("2026-10-1é"), None);
+        assert_eq!(parse_date(""), None);
+    }
+
+    #[test]
+    fn test_parse_time() {
+        assert_eq!(parse_time("00:00"), Some(Time { hour: 0, minute: 0, second: 0, nanosecond: 0 }));
+        assert_eq!(parse_time("10:27"), Some(Time { hour: 10, minute: 27, second: 0, nanosecond: 0 }));
+        assert_eq!(parse_time("23:59:58"), Some(Time { hour: 23, minute: 59, second: 58, nanosecond: 0 }));
+        assert_eq!(parse_time("23:59:58.25"), Some(Time { hour: 23, minute: 59, second: 58, nanosecond: 250_000_000 }));
+        assert_eq!(parse_time("24:00"), Some(Time { hour: 24, minute: 0, second: 0, nanosecond: 0 }));
+        assert_eq!(parse_time("24:00:00"), Some(Time { hour: 24, minute: 0, second: 0, nanosecond: 0 }));
+        assert_eq!(parse_time("24:01"), None);
+        assert_eq!(parse_time("23:60"), None);
+        assert_eq!(parse_time("23:59:60"), None);
+        assert_eq!(parse_time("23:59:58."), None);
+        assert_eq!(parse_time("23:59:58.1234567890"), None);
+        assert_eq!(parse_time("9:27"), None);
+        assert_eq!(parse_time("10-27"), None);
+        assert_eq!(parse_time("+1:27"), None);
+        assert_eq!(parse_time("12:0é"), None);
+        assert_eq!(parse_time("12:00:0é"), None);
+        assert_eq!(parse_time("12:00:00é"), None);
+        assert_eq!(parse_time("é2:00"), None);
+        assert_eq!(parse_time(""), None);
+    }
+
+    #[test]
+    fn test_parse_date_time() {
+        let date = Date { year: 2026, month: 10, day: 18 };
+        assert_eq!(parse_date_time("2026-10-18T10:27Z"), Some(DateTime { date, time: Time { hour: 10, minute: 27, second: 0, nanosecond: 0 } }));
+        assert_eq!(
+            parse_date_time("2026-10-18T10:27:13.5Z"),
+            Some(DateTime { date, time: Time { hour: 10, minute: 27, second: 13, nanosecond: 500_000_000 } })
+        );
+        assert_eq!(parse_date_time("2026-10-18T07:27-03:00"), Some(DateTime { date, time: Time { hour: 10, minute: 27, second: 0, nanosecond: 0 } }));
+        assert_eq!(
+            parse_date_time("2026-10-18T15:57:00+05:30"),
+            Some(DateTime { date, time: Time { hour: 10, minute: 27, second: 0, nanosecond: 0 } })
+        );
+        assert_eq!(
+            parse_date_time("2026-12-31T22:00-03:00"),
+            Some(DateTime { date: Date { year: 2027, month: 1, day: 1 }, time: Time { hour: 1, minute: 0, second: 0, nanosecond: 0 } })
+        );
+        assert_eq!(
+            parse_date_time("2024-03-01T01:00+02:00"),
+            Some(DateTime { date: Date { year: 2024, month: 2, day: 29 }, time: Time { hour: 23, minute: 0, second: 0, nanosecond: 0 } })
+        );
+        assert_eq!(
+            parse_date_time("2026-10-18T24:00Z"),
+            Some(DateTime { date: Date { year: 2026, month: 10, day: 19 }, time: Time { hour: 0, minute: 0, second: 0, nanosecond: 0 } })
+        );
+        assert_eq!(parse_date_time("2026-10-18T10:27"), None);
+        assert_eq!(parse_date_time("2026-10-18 10:27Z"), None);
+        assert_eq!(parse_date_time("2026-10-18T10:27+0300"), None);
+        assert_eq!(parse_date_time("2026-10-18T10:27+24:00"), None);
+        assert_eq!(parse_date_time("2026-10-32T10:27Z"), None);
+        assert_eq!(parse_date_time("2026-10-1éT10:27Z"), None);
+        assert_eq!(parse_date_time("2026-10-18T10:2éZ"), None);
+        assert_eq!(parse_date_time("2026-10-18T10:27+0é:00"), None);
+        assert_eq!(parse_date_time("2026-10-18T10:27:00é+03:00"), None);
+        assert_eq!(parse_date_time(""), None);
+    }
+
+    #[test]
+    fn test_add_days() {
+        let date = Date { year: 2024, month: 2, day: 28 };
+        assert_eq!(add_days(&date, 0), Some(date));
+        assert_eq!(add_days(&date, 1), Some(Date { year: 2024, month: 2, day: 29 }));
+        assert_eq!(add_days(&date, 2), Some(Date { year: 2024, month: 3, day: 1 }));
+        assert_eq!(add_days(&date, -59), Some(Date { year: 2023, month: 12, day: 31 }));
+        assert_eq!(add_days(&Date { year: 9999, month: 12, day: 31 }, 1), None);
+        assert_eq!(date_from_unix_days(0), Some(Date { year: 1970, month: 1, day: 1 }));
+        assert_eq!(date_from_unix_days(20379), Some(Date { year: 2025, month: 10, day: 18 }));
+    }
+
+    #[test]
+    fn test_add_months() {
+        let date = Date { year: 2024, month: 1, day: 31 };
+        assert_eq!(add_months(&date, 1), Some(Date { year: 2024, month: 2, day: 29 }));
+        assert_eq!(add_months(&date, 13), Some(Date { year: 2025, month: 2, day: 28 }));
+        assert_eq!(add_months(&date, -1), Some(Date { year: 2023, month: 12, day: 31 }));
+        assert_eq!(add_months(&Date { year: 2024, month: 2, day: 29 }, -12 * 18), Some(Date { year: 2006, month: 2, day: 28 }));
+        assert_eq!(add_months(&Date { year: 0, month: 1, day: 1 }, -1), None);
+    }
+
+    #[test]
+    fn calendar_ord() {
+        assert!(parse_date("2026-10-18") > parse_date("2026-09-30"));
+        assert!(parse_date("2026-10-18") < parse_date("2027-01-01"));
+        assert!(parse_time("24:00") > parse_time("23:59:59.999"));
+        assert!(parse_time("09:00") < parse_time("10:00"));
+        assert!(parse_date_time("2026-10-18T10:00-03:00") > parse_date_time("2026-10-18T12:00Z"));
+        assert_eq!(parse_date_time("2026-10-18T10:00-03:00"), parse_date_time("2026-10-18T13:00Z"));
+    }
+
+    #[test]
+    fn calendar_to_string() {
+        assert_eq!(Date { year: 970, month: 1, day: 2 }.to_string(), "0970-01-02".to_string());
+        assert_eq!(Time { hour: 9, minute: 5, second: 0, nanosecond: 0 }.to_string(), "09:05".to_string());
+        assert_eq!(Time { hour: 9, minute: 5, second: 7, nanosecond: 0 }.to_string(), "09:05:07".to_string());
+        assert_eq!(Time { hour: 9, minute: 5, second: 7, nanosecond: 120_000_000 }.to_string(), "09:05:07.12".to_string());
+        assert_eq!(parse_date_time("2026-10-18T07:27-03:00").unwrap().to_string(), "2026-10-18T10:27Z".to_string());
+    }
+}
@@ -0,0 +1,51 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::{Date, DateTime, Time, date_from_unix_days};
+
+pub trait Clock {
+    fn now(&self) -> DateTime;
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SystemClock;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct FixedClock(pub DateTime);
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime {
+        let elapsed = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        let seconds = elapsed.as_secs();
+        let date = date_from_unix_days((seconds / 86400) as i64).unwrap_or(Date { year: 1970, month: 1, day: 1 });
+        let seconds_of_day = (seconds % 86400) as u32;
+        let time =
+            Time { hour: seconds_of_day / 3600, minute: seconds_of_day % 3600 / 60, second: seconds_of_day % 60, nanosecond: elapsed.subsec_nanos() };
+        DateTime { date, time }
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::parse_date_time;
+
+    use super::{Clock, FixedClock, SystemClock};
+
+    #[test]
+    fn fixed_clock() {
+        let now = parse_date_time("2026-10-18T10:27Z").unwrap();
+        assert_eq!(FixedClock(now).now(), now);
+    }
+
+    #[test]
+    fn system_clock() {
+        let now = SystemClock.now();
+        assert!(now > parse_date_time("2024-01-01T00:00Z").unwrap());
+        assert!(now.time.hour < 24);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::value::Value;
 
-use super::{OperandValue, Operation, resolve_operand_value};
+use super::{Clock, Operand, OperandValue, Operation, SystemClock, operand_value_as, resolve_operand_value, resolve_relative};
 
 use compare_btwn::compare_btwn;
 use compare_eq::compare_eq;
@@ -18,35 +18,46 @@ mod compare_le;
 mod compare_lt;
 mod compare_ne;
 
+fn resolve(operand: &Operand, value: &OperandValue, root: &Value, clock: &dyn Clock) -> Option<OperandValue> {
+    match operand {
+        Operand::Now(relative) => resolve_relative(relative, value, &clock.now()),
+        _ => operand_value_as(resolve_operand_value(operand, root)?, value),
+    }
+}
+
 pub fn compare(operation: &Operation, value: &OperandValue, root: &Value) -> Option<Result<(), ()>> {
+    compare_with_clock(operation, value, root, &SystemClock)
+}
+
+pub fn compare_with_clock(operation: &Operation, value: &OperandValue, root: &Value, clock: &dyn Clock) -> Option<Result<(), ()>> {
     match operation {
         Operation::Eq(operand) => {
-            let operand_value = resolve_operand_value(operand, root)?;
+            let operand_value = resolve(operand, value, root, clock)?;
             compare_eq(value, &operand_value)
         }
         Operation::Ne(operand) => {
-            let operand_value = resolve_operand_value(operand, root)?;
+            let operand_value = resolve(operand, value, root, clock)?;
             compare_ne(value, &operand_value)
         }
         Operation::Gt(operand) => {
-            let operand_value = resolve_operand_value(operand, root)?;
+            let operand_value = resolve(operand, value, root, clock)?;
             compare_gt(value, &operand_value)
         }
         Operation::Ge(operand) => {
-            let operand_value = resolve_operand_value(operand, root)?;
+            let operand_value = resolve(operand, value, root, clock)?;
             compare_ge(value, &operand_value)
         }
         Operation::Lt(operand) => {
-            let operand_value = resolve_operand_value(operand, root)?;
+            let operand_value = resolve(operand, value, root, clock)?;
             compare_lt(value, &operand_value)
         }
         Operation::Le(operand) => {
-            let operand_value = resolve_operand_value(operand, root)?;
+            let operand_value = resolve(operand, value, root, clock)?;
             compare_le(value, &operand_value)
         }
         Operation::Btwn(operand_a, operand_b) => {
-            let operand_value_a = resolve_operand_value(operand_a, root)?;
-            let operand_value_b = resolve_operand_value(operand_b, root)?;
+            let operand_value_a = resolve(operand_a, value, root, clock)?;
+            let operand_value_b = resolve(operand_b, value, root, clock)?;
             compare_btwn(value, &operand_value_a, &operand_value_b)
         }
     }
@@ -58,9 +69,9 @@ mod tests {
 
     use crate::value::Value;
 
-    use super::super::{Operand, OperandValue, Operation};
+    use super::super::{FixedClock, Operand, OperandValue, Operation, Relative, parse_date, parse_date_time};
 
-    use super::compare;
+    use super::{compare, compare_with_clock};
 
     static ROOT: LazyLock<Value> = LazyLock::new(|| {
         Value::Obj(BTreeMap::from([
@@ -160,4 +171,22 @@ mod tests {
         assert_eq!(compare(&v_field_not_found_b, &OperandValue::U64(23), &ROOT), None);
         assert_eq!(compare(&v_value, &OperandValue::Bool(false), &ROOT), None);
     }
+
+    #[test]
+    fn compare_now() {
+        let clock = FixedClock(parse_date_time("2026-10-18T10:27Z").unwrap());
+        let le_today = Operation::Le(Operand::Now(Relative::Days(0)));
+        let within_days = Operation::Btwn(Operand::Now(Relative::Days(0)), Operand::Now(Relative::Days(30)));
+        let date = |value: &str| OperandValue::Date(parse_date(value).unwrap());
+        let date_time = |value: &str| OperandValue::DateTime(parse_date_time(value).unwrap());
+        assert_eq!(compare_with_clock(&le_today, &date("2026-10-18"), &ROOT, &clock), Some(Ok(())));
+        assert_eq!(compare_with_clock(&le_today, &date("2026-10-19"), &ROOT, &clock), Some(Err(())));
+        assert_eq!(compare_with_clock(&le_today, &date_time("2026-10-18T10:27Z"), &ROOT, &clock), Some(Ok(())));
+        assert_eq!(compare_with_clock(&le_today, &date_time("2026-10-18T10:28Z"), &ROOT, &clock), Some(Err(())));
+        assert_eq!(compare_with_clock(&within_days, &date("2026-11-17"), &ROOT, &clock), Some(Ok(())));
+        assert_eq!(compare_with_clock(&within_days, &date("2026-11-18"), &ROOT, &clock), Some(Err(())));
+        assert_eq!(compare_with_clock(&within_days, &date("2026-10-17"), &ROOT, &clock), Some(Err(())));
+        assert_eq!(compare_with_clock(&le_today, &OperandValue::U64(42), &ROOT, &clock), None);
+        assert_eq!(compare(&le_today, &date("1970-01-01"), &ROOT), Some(Ok(())));
+    }
 }
@@ -1,10 +1,17 @@
-pub use compare::compare;
+pub use calendar::{Date, DateTime, Time, parse_date, parse_date_time, parse_time};
+pub use clock::{Clock, FixedClock, SystemClock};
+pub use compare::{compare, compare_with_clock};
 pub use operand_value::OperandValue;
-pub use operation::{Operand, Operation};
+pub use operation::{Operand, Operation, Relative};
 
-use operand_value::operand_value_from_value;
-use operation::resolve_operand_value;
+use calendar::{add_days, add_months, date_from_unix_days};
+use operand_value::{operand_value_as, operand_value_from_value};
+use operation::{resolve_operand_value, resolve_relative};
 
+mod calendar;
+mod clock;
 mod compare;
 mod operand_value;
+// Holds the Operation type itself, next to its calendar, clock and compare helpers.
+#[allow(clippy::module_inception)]
 mod operation;
@@ -1,6 +1,9 @@
 use crate::value::Value;
 
+use super::{Date, DateTime, Time, parse_date, parse_date_time, parse_time};
+
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
 pub enum OperandValue {
     U64(u64),
     I64(i64),
@@ -9,6 +12,9 @@ pub enum OperandValue {
     ISize(isize),
     Bool(bool),
     Str(String),
+    Date(Date),
+    Time(Time),
+    DateTime(DateTime),
 }
 
 impl From<u64> for OperandValue {
@@ -53,44 +59,39 @@ impl From<&str> for OperandValue {
     }
 }
 
+impl From<Date> for OperandValue {
+    fn from(value: Date) -> Self {
+        OperandValue::Date(value)
+    }
+}
+
+impl From<Time> for OperandValue {
+    fn from(value: Time) -> Self {
+        OperandValue::Time(value)
+    }
+}
+
+impl From<DateTime> for OperandValue {
+    fn from(value: DateTime) -> Self {
+        OperandValue::DateTime(value)
+    }
+}
+
 impl PartialOrd for OperandValue {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        if let OperandValue::U64(a) = self {
-            if let OperandValue::U64(b) = other {
-                return a.partial_cmp(b);
-            }
-        }
-        if let OperandValue::I64(a) = self {
-            if let OperandValue::I64(b) = other {
-                return a.partial_cmp(b);
-            }
-        }
-        if let OperandValue::F64(a) = self {
-            if let OperandValue::F64(b) = other {
-                return a.partial_cmp(b);
-            }
-        }
-        if let OperandValue::USize(a) = self {
-            if let OperandValue::USize(b) = other {
-                return a.partial_cmp(b);
-            }
+        match (self, other) {
+            (OperandValue::U64(a), OperandValue::U64(b)) => a.partial_cmp(b),
+            (OperandValue::I64(a), OperandValue::I64(b)) => a.partial_cmp(b),
+            (OperandValue::F64(a), OperandValue::F64(b)) => a.partial_cmp(b),
+            (OperandValue::USize(a), OperandValue::USize(b)) => a.partial_cmp(b),
+            (OperandValue::ISize(a), OperandValue::ISize(b)) => a.partial_cmp(b),
+            (OperandValue::Bool(a), OperandValue::Bool(b)) => a.partial_cmp(b),
+            (OperandValue::Str(a), OperandValue::Str(b)) => a.partial_cmp(b),
+            (OperandValue::Date(a), OperandValue::Date(b)) => a.partial_cmp(b),
+            (OperandValue::Time(a), OperandValue::Time(b)) => a.partial_cmp(b),
+            (OperandValue::DateTime(a), OperandValue::DateTime(b)) => a.partial_cmp(b),
+            _ => None,
         }
-        if let OperandValue::ISize(a) = self {
-            if let OperandValue::ISize(b) = other {
-                return a.partial_cmp(b);
-            }
-        }
-        if let OperandValue::Bool(a) = self {
-            if let OperandValue::Bool(b) = other {
-                return a.partial_cmp(b);
-            }
-        }
-        if let OperandValue::Str(a) = self {
-            if let OperandValue::Str(b) = other {
-                return a.partial_cmp(b);
-            }
-        }
-        None
     }
 }
 
@@ -104,11 +105,23 @@ impl std::fmt::Display for OperandValue {
             OperandValue::ISize(val) => val.to_string(),
             OperandValue::Bool(val) => val.to_string(),
             OperandValue::Str(val) => "\"".to_string() + val + "\"",
+            OperandValue::Date(val) => "\"".to_string() + &val.to_string() + "\"",
+            OperandValue::Time(val) => "\"".to_string() + &val.to_string() + "\"",
+            OperandValue::DateTime(val) => "\"".to_string() + &val.to_string() + "\"",
         };
         write!(f, "{}", str)
     }
 }
 
+pub fn operand_value_as(value: OperandValue, kind: &OperandValue) -> Option<OperandValue> {
+    match (kind, value) {
+        (OperandValue::Date(_), OperandValue::Str(val)) => parse_date(&val).map(OperandValue::Date),
+        (OperandValue::Time(_), OperandValue::Str(val)) => parse_time(&val).map(OperandValue::Time),
+        (OperandValue::DateTime(_), OperandValue::Str(val)) => parse_date_time(&val).map(OperandValue::DateTime),
+        (_, value) => Some(value),
+    }
+}
+
 pub fn operand_value_from_value(value: &Value) -> Option<OperandValue> {
     match value {
         Value::U64(val) => Some(OperandValue::U64(*val)),
@@ -129,7 +142,9 @@ mod tests {
         stub::{arr_bool_stub, arr_f64_stub, arr_i64_stub, arr_isize_stub, arr_str_stub, arr_u64_stub, arr_usize_stub, obj_stub},
     };
 
-    use super::{OperandValue, operand_value_from_value};
+    use super::super::{parse_date, parse_date_time, parse_time};
+
+    use super::{OperandValue, operand_value_as, operand_value_from_value};
 
     #[test]
     fn operand_value_from() {
@@ -297,6 +312,34 @@ mod tests {
         assert!(OperandValue::from("rock lee") <= OperandValue::from("tenten"));
     }
 
+    #[test]
+    fn operand_value_calendar() {
+        let date = |value: &str| OperandValue::Date(parse_date(value).unwrap());
+        let time = |value: &str| OperandValue::Time(parse_time(value).unwrap());
+        let date_time = |value: &str| OperandValue::DateTime(parse_date_time(value).unwrap());
+        assert!(date("2026-10-18") > date("2026-09-30"));
+        assert!(date("2026-10-18") == date("2026-10-18"));
+        assert!(time("24:00") > time("23:59"));
+        assert!(time("09:00") < time("10:00"));
+        assert!(date_time("2026-10-18T10:00-03:00") > date_time("2026-10-18T12:00Z"));
+        assert!(date_time("2026-10-18T10:00-03:00") == date_time("2026-10-18T13:00Z"));
+        assert_eq!(date("2026-10-18").partial_cmp(&OperandValue::from("2026-10-18")), None);
+        assert_eq!(date("2026-10-18").partial_cmp(&date_time("2026-10-18T00:00Z")), None);
+    }
+
+    #[test]
+    fn test_operand_value_as() {
+        let date = OperandValue::Date(parse_date("2026-10-18").unwrap());
+        let time = OperandValue::Time(parse_time("10:27").unwrap());
+        let date_time = OperandValue::DateTime(parse_date_time("2026-10-18T10:27Z").unwrap());
+        assert_eq!(operand_value_as(OperandValue::from("2026-10-18"), &date), Some(date.clone()));
+        assert_eq!(operand_value_as(OperandValue::from("10:27"), &time), Some(time.clone()));
+        assert_eq!(operand_value_as(OperandValue::from("2026-10-18T07:27-03:00"), &date_time), Some(date_time.clone()));
+        assert_eq!(operand_value_as(OperandValue::from("18/10/2026"), &date), None);
+        assert_eq!(operand_value_as(OperandValue::U64(42), &date), Some(OperandValue::U64(42)));
+        assert_eq!(operand_value_as(OperandValue::from("2026-10-18"), &OperandValue::from("a")), Some(OperandValue::from("2026-10-18")));
+    }
+
     #[test]
     fn operand_value_different_type() {
         assert_eq!(OperandValue::U64(42).partial_cmp(&OperandValue::I64(-42)), None);
@@ -360,5 +403,8 @@ mod tests {
         assert_eq!(OperandValue::ISize(-47).to_string(), "-47".to_string());
         assert_eq!(OperandValue::Bool(true).to_string(), "true".to_string());
         assert_eq!(OperandValue::from("Non sequitur").to_string(), r#""Non sequitur""#.to_string());
+        assert_eq!(OperandValue::Date(parse_date("2026-10-18").unwrap()).to_string(), r#""2026-10-18""#.to_string());
+        assert_eq!(OperandValue::Time(parse_time("10:27").unwrap()).to_string(), r#""10:27""#.to_string());
+        assert_eq!(OperandValue::DateTime(parse_date_time("2026-10-18T07:27-03:00").unwrap()).to_string(), r#""2026-10-18T10:27Z""#.to_string());
     }
 }
@@ -1,11 +1,42 @@
 use crate::value::{Value, path::resolve_path};
 
-use super::{OperandValue, operand_value_from_value};
+use super::{Date, DateTime, OperandValue, add_days, add_months, operand_value_from_value};
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum Relative {
+    Days(i64),
+    Months(i64),
+    Years(i64),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
 pub enum Operand {
     Value(OperandValue),
     FieldPath(String),
+    Now(Relative),
+}
+
+impl Relative {
+    pub fn amount(&self) -> i64 {
+        match self {
+            Relative::Days(amount) | Relative::Months(amount) | Relative::Years(amount) => *amount,
+        }
+    }
+}
+
+fn relative_to_string(relative: &Relative) -> String {
+    let unit = match relative {
+        Relative::Days(_) => "D",
+        Relative::Months(_) => "M",
+        Relative::Years(_) => "Y",
+    };
+    match relative.amount() {
+        0 => "now".into(),
+        amount if amount > 0 => format!("now+P{}{}", amount, unit),
+        amount => format!("now-P{}{}", amount.unsigned_abs(), unit),
+    }
 }
 
 impl std::fmt::Display for Operand {
@@ -13,12 +44,14 @@ impl std::fmt::Display for Operand {
         let str = match self {
             Operand::Value(value) => value.to_string(),
             Operand::FieldPath(path) => "\"".to_string() + path + "\"",
+            Operand::Now(relative) => relative_to_string(relative),
         };
         write!(f, "{}", str)
     }
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
 pub enum Operation {
     Eq(Operand),
     Ne(Operand),
@@ -36,6 +69,23 @@ pub fn resolve_operand_value(operation: &Operand, root: &Value) -> Option<Operan
             let field = resolve_path(root, field_path)?;
             operand_value_from_value(&field)
         }
+        Operand::Now(_) => None,
+    }
+}
+
+fn shift_date(date: &Date, relative: &Relative) -> Option<Date> {
+    match relative {
+        Relative::Days(days) => add_days(date, *days),
+        Relative::Months(months) => add_months(date, *months),
+        Relative::Years(years) => add_months(date, years * 12),
+    }
+}
+
+pub fn resolve_relative(relative: &Relative, kind: &OperandValue, now: &DateTime) -> Option<OperandValue> {
+    match kind {
+        OperandValue::Date(_) => shift_date(&now.date, relative).map(OperandValue::Date),
+        OperandValue::DateTime(_) => shift_date(&now.date, relative).map(|date| OperandValue::DateTime(DateTime { date, time: now.time })),
+        _ => None,
     }
 }
 
@@ -47,7 +97,9 @@ mod tests {
 
     use super::super::OperandValue;
 
-    use super::{Operand, resolve_operand_value};
+    use super::super::{Date, DateTime, Time};
+
+    use super::{Operand, Relative, resolve_operand_value, resolve_relative};
 
     static ROOT: LazyLock<Value> = LazyLock::new(|| {
         Value::Obj(BTreeMap::from([
@@ -94,9 +146,32 @@ mod tests {
         assert_eq!(resolve_operand_value(&Operand::FieldPath("field.value.some.foo.bar".into()), &ROOT), None);
     }
 
+    #[test]
+    fn test_resolve_relative() {
+        let now = DateTime { date: Date { year: 2026, month: 10, day: 18 }, time: Time { hour: 10, minute: 27, second: 0, nanosecond: 0 } };
+        let date = OperandValue::Date(now.date);
+        let date_time = OperandValue::DateTime(now);
+        assert_eq!(resolve_relative(&Relative::Days(0), &date, &now), Some(date.clone()));
+        assert_eq!(resolve_relative(&Relative::Days(30), &date, &now), Some(OperandValue::Date(Date { year: 2026, month: 11, day: 17 })));
+        assert_eq!(resolve_relative(&Relative::Months(-1), &date, &now), Some(OperandValue::Date(Date { year: 2026, month: 9, day: 18 })));
+        assert_eq!(resolve_relative(&Relative::Years(-18), &date, &now), Some(OperandValue::Date(Date { year: 2008, month: 10, day: 18 })));
+        assert_eq!(resolve_relative(&Relative::Days(0), &date_time, &now), Some(date_time.clone()));
+        assert_eq!(
+            resolve_relative(&Relative::Days(-1), &date_time, &now),
+            Some(OperandValue::DateTime(DateTime { date: Date { year: 2026, month: 10, day: 17 }, time: now.time }))
+        );
+        assert_eq!(resolve_relative(&Relative::Days(0), &OperandValue::U64(1), &now), None);
+        assert_eq!(resolve_operand_value(&Operand::Now(Relative::Days(0)), &ROOT), None);
+    }
+
     #[test]
     fn test_to_string() {
         assert_eq!(Operand::Value(OperandValue::U64(4)).to_string(), "4".to_string());
         assert_eq!(Operand::FieldPath("user.info.details.name".into()).to_string(), r#""user.info.details.name""#.to_string());
+        assert_eq!(Operand::Now(Relative::Days(0)).to_string(), "now".to_string());
+        assert_eq!(Operand::Now(Relative::Years(0)).to_string(), "now".to_string());
+        assert_eq!(Operand::Now(Relative::Days(30)).to_string(), "now+P30D".to_string());
+        assert_eq!(Operand::Now(Relative::Months(6)).to_string(), "now+P6M".to_string());
+        assert_eq!(Operand::Now(Relative::Years(-18)).to_string(), "now-P18Y".to_string());
     }
 }
@@ -0,0 +1,173 @@
+use crate::operation::{Operand, OperandValue, Operation};
+
+use super::Schema;
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArrSchema {
+    pub required: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub label: Option<String>,
+    pub item: Box<Schema>,
+    pub len: Vec<Operation>,
+}
+
+impl From<Schema> for ArrSchema {
+    fn from(item: Schema) -> Self {
+        ArrSchema { required: true, label: None, item: Box::new(item), len: vec![] }
+    }
+}
+
+impl ArrSchema {
+    pub fn optional(self) -> Self {
+        ArrSchema { required: false, ..self }
+    }
+
+    pub fn label(self, label: &str) -> Self {
+        ArrSchema { label: Some(label.into()), ..self }
+    }
+
+    pub fn len_eq(mut self, len: usize) -> Self {
+        self.len.push(Operation::Eq(Operand::Value(OperandValue::USize(len))));
+        self
+    }
+
+    pub fn len_ne(mut self, len: usize) -> Self {
+        self.len.push(Operation::Ne(Operand::Value(OperandValue::USize(len))));
+        self
+    }
+
+    pub fn len_gt(mut self, len: usize) -> Self {
+        self.len.push(Operation::Gt(Operand::Value(OperandValue::USize(len))));
+        self
+    }
+
+    pub fn len_ge(mut self, len: usize) -> Self {
+        self.len.push(Operation::Ge(Operand::Value(OperandValue::USize(len))));
+        self
+    }
+
+    pub fn len_lt(mut self, len: usize) -> Self {
+        self.len.push(Operation::Lt(Operand::Value(OperandValue::USize(len))));
+        self
+    }
+
+    pub fn len_le(mut self, len: usize) -> Self {
+        self.len.push(Operation::Le(Operand::Value(OperandValue::USize(len))));
+        self
+    }
+
+    pub fn len_btwn(mut self, len_a: usize, len_b: usize) -> Self {
+        self.len.push(Operation::Btwn(Operand::Value(OperandValue::USize(len_a)), Operand::Value(OperandValue::USize(len_b))));
+        self
+    }
+
+    pub fn len_eq_field(mut self, field: String) -> Self {
+        self.len.push(Operation::Eq(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn len_ne_field(mut self, field: String) -> Self {
+        self.len.push(Operation::Ne(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn len_gt_field(mut self, field: String) -> Self {
+        self.len.push(Operation::Gt(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn len_ge_field(mut self, field: String) -> Self {
+        self.len.push(Operation::Ge(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn len_lt_field(mut self, field: String) -> Self {
+        self.len.push(Operation::Lt(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn len_le_field(mut self, field: String) -> Self {
+        self.len.push(Operation::Le(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn len_btwn_field(mut self, field_a: String, field_b: String) -> Self {
+        self.len.push(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b)));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::LazyLock;
+
+    use crate::{
+        operation::{Operand, OperandValue, Operation},
+        schema::{Schema, StrSchema},
+    };
+
+    use super::ArrSchema;
+
+    static ITEM: LazyLock<Box<Schema>> = LazyLock::new(|| Box::new(Schema::from(StrSchema::default())));
+
+    const OPERATION_VALUE_EQ: Operation = Operation::Eq(Operand::Value(OperandValue::USize(1)));
+    const OPERATION_VALUE_NE: Operation = Operation::Ne(Operand::Value(OperandValue::USize(2)));
+    const OPERATION_VALUE_GT: Operation = Operation::Gt(Operand::Value(OperandValue::USize(3)));
+    const OPERATION_VALUE_GE: Operation = Operation::Ge(Operand::Value(OperandValue::USize(4)));
+    const OPERATION_VALUE_LT: Operation = Operation::Lt(Operand::Value(OperandValue::USize(5)));
+    const OPERATION_VALUE_LE: Operation = Operation::Le(Operand::Value(OperandValue::USize(6)));
+    const OPERATION_VALUE_BTWN: Operation = Operation::Btwn(Operand::Value(OperandValue::USize(1)), Operand::Value(OperandValue::USize(9)));
+
+    const FIELD: &str = "user.data.info.tags.min";
+    const FIELD_B: &str = "user.data.info.tags.max";
+
+    static OPERAND_FIELD: LazyLock<Operand> = LazyLock::new(|| Operand::FieldPath(FIELD.into()));
+    static OPERAND_FIELD_B: LazyLock<Operand> = LazyLock::new(|| Operand::FieldPath(FIELD_B.into()));
+
+    static OPERATION_FIELD_EQ: LazyLock<Operation> = LazyLock::new(|| Operation::Eq(OPERAND_FIELD.clone()));
+    static OPERATION_FIELD_NE: LazyLock<Operation> = LazyLock::new(|| Operation::Ne(OPERAND_FIELD.clone()));
+    static OPERATION_FIELD_GT: LazyLock<Operation> = LazyLock::new(|| Operation::Gt(OPERAND_FIELD.clone()));
+    static OPERATION_FIELD_GE: LazyLock<Operation> = LazyLock::new(|| Operation::Ge(OPERAND_FIELD.clone()));
+    static OPERATION_FIELD_LT: LazyLock<Operation> = LazyLock::new(|| Operation::Lt(OPERAND_FIELD.clone()));
+    static OPERATION_FIELD_LE: LazyLock<Operation> = LazyLock::new(|| Operation::Le(OPERAND_FIELD.clone()));
+    static OPERATION_FIELD_BTWN: LazyLock<Operation> = LazyLock::new(|| Operation::Btwn(OPERAND_FIELD.clone(), OPERAND_FIELD_B.clone()));
+
+    #[test]
+    fn arr_schema() {
+        let schema = ArrSchema::from(Schema::from(StrSchema::default()));
+        assert_eq!(schema, ArrSchema { required: true, label: None, item: ITEM.clone(), len: vec![] });
+        assert_eq!(schema.optional(), ArrSchema { required: false, label: None, item: ITEM.clone(), len: vec![] });
+    }
+
+    #[test]
+    fn arr_schema_len_value() {
+        let schema = ArrSchema::from(Schema::from(StrSchema::default()));
+        assert_eq!(schema.clone().len_eq(1), ArrSchema { required: true, label: None, item: ITEM.clone(), len: vec![OPERATION_VALUE_EQ] });
+        assert_eq!(schema.clone().len_ne(2), ArrSchema { required: true, label: None, item: ITEM.clone(), len: vec![OPERATION_VALUE_NE] });
+        assert_eq!(schema.clone().len_gt(3), ArrSchema { required: true, label: None, item: ITEM.clone(), len: vec![OPERATION_VALUE_GT] });
+        assert_eq!(schema.clone().len_ge(4), ArrSchema { required: true, label: None, item: ITEM.clone(), len: vec![OPERATION_VALUE_GE] });
+        assert_eq!(schema.clone().len_lt(5), ArrSchema { required: true, label: None, item: ITEM.clone(), len: vec![OPERATION_VALUE_LT] });
+        assert_eq!(schema.clone().len_le(6), ArrSchema { required: true, label: None, item: ITEM.clone(), len: vec![OPERATION_VALUE_LE] });
+        assert_eq!(schema.len_btwn(1, 9), ArrSchema { required: true, label: None, item: ITEM.clone(), len: vec![OPERATION_VALUE_BTWN] });
+    }
+
+    #[test]
+    fn arr_schema_len_field() {
+        let schema = ArrSchema::from(Schema::from(StrSchema::default()));
+        let validation_eq = schema.clone().len_eq_field(FIELD.into());
+        let validation_ne = schema.clone().len_ne_field(FIELD.into());
+        let validation_gt = schema.clone().len_gt_field(FIELD.into());
+        let validation_ge = schema.clone().len_ge_field(FIELD.into());
+        let validation_lt = schema.clone().len_lt_field(FIELD.into());
+        let validation_le = schema.clone().len_le_field(FIELD.into());
+        let validation_btwn = schema.len_btwn_field(FIELD.into(), FIELD_B.into());
+        assert_eq!(validation_eq, ArrSchema { required: true, label: None, item: ITEM.clone(), len: vec![OPERATION_FIELD_EQ.clone()] });
+        assert_eq!(validation_ne, ArrSchema { required: true, label: None, item: ITEM.clone(), len: vec![OPERATION_FIELD_NE.clone()] });
+        assert_eq!(validation_gt, ArrSchema { required: true, label: None, item: ITEM.clone(), len: vec![OPERATION_FIELD_GT.clone()] });
+        assert_eq!(validation_ge, ArrSchema { required: true, label: None, item: ITEM.clone(), len: vec![OPERATION_FIELD_GE.clone()] });
+        assert_eq!(validation_lt, ArrSchema { required: true, label: None, item: ITEM.clone(), len: vec![OPERATION_FIELD_LT.clone()] });
+        assert_eq!(validation_le, ArrSchema { required: true, label: None, item: ITEM.clone(), len: vec![OPERATION_FIELD_LE.clone()] });
+        assert_eq!(validation_btwn, ArrSchema { required: true, label: None, item: ITEM.clone(), len: vec![OPERATION_FIELD_BTWN.clone()] });
+    }
+}
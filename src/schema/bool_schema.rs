@@ -1,14 +1,19 @@
 use crate::operation::{Operand, OperandValue, Operation};
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BoolSchema {
     pub required: bool,
-    pub operation: Option<Operation>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub label: Option<String>,
+    pub operations: Vec<Operation>,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub default: Option<bool>,
 }
 
 impl Default for BoolSchema {
     fn default() -> Self {
-        BoolSchema { required: true, operation: None }
+        BoolSchema { required: true, label: None, operations: vec![], default: None }
     }
 }
 
@@ -17,63 +22,82 @@ impl BoolSchema {
         BoolSchema { required: false, ..self }
     }
 
-    pub fn eq(self, value: bool) -> Self {
-        BoolSchema { operation: Some(Operation::Eq(Operand::Value(OperandValue::Bool(value)))), ..self }
+    pub fn label(self, label: &str) -> Self {
+        BoolSchema { label: Some(label.into()), ..self }
     }
 
-    pub fn ne(self, value: bool) -> Self {
-        BoolSchema { operation: Some(Operation::Ne(Operand::Value(OperandValue::Bool(value)))), ..self }
+    pub fn default_value(self, value: bool) -> Self {
+        BoolSchema { default: Some(value), ..self }
     }
 
-    pub fn gt(self, value: bool) -> Self {
-        BoolSchema { operation: Some(Operation::Gt(Operand::Value(OperandValue::Bool(value)))), ..self }
+    pub fn eq(mut self, value: bool) -> Self {
+        self.operations.push(Operation::Eq(Operand::Value(OperandValue::Bool(value))));
+        self
     }
 
-    pub fn ge(self, value: bool) -> Self {
-        BoolSchema { operation: Some(Operation::Ge(Operand::Value(OperandValue::Bool(value)))), ..self }
+    pub fn ne(mut self, value: bool) -> Self {
+        self.operations.push(Operation::Ne(Operand::Value(OperandValue::Bool(value))));
+        self
     }
 
-    pub fn lt(self, value: bool) -> Self {
-        BoolSchema { operation: Some(Operation::Lt(Operand::Value(OperandValue::Bool(value)))), ..self }
+    pub fn gt(mut self, value: bool) -> Self {
+        self.operations.push(Operation::Gt(Operand::Value(OperandValue::Bool(value))));
+        self
     }
 
-    pub fn le(self, value: bool) -> Self {
-        BoolSchema { operation: Some(Operation::Le(Operand::Value(OperandValue::Bool(value)))), ..self }
+    pub fn ge(mut self, value: bool) -> Self {
+        self.operations.push(Operation::Ge(Operand::Value(OperandValue::Bool(value))));
+        self
     }
 
-    pub fn btwn(self, value_a: bool, value_b: bool) -> Self {
-        BoolSchema {
-            operation: Some(Operation::Btwn(Operand::Value(OperandValue::Bool(value_a)), Operand::Value(OperandValue::Bool(value_b)))),
-            ..self
-        }
+    pub fn lt(mut self, value: bool) -> Self {
+        self.operations.push(Operation::Lt(Operand::Value(OperandValue::Bool(value))));
+        self
     }
 
-    pub fn eq_field(self, field: String) -> Self {
-        BoolSchema { operation: Some(Operation::Eq(Operand::FieldPath(field))), ..self }
+    pub fn le(mut self, value: bool) -> Self {
+        self.operations.push(Operation::Le(Operand::Value(OperandValue::Bool(value))));
+        self
     }
 
-    pub fn ne_field(self, field: String) -> Self {
-        BoolSchema { operation: Some(Operation::Ne(Operand::FieldPath(field))), ..self }
+    pub fn btwn(mut self, value_a: bool, value_b: bool) -> Self {
+        self.operations.push(Operation::Btwn(Operand::Value(OperandValue::Bool(value_a)), Operand::Value(OperandValue::Bool(value_b))));
+        self
     }
 
-    pub fn gt_field(self, field: String) -> Self {
-        BoolSchema { operation: Some(Operation::Gt(Operand::FieldPath(field))), ..self }
+    pub fn eq_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Eq(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn ge_field(self, field: String) -> Self {
-        BoolSchema { operation: Some(Operation::Ge(Operand::FieldPath(field))), ..self }
+    pub fn ne_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Ne(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn lt_field(self, field: String) -> Self {
-        BoolSchema { operation: Some(Operation::Lt(Operand::FieldPath(field))), ..self }
+    pub fn gt_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Gt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn le_field(self, field: String) -> Self {
-        BoolSchema { operation: Some(Operation::Le(Operand::FieldPath(field))), ..self }
+    pub fn ge_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Ge(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn btwn_field(self, field_a: String, field_b: String) -> Self {
-        BoolSchema { operation: Some(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b))), ..self }
+    pub fn lt_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Lt(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn le_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Le(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn btwn_field(mut self, field_a: String, field_b: String) -> Self {
+        self.operations.push(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b)));
+        self
     }
 }
 
@@ -109,19 +133,26 @@ mod tests {
 
     #[test]
     fn bool_schema() {
-        assert_eq!(BoolSchema::default(), BoolSchema { required: true, operation: None });
-        assert_eq!(BoolSchema::default().optional(), BoolSchema { required: false, operation: None });
+        assert_eq!(BoolSchema::default(), BoolSchema { required: true, label: None, operations: vec![], default: None });
+        assert_eq!(BoolSchema::default().optional(), BoolSchema { required: false, label: None, operations: vec![], default: None });
+        assert_eq!(
+            BoolSchema::default().optional().default_value(true),
+            BoolSchema { required: false, label: None, operations: vec![], default: Some(true) }
+        );
     }
 
     #[test]
     fn bool_schema_operation_value() {
-        assert_eq!(BoolSchema::default().eq(false), BoolSchema { required: true, operation: Some(OPERATION_VALUE_EQ) });
-        assert_eq!(BoolSchema::default().ne(false), BoolSchema { required: true, operation: Some(OPERATION_VALUE_NE) });
-        assert_eq!(BoolSchema::default().gt(false), BoolSchema { required: true, operation: Some(OPERATION_VALUE_GT) });
-        assert_eq!(BoolSchema::default().ge(false), BoolSchema { required: true, operation: Some(OPERATION_VALUE_GE) });
-        assert_eq!(BoolSchema::default().lt(false), BoolSchema { required: true, operation: Some(OPERATION_VALUE_LT) });
-        assert_eq!(BoolSchema::default().le(false), BoolSchema { required: true, operation: Some(OPERATION_VALUE_LE) });
-        assert_eq!(BoolSchema::default().btwn(false, true), BoolSchema { required: true, operation: Some(OPERATION_VALUE_BTWN) });
+        assert_eq!(BoolSchema::default().eq(false), BoolSchema { required: true, label: None, operations: vec![OPERATION_VALUE_EQ], default: None });
+        assert_eq!(BoolSchema::default().ne(false), BoolSchema { required: true, label: None, operations: vec![OPERATION_VALUE_NE], default: None });
+        assert_eq!(BoolSchema::default().gt(false), BoolSchema { required: true, label: None, operations: vec![OPERATION_VALUE_GT], default: None });
+        assert_eq!(BoolSchema::default().ge(false), BoolSchema { required: true, label: None, operations: vec![OPERATION_VALUE_GE], default: None });
+        assert_eq!(BoolSchema::default().lt(false), BoolSchema { required: true, label: None, operations: vec![OPERATION_VALUE_LT], default: None });
+        assert_eq!(BoolSchema::default().le(false), BoolSchema { required: true, label: None, operations: vec![OPERATION_VALUE_LE], default: None });
+        assert_eq!(
+            BoolSchema::default().btwn(false, true),
+            BoolSchema { required: true, label: None, operations: vec![OPERATION_VALUE_BTWN], default: None }
+        );
     }
 
     #[test]
@@ -133,12 +164,12 @@ mod tests {
         let validation_lt = BoolSchema::default().lt_field(FIELD.into());
         let validation_le = BoolSchema::default().le_field(FIELD.into());
         let validation_btwn = BoolSchema::default().btwn_field(FIELD.into(), FIELD_B.into());
-        assert_eq!(validation_eq, BoolSchema { required: true, operation: Some(OPERATION_FIELD_EQ.clone()) });
-        assert_eq!(validation_ne, BoolSchema { required: true, operation: Some(OPERATION_FIELD_NE.clone()) });
-        assert_eq!(validation_gt, BoolSchema { required: true, operation: Some(OPERATION_FIELD_GT.clone()) });
-        assert_eq!(validation_ge, BoolSchema { required: true, operation: Some(OPERATION_FIELD_GE.clone()) });
-        assert_eq!(validation_lt, BoolSchema { required: true, operation: Some(OPERATION_FIELD_LT.clone()) });
-        assert_eq!(validation_le, BoolSchema { required: true, operation: Some(OPERATION_FIELD_LE.clone()) });
-        assert_eq!(validation_btwn, BoolSchema { required: true, operation: Some(OPERATION_FIELD_BTWN.clone()) });
+        assert_eq!(validation_eq, BoolSchema { required: true, label: None, operations: vec![OPERATION_FIELD_EQ.clone()], default: None });
+        assert_eq!(validation_ne, BoolSchema { required: true, label: None, operations: vec![OPERATION_FIELD_NE.clone()], default: None });
+        assert_eq!(validation_gt, BoolSchema { required: true, label: None, operations: vec![OPERATION_FIELD_GT.clone()], default: None });
+        assert_eq!(validation_ge, BoolSchema { required: true, label: None, operations: vec![OPERATION_FIELD_GE.clone()], default: None });
+        assert_eq!(validation_lt, BoolSchema { required: true, label: None, operations: vec![OPERATION_FIELD_LT.clone()], default: None });
+        assert_eq!(validation_le, BoolSchema { required: true, label: None, operations: vec![OPERATION_FIELD_LE.clone()], default: None });
+        assert_eq!(validation_btwn, BoolSchema { required: true, label: None, operations: vec![OPERATION_FIELD_BTWN.clone()], default: None });
     }
 }
@@ -0,0 +1,162 @@
+use std::{collections::BTreeMap, fmt, sync::Arc};
+#[cfg(feature = "async")]
+use std::{future::Future, pin::Pin};
+
+use crate::{operation::OperandValue, value::Value};
+
+use super::Schema;
+
+pub type CustomParams = BTreeMap<String, OperandValue>;
+
+pub type CustomFn = Arc<dyn Fn(&Value, &Value) -> Result<(), CustomParams> + Send + Sync>;
+
+#[cfg(feature = "async")]
+pub type AsyncCustomFn = Arc<dyn Fn(Value, Value) -> Pin<Box<dyn Future<Output = Result<(), CustomParams>> + Send>> + Send + Sync>;
+
+#[derive(Clone)]
+pub struct CustomValidator {
+    pub code: String,
+    pub validate: CustomFn,
+}
+
+impl fmt::Debug for CustomValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CustomValidator({:?})", self.code)
+    }
+}
+
+impl PartialEq for CustomValidator {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code
+    }
+}
+
+#[cfg(feature = "async")]
+#[derive(Clone)]
+pub struct AsyncCustomValidator {
+    pub code: String,
+    pub validate: AsyncCustomFn,
+}
+
+#[cfg(feature = "async")]
+impl fmt::Debug for AsyncCustomValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "AsyncCustomValidator({:?})", self.code)
+    }
+}
+
+#[cfg(feature = "async")]
+impl PartialEq for AsyncCustomValidator {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CustomSchema {
+    pub schema: Box<Schema>,
+    pub validators: Vec<CustomValidator>,
+    #[cfg(feature = "async")]
+    pub async_validators: Vec<AsyncCustomValidator>,
+}
+
+impl From<Schema> for CustomSchema {
+    fn from(schema: Schema) -> Self {
+        CustomSchema {
+            schema: Box::new(schema),
+            validators: vec![],
+            #[cfg(feature = "async")]
+            async_validators: vec![],
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CustomSchema {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.schema.serialize(serializer)
+    }
+}
+
+impl CustomSchema {
+    pub fn optional(self) -> Self {
+        CustomSchema { schema: Box::new(self.schema.optional()), ..self }
+    }
+
+    pub fn label(self, label: &str) -> Self {
+        CustomSchema { schema: Box::new(self.schema.label(label)), ..self }
+    }
+
+    pub fn validator<F>(mut self, code: &str, validate: F) -> Self
+    where
+        F: Fn(&Value, &Value) -> Result<(), CustomParams> + Send + Sync + 'static,
+    {
+        self.validators.push(CustomValidator { code: code.into(), validate: Arc::new(validate) });
+        self
+    }
+
+    #[cfg(feature = "async")]
+    pub fn async_validator<F, Fut>(mut self, code: &str, validate: F) -> Self
+    where
+        F: Fn(Value, Value) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), CustomParams>> + Send + 'static,
+    {
+        let validate: AsyncCustomFn = Arc::new(move |value, root| Box::pin(validate(value, root)));
+        self.async_validators.push(AsyncCustomValidator { code: code.into(), validate });
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        schema::{Schema, StrSchema},
+        value::Value,
+    };
+
+    use super::CustomSchema;
+
+    #[test]
+    fn custom_schema() {
+        let schema = CustomSchema::from(Schema::from(StrSchema::default())).validator("not_reserved", |value, _| match value {
+            Value::Str(str_value) if str_value == "root" => Err(BTreeMap::new()),
+            _ => Ok(()),
+        });
+        assert_eq!(schema.schema, Box::new(Schema::from(StrSchema::default())));
+        assert_eq!(schema.validators.len(), 1);
+        assert_eq!(schema.validators[0].code, "not_reserved");
+        assert_eq!((schema.validators[0].validate)(&Value::from("root"), &Value::None), Err(BTreeMap::new()));
+        assert_eq!((schema.validators[0].validate)(&Value::from("paul"), &Value::None), Ok(()));
+        assert_eq!(format!("{:?}", schema.validators[0]), r#"CustomValidator("not_reserved")"#);
+    }
+
+    #[test]
+    fn custom_schema_optional_label() {
+        let schema = CustomSchema::from(Schema::from(StrSchema::default())).validator("a", |_, _| Ok(()));
+        assert_eq!(schema.clone().optional().schema, Box::new(Schema::from(StrSchema::default().optional())));
+        assert_eq!(schema.clone().label("Username").schema, Box::new(Schema::from(StrSchema::default().label("Username"))));
+        assert_eq!(schema.clone().optional().validators, schema.validators);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn custom_schema_async_validator() {
+        let schema = CustomSchema::from(Schema::from(StrSchema::default())).async_validator("email_unique", |value, _| async move {
+            match value {
+                Value::Str(email) if email == "paul@gmail.com" => Err(BTreeMap::new()),
+                _ => Ok(()),
+            }
+        });
+        assert_eq!(schema.validators, vec![]);
+        assert_eq!(schema.async_validators.len(), 1);
+        assert_eq!(schema.async_validators[0].code, "email_unique");
+        assert_eq!(
+            futures_executor::block_on((schema.async_validators[0].validate)(Value::from("paul@gmail.com"), Value::None)),
+            Err(BTreeMap::new())
+        );
+        assert_eq!(futures_executor::block_on((schema.async_validators[0].validate)(Value::from("john@gmail.com"), Value::None)), Ok(()));
+        assert_eq!(format!("{:?}", schema.async_validators[0]), r#"AsyncCustomValidator("email_unique")"#);
+    }
+}
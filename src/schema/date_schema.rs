@@ -1,14 +1,17 @@
-use crate::operation::{Operand, OperandValue, Operation};
+use crate::operation::{Date, Operand, OperandValue, Operation, Relative};
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DateSchema {
     pub required: bool,
-    pub operation: Option<Operation>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub label: Option<String>,
+    pub operations: Vec<Operation>,
 }
 
 impl Default for DateSchema {
     fn default() -> Self {
-        DateSchema { required: true, operation: None }
+        DateSchema { required: true, label: None, operations: vec![] }
     }
 }
 
@@ -17,67 +20,103 @@ impl DateSchema {
         DateSchema { required: false, ..self }
     }
 
-    pub fn eq(self, value: String) -> Self {
-        DateSchema { operation: Some(Operation::Eq(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn label(self, label: &str) -> Self {
+        DateSchema { label: Some(label.into()), ..self }
     }
 
-    pub fn ne(self, value: String) -> Self {
-        DateSchema { operation: Some(Operation::Ne(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn eq(mut self, value: Date) -> Self {
+        self.operations.push(Operation::Eq(Operand::Value(OperandValue::Date(value))));
+        self
     }
 
-    pub fn gt(self, value: String) -> Self {
-        DateSchema { operation: Some(Operation::Gt(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn ne(mut self, value: Date) -> Self {
+        self.operations.push(Operation::Ne(Operand::Value(OperandValue::Date(value))));
+        self
     }
 
-    pub fn ge(self, value: String) -> Self {
-        DateSchema { operation: Some(Operation::Ge(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn gt(mut self, value: Date) -> Self {
+        self.operations.push(Operation::Gt(Operand::Value(OperandValue::Date(value))));
+        self
     }
 
-    pub fn lt(self, value: String) -> Self {
-        DateSchema { operation: Some(Operation::Lt(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn ge(mut self, value: Date) -> Self {
+        self.operations.push(Operation::Ge(Operand::Value(OperandValue::Date(value))));
+        self
     }
 
-    pub fn le(self, value: String) -> Self {
-        DateSchema { operation: Some(Operation::Le(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn lt(mut self, value: Date) -> Self {
+        self.operations.push(Operation::Lt(Operand::Value(OperandValue::Date(value))));
+        self
     }
 
-    pub fn btwn(self, value_a: String, value_b: String) -> Self {
-        DateSchema {
-            operation: Some(Operation::Btwn(Operand::Value(OperandValue::Str(value_a)), Operand::Value(OperandValue::Str(value_b)))),
-            ..self
-        }
+    pub fn le(mut self, value: Date) -> Self {
+        self.operations.push(Operation::Le(Operand::Value(OperandValue::Date(value))));
+        self
     }
 
-    pub fn eq_field(self, field: String) -> Self {
-        DateSchema { operation: Some(Operation::Eq(Operand::FieldPath(field))), ..self }
+    pub fn btwn(mut self, value_a: Date, value_b: Date) -> Self {
+        self.operations.push(Operation::Btwn(Operand::Value(OperandValue::Date(value_a)), Operand::Value(OperandValue::Date(value_b))));
+        self
     }
 
-    pub fn ne_field(self, field: String) -> Self {
-        DateSchema { operation: Some(Operation::Ne(Operand::FieldPath(field))), ..self }
+    pub fn eq_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Eq(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn gt_field(self, field: String) -> Self {
-        DateSchema { operation: Some(Operation::Gt(Operand::FieldPath(field))), ..self }
+    pub fn ne_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Ne(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn ge_field(self, field: String) -> Self {
-        DateSchema { operation: Some(Operation::Ge(Operand::FieldPath(field))), ..self }
+    pub fn gt_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Gt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn lt_field(self, field: String) -> Self {
-        DateSchema { operation: Some(Operation::Lt(Operand::FieldPath(field))), ..self }
+    pub fn ge_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Ge(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn le_field(self, field: String) -> Self {
-        DateSchema { operation: Some(Operation::Le(Operand::FieldPath(field))), ..self }
+    pub fn lt_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Lt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn btwn_field(self, field_a: String, field_b: String) -> Self {
-        DateSchema { operation: Some(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b))), ..self }
+    pub fn le_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Le(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn unix_epoch(self) -> Self {
-        DateSchema { operation: Some(Operation::Ge(Operand::Value(OperandValue::Str("1970-01-01".into())))), ..self }
+    pub fn btwn_field(mut self, field_a: String, field_b: String) -> Self {
+        self.operations.push(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b)));
+        self
+    }
+
+    pub fn unix_epoch(mut self) -> Self {
+        self.operations.push(Operation::Ge(Operand::Value(OperandValue::Date(Date { year: 1970, month: 1, day: 1 }))));
+        self
+    }
+
+    pub fn le_today(mut self) -> Self {
+        self.operations.push(Operation::Le(Operand::Now(Relative::Days(0))));
+        self
+    }
+
+    pub fn ge_today(mut self) -> Self {
+        self.operations.push(Operation::Ge(Operand::Now(Relative::Days(0))));
+        self
+    }
+
+    pub fn age_ge(mut self, years: u32) -> Self {
+        self.operations.push(Operation::Le(Operand::Now(Relative::Years(-(years as i64)))));
+        self
+    }
+
+    pub fn within_days(mut self, days: u32) -> Self {
+        self.operations.push(Operation::Btwn(Operand::Now(Relative::Days(0)), Operand::Now(Relative::Days(days as i64))));
+        self
     }
 }
 
@@ -85,15 +124,15 @@ impl DateSchema {
 mod tests {
     use std::sync::LazyLock;
 
-    use crate::operation::{Operand, OperandValue, Operation};
+    use crate::operation::{Operand, OperandValue, Operation, Relative, parse_date};
 
     use super::DateSchema;
 
     const VALUE: &str = "2027-08-02";
     const VALUE_B: &str = "2030-11-25";
 
-    static OPERAND_VALUE: LazyLock<Operand> = LazyLock::new(|| Operand::Value(OperandValue::from(VALUE)));
-    static OPERAND_VALUE_B: LazyLock<Operand> = LazyLock::new(|| Operand::Value(OperandValue::from(VALUE_B)));
+    static OPERAND_VALUE: LazyLock<Operand> = LazyLock::new(|| Operand::Value(OperandValue::Date(parse_date(VALUE).unwrap())));
+    static OPERAND_VALUE_B: LazyLock<Operand> = LazyLock::new(|| Operand::Value(OperandValue::Date(parse_date(VALUE_B).unwrap())));
 
     static OPERATION_VALUE_EQ: LazyLock<Operation> = LazyLock::new(|| Operation::Eq(OPERAND_VALUE.clone()));
     static OPERATION_VALUE_NE: LazyLock<Operation> = LazyLock::new(|| Operation::Ne(OPERAND_VALUE.clone()));
@@ -119,26 +158,26 @@ mod tests {
 
     #[test]
     fn date_schema() {
-        assert_eq!(DateSchema::default(), DateSchema { required: true, operation: None });
-        assert_eq!(DateSchema::default().optional(), DateSchema { required: false, operation: None });
+        assert_eq!(DateSchema::default(), DateSchema { required: true, label: None, operations: vec![] });
+        assert_eq!(DateSchema::default().optional(), DateSchema { required: false, label: None, operations: vec![] });
     }
 
     #[test]
     fn date_schema_operation_value() {
-        let validation_eq = DateSchema::default().eq(VALUE.into());
-        let validation_ne = DateSchema::default().ne(VALUE.into());
-        let validation_gt = DateSchema::default().gt(VALUE.into());
-        let validation_ge = DateSchema::default().ge(VALUE.into());
-        let validation_lt = DateSchema::default().lt(VALUE.into());
-        let validation_le = DateSchema::default().le(VALUE.into());
-        let validation_btwn = DateSchema::default().btwn(VALUE.into(), VALUE_B.into());
-        assert_eq!(validation_eq, DateSchema { required: true, operation: Some(OPERATION_VALUE_EQ.clone()) });
-        assert_eq!(validation_ne, DateSchema { required: true, operation: Some(OPERATION_VALUE_NE.clone()) });
-        assert_eq!(validation_gt, DateSchema { required: true, operation: Some(OPERATION_VALUE_GT.clone()) });
-        assert_eq!(validation_ge, DateSchema { required: true, operation: Some(OPERATION_VALUE_GE.clone()) });
-        assert_eq!(validation_lt, DateSchema { required: true, operation: Some(OPERATION_VALUE_LT.clone()) });
-        assert_eq!(validation_le, DateSchema { required: true, operation: Some(OPERATION_VALUE_LE.clone()) });
-        assert_eq!(validation_btwn, DateSchema { required: true, operation: Some(OPERATION_VALUE_BTWN.clone()) });
+        let validation_eq = DateSchema::default().eq(parse_date(VALUE).unwrap());
+        let validation_ne = DateSchema::default().ne(parse_date(VALUE).unwrap());
+        let validation_gt = DateSchema::default().gt(parse_date(VALUE).unwrap());
+        let validation_ge = DateSchema::default().ge(parse_date(VALUE).unwrap());
+        let validation_lt = DateSchema::default().lt(parse_date(VALUE).unwrap());
+        let validation_le = DateSchema::default().le(parse_date(VALUE).unwrap());
+        let validation_btwn = DateSchema::default().btwn(parse_date(VALUE).unwrap(), parse_date(VALUE_B).unwrap());
+        assert_eq!(validation_eq, DateSchema { required: true, label: None, operations: vec![OPERATION_VALUE_EQ.clone()] });
+        assert_eq!(validation_ne, DateSchema { required: true, label: None, operations: vec![OPERATION_VALUE_NE.clone()] });
+        assert_eq!(validation_gt, DateSchema { required: true, label: None, operations: vec![OPERATION_VALUE_GT.clone()] });
+        assert_eq!(validation_ge, DateSchema { required: true, label: None, operations: vec![OPERATION_VALUE_GE.clone()] });
+        assert_eq!(validation_lt, DateSchema { required: true, label: None, operations: vec![OPERATION_VALUE_LT.clone()] });
+        assert_eq!(validation_le, DateSchema { required: true, label: None, operations: vec![OPERATION_VALUE_LE.clone()] });
+        assert_eq!(validation_btwn, DateSchema { required: true, label: None, operations: vec![OPERATION_VALUE_BTWN.clone()] });
     }
 
     #[test]
@@ -150,20 +189,39 @@ mod tests {
         let validation_lt = DateSchema::default().lt_field(FIELD.into());
         let validation_le = DateSchema::default().le_field(FIELD.into());
         let validation_btwn = DateSchema::default().btwn_field(FIELD.into(), FIELD_B.into());
-        assert_eq!(validation_eq, DateSchema { required: true, operation: Some(OPERATION_FIELD_EQ.clone()) });
-        assert_eq!(validation_ne, DateSchema { required: true, operation: Some(OPERATION_FIELD_NE.clone()) });
-        assert_eq!(validation_gt, DateSchema { required: true, operation: Some(OPERATION_FIELD_GT.clone()) });
-        assert_eq!(validation_ge, DateSchema { required: true, operation: Some(OPERATION_FIELD_GE.clone()) });
-        assert_eq!(validation_lt, DateSchema { required: true, operation: Some(OPERATION_FIELD_LT.clone()) });
-        assert_eq!(validation_le, DateSchema { required: true, operation: Some(OPERATION_FIELD_LE.clone()) });
-        assert_eq!(validation_btwn, DateSchema { required: true, operation: Some(OPERATION_FIELD_BTWN.clone()) });
+        assert_eq!(validation_eq, DateSchema { required: true, label: None, operations: vec![OPERATION_FIELD_EQ.clone()] });
+        assert_eq!(validation_ne, DateSchema { required: true, label: None, operations: vec![OPERATION_FIELD_NE.clone()] });
+        assert_eq!(validation_gt, DateSchema { required: true, label: None, operations: vec![OPERATION_FIELD_GT.clone()] });
+        assert_eq!(validation_ge, DateSchema { required: true, label: None, operations: vec![OPERATION_FIELD_GE.clone()] });
+        assert_eq!(validation_lt, DateSchema { required: true, label: None, operations: vec![OPERATION_FIELD_LT.clone()] });
+        assert_eq!(validation_le, DateSchema { required: true, label: None, operations: vec![OPERATION_FIELD_LE.clone()] });
+        assert_eq!(validation_btwn, DateSchema { required: true, label: None, operations: vec![OPERATION_FIELD_BTWN.clone()] });
     }
 
     #[test]
     fn date_schema_unix_epoch() {
         assert_eq!(
             DateSchema::default().unix_epoch(),
-            DateSchema { required: true, operation: Some(Operation::Ge(Operand::Value(OperandValue::Str("1970-01-01".into())))) }
+            DateSchema {
+                required: true,
+                label: None,
+                operations: vec![Operation::Ge(Operand::Value(OperandValue::Date(parse_date("1970-01-01").unwrap())))]
+            }
+        );
+    }
+
+    #[test]
+    fn date_schema_relative() {
+        let today = Operand::Now(Relative::Days(0));
+        assert_eq!(DateSchema::default().le_today(), DateSchema { required: true, label: None, operations: vec![Operation::Le(today.clone())] });
+        assert_eq!(DateSchema::default().ge_today(), DateSchema { required: true, label: None, operations: vec![Operation::Ge(today.clone())] });
+        assert_eq!(
+            DateSchema::default().age_ge(18),
+            DateSchema { required: true, label: None, operations: vec![Operation::Le(Operand::Now(Relative::Years(-18)))] }
+        );
+        assert_eq!(
+            DateSchema::default().within_days(30),
+            DateSchema { required: true, label: None, operations: vec![Operation::Btwn(today, Operand::Now(Relative::Days(30)))] }
         );
     }
 }
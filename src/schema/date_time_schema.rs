@@ -1,14 +1,17 @@
-use crate::operation::{Operand, OperandValue, Operation};
+use crate::operation::{Date, DateTime, Operand, OperandValue, Operation, Relative, Time};
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DateTimeSchema {
     pub required: bool,
-    pub operation: Option<Operation>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub label: Option<String>,
+    pub operations: Vec<Operation>,
 }
 
 impl Default for DateTimeSchema {
     fn default() -> Self {
-        DateTimeSchema { required: true, operation: None }
+        DateTimeSchema { required: true, label: None, operations: vec![] }
     }
 }
 
@@ -17,67 +20,101 @@ impl DateTimeSchema {
         DateTimeSchema { required: false, ..self }
     }
 
-    pub fn eq(self, value: String) -> Self {
-        DateTimeSchema { operation: Some(Operation::Eq(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn label(self, label: &str) -> Self {
+        DateTimeSchema { label: Some(label.into()), ..self }
     }
 
-    pub fn ne(self, value: String) -> Self {
-        DateTimeSchema { operation: Some(Operation::Ne(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn eq(mut self, value: DateTime) -> Self {
+        self.operations.push(Operation::Eq(Operand::Value(OperandValue::DateTime(value))));
+        self
     }
 
-    pub fn gt(self, value: String) -> Self {
-        DateTimeSchema { operation: Some(Operation::Gt(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn ne(mut self, value: DateTime) -> Self {
+        self.operations.push(Operation::Ne(Operand::Value(OperandValue::DateTime(value))));
+        self
     }
 
-    pub fn ge(self, value: String) -> Self {
-        DateTimeSchema { operation: Some(Operation::Ge(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn gt(mut self, value: DateTime) -> Self {
+        self.operations.push(Operation::Gt(Operand::Value(OperandValue::DateTime(value))));
+        self
     }
 
-    pub fn lt(self, value: String) -> Self {
-        DateTimeSchema { operation: Some(Operation::Lt(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn ge(mut self, value: DateTime) -> Self {
+        self.operations.push(Operation::Ge(Operand::Value(OperandValue::DateTime(value))));
+        self
     }
 
-    pub fn le(self, value: String) -> Self {
-        DateTimeSchema { operation: Some(Operation::Le(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn lt(mut self, value: DateTime) -> Self {
+        self.operations.push(Operation::Lt(Operand::Value(OperandValue::DateTime(value))));
+        self
     }
 
-    pub fn btwn(self, value_a: String, value_b: String) -> Self {
-        DateTimeSchema {
-            operation: Some(Operation::Btwn(Operand::Value(OperandValue::Str(value_a)), Operand::Value(OperandValue::Str(value_b)))),
-            ..self
-        }
+    pub fn le(mut self, value: DateTime) -> Self {
+        self.operations.push(Operation::Le(Operand::Value(OperandValue::DateTime(value))));
+        self
     }
 
-    pub fn eq_field(self, field: String) -> Self {
-        DateTimeSchema { operation: Some(Operation::Eq(Operand::FieldPath(field))), ..self }
+    pub fn btwn(mut self, value_a: DateTime, value_b: DateTime) -> Self {
+        self.operations.push(Operation::Btwn(Operand::Value(OperandValue::DateTime(value_a)), Operand::Value(OperandValue::DateTime(value_b))));
+        self
     }
 
-    pub fn ne_field(self, field: String) -> Self {
-        DateTimeSchema { operation: Some(Operation::Ne(Operand::FieldPath(field))), ..self }
+    pub fn eq_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Eq(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn gt_field(self, field: String) -> Self {
-        DateTimeSchema { operation: Some(Operation::Gt(Operand::FieldPath(field))), ..self }
+    pub fn ne_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Ne(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn ge_field(self, field: String) -> Self {
-        DateTimeSchema { operation: Some(Operation::Ge(Operand::FieldPath(field))), ..self }
+    pub fn gt_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Gt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn lt_field(self, field: String) -> Self {
-        DateTimeSchema { operation: Some(Operation::Lt(Operand::FieldPath(field))), ..self }
+    pub fn ge_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Ge(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn le_field(self, field: String) -> Self {
-        DateTimeSchema { operation: Some(Operation::Le(Operand::FieldPath(field))), ..self }
+    pub fn lt_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Lt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn btwn_field(self, field_a: String, field_b: String) -> Self {
-        DateTimeSchema { operation: Some(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b))), ..self }
+    pub fn le_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Le(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn unix_epoch(self) -> Self {
-        DateTimeSchema { operation: Some(Operation::Ge(Operand::Value(OperandValue::Str("1970-01-01T00:00Z".into())))), ..self }
+    pub fn btwn_field(mut self, field_a: String, field_b: String) -> Self {
+        self.operations.push(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b)));
+        self
+    }
+
+    pub fn unix_epoch(mut self) -> Self {
+        self.operations.push(Operation::Ge(Operand::Value(OperandValue::DateTime(DateTime {
+            date: Date { year: 1970, month: 1, day: 1 },
+            time: Time { hour: 0, minute: 0, second: 0, nanosecond: 0 },
+        }))));
+        self
+    }
+
+    pub fn le_now(mut self) -> Self {
+        self.operations.push(Operation::Le(Operand::Now(Relative::Days(0))));
+        self
+    }
+
+    pub fn ge_now(mut self) -> Self {
+        self.operations.push(Operation::Ge(Operand::Now(Relative::Days(0))));
+        self
+    }
+
+    pub fn within_days(mut self, days: u32) -> Self {
+        self.operations.push(Operation::Btwn(Operand::Now(Relative::Days(0)), Operand::Now(Relative::Days(days as i64))));
+        self
     }
 }
 
@@ -85,15 +122,15 @@ impl DateTimeSchema {
 mod tests {
     use std::sync::LazyLock;
 
-    use crate::operation::{Operand, OperandValue, Operation};
+    use crate::operation::{Operand, OperandValue, Operation, Relative, parse_date_time};
 
     use super::DateTimeSchema;
 
     const VALUE: &str = "2027-08-02T10:27Z";
     const VALUE_B: &str = "2030-11-25T03:01Z";
 
-    static OPERAND_VALUE: LazyLock<Operand> = LazyLock::new(|| Operand::Value(OperandValue::from(VALUE)));
-    static OPERAND_VALUE_B: LazyLock<Operand> = LazyLock::new(|| Operand::Value(OperandValue::from(VALUE_B)));
+    static OPERAND_VALUE: LazyLock<Operand> = LazyLock::new(|| Operand::Value(OperandValue::DateTime(parse_date_time(VALUE).unwrap())));
+    static OPERAND_VALUE_B: LazyLock<Operand> = LazyLock::new(|| Operand::Value(OperandValue::DateTime(parse_date_time(VALUE_B).unwrap())));
 
     static OPERATION_VALUE_EQ: LazyLock<Operation> = LazyLock::new(|| Operation::Eq(OPERAND_VALUE.clone()));
     static OPERATION_VALUE_NE: LazyLock<Operation> = LazyLock::new(|| Operation::Ne(OPERAND_VALUE.clone()));
@@ -119,26 +156,26 @@ mod tests {
 
     #[test]
     fn date_time_schema() {
-        assert_eq!(DateTimeSchema::default(), DateTimeSchema { required: true, operation: None });
-        assert_eq!(DateTimeSchema::default().optional(), DateTimeSchema { required: false, operation: None });
+        assert_eq!(DateTimeSchema::default(), DateTimeSchema { required: true, label: None, operations: vec![] });
+        assert_eq!(DateTimeSchema::default().optional(), DateTimeSchema { required: false, label: None, operations: vec![] });
     }
 
     #[test]
     fn date_time_schema_operation_value() {
-        let validation_eq = DateTimeSchema::default().eq(VALUE.into());
-        let validation_ne = DateTimeSchema::default().ne(VALUE.into());
-        let validation_gt = DateTimeSchema::default().gt(VALUE.into());
-        let validation_ge = DateTimeSchema::default().ge(VALUE.into());
-        let validation_lt = DateTimeSchema::default().lt(VALUE.into());
-        let validation_le = DateTimeSchema::default().le(VALUE.into());
-        let validation_btwn = DateTimeSchema::default().btwn(VALUE.into(), VALUE_B.into());
-        assert_eq!(validation_eq, DateTimeSchema { required: true, operation: Some(OPERATION_VALUE_EQ.clone()) });
-        assert_eq!(validation_ne, DateTimeSchema { required: true, operation: Some(OPERATION_VALUE_NE.clone()) });
-        assert_eq!(validation_gt, DateTimeSchema { required: true, operation: Some(OPERATION_VALUE_GT.clone()) });
-        assert_eq!(validation_ge, DateTimeSchema { required: true, operation: Some(OPERATION_VALUE_GE.clone()) });
-        assert_eq!(validation_lt, DateTimeSchema { required: true, operation: Some(OPERATION_VALUE_LT.clone()) });
-        assert_eq!(validation_le, DateTimeSchema { required: true, operation: Some(OPERATION_VALUE_LE.clone()) });
-        assert_eq!(validation_btwn, DateTimeSchema { required: true, operation: Some(OPERATION_VALUE_BTWN.clone()) });
+        let validation_eq = DateTimeSchema::default().eq(parse_date_time(VALUE).unwrap());
+        let validation_ne = DateTimeSchema::default().ne(parse_date_time(VALUE).unwrap());
+        let validation_gt = DateTimeSchema::default().gt(parse_date_time(VALUE).unwrap());
+        let validation_ge = DateTimeSchema::default().ge(parse_date_time(VALUE).unwrap());
+        let validation_lt = DateTimeSchema::default().lt(parse_date_time(VALUE).unwrap());
+        let validation_le = DateTimeSchema::default().le(parse_date_time(VALUE).unwrap());
+        let validation_btwn = DateTimeSchema::default().btwn(parse_date_time(VALUE).unwrap(), parse_date_time(VALUE_B).unwrap());
+        assert_eq!(validation_eq, DateTimeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_EQ.clone()] });
+        assert_eq!(validation_ne, DateTimeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_NE.clone()] });
+        assert_eq!(validation_gt, DateTimeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_GT.clone()] });
+        assert_eq!(validation_ge, DateTimeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_GE.clone()] });
+        assert_eq!(validation_lt, DateTimeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_LT.clone()] });
+        assert_eq!(validation_le, DateTimeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_LE.clone()] });
+        assert_eq!(validation_btwn, DateTimeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_BTWN.clone()] });
     }
 
     #[test]
@@ -150,20 +187,35 @@ mod tests {
         let validation_lt = DateTimeSchema::default().lt_field(FIELD.into());
         let validation_le = DateTimeSchema::default().le_field(FIELD.into());
         let validation_btwn = DateTimeSchema::default().btwn_field(FIELD.into(), FIELD_B.into());
-        assert_eq!(validation_eq, DateTimeSchema { required: true, operation: Some(OPERATION_FIELD_EQ.clone()) });
-        assert_eq!(validation_ne, DateTimeSchema { required: true, operation: Some(OPERATION_FIELD_NE.clone()) });
-        assert_eq!(validation_gt, DateTimeSchema { required: true, operation: Some(OPERATION_FIELD_GT.clone()) });
-        assert_eq!(validation_ge, DateTimeSchema { required: true, operation: Some(OPERATION_FIELD_GE.clone()) });
-        assert_eq!(validation_lt, DateTimeSchema { required: true, operation: Some(OPERATION_FIELD_LT.clone()) });
-        assert_eq!(validation_le, DateTimeSchema { required: true, operation: Some(OPERATION_FIELD_LE.clone()) });
-        assert_eq!(validation_btwn, DateTimeSchema { required: true, operation: Some(OPERATION_FIELD_BTWN.clone()) });
+        assert_eq!(validation_eq, DateTimeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_EQ.clone()] });
+        assert_eq!(validation_ne, DateTimeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_NE.clone()] });
+        assert_eq!(validation_gt, DateTimeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_GT.clone()] });
+        assert_eq!(validation_ge, DateTimeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_GE.clone()] });
+        assert_eq!(validation_lt, DateTimeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_LT.clone()] });
+        assert_eq!(validation_le, DateTimeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_LE.clone()] });
+        assert_eq!(validation_btwn, DateTimeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_BTWN.clone()] });
     }
 
     #[test]
     fn date_time_schema_unix_epoch() {
         assert_eq!(
             DateTimeSchema::default().unix_epoch(),
-            DateTimeSchema { required: true, operation: Some(Operation::Ge(Operand::Value(OperandValue::Str("1970-01-01T00:00Z".into())))) }
+            DateTimeSchema {
+                required: true,
+                label: None,
+                operations: vec![Operation::Ge(Operand::Value(OperandValue::DateTime(parse_date_time("1970-01-01T00:00Z").unwrap())))]
+            }
+        );
+    }
+
+    #[test]
+    fn date_time_schema_relative() {
+        let now = Operand::Now(Relative::Days(0));
+        assert_eq!(DateTimeSchema::default().le_now(), DateTimeSchema { required: true, label: None, operations: vec![Operation::Le(now.clone())] });
+        assert_eq!(DateTimeSchema::default().ge_now(), DateTimeSchema { required: true, label: None, operations: vec![Operation::Ge(now.clone())] });
+        assert_eq!(
+            DateTimeSchema::default().within_days(7),
+            DateTimeSchema { required: true, label: None, operations: vec![Operation::Btwn(now, Operand::Now(Relative::Days(7)))] }
         );
     }
 }
@@ -1,17 +1,24 @@
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EmailSchema {
     pub required: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub label: Option<String>,
 }
 
 impl Default for EmailSchema {
     fn default() -> Self {
-        EmailSchema { required: true }
+        EmailSchema { required: true, label: None }
     }
 }
 
 impl EmailSchema {
     pub fn optional(self) -> Self {
-        EmailSchema { required: false }
+        EmailSchema { required: false, ..self }
+    }
+
+    pub fn label(self, label: &str) -> Self {
+        EmailSchema { label: Some(label.into()), ..self }
     }
 }
 
@@ -21,7 +28,7 @@ mod tests {
 
     #[test]
     fn email_validation() {
-        assert_eq!(EmailSchema::default(), EmailSchema { required: true });
-        assert_eq!(EmailSchema::default().optional(), EmailSchema { required: false });
+        assert_eq!(EmailSchema::default(), EmailSchema { required: true, label: None });
+        assert_eq!(EmailSchema::default().optional(), EmailSchema { required: false, label: None });
     }
 }
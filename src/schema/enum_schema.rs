@@ -1,4 +1,7 @@
+use crate::value::Value;
+
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
 pub enum EnumValues {
     USize(Vec<usize>),
     ISize(Vec<isize>),
@@ -54,62 +57,117 @@ impl<const N: usize> From<[&str; N]> for EnumValues {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum EnumValue {
+    USize(usize),
+    ISize(isize),
+    Str(String),
+}
+
+impl From<usize> for EnumValue {
+    fn from(value: usize) -> Self {
+        EnumValue::USize(value)
+    }
+}
+
+impl From<isize> for EnumValue {
+    fn from(value: isize) -> Self {
+        EnumValue::ISize(value)
+    }
+}
+
+impl From<String> for EnumValue {
+    fn from(value: String) -> Self {
+        EnumValue::Str(value)
+    }
+}
+
+impl From<&str> for EnumValue {
+    fn from(value: &str) -> Self {
+        EnumValue::Str(value.into())
+    }
+}
+
+impl From<EnumValue> for Value {
+    fn from(value: EnumValue) -> Self {
+        match value {
+            EnumValue::USize(value) => Value::USize(value),
+            EnumValue::ISize(value) => Value::ISize(value),
+            EnumValue::Str(value) => Value::Str(value),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumSchema {
     pub required: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub label: Option<String>,
     pub values: EnumValues,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub default: Option<EnumValue>,
 }
 
 impl EnumSchema {
     pub fn optional(self) -> Self {
         EnumSchema { required: false, ..self }
     }
+
+    pub fn label(self, label: &str) -> Self {
+        EnumSchema { label: Some(label.into()), ..self }
+    }
+
+    pub fn default_value<T: Into<EnumValue>>(self, value: T) -> Self {
+        EnumSchema { default: Some(value.into()), ..self }
+    }
 }
 
 impl From<Vec<usize>> for EnumSchema {
     fn from(values: Vec<usize>) -> Self {
-        EnumSchema { required: true, values: EnumValues::from(values) }
+        EnumSchema { required: true, label: None, values: EnumValues::from(values), default: None }
     }
 }
 
 impl From<Vec<isize>> for EnumSchema {
     fn from(values: Vec<isize>) -> Self {
-        EnumSchema { required: true, values: EnumValues::from(values) }
+        EnumSchema { required: true, label: None, values: EnumValues::from(values), default: None }
     }
 }
 
 impl From<Vec<String>> for EnumSchema {
     fn from(values: Vec<String>) -> Self {
-        EnumSchema { required: true, values: EnumValues::from(values) }
+        EnumSchema { required: true, label: None, values: EnumValues::from(values), default: None }
     }
 }
 
 impl From<Vec<&str>> for EnumSchema {
     fn from(values: Vec<&str>) -> Self {
-        EnumSchema { required: true, values: EnumValues::from(values) }
+        EnumSchema { required: true, label: None, values: EnumValues::from(values), default: None }
     }
 }
 
 impl<const N: usize> From<[usize; N]> for EnumSchema {
     fn from(values: [usize; N]) -> Self {
-        EnumSchema { required: true, values: EnumValues::from(values) }
+        EnumSchema { required: true, label: None, values: EnumValues::from(values), default: None }
     }
 }
 
 impl<const N: usize> From<[isize; N]> for EnumSchema {
     fn from(values: [isize; N]) -> Self {
-        EnumSchema { required: true, values: EnumValues::from(values) }
+        EnumSchema { required: true, label: None, values: EnumValues::from(values), default: None }
     }
 }
 
 impl<const N: usize> From<[String; N]> for EnumSchema {
     fn from(values: [String; N]) -> Self {
-        EnumSchema { required: true, values: EnumValues::from(values) }
+        EnumSchema { required: true, label: None, values: EnumValues::from(values), default: None }
     }
 }
 
 impl<const N: usize> From<[&str; N]> for EnumSchema {
     fn from(values: [&str; N]) -> Self {
-        EnumSchema { required: true, values: EnumValues::from(values) }
+        EnumSchema { required: true, label: None, values: EnumValues::from(values), default: None }
     }
 }
 
@@ -137,7 +195,9 @@ impl std::fmt::Display for EnumValues {
 mod tests {
     use std::sync::LazyLock;
 
-    use super::{EnumSchema, EnumValues};
+    use crate::value::Value;
+
+    use super::{EnumSchema, EnumValue, EnumValues};
 
     const SLICE_U: [usize; 6] = [0, 1, 2, 3, 4, 5];
     const SLICE_I: [isize; 5] = [-2, -1, 0, 1, 2];
@@ -170,18 +230,64 @@ mod tests {
 
     #[test]
     fn enum_schema_from() {
-        assert_eq!(EnumSchema::from(SLICE_U), EnumSchema { required: true, values: EnumValues::USize(VEC_U.clone()) });
-        assert_eq!(EnumSchema::from(VEC_U.clone()), EnumSchema { required: true, values: EnumValues::USize(VEC_U.clone()) });
-        assert_eq!(EnumSchema::from(SLICE_I), EnumSchema { required: true, values: EnumValues::ISize(VEC_I.clone()) });
-        assert_eq!(EnumSchema::from(VEC_I.clone()), EnumSchema { required: true, values: EnumValues::ISize(VEC_I.clone()) });
-        assert_eq!(EnumSchema::from(SLICE_STR), EnumSchema { required: true, values: EnumValues::Str(VEC_STRING.clone()) });
-        assert_eq!(EnumSchema::from(VEC_STR.clone()), EnumSchema { required: true, values: EnumValues::Str(VEC_STRING.clone()) });
-        assert_eq!(EnumSchema::from(SLICE_STRING.clone()), EnumSchema { required: true, values: EnumValues::Str(VEC_STRING.clone()) });
-        assert_eq!(EnumSchema::from(VEC_STRING.clone()), EnumSchema { required: true, values: EnumValues::Str(VEC_STRING.clone()) });
+        assert_eq!(EnumSchema::from(SLICE_U), EnumSchema { required: true, label: None, values: EnumValues::USize(VEC_U.clone()), default: None });
+        assert_eq!(
+            EnumSchema::from(VEC_U.clone()),
+            EnumSchema { required: true, label: None, values: EnumValues::USize(VEC_U.clone()), default: None }
+        );
+        assert_eq!(EnumSchema::from(SLICE_I), EnumSchema { required: true, label: None, values: EnumValues::ISize(VEC_I.clone()), default: None });
+        assert_eq!(
+            EnumSchema::from(VEC_I.clone()),
+            EnumSchema { required: true, label: None, values: EnumValues::ISize(VEC_I.clone()), default: None }
+        );
+        assert_eq!(
+            EnumSchema::from(SLICE_STR),
+            EnumSchema { required: true, label: None, values: EnumValues::Str(VEC_STRING.clone()), default: None }
+        );
+        assert_eq!(
+            EnumSchema::from(VEC_STR.clone()),
+            EnumSchema { required: true, label: None, values: EnumValues::Str(VEC_STRING.clone()), default: None }
+        );
+        assert_eq!(
+            EnumSchema::from(SLICE_STRING.clone()),
+            EnumSchema { required: true, label: None, values: EnumValues::Str(VEC_STRING.clone()), default: None }
+        );
+        assert_eq!(
+            EnumSchema::from(VEC_STRING.clone()),
+            EnumSchema { required: true, label: None, values: EnumValues::Str(VEC_STRING.clone()), default: None }
+        );
     }
 
     #[test]
     fn enum_schema_optional() {
-        assert_eq!(EnumSchema::from(SLICE_STR).optional(), EnumSchema { required: false, values: EnumValues::Str(VEC_STRING.clone()) });
+        assert_eq!(
+            EnumSchema::from(SLICE_STR).optional(),
+            EnumSchema { required: false, label: None, values: EnumValues::Str(VEC_STRING.clone()), default: None }
+        );
+    }
+
+    #[test]
+    fn enum_schema_label() {
+        assert_eq!(
+            EnumSchema::from(SLICE_STR).label("Role"),
+            EnumSchema { required: true, label: Some("Role".into()), values: EnumValues::Str(VEC_STRING.clone()), default: None }
+        );
+    }
+
+    #[test]
+    fn enum_schema_default_value() {
+        assert_eq!(
+            EnumSchema::from(SLICE_STR).optional().default_value("USER"),
+            EnumSchema { required: false, label: None, values: EnumValues::Str(VEC_STRING.clone()), default: Some(EnumValue::Str("USER".into())) }
+        );
+        assert_eq!(EnumSchema::from(SLICE_U).default_value(2_usize).default, Some(EnumValue::USize(2)));
+        assert_eq!(EnumSchema::from(SLICE_I).default_value(-1_isize).default, Some(EnumValue::ISize(-1)));
+    }
+
+    #[test]
+    fn enum_value_to_value() {
+        assert_eq!(Value::from(EnumValue::USize(2)), Value::USize(2));
+        assert_eq!(Value::from(EnumValue::ISize(-1)), Value::ISize(-1));
+        assert_eq!(Value::from(EnumValue::Str("USER".into())), Value::from("USER"));
     }
 }
@@ -1,14 +1,17 @@
 use crate::operation::{Operand, OperandValue, Operation};
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct F64Schema {
     pub required: bool,
-    pub operation: Option<Operation>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub label: Option<String>,
+    pub operations: Vec<Operation>,
 }
 
 impl Default for F64Schema {
     fn default() -> Self {
-        F64Schema { required: true, operation: None }
+        F64Schema { required: true, label: None, operations: vec![] }
     }
 }
 
@@ -17,60 +20,78 @@ impl F64Schema {
         F64Schema { required: false, ..self }
     }
 
-    pub fn eq(self, value: f64) -> Self {
-        F64Schema { operation: Some(Operation::Eq(Operand::Value(OperandValue::F64(value)))), ..self }
+    pub fn label(self, label: &str) -> Self {
+        F64Schema { label: Some(label.into()), ..self }
     }
 
-    pub fn ne(self, value: f64) -> Self {
-        F64Schema { operation: Some(Operation::Ne(Operand::Value(OperandValue::F64(value)))), ..self }
+    pub fn eq(mut self, value: f64) -> Self {
+        self.operations.push(Operation::Eq(Operand::Value(OperandValue::F64(value))));
+        self
     }
 
-    pub fn gt(self, value: f64) -> Self {
-        F64Schema { operation: Some(Operation::Gt(Operand::Value(OperandValue::F64(value)))), ..self }
+    pub fn ne(mut self, value: f64) -> Self {
+        self.operations.push(Operation::Ne(Operand::Value(OperandValue::F64(value))));
+        self
     }
 
-    pub fn ge(self, value: f64) -> Self {
-        F64Schema { operation: Some(Operation::Ge(Operand::Value(OperandValue::F64(value)))), ..self }
+    pub fn gt(mut self, value: f64) -> Self {
+        self.operations.push(Operation::Gt(Operand::Value(OperandValue::F64(value))));
+        self
     }
 
-    pub fn lt(self, value: f64) -> Self {
-        F64Schema { operation: Some(Operation::Lt(Operand::Value(OperandValue::F64(value)))), ..self }
+    pub fn ge(mut self, value: f64) -> Self {
+        self.operations.push(Operation::Ge(Operand::Value(OperandValue::F64(value))));
+        self
     }
 
-    pub fn le(self, value: f64) -> Self {
-        F64Schema { operation: Some(Operation::Le(Operand::Value(OperandValue::F64(value)))), ..self }
+    pub fn lt(mut self, value: f64) -> Self {
+        self.operations.push(Operation::Lt(Operand::Value(OperandValue::F64(value))));
+        self
     }
 
-    pub fn btwn(self, value_a: f64, value_b: f64) -> Self {
-        F64Schema { operation: Some(Operation::Btwn(Operand::Value(OperandValue::F64(value_a)), Operand::Value(OperandValue::F64(value_b)))), ..self }
+    pub fn le(mut self, value: f64) -> Self {
+        self.operations.push(Operation::Le(Operand::Value(OperandValue::F64(value))));
+        self
     }
 
-    pub fn eq_field(self, field: String) -> Self {
-        F64Schema { operation: Some(Operation::Eq(Operand::FieldPath(field))), ..self }
+    pub fn btwn(mut self, value_a: f64, value_b: f64) -> Self {
+        self.operations.push(Operation::Btwn(Operand::Value(OperandValue::F64(value_a)), Operand::Value(OperandValue::F64(value_b))));
+        self
     }
 
-    pub fn ne_field(self, field: String) -> Self {
-        F64Schema { operation: Some(Operation::Ne(Operand::FieldPath(field))), ..self }
+    pub fn eq_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Eq(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn gt_field(self, field: String) -> Self {
-        F64Schema { operation: Some(Operation::Gt(Operand::FieldPath(field))), ..self }
+    pub fn ne_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Ne(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn ge_field(self, field: String) -> Self {
-        F64Schema { operation: Some(Operation::Ge(Operand::FieldPath(field))), ..self }
+    pub fn gt_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Gt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn lt_field(self, field: String) -> Self {
-        F64Schema { operation: Some(Operation::Lt(Operand::FieldPath(field))), ..self }
+    pub fn ge_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Ge(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn le_field(self, field: String) -> Self {
-        F64Schema { operation: Some(Operation::Le(Operand::FieldPath(field))), ..self }
+    pub fn lt_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Lt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn btwn_field(self, field_a: String, field_b: String) -> Self {
-        F64Schema { operation: Some(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b))), ..self }
+    pub fn le_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Le(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn btwn_field(mut self, field_a: String, field_b: String) -> Self {
+        self.operations.push(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b)));
+        self
     }
 }
 
@@ -106,19 +127,19 @@ mod tests {
 
     #[test]
     fn f64_schema() {
-        assert_eq!(F64Schema::default(), F64Schema { required: true, operation: None });
-        assert_eq!(F64Schema::default().optional(), F64Schema { required: false, operation: None });
+        assert_eq!(F64Schema::default(), F64Schema { required: true, label: None, operations: vec![] });
+        assert_eq!(F64Schema::default().optional(), F64Schema { required: false, label: None, operations: vec![] });
     }
 
     #[test]
     fn f64_schema_operation_value() {
-        assert_eq!(F64Schema::default().eq(-1.5), F64Schema { required: true, operation: Some(OPERATION_VALUE_EQ) });
-        assert_eq!(F64Schema::default().ne(-2.5), F64Schema { required: true, operation: Some(OPERATION_VALUE_NE) });
-        assert_eq!(F64Schema::default().gt(-3.5), F64Schema { required: true, operation: Some(OPERATION_VALUE_GT) });
-        assert_eq!(F64Schema::default().ge(-4.5), F64Schema { required: true, operation: Some(OPERATION_VALUE_GE) });
-        assert_eq!(F64Schema::default().lt(-5.5), F64Schema { required: true, operation: Some(OPERATION_VALUE_LT) });
-        assert_eq!(F64Schema::default().le(-6.5), F64Schema { required: true, operation: Some(OPERATION_VALUE_LE) });
-        assert_eq!(F64Schema::default().btwn(-42.5, 42.5), F64Schema { required: true, operation: Some(OPERATION_VALUE_BTWN) });
+        assert_eq!(F64Schema::default().eq(-1.5), F64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_EQ] });
+        assert_eq!(F64Schema::default().ne(-2.5), F64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_NE] });
+        assert_eq!(F64Schema::default().gt(-3.5), F64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_GT] });
+        assert_eq!(F64Schema::default().ge(-4.5), F64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_GE] });
+        assert_eq!(F64Schema::default().lt(-5.5), F64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_LT] });
+        assert_eq!(F64Schema::default().le(-6.5), F64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_LE] });
+        assert_eq!(F64Schema::default().btwn(-42.5, 42.5), F64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_BTWN] });
     }
 
     #[test]
@@ -130,12 +151,12 @@ mod tests {
         let validation_lt = F64Schema::default().lt_field(FIELD.into());
         let validation_le = F64Schema::default().le_field(FIELD.into());
         let validation_btwn = F64Schema::default().btwn_field(FIELD.into(), FIELD_B.into());
-        assert_eq!(validation_eq, F64Schema { required: true, operation: Some(OPERATION_FIELD_EQ.clone()) });
-        assert_eq!(validation_ne, F64Schema { required: true, operation: Some(OPERATION_FIELD_NE.clone()) });
-        assert_eq!(validation_gt, F64Schema { required: true, operation: Some(OPERATION_FIELD_GT.clone()) });
-        assert_eq!(validation_ge, F64Schema { required: true, operation: Some(OPERATION_FIELD_GE.clone()) });
-        assert_eq!(validation_lt, F64Schema { required: true, operation: Some(OPERATION_FIELD_LT.clone()) });
-        assert_eq!(validation_le, F64Schema { required: true, operation: Some(OPERATION_FIELD_LE.clone()) });
-        assert_eq!(validation_btwn, F64Schema { required: true, operation: Some(OPERATION_FIELD_BTWN.clone()) });
+        assert_eq!(validation_eq, F64Schema { required: true, label: None, operations: vec![OPERATION_FIELD_EQ.clone()] });
+        assert_eq!(validation_ne, F64Schema { required: true, label: None, operations: vec![OPERATION_FIELD_NE.clone()] });
+        assert_eq!(validation_gt, F64Schema { required: true, label: None, operations: vec![OPERATION_FIELD_GT.clone()] });
+        assert_eq!(validation_ge, F64Schema { required: true, label: None, operations: vec![OPERATION_FIELD_GE.clone()] });
+        assert_eq!(validation_lt, F64Schema { required: true, label: None, operations: vec![OPERATION_FIELD_LT.clone()] });
+        assert_eq!(validation_le, F64Schema { required: true, label: None, operations: vec![OPERATION_FIELD_LE.clone()] });
+        assert_eq!(validation_btwn, F64Schema { required: true, label: None, operations: vec![OPERATION_FIELD_BTWN.clone()] });
     }
 }
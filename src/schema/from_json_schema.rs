@@ -0,0 +1,946 @@
+use std::collections::BTreeMap;
+
+use serde_json::{Map, Value as JsonValue};
+
+use crate::operation::{Operand, OperandValue, Operation, parse_date, parse_date_time, parse_time};
+
+use super::{
+    ArrSchema, BoolSchema, DateSchema, DateTimeSchema, EmailSchema, EnumSchema, EnumValue, EnumValues, F64Schema, I64Schema, MapSchema,
+    Normalization, ObjSchema, Pattern, Schema, StrPredicate, StrSchema, TimeSchema, U64Schema, UnionSchema, UnknownKeys,
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum JsonSchemaWarning {
+    UnsupportedKeyword(String, String),
+    UnsupportedValue(String, String),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum JsonSchemaErr {
+    Schema(String),
+    Type(String),
+    Enum(String),
+}
+
+const ANNOTATIONS: [&str; 10] = ["$schema", "$id", "$comment", "title", "description", "examples", "default", "deprecated", "readOnly", "writeOnly"];
+
+const NUMBER_KEYWORDS: [&str; 8] = ["type", "const", "not", "minimum", "maximum", "exclusiveMinimum", "exclusiveMaximum", "x-araucaria-operations"];
+const BOOL_KEYWORDS: [&str; 4] = ["type", "const", "not", "x-araucaria-operations"];
+const STR_KEYWORDS: [&str; 18] = [
+    "type",
+    "const",
+    "not",
+    "format",
+    "minLength",
+    "maxLength",
+    "pattern",
+    "x-araucaria-patterns",
+    "x-araucaria-predicates",
+    "x-araucaria-len-normalization",
+    "x-araucaria-operations",
+    "x-araucaria-chars-len",
+    "x-araucaria-bytes-len",
+    "x-araucaria-graphemes-len",
+    "x-araucaria-lowercase-len",
+    "x-araucaria-uppercase-len",
+    "x-araucaria-numbers-len",
+    "x-araucaria-symbols-len",
+];
+const EMAIL_KEYWORDS: [&str; 2] = ["type", "format"];
+const DATE_KEYWORDS: [&str; 5] = ["type", "const", "not", "format", "x-araucaria-operations"];
+const ARR_KEYWORDS: [&str; 5] = ["type", "items", "minItems", "maxItems", "x-araucaria-len"];
+const OBJ_KEYWORDS: [&str; 4] = ["type", "properties", "required", "additionalProperties"];
+const MAP_KEYWORDS: [&str; 6] = ["type", "propertyNames", "additionalProperties", "minProperties", "maxProperties", "x-araucaria-len"];
+const ENUM_KEYWORDS: [&str; 2] = ["type", "enum"];
+const UNION_KEYWORDS: [&str; 1] = ["anyOf"];
+
+fn pointer(path: &str, keyword: &str) -> String {
+    format!("{}/{}", path, keyword.replace('~', "~0").replace('/', "~1"))
+}
+
+fn warn_keywords(map: &Map<String, JsonValue>, keywords: &[&str], path: &str, warnings: &mut Vec<JsonSchemaWarning>) {
+    for keyword in map.keys() {
+        if !keywords.contains(&keyword.as_str()) && !ANNOTATIONS.contains(&keyword.as_str()) {
+            warnings.push(JsonSchemaWarning::UnsupportedKeyword(path.into(), keyword.clone()));
+        }
+    }
+}
+
+fn json_u64(value: &JsonValue) -> Option<OperandValue> {
+    value.as_u64().map(OperandValue::U64)
+}
+
+fn json_i64(value: &JsonValue) -> Option<OperandValue> {
+    value.as_i64().map(OperandValue::I64)
+}
+
+fn json_f64(value: &JsonValue) -> Option<OperandValue> {
+    value.as_f64().map(OperandValue::F64)
+}
+
+fn json_bool(value: &JsonValue) -> Option<OperandValue> {
+    value.as_bool().map(OperandValue::Bool)
+}
+
+fn json_str(value: &JsonValue) -> Option<OperandValue> {
+    value.as_str().map(|value| OperandValue::Str(value.into()))
+}
+
+fn json_date(value: &JsonValue) -> Option<OperandValue> {
+    value.as_str().and_then(parse_date).map(OperandValue::Date)
+}
+
+fn json_time(value: &JsonValue) -> Option<OperandValue> {
+    value.as_str().and_then(parse_time).map(OperandValue::Time)
+}
+
+fn json_date_time(value: &JsonValue) -> Option<OperandValue> {
+    value.as_str().and_then(parse_date_time).map(OperandValue::DateTime)
+}
+
+fn json_usize(value: &JsonValue) -> Option<OperandValue> {
+    value.as_u64().and_then(|value| usize::try_from(value).ok()).map(OperandValue::USize)
+}
+
+fn keyword_operand(
+    map: &Map<String, JsonValue>,
+    keyword: &str,
+    convert: fn(&JsonValue) -> Option<OperandValue>,
+    path: &str,
+    warnings: &mut Vec<JsonSchemaWarning>,
+) -> Option<Operand> {
+    let value = map.get(keyword)?;
+    match convert(value) {
+        Some(value) => Some(Operand::Value(value)),
+        None => {
+            warnings.push(JsonSchemaWarning::UnsupportedValue(path.into(), keyword.into()));
+            None
+        }
+    }
+}
+
+fn extension_operations(map: &Map<String, JsonValue>, keyword: &str, path: &str, warnings: &mut Vec<JsonSchemaWarning>) -> Vec<Operation> {
+    match map.get(keyword) {
+        Some(value) => serde_json::from_value(value.clone()).unwrap_or_else(|_| {
+            warnings.push(JsonSchemaWarning::UnsupportedValue(path.into(), keyword.into()));
+            vec![]
+        }),
+        None => vec![],
+    }
+}
+
+fn range_operations(
+    map: &Map<String, JsonValue>,
+    min: &str,
+    max: &str,
+    convert: fn(&JsonValue) -> Option<OperandValue>,
+    path: &str,
+    warnings: &mut Vec<JsonSchemaWarning>,
+) -> Vec<Operation> {
+    match (keyword_operand(map, min, convert, path, warnings), keyword_operand(map, max, convert, path, warnings)) {
+        (Some(operand_a), Some(operand_b)) if operand_a == operand_b => vec![Operation::Eq(operand_a)],
+        (Some(operand_a), Some(operand_b)) => vec![Operation::Btwn(operand_a, operand_b)],
+        (Some(operand_a), None) => vec![Operation::Ge(operand_a)],
+        (None, Some(operand_b)) => vec![Operation::Le(operand_b)],
+        (None, None) => vec![],
+    }
+}
+
+fn operations(
+    map: &Map<String, JsonValue>,
+    convert: fn(&JsonValue) -> Option<OperandValue>,
+    ordered: bool,
+    path: &str,
+    warnings: &mut Vec<JsonSchemaWarning>,
+) -> Vec<Operation> {
+    let mut operations = vec![];
+    if let Some(operand) = keyword_operand(map, "const", convert, path, warnings) {
+        operations.push(Operation::Eq(operand));
+    }
+    if let Some(not) = map.get("not") {
+        match not.as_object().filter(|not| not.len() == 1).and_then(|not| not.get("const")).and_then(convert) {
+            Some(value) => operations.push(Operation::Ne(Operand::Value(value))),
+            None => warnings.push(JsonSchemaWarning::UnsupportedValue(path.into(), "not".into())),
+        }
+    }
+    if ordered {
+        operations.extend(range_operations(map, "minimum", "maximum", convert, path, warnings));
+        if let Some(operand) = keyword_operand(map, "exclusiveMinimum", convert, path, warnings) {
+            operations.push(Operation::Gt(operand));
+        }
+        if let Some(operand) = keyword_operand(map, "exclusiveMaximum", convert, path, warnings) {
+            operations.push(Operation::Lt(operand));
+        }
+    }
+    operations.extend(extension_operations(map, "x-araucaria-operations", path, warnings));
+    operations
+}
+
+fn len_operations(
+    map: &Map<String, JsonValue>,
+    min: &str,
+    max: &str,
+    key: &str,
+    path: &str,
+    warnings: &mut Vec<JsonSchemaWarning>,
+) -> Vec<Operation> {
+    let mut operations = range_operations(map, min, max, json_usize, path, warnings);
+    operations.extend(extension_operations(map, key, path, warnings));
+    operations
+}
+
+fn is_keyword(map: &Map<String, JsonValue>, keyword: &str, predicate: fn(f64) -> bool) -> bool {
+    map.get(keyword).and_then(|value| value.as_f64()).is_some_and(predicate)
+}
+
+fn integer_schema(map: &Map<String, JsonValue>, path: &str, warnings: &mut Vec<JsonSchemaWarning>) -> Schema {
+    let non_negative = is_keyword(map, "minimum", |value| value >= 0.0)
+        || is_keyword(map, "exclusiveMinimum", |value| value >= -1.0)
+        || is_keyword(map, "const", |value| value >= 0.0);
+    let negative = is_keyword(map, "maximum", |value| value < 0.0)
+        || is_keyword(map, "exclusiveMaximum", |value| value <= 0.0)
+        || is_keyword(map, "const", |value| value < 0.0);
+    if non_negative {
+        let mut map = map.clone();
+        if is_keyword(&map, "exclusiveMinimum", |value| value < 0.0) {
+            map.remove("exclusiveMinimum");
+        }
+        return Schema::from(U64Schema {
+            required: true,
+            label: None,
+            operations: operations(&map, json_u64, true, path, warnings),
+            coerce: false,
+            default: None,
+        });
+    }
+    let i64_schema = I64Schema { required: true, label: None, operations: operations(map, json_i64, true, path, warnings) };
+    if negative {
+        return Schema::from(i64_schema);
+    }
+    let u64_schema =
+        U64Schema { required: true, label: None, operations: operations(map, json_u64, true, path, &mut vec![]), coerce: false, default: None };
+    Schema::from(UnionSchema::from([Schema::from(u64_schema), Schema::from(i64_schema)]))
+}
+
+fn integer_bound(value: &JsonValue, round: fn(f64) -> f64) -> Option<JsonValue> {
+    if value.is_u64() || value.is_i64() {
+        return Some(value.clone());
+    }
+    value.as_f64().map(|value| JsonValue::from(round(value) as i64))
+}
+
+fn number_integer_map(map: &Map<String, JsonValue>) -> Option<Map<String, JsonValue>> {
+    if map.contains_key("x-araucaria-operations") {
+        return None;
+    }
+    let mut map = map.clone();
+    for (keyword, round) in
+        [("minimum", f64::ceil as fn(f64) -> f64), ("maximum", f64::floor), ("exclusiveMinimum", f64::floor), ("exclusiveMaximum", f64::ceil)]
+    {
+        if let Some(bound) = map.get(keyword).and_then(|value| integer_bound(value, round)) {
+            map.insert(keyword.into(), bound);
+        }
+    }
+    if let Some(value) = map.get("const") {
+        let value = value.as_f64().filter(|value| value.fract() == 0.0)?;
+        map.insert("const".into(), JsonValue::from(value as i64));
+    }
+    let not = map.get("not").and_then(|not| not.get("const")).and_then(|value| value.as_f64());
+    match not {
+        Some(value) if value.fract() == 0.0 => {
+            map.insert("not".into(), serde_json::json!({ "const": value as i64 }));
+        }
+        Some(_) => {
+            map.remove("not");
+        }
+        None => {}
+    }
+    Some(map)
+}
+
+fn number_schema(map: &Map<String, JsonValue>, path: &str, warnings: &mut Vec<JsonSchemaWarning>) -> Schema {
+    let f64_schema = Schema::from(F64Schema { required: true, label: None, operations: operations(map, json_f64, true, path, warnings) });
+    let Some(integer_map) = number_integer_map(map) else {
+        return f64_schema;
+    };
+    let mut variants = vec![f64_schema];
+    match integer_schema(&integer_map, path, &mut vec![]) {
+        Schema::Union(union) => variants.extend(union.variants),
+        schema => variants.push(schema),
+    }
+    Schema::from(UnionSchema::from(variants))
+}
+
+fn patterns(map: &Map<String, JsonValue>, path: &str, warnings: &mut Vec<JsonSchemaWarning>) -> Vec<Pattern> {
+    let mut result = vec![];
+    for keyword in ["pattern", "x-araucaria-patterns"] {
+        let sources = match map.get(keyword) {
+            Some(JsonValue::String(source)) if keyword == "pattern" => vec![Some(source.as_str())],
+            Some(JsonValue::Array(sources)) if keyword == "x-araucaria-patterns" => sources.iter().map(|source| source.as_str()).collect(),
+            Some(_) => vec![None],
+            None => vec![],
+        };
+        let patterns: Option<Vec<Pattern>> = sources.into_iter().map(|source| source.and_then(|source| Pattern::new(source).ok())).collect();
+        match patterns {
+            Some(patterns) => result.extend(patterns),
+            None => warnings.push(JsonSchemaWarning::UnsupportedValue(path.into(), keyword.into())),
+        }
+    }
+    result
+}
+
+fn predicates(map: &Map<String, JsonValue>, path: &str, warnings: &mut Vec<JsonSchemaWarning>) -> Vec<StrPredicate> {
+    match map.get("x-araucaria-predicates") {
+        Some(value) => serde_json::from_value(value.clone()).unwrap_or_else(|_| {
+            warnings.push(JsonSchemaWarning::UnsupportedValue(path.into(), "x-araucaria-predicates".into()));
+            vec![]
+        }),
+        None => vec![],
+    }
+}
+
+fn len_normalization(map: &Map<String, JsonValue>, path: &str, warnings: &mut Vec<JsonSchemaWarning>) -> Option<Normalization> {
+    match map.get("x-araucaria-len-normalization") {
+        Some(value) => serde_json::from_value(value.clone()).map(Some).unwrap_or_else(|_| {
+            warnings.push(JsonSchemaWarning::UnsupportedValue(path.into(), "x-araucaria-len-normalization".into()));
+            None
+        }),
+        None => None,
+    }
+}
+
+fn str_schema(map: &Map<String, JsonValue>, path: &str, warnings: &mut Vec<JsonSchemaWarning>) -> Schema {
+    let format = map.get("format").and_then(|format| format.as_str());
+    match format {
+        Some("email") => {
+            warn_keywords(map, &EMAIL_KEYWORDS, path, warnings);
+            return Schema::from(EmailSchema::default());
+        }
+        Some("date") => {
+            warn_keywords(map, &DATE_KEYWORDS, path, warnings);
+            return Schema::from(DateSchema { required: true, label: None, operations: operations(map, json_date, false, path, warnings) });
+        }
+        Some("time") => {
+            warn_keywords(map, &DATE_KEYWORDS, path, warnings);
+            return Schema::from(TimeSchema { required: true, label: None, operations: operations(map, json_time, false, path, warnings) });
+        }
+        Some("date-time") => {
+            warn_keywords(map, &DATE_KEYWORDS, path, warnings);
+            return Schema::from(DateTimeSchema { required: true, label: None, operations: operations(map, json_date_time, false, path, warnings) });
+        }
+        Some(_) => warnings.push(JsonSchemaWarning::UnsupportedValue(path.into(), "format".into())),
+        None => {
+            if map.contains_key("format") {
+                warnings.push(JsonSchemaWarning::UnsupportedValue(path.into(), "format".into()));
+            }
+        }
+    }
+    warn_keywords(map, &STR_KEYWORDS, path, warnings);
+    Schema::from(StrSchema {
+        required: true,
+        label: None,
+        operations: operations(map, json_str, false, path, warnings),
+        bytes_len: extension_operations(map, "x-araucaria-bytes-len", path, warnings),
+        chars_len: len_operations(map, "minLength", "maxLength", "x-araucaria-chars-len", path, warnings),
+        graphemes_len: extension_operations(map, "x-araucaria-graphemes-len", path, warnings),
+        lowercase_len: extension_operations(map, "x-araucaria-lowercase-len", path, warnings),
+        uppercase_len: extension_operations(map, "x-araucaria-uppercase-len", path, warnings),
+        numbers_len: extension_operations(map, "x-araucaria-numbers-len", path, warnings),
+        symbols_len: extension_operations(map, "x-araucaria-symbols-len", path, warnings),
+        patterns: patterns(map, path, warnings),
+        predicates: predicates(map, path, warnings),
+        len_normalization: len_normalization(map, path, warnings),
+        transforms: vec![],
+        default: None,
+    })
+}
+
+fn arr_schema(map: &Map<String, JsonValue>, path: &str, warnings: &mut Vec<JsonSchemaWarning>) -> Result<Schema, JsonSchemaErr> {
+    warn_keywords(map, &ARR_KEYWORDS, path, warnings);
+    let items_path = pointer(path, "items");
+    let item = match map.get("items") {
+        Some(items) => import(items, &items_path, warnings)?,
+        None => return Err(JsonSchemaErr::Schema(items_path)),
+    };
+    let len = len_operations(map, "minItems", "maxItems", "x-araucaria-len", path, warnings);
+    Ok(Schema::from(ArrSchema { required: true, label: None, item: Box::new(item), len }))
+}
+
+fn map_schema(map: &Map<String, JsonValue>, path: &str, warnings: &mut Vec<JsonSchemaWarning>) -> Result<Schema, JsonSchemaErr> {
+    warn_keywords(map, &MAP_KEYWORDS, path, warnings);
+    let value = match map.get("additionalProperties") {
+        Some(value) => import(value, &pointer(path, "additionalProperties"), warnings)?,
+        None => return Err(JsonSchemaErr::Schema(pointer(path, "additionalProperties"))),
+    };
+    let mut schema = MapSchema::from(value);
+    if let Some(key) = map.get("propertyNames") {
+        schema = schema.key(import(key, &pointer(path, "propertyNames"), warnings)?);
+    }
+    schema.len = len_operations(map, "minProperties", "maxProperties", "x-araucaria-len", path, warnings);
+    Ok(Schema::from(schema))
+}
+
+fn obj_schema(map: &Map<String, JsonValue>, path: &str, warnings: &mut Vec<JsonSchemaWarning>) -> Result<Schema, JsonSchemaErr> {
+    if !map.contains_key("properties") && map.get("additionalProperties").is_some_and(|value| value.is_object()) {
+        return map_schema(map, path, warnings);
+    }
+    warn_keywords(map, &OBJ_KEYWORDS, path, warnings);
+    let mut required: Vec<&str> = vec![];
+    if let Some(value) = map.get("required") {
+        match value.as_array().and_then(|keys| keys.iter().map(|key| key.as_str()).collect::<Option<Vec<&str>>>()) {
+            Some(keys) => required = keys,
+            None => warnings.push(JsonSchemaWarning::UnsupportedValue(path.into(), "required".into())),
+        }
+    }
+    let properties_path = pointer(path, "properties");
+    let mut validation = BTreeMap::new();
+    match map.get("properties") {
+        Some(JsonValue::Object(properties)) => {
+            for (key, property) in properties {
+                let schema = import(property, &pointer(&properties_path, key), warnings)?;
+                let schema = if required.contains(&key.as_str()) { schema } else { schema.optional() };
+                validation.insert(key.clone(), schema);
+            }
+        }
+        Some(_) => warnings.push(JsonSchemaWarning::UnsupportedValue(path.into(), "properties".into())),
+        None => {}
+    }
+    if required.iter().any(|key| !validation.contains_key(*key)) {
+        warnings.push(JsonSchemaWarning::UnsupportedValue(path.into(), "required".into()));
+    }
+    let unknown_keys = match map.get("additionalProperties") {
+        Some(JsonValue::Bool(false)) => UnknownKeys::Reject,
+        Some(JsonValue::Bool(true)) => UnknownKeys::Passthrough,
+        Some(_) => {
+            warnings.push(JsonSchemaWarning::UnsupportedValue(path.into(), "additionalProperties".into()));
+            UnknownKeys::Strip
+        }
+        None => UnknownKeys::Strip,
+    };
+    Ok(Schema::from(ObjSchema::from(validation).unknown_keys(unknown_keys)))
+}
+
+fn enum_schema(map: &Map<String, JsonValue>, path: &str, warnings: &mut Vec<JsonSchemaWarning>) -> Result<Schema, JsonSchemaErr> {
+    warn_keywords(map, &ENUM_KEYWORDS, path, warnings);
+    let values = match map.get("enum") {
+        Some(JsonValue::Array(values)) => values,
+        _ => return Err(JsonSchemaErr::Enum(path.into())),
+    };
+    let nullable = values.iter().any(|value| value.is_null());
+    let values: Vec<&JsonValue> = values.iter().filter(|value| !value.is_null()).collect();
+    let enum_values = if let Some(values) = values.iter().map(|value| value.as_str().map(String::from)).collect::<Option<Vec<String>>>() {
+        EnumValues::from(values)
+    } else if let Some(values) =
+        values.iter().map(|value| value.as_u64().and_then(|value| usize::try_from(value).ok())).collect::<Option<Vec<usize>>>()
+    {
+        EnumValues::from(values)
+    } else if let Some(values) =
+        values.iter().map(|value| value.as_i64().and_then(|value| isize::try_from(value).ok())).collect::<Option<Vec<isize>>>()
+    {
+        EnumValues::from(values)
+    } else {
+        return Err(JsonSchemaErr::Enum(path.into()));
+    };
+    let schema = Schema::from(EnumSchema { required: true, label: None, values: enum_values, default: None });
+    Ok(if nullable { schema.optional() } else { schema })
+}
+
+fn union_schema(map: &Map<String, JsonValue>, path: &str, warnings: &mut Vec<JsonSchemaWarning>) -> Result<Schema, JsonSchemaErr> {
+    warn_keywords(map, &UNION_KEYWORDS, path, warnings);
+    let any_of_path = pointer(path, "anyOf");
+    let variants = match map.get("anyOf") {
+        Some(JsonValue::Array(variants)) => variants,
+        _ => return Err(JsonSchemaErr::Schema(any_of_path)),
+    };
+    let mut schemas = vec![];
+    for (index, variant) in variants.iter().enumerate() {
+        schemas.push(import(variant, &pointer(&any_of_path, &index.to_string()), warnings)?);
+    }
+    Ok(Schema::from(UnionSchema::from(schemas)))
+}
+
+fn inferred_type(map: &Map<String, JsonValue>) -> Option<&'static str> {
+    if map.contains_key("properties") || map.contains_key("additionalProperties") || map.contains_key("propertyNames") {
+        return Some("object");
+    }
+    if map.contains_key("items") {
+        return Some("array");
+    }
+    if map.contains_key("format") || map.contains_key("minLength") || map.contains_key("maxLength") {
+        return Some("string");
+    }
+    match map.get("const") {
+        Some(JsonValue::String(_)) => Some("string"),
+        Some(JsonValue::Bool(_)) => Some("boolean"),
+        Some(JsonValue::Number(number)) if number.is_f64() => Some("number"),
+        Some(JsonValue::Number(_)) => Some("integer"),
+        _ => None,
+    }
+}
+
+fn typed_schema(map: &Map<String, JsonValue>, type_name: &str, path: &str, warnings: &mut Vec<JsonSchemaWarning>) -> Result<Schema, JsonSchemaErr> {
+    match type_name {
+        "integer" => {
+            warn_keywords(map, &NUMBER_KEYWORDS, path, warnings);
+            Ok(integer_schema(map, path, warnings))
+        }
+        "number" => {
+            warn_keywords(map, &NUMBER_KEYWORDS, path, warnings);
+            Ok(number_schema(map, path, warnings))
+        }
+        "boolean" => {
+            warn_keywords(map, &BOOL_KEYWORDS, path, warnings);
+            Ok(Schema::from(BoolSchema { required: true, label: None, operations: operations(map, json_bool, false, path, warnings), default: None }))
+        }
+        "string" => Ok(str_schema(map, path, warnings)),
+        "array" => arr_schema(map, path, warnings),
+        "object" => obj_schema(map, path, warnings),
+        _ => Err(JsonSchemaErr::Type(path.into())),
+    }
+}
+
+fn default_value(schema: Schema, default: &JsonValue, path: &str, warnings: &mut Vec<JsonSchemaWarning>) -> Schema {
+    let result = match (schema, default) {
+        (schema, JsonValue::Null) => Ok(schema),
+        (Schema::U64(schema), JsonValue::Number(number)) => match number.as_u64() {
+            Some(value) => Ok(Schema::U64(schema.default_value(value))),
+            None => Err(Schema::U64(schema)),
+        },
+        (Schema::Bool(schema), JsonValue::Bool(value)) => Ok(Schema::Bool(schema.default_value(*value))),
+        (Schema::Str(schema), JsonValue::String(value)) => Ok(Schema::Str(schema.default_value(value))),
+        (Schema::Enum(schema), default) => {
+            let value = match (&schema.values, default) {
+                (EnumValues::Str(_), JsonValue::String(value)) => Some(EnumValue::from(value.as_str())),
+                (EnumValues::USize(_), JsonValue::Number(number)) => {
+                    number.as_u64().and_then(|value| usize::try_from(value).ok()).map(EnumValue::from)
+                }
+                (EnumValues::ISize(_), JsonValue::Number(number)) => {
+                    number.as_i64().and_then(|value| isize::try_from(value).ok()).map(EnumValue::from)
+                }
+                _ => None,
+            };
+            match value {
+                Some(value) => Ok(Schema::Enum(schema.default_value(value))),
+                None => Err(Schema::Enum(schema)),
+            }
+        }
+        (Schema::Obj(schema), JsonValue::Object(_)) => match serde_json::from_value(default.clone()) {
+            Ok(value) => Ok(Schema::Obj(schema.default_value(value))),
+            Err(_) => Err(Schema::Obj(schema)),
+        },
+        (schema, _) => Err(schema),
+    };
+    result.unwrap_or_else(|schema| {
+        warnings.push(JsonSchemaWarning::UnsupportedValue(path.into(), "default".into()));
+        schema
+    })
+}
+
+fn import(value: &JsonValue, path: &str, warnings: &mut Vec<JsonSchemaWarning>) -> Result<Schema, JsonSchemaErr> {
+    let schema = typed_import(value, path, warnings)?;
+    let schema = match value.get("title") {
+        Some(JsonValue::String(title)) => schema.label(title),
+        _ => schema,
+    };
+    Ok(match value.get("default") {
+        Some(default) => default_value(schema, default, path, warnings),
+        None => schema,
+    })
+}
+
+fn typed_import(value: &JsonValue, path: &str, warnings: &mut Vec<JsonSchemaWarning>) -> Result<Schema, JsonSchemaErr> {
+    let map = match value {
+        JsonValue::Object(map) => map,
+        _ => return Err(JsonSchemaErr::Schema(path.into())),
+    };
+    if map.contains_key("enum") {
+        return enum_schema(map, path, warnings);
+    }
+    if map.contains_key("anyOf") {
+        return union_schema(map, path, warnings);
+    }
+    match map.get("type") {
+        Some(JsonValue::String(type_name)) => typed_schema(map, type_name, path, warnings),
+        Some(JsonValue::Array(type_names)) => {
+            let type_names: Vec<&str> =
+                type_names.iter().filter_map(|type_name| type_name.as_str()).filter(|type_name| *type_name != "null").collect();
+            let nullable = type_names.len() < map["type"].as_array().map(|type_names| type_names.len()).unwrap_or_default();
+            let schema = match type_names.as_slice() {
+                [type_name] => typed_schema(map, type_name, path, warnings)?,
+                _ => return Err(JsonSchemaErr::Type(path.into())),
+            };
+            Ok(if nullable { schema.optional() } else { schema })
+        }
+        Some(_) => Err(JsonSchemaErr::Type(path.into())),
+        None => match inferred_type(map) {
+            Some(type_name) => typed_schema(map, type_name, path, warnings),
+            None => Err(JsonSchemaErr::Type(path.into())),
+        },
+    }
+}
+
+impl Schema {
+    pub fn from_json_schema(value: &JsonValue) -> Result<(Schema, Vec<JsonSchemaWarning>), JsonSchemaErr> {
+        let mut warnings = vec![];
+        let schema = import(value, "", &mut warnings)?;
+        Ok((schema, warnings))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde_json::json;
+
+    use crate::{
+        operation::{Operand, Operation, parse_date},
+        schema::{
+            ArrSchema, BoolSchema, Charset, DateSchema, EmailSchema, EnumSchema, F64Schema, I64Schema, MapSchema, Normalization, ObjSchema, Pattern,
+            Schema, StrSchema, U64Schema, UnionSchema, UnknownKeys,
+        },
+        validation::validate,
+        value::Value,
+    };
+
+    use super::{JsonSchemaErr, JsonSchemaWarning};
+
+    #[test]
+    fn from_json_schema_primitives() {
+        assert_eq!(
+            Schema::from_json_schema(&json!({ "type": "string", "minLength": 1, "maxLength": 64 })),
+            Ok((Schema::from(StrSchema::default().chars_len_btwn(1, 64)), vec![]))
+        );
+        assert_eq!(
+            Schema::from_json_schema(&json!({ "type": "string", "maxLength": 64 })),
+            Ok((Schema::from(StrSchema::default().chars_len_le(64)), vec![]))
+        );
+        assert_eq!(
+            Schema::from_json_schema(&json!({ "type": "integer", "minimum": 1, "maximum": 9 })),
+            Ok((Schema::from(U64Schema::default().btwn(1, 9)), vec![]))
+        );
+        assert_eq!(
+            Schema::from_json_schema(&json!({ "type": "integer", "exclusiveMinimum": 0 })),
+            Ok((Schema::from(U64Schema::default().gt(0)), vec![]))
+        );
+        assert_eq!(Schema::from_json_schema(&json!({ "type": "integer", "maximum": -1 })), Ok((Schema::from(I64Schema::default().le(-1)), vec![])));
+        assert_eq!(
+            Schema::from_json_schema(&json!({ "type": "integer", "minimum": -10, "maximum": 10 })),
+            Ok((
+                Schema::from(UnionSchema::from([Schema::from(U64Schema::default().le(10)), Schema::from(I64Schema::default().btwn(-10, 10))])),
+                vec![]
+            ))
+        );
+        assert_eq!(
+            Schema::from_json_schema(&json!({ "type": "number", "exclusiveMaximum": 1.5 })),
+            Ok((
+                Schema::from(UnionSchema::from([
+                    Schema::from(F64Schema::default().lt(1.5)),
+                    Schema::from(U64Schema::default().lt(2)),
+                    Schema::from(I64Schema::default().lt(2)),
+                ])),
+                vec![]
+            ))
+        );
+        assert_eq!(Schema::from_json_schema(&json!({ "type": "string", "format": "email" })), Ok((Schema::from(EmailSchema::default()), vec![])));
+        assert_eq!(
+            Schema::from_json_schema(&json!({ "type": "string", "format": "date", "const": "1970-01-01" })),
+            Ok((Schema::from(DateSchema::default().eq(parse_date("1970-01-01").unwrap())), vec![]))
+        );
+        assert_eq!(
+            Schema::from_json_schema(&json!({ "enum": ["ADMIN", "USER", null] })),
+            Ok((Schema::from(EnumSchema::from(["ADMIN", "USER"]).optional()), vec![]))
+        );
+        assert_eq!(Schema::from_json_schema(&json!({ "enum": [-1, 0, 1] })), Ok((Schema::from(EnumSchema::from([-1_isize, 0, 1])), vec![])));
+        assert_eq!(
+            Schema::from_json_schema(&json!({ "type": ["string", "null"], "not": { "const": "root" } })),
+            Ok((Schema::from(StrSchema::default().ne("root".into()).optional()), vec![]))
+        );
+    }
+
+    #[test]
+    fn from_json_schema_obj() {
+        let value = json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "User",
+            "type": "object",
+            "properties": {
+                "name": { "type": "string", "minLength": 1 },
+                "nickname": { "type": "string" },
+                "tags": { "type": "array", "items": { "type": "string" }, "maxItems": 3 },
+                "scores": { "type": "object", "additionalProperties": { "type": "integer", "minimum": 0 }, "minProperties": 1 }
+            },
+            "required": ["name", "tags", "scores"],
+            "additionalProperties": false
+        });
+        assert_eq!(
+            Schema::from_json_schema(&value),
+            Ok((
+                Schema::from(
+                    ObjSchema::from([
+                        ("name".into(), Schema::from(StrSchema::default().chars_len_ge(1))),
+                        ("nickname".into(), Schema::from(StrSchema::default().optional())),
+                        ("tags".into(), Schema::from(ArrSchema::from(Schema::from(StrSchema::default())).len_le(3))),
+                        ("scores".into(), Schema::from(MapSchema::from(Schema::from(U64Schema::default().ge(0))).len_ge(1))),
+                    ])
+                    .unknown_keys(UnknownKeys::Reject)
+                    .label("User")
+                ),
+                vec![]
+            ))
+        );
+    }
+
+    #[test]
+    fn from_json_schema_warnings() {
+        let value = json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string", "pattern": "^[a-z]+$", "format": "hostname", "minLength": "one" },
+                "email": { "type": "string", "format": "email", "maxLength": 64 },
+                "age": { "type": "integer", "minimum": 0, "multipleOf": 2, "x-araucaria-operations": [{ "le": { "field_path": "max_age" } }] }
+            },
+            "required": ["name", "phone"],
+            "patternProperties": { "^x-": { "type": "string" } }
+        });
+        assert_eq!(
+            Schema::from_json_schema(&value),
+            Ok((
+                Schema::from(ObjSchema::from([
+                    ("name".into(), Schema::from(StrSchema::default().pattern(Pattern::new("^[a-z]+$").unwrap()))),
+                    ("email".into(), Schema::from(EmailSchema::default().optional())),
+                    ("age".into(), Schema::from(U64Schema::default().ge(0).le_field("max_age".into()).optional()),),
+                ])),
+                vec![
+                    JsonSchemaWarning::UnsupportedKeyword("".into(), "patternProperties".into()),
+                    JsonSchemaWarning::UnsupportedKeyword("/properties/age".into(), "multipleOf".into()),
+                    JsonSchemaWarning::UnsupportedKeyword("/properties/email".into(), "maxLength".into()),
+                    JsonSchemaWarning::UnsupportedValue("/properties/name".into(), "format".into()),
+                    JsonSchemaWarning::UnsupportedValue("/properties/name".into(), "minLength".into()),
+                    JsonSchemaWarning::UnsupportedValue("".into(), "required".into()),
+                ]
+            ))
+        );
+    }
+
+    #[test]
+    fn from_json_schema_number_integer_values() {
+        assert_eq!(
+            Schema::from_json_schema(&json!({ "type": "number", "minimum": 0.5, "maximum": 10 })),
+            Ok((
+                Schema::from(UnionSchema::from(
+                    [Schema::from(F64Schema::default().btwn(0.5, 10.0)), Schema::from(U64Schema::default().btwn(1, 10)),]
+                )),
+                vec![]
+            ))
+        );
+        assert_eq!(
+            Schema::from_json_schema(&json!({ "type": "number", "exclusiveMinimum": -2.5, "exclusiveMaximum": -0.5 })),
+            Ok((
+                Schema::from(UnionSchema::from([
+                    Schema::from(F64Schema::default().gt(-2.5).lt(-0.5)),
+                    Schema::from(I64Schema::default().gt(-3).lt(0))
+                ])),
+                vec![]
+            ))
+        );
+        assert_eq!(Schema::from_json_schema(&json!({ "type": "number", "const": 2.5 })), Ok((Schema::from(F64Schema::default().eq(2.5)), vec![])));
+        assert_eq!(
+            Schema::from_json_schema(&json!({ "type": "number", "x-araucaria-operations": [{ "gt": { "value": { "f64": 0.5 } } }] })),
+            Ok((Schema::from(F64Schema::default().gt(0.5)), vec![]))
+        );
+        let (schema, warnings) = Schema::from_json_schema(&json!({
+            "type": "object",
+            "properties": { "price": { "type": "number", "minimum": 0.5, "not": { "const": 7.5 } } },
+            "required": ["price"]
+        }))
+        .unwrap();
+        assert_eq!(warnings, vec![]);
+        assert_eq!(validate(&schema, &Value::from(json!({ "price": 10 }))), Ok(()));
+        assert_eq!(validate(&schema, &Value::from(json!({ "price": 7 }))), Ok(()));
+        assert_eq!(validate(&schema, &Value::from(json!({ "price": 10.5 }))), Ok(()));
+        assert_eq!(validate(&schema, &Value::from(json!({ "price": 1 }))), Ok(()));
+        assert!(validate(&schema, &Value::from(json!({ "price": 0 }))).is_err());
+        assert!(validate(&schema, &Value::from(json!({ "price": -1 }))).is_err());
+        assert!(validate(&schema, &Value::from(json!({ "price": 7.5 }))).is_err());
+    }
+
+    #[test]
+    fn from_json_schema_number_integer_precision() {
+        let (schema, warnings) = Schema::from_json_schema(&json!({ "type": "number", "maximum": 9007199254740992_u64 })).unwrap();
+        assert_eq!(warnings, vec![]);
+        assert_eq!(validate(&schema, &Value::U64(9007199254740992)), Ok(()));
+        assert!(validate(&schema, &Value::U64(9007199254740993)).is_err());
+        assert!(validate(&schema, &Value::U64(u64::MAX)).is_err());
+        let (schema, warnings) = Schema::from_json_schema(&json!({ "type": "number", "minimum": -9007199254740992_i64 })).unwrap();
+        assert_eq!(warnings, vec![]);
+        assert_eq!(validate(&schema, &Value::I64(-9007199254740992)), Ok(()));
+        assert!(validate(&schema, &Value::I64(-9007199254740993)).is_err());
+        assert!(validate(&schema, &Value::I64(i64::MIN)).is_err());
+        let (schema, _) = Schema::from_json_schema(&json!({ "type": "number" })).unwrap();
+        assert_eq!(validate(&schema, &Value::U64(u64::MAX)), Ok(()));
+        assert_eq!(validate(&schema, &Value::I64(i64::MIN)), Ok(()));
+    }
+
+    #[test]
+    fn from_json_schema_integer_signed_bounds() {
+        assert_eq!(Schema::from_json_schema(&json!({ "type": "integer", "exclusiveMinimum": -1 })), Ok((Schema::from(U64Schema::default()), vec![])));
+        assert_eq!(
+            Schema::from_json_schema(&json!({ "type": "integer", "exclusiveMinimum": -0.5, "maximum": 5 })),
+            Ok((Schema::from(U64Schema::default().le(5)), vec![]))
+        );
+        assert_eq!(
+            Schema::from_json_schema(&json!({ "type": "integer", "exclusiveMinimum": -3 })),
+            Ok((Schema::from(UnionSchema::from([Schema::from(U64Schema::default()), Schema::from(I64Schema::default().gt(-3))])), vec![]))
+        );
+    }
+
+    #[test]
+    fn from_json_schema_default() {
+        let schema = Schema::from(ObjSchema::from([
+            ("port".into(), Schema::from(U64Schema::default().ge(1).optional().default_value(8080))),
+            ("tls".into(), Schema::from(BoolSchema::default().optional().default_value(false))),
+            ("host".into(), Schema::from(StrSchema::default().optional().default_value("localhost"))),
+            ("level".into(), Schema::from(EnumSchema::from(["debug", "info"]).optional().default_value("info"))),
+            ("retries".into(), Schema::from(EnumSchema::from([0_usize, 1, 3]).optional().default_value(3_usize))),
+            (
+                "limits".into(),
+                Schema::from(
+                    ObjSchema::from([("rps".into(), Schema::from(U64Schema::default().ge(1)))])
+                        .optional()
+                        .default_value(BTreeMap::from([("rps".into(), Value::U64(100))])),
+                ),
+            ),
+        ]));
+        assert_eq!(Schema::from_json_schema(&schema.to_json_schema()), Ok((schema, vec![])));
+        assert_eq!(
+            Schema::from_json_schema(&json!({
+                "type": "object",
+                "properties": {
+                    "port": { "type": "integer", "minimum": 1, "default": "8080" },
+                    "ratio": { "type": "number", "default": 0.5 },
+                    "level": { "enum": ["debug", "info"], "default": 1 },
+                    "name": { "type": "string", "default": null }
+                }
+            })),
+            Ok((
+                Schema::from(ObjSchema::from([
+                    ("port".into(), Schema::from(U64Schema::default().ge(1).optional())),
+                    (
+                        "ratio".into(),
+                        Schema::from(
+                            UnionSchema::from([
+                                Schema::from(F64Schema::default()),
+                                Schema::from(U64Schema::default()),
+                                Schema::from(I64Schema::default()),
+                            ])
+                            .optional(),
+                        ),
+                    ),
+                    ("level".into(), Schema::from(EnumSchema::from(["debug", "info"]).optional())),
+                    ("name".into(), Schema::from(StrSchema::default().optional())),
+                ])),
+                vec![
+                    JsonSchemaWarning::UnsupportedValue("/properties/level".into(), "default".into()),
+                    JsonSchemaWarning::UnsupportedValue("/properties/port".into(), "default".into()),
+                    JsonSchemaWarning::UnsupportedValue("/properties/ratio".into(), "default".into()),
+                ]
+            ))
+        );
+    }
+
+    #[test]
+    fn from_json_schema_pattern() {
+        assert_eq!(
+            Schema::from_json_schema(&json!({ "type": "string", "pattern": "^[A-Z]{3}", "x-araucaria-patterns": ["[0-9]{4}$"] })),
+            Ok((Schema::from(StrSchema::default().pattern(Pattern::new("^[A-Z]{3}").unwrap()).pattern(Pattern::new("[0-9]{4}$").unwrap())), vec![]))
+        );
+        assert_eq!(
+            Schema::from_json_schema(&json!({ "type": "string", "pattern": "[a-z", "x-araucaria-patterns": [1] })),
+            Ok((
+                Schema::from(StrSchema::default()),
+                vec![
+                    JsonSchemaWarning::UnsupportedValue("".into(), "pattern".into()),
+                    JsonSchemaWarning::UnsupportedValue("".into(), "x-araucaria-patterns".into())
+                ]
+            ))
+        );
+    }
+
+    #[test]
+    fn from_json_schema_predicates() {
+        assert_eq!(
+            Schema::from_json_schema(&json!({
+                "type": "string",
+                "x-araucaria-predicates": [{ "ends_with": ".json" }, { "contains": "/" }, { "only_chars": { "custom": "abc./" } }]
+            })),
+            Ok((Schema::from(StrSchema::default().ends_with(".json").contains("/").only_chars(Charset::Custom("abc./".into()))), vec![]))
+        );
+        assert_eq!(
+            Schema::from_json_schema(&json!({ "type": "string", "x-araucaria-predicates": [{ "only_chars": "octal" }] })),
+            Ok((Schema::from(StrSchema::default()), vec![JsonSchemaWarning::UnsupportedValue("".into(), "x-araucaria-predicates".into())]))
+        );
+        assert_eq!(
+            Schema::from_json_schema(&json!({
+                "type": "string",
+                "x-araucaria-predicates": ["trimmed", "no_control_chars", { "normalized": "nfkc" }],
+                "x-araucaria-len-normalization": "nfc"
+            })),
+            Ok((
+                Schema::from(StrSchema::default().trimmed().no_control_chars().normalized(Normalization::Nfkc).normalize_len(Normalization::Nfc)),
+                vec![]
+            ))
+        );
+        assert_eq!(
+            Schema::from_json_schema(&json!({ "type": "string", "x-araucaria-len-normalization": "nfd" })),
+            Ok((Schema::from(StrSchema::default()), vec![JsonSchemaWarning::UnsupportedValue("".into(), "x-araucaria-len-normalization".into())]))
+        );
+    }
+
+    #[test]
+    fn from_json_schema_err() {
+        assert_eq!(Schema::from_json_schema(&json!(true)), Err(JsonSchemaErr::Schema("".into())));
+        assert_eq!(Schema::from_json_schema(&json!({ "type": "null" })), Err(JsonSchemaErr::Type("".into())));
+        assert_eq!(Schema::from_json_schema(&json!({ "type": ["string", "integer"] })), Err(JsonSchemaErr::Type("".into())));
+        assert_eq!(Schema::from_json_schema(&json!({ "type": "array" })), Err(JsonSchemaErr::Schema("/items".into())));
+        assert_eq!(
+            Schema::from_json_schema(&json!({ "properties": { "a/b": { "oneOf": [] } } })),
+            Err(JsonSchemaErr::Type("/properties/a~1b".into()))
+        );
+        assert_eq!(Schema::from_json_schema(&json!({ "enum": ["a", 1] })), Err(JsonSchemaErr::Enum("".into())));
+    }
+
+    #[test]
+    fn from_json_schema_round_trip() {
+        let schema = Schema::from(ObjSchema::from([
+            ("name".into(), Schema::from(StrSchema::default().chars_len_btwn(1, 64).graphemes_len_le(32).label("Name"))),
+            ("birthdate".into(), Schema::from(DateSchema::default().unix_epoch().optional())),
+            ("role".into(), Schema::from(EnumSchema::from(["ADMIN", "USER"]))),
+            ("min".into(), Schema::from(U64Schema::default().ge(1))),
+            ("max".into(), Schema::from(U64Schema::default().ge(1).gt_field("min".into()))),
+        ]));
+        assert_eq!(Schema::from_json_schema(&schema.to_json_schema()), Ok((schema, vec![])));
+        assert_eq!(
+            Schema::from_json_schema(&json!({ "type": "integer", "minimum": 0, "x-araucaria-operations": [{ "gt": "min" }] })),
+            Ok((Schema::from(U64Schema::default().ge(0)), vec![JsonSchemaWarning::UnsupportedValue("".into(), "x-araucaria-operations".into())]))
+        );
+        assert_eq!(
+            Schema::from_json_schema(&json!({ "type": "integer", "minimum": 0, "x-araucaria-operations": [{ "gt": { "field_path": "min" } }] })),
+            Ok((
+                Schema::from(U64Schema {
+                    required: true,
+                    label: None,
+                    operations: vec![Operation::Ge(Operand::Value(0_u64.into())), Operation::Gt(Operand::FieldPath("min".into()))],
+                    coerce: false,
+                    default: None
+                }),
+                vec![]
+            ))
+        );
+        let custom = Schema::from(StrSchema::default()).custom("username_not_reserved", |_, _| Ok(()));
+        assert_eq!(
+            Schema::from_json_schema(&custom.to_json_schema()),
+            Ok((Schema::from(StrSchema::default()), vec![JsonSchemaWarning::UnsupportedKeyword("".into(), "x-araucaria-custom".into())]))
+        );
+    }
+}
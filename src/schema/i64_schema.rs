@@ -1,14 +1,17 @@
 use crate::operation::{Operand, OperandValue, Operation};
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct I64Schema {
     pub required: bool,
-    pub operation: Option<Operation>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub label: Option<String>,
+    pub operations: Vec<Operation>,
 }
 
 impl Default for I64Schema {
     fn default() -> Self {
-        I64Schema { required: true, operation: None }
+        I64Schema { required: true, label: None, operations: vec![] }
     }
 }
 
@@ -17,60 +20,78 @@ impl I64Schema {
         I64Schema { required: false, ..self }
     }
 
-    pub fn eq(self, value: i64) -> Self {
-        I64Schema { operation: Some(Operation::Eq(Operand::Value(OperandValue::I64(value)))), ..self }
+    pub fn label(self, label: &str) -> Self {
+        I64Schema { label: Some(label.into()), ..self }
     }
 
-    pub fn ne(self, value: i64) -> Self {
-        I64Schema { operation: Some(Operation::Ne(Operand::Value(OperandValue::I64(value)))), ..self }
+    pub fn eq(mut self, value: i64) -> Self {
+        self.operations.push(Operation::Eq(Operand::Value(OperandValue::I64(value))));
+        self
     }
 
-    pub fn gt(self, value: i64) -> Self {
-        I64Schema { operation: Some(Operation::Gt(Operand::Value(OperandValue::I64(value)))), ..self }
+    pub fn ne(mut self, value: i64) -> Self {
+        self.operations.push(Operation::Ne(Operand::Value(OperandValue::I64(value))));
+        self
     }
 
-    pub fn ge(self, value: i64) -> Self {
-        I64Schema { operation: Some(Operation::Ge(Operand::Value(OperandValue::I64(value)))), ..self }
+    pub fn gt(mut self, value: i64) -> Self {
+        self.operations.push(Operation::Gt(Operand::Value(OperandValue::I64(value))));
+        self
     }
 
-    pub fn lt(self, value: i64) -> Self {
-        I64Schema { operation: Some(Operation::Lt(Operand::Value(OperandValue::I64(value)))), ..self }
+    pub fn ge(mut self, value: i64) -> Self {
+        self.operations.push(Operation::Ge(Operand::Value(OperandValue::I64(value))));
+        self
     }
 
-    pub fn le(self, value: i64) -> Self {
-        I64Schema { operation: Some(Operation::Le(Operand::Value(OperandValue::I64(value)))), ..self }
+    pub fn lt(mut self, value: i64) -> Self {
+        self.operations.push(Operation::Lt(Operand::Value(OperandValue::I64(value))));
+        self
     }
 
-    pub fn btwn(self, value_a: i64, value_b: i64) -> Self {
-        I64Schema { operation: Some(Operation::Btwn(Operand::Value(OperandValue::I64(value_a)), Operand::Value(OperandValue::I64(value_b)))), ..self }
+    pub fn le(mut self, value: i64) -> Self {
+        self.operations.push(Operation::Le(Operand::Value(OperandValue::I64(value))));
+        self
     }
 
-    pub fn eq_field(self, field: String) -> Self {
-        I64Schema { operation: Some(Operation::Eq(Operand::FieldPath(field))), ..self }
+    pub fn btwn(mut self, value_a: i64, value_b: i64) -> Self {
+        self.operations.push(Operation::Btwn(Operand::Value(OperandValue::I64(value_a)), Operand::Value(OperandValue::I64(value_b))));
+        self
     }
 
-    pub fn ne_field(self, field: String) -> Self {
-        I64Schema { operation: Some(Operation::Ne(Operand::FieldPath(field))), ..self }
+    pub fn eq_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Eq(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn gt_field(self, field: String) -> Self {
-        I64Schema { operation: Some(Operation::Gt(Operand::FieldPath(field))), ..self }
+    pub fn ne_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Ne(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn ge_field(self, field: String) -> Self {
-        I64Schema { operation: Some(Operation::Ge(Operand::FieldPath(field))), ..self }
+    pub fn gt_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Gt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn lt_field(self, field: String) -> Self {
-        I64Schema { operation: Some(Operation::Lt(Operand::FieldPath(field))), ..self }
+    pub fn ge_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Ge(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn le_field(self, field: String) -> Self {
-        I64Schema { operation: Some(Operation::Le(Operand::FieldPath(field))), ..self }
+    pub fn lt_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Lt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn btwn_field(self, field_a: String, field_b: String) -> Self {
-        I64Schema { operation: Some(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b))), ..self }
+    pub fn le_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Le(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn btwn_field(mut self, field_a: String, field_b: String) -> Self {
+        self.operations.push(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b)));
+        self
     }
 }
 
@@ -106,19 +127,19 @@ mod tests {
 
     #[test]
     fn i64_schema() {
-        assert_eq!(I64Schema::default(), I64Schema { required: true, operation: None });
-        assert_eq!(I64Schema::default().optional(), I64Schema { required: false, operation: None });
+        assert_eq!(I64Schema::default(), I64Schema { required: true, label: None, operations: vec![] });
+        assert_eq!(I64Schema::default().optional(), I64Schema { required: false, label: None, operations: vec![] });
     }
 
     #[test]
     fn i64_schema_operation_value() {
-        assert_eq!(I64Schema::default().eq(-1), I64Schema { required: true, operation: Some(OPERATION_VALUE_EQ) });
-        assert_eq!(I64Schema::default().ne(-2), I64Schema { required: true, operation: Some(OPERATION_VALUE_NE) });
-        assert_eq!(I64Schema::default().gt(-3), I64Schema { required: true, operation: Some(OPERATION_VALUE_GT) });
-        assert_eq!(I64Schema::default().ge(-4), I64Schema { required: true, operation: Some(OPERATION_VALUE_GE) });
-        assert_eq!(I64Schema::default().lt(-5), I64Schema { required: true, operation: Some(OPERATION_VALUE_LT) });
-        assert_eq!(I64Schema::default().le(-6), I64Schema { required: true, operation: Some(OPERATION_VALUE_LE) });
-        assert_eq!(I64Schema::default().btwn(-42, 42), I64Schema { required: true, operation: Some(OPERATION_VALUE_BTWN) });
+        assert_eq!(I64Schema::default().eq(-1), I64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_EQ] });
+        assert_eq!(I64Schema::default().ne(-2), I64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_NE] });
+        assert_eq!(I64Schema::default().gt(-3), I64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_GT] });
+        assert_eq!(I64Schema::default().ge(-4), I64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_GE] });
+        assert_eq!(I64Schema::default().lt(-5), I64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_LT] });
+        assert_eq!(I64Schema::default().le(-6), I64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_LE] });
+        assert_eq!(I64Schema::default().btwn(-42, 42), I64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_BTWN] });
     }
 
     #[test]
@@ -130,12 +151,12 @@ mod tests {
         let validation_lt = I64Schema::default().lt_field(FIELD.into());
         let validation_le = I64Schema::default().le_field(FIELD.into());
         let validation_btwn = I64Schema::default().btwn_field(FIELD.into(), FIELD_B.into());
-        assert_eq!(validation_eq, I64Schema { required: true, operation: Some(OPERATION_FIELD_EQ.clone()) });
-        assert_eq!(validation_ne, I64Schema { required: true, operation: Some(OPERATION_FIELD_NE.clone()) });
-        assert_eq!(validation_gt, I64Schema { required: true, operation: Some(OPERATION_FIELD_GT.clone()) });
-        assert_eq!(validation_ge, I64Schema { required: true, operation: Some(OPERATION_FIELD_GE.clone()) });
-        assert_eq!(validation_lt, I64Schema { required: true, operation: Some(OPERATION_FIELD_LT.clone()) });
-        assert_eq!(validation_le, I64Schema { required: true, operation: Some(OPERATION_FIELD_LE.clone()) });
-        assert_eq!(validation_btwn, I64Schema { required: true, operation: Some(OPERATION_FIELD_BTWN.clone()) });
+        assert_eq!(validation_eq, I64Schema { required: true, label: None, operations: vec![OPERATION_FIELD_EQ.clone()] });
+        assert_eq!(validation_ne, I64Schema { required: true, label: None, operations: vec![OPERATION_FIELD_NE.clone()] });
+        assert_eq!(validation_gt, I64Schema { required: true, label: None, operations: vec![OPERATION_FIELD_GT.clone()] });
+        assert_eq!(validation_ge, I64Schema { required: true, label: None, operations: vec![OPERATION_FIELD_GE.clone()] });
+        assert_eq!(validation_lt, I64Schema { required: true, label: None, operations: vec![OPERATION_FIELD_LT.clone()] });
+        assert_eq!(validation_le, I64Schema { required: true, label: None, operations: vec![OPERATION_FIELD_LE.clone()] });
+        assert_eq!(validation_btwn, I64Schema { required: true, label: None, operations: vec![OPERATION_FIELD_BTWN.clone()] });
     }
 }
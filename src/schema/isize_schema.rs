@@ -1,14 +1,17 @@
 use crate::operation::{Operand, OperandValue, Operation};
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ISizeSchema {
     pub required: bool,
-    pub operation: Option<Operation>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub label: Option<String>,
+    pub operations: Vec<Operation>,
 }
 
 impl Default for ISizeSchema {
     fn default() -> Self {
-        ISizeSchema { required: true, operation: None }
+        ISizeSchema { required: true, label: None, operations: vec![] }
     }
 }
 
@@ -17,63 +20,78 @@ impl ISizeSchema {
         ISizeSchema { required: false, ..self }
     }
 
-    pub fn eq(self, value: isize) -> Self {
-        ISizeSchema { operation: Some(Operation::Eq(Operand::Value(OperandValue::ISize(value)))), ..self }
+    pub fn label(self, label: &str) -> Self {
+        ISizeSchema { label: Some(label.into()), ..self }
     }
 
-    pub fn ne(self, value: isize) -> Self {
-        ISizeSchema { operation: Some(Operation::Ne(Operand::Value(OperandValue::ISize(value)))), ..self }
+    pub fn eq(mut self, value: isize) -> Self {
+        self.operations.push(Operation::Eq(Operand::Value(OperandValue::ISize(value))));
+        self
     }
 
-    pub fn gt(self, value: isize) -> Self {
-        ISizeSchema { operation: Some(Operation::Gt(Operand::Value(OperandValue::ISize(value)))), ..self }
+    pub fn ne(mut self, value: isize) -> Self {
+        self.operations.push(Operation::Ne(Operand::Value(OperandValue::ISize(value))));
+        self
     }
 
-    pub fn ge(self, value: isize) -> Self {
-        ISizeSchema { operation: Some(Operation::Ge(Operand::Value(OperandValue::ISize(value)))), ..self }
+    pub fn gt(mut self, value: isize) -> Self {
+        self.operations.push(Operation::Gt(Operand::Value(OperandValue::ISize(value))));
+        self
     }
 
-    pub fn lt(self, value: isize) -> Self {
-        ISizeSchema { operation: Some(Operation::Lt(Operand::Value(OperandValue::ISize(value)))), ..self }
+    pub fn ge(mut self, value: isize) -> Self {
+        self.operations.push(Operation::Ge(Operand::Value(OperandValue::ISize(value))));
+        self
     }
 
-    pub fn le(self, value: isize) -> Self {
-        ISizeSchema { operation: Some(Operation::Le(Operand::Value(OperandValue::ISize(value)))), ..self }
+    pub fn lt(mut self, value: isize) -> Self {
+        self.operations.push(Operation::Lt(Operand::Value(OperandValue::ISize(value))));
+        self
     }
 
-    pub fn btwn(self, value_a: isize, value_b: isize) -> Self {
-        ISizeSchema {
-            operation: Some(Operation::Btwn(Operand::Value(OperandValue::ISize(value_a)), Operand::Value(OperandValue::ISize(value_b)))),
-            ..self
-        }
+    pub fn le(mut self, value: isize) -> Self {
+        self.operations.push(Operation::Le(Operand::Value(OperandValue::ISize(value))));
+        self
     }
 
-    pub fn eq_field(self, field: String) -> Self {
-        ISizeSchema { operation: Some(Operation::Eq(Operand::FieldPath(field))), ..self }
+    pub fn btwn(mut self, value_a: isize, value_b: isize) -> Self {
+        self.operations.push(Operation::Btwn(Operand::Value(OperandValue::ISize(value_a)), Operand::Value(OperandValue::ISize(value_b))));
+        self
     }
 
-    pub fn ne_field(self, field: String) -> Self {
-        ISizeSchema { operation: Some(Operation::Ne(Operand::FieldPath(field))), ..self }
+    pub fn eq_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Eq(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn gt_field(self, field: String) -> Self {
-        ISizeSchema { operation: Some(Operation::Gt(Operand::FieldPath(field))), ..self }
+    pub fn ne_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Ne(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn ge_field(self, field: String) -> Self {
-        ISizeSchema { operation: Some(Operation::Ge(Operand::FieldPath(field))), ..self }
+    pub fn gt_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Gt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn lt_field(self, field: String) -> Self {
-        ISizeSchema { operation: Some(Operation::Lt(Operand::FieldPath(field))), ..self }
+    pub fn ge_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Ge(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn le_field(self, field: String) -> Self {
-        ISizeSchema { operation: Some(Operation::Le(Operand::FieldPath(field))), ..self }
+    pub fn lt_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Lt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn btwn_field(self, field_a: String, field_b: String) -> Self {
-        ISizeSchema { operation: Some(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b))), ..self }
+    pub fn le_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Le(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn btwn_field(mut self, field_a: String, field_b: String) -> Self {
+        self.operations.push(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b)));
+        self
     }
 }
 
@@ -109,19 +127,19 @@ mod tests {
 
     #[test]
     fn isize_schema() {
-        assert_eq!(ISizeSchema::default(), ISizeSchema { required: true, operation: None });
-        assert_eq!(ISizeSchema::default().optional(), ISizeSchema { required: false, operation: None });
+        assert_eq!(ISizeSchema::default(), ISizeSchema { required: true, label: None, operations: vec![] });
+        assert_eq!(ISizeSchema::default().optional(), ISizeSchema { required: false, label: None, operations: vec![] });
     }
 
     #[test]
     fn isize_schema_operation_value() {
-        assert_eq!(ISizeSchema::default().eq(-1), ISizeSchema { required: true, operation: Some(OPERATION_VALUE_EQ) });
-        assert_eq!(ISizeSchema::default().ne(-2), ISizeSchema { required: true, operation: Some(OPERATION_VALUE_NE) });
-        assert_eq!(ISizeSchema::default().gt(-3), ISizeSchema { required: true, operation: Some(OPERATION_VALUE_GT) });
-        assert_eq!(ISizeSchema::default().ge(-4), ISizeSchema { required: true, operation: Some(OPERATION_VALUE_GE) });
-        assert_eq!(ISizeSchema::default().lt(-5), ISizeSchema { required: true, operation: Some(OPERATION_VALUE_LT) });
-        assert_eq!(ISizeSchema::default().le(-6), ISizeSchema { required: true, operation: Some(OPERATION_VALUE_LE) });
-        assert_eq!(ISizeSchema::default().btwn(-42, 42), ISizeSchema { required: true, operation: Some(OPERATION_VALUE_BTWN) });
+        assert_eq!(ISizeSchema::default().eq(-1), ISizeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_EQ] });
+        assert_eq!(ISizeSchema::default().ne(-2), ISizeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_NE] });
+        assert_eq!(ISizeSchema::default().gt(-3), ISizeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_GT] });
+        assert_eq!(ISizeSchema::default().ge(-4), ISizeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_GE] });
+        assert_eq!(ISizeSchema::default().lt(-5), ISizeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_LT] });
+        assert_eq!(ISizeSchema::default().le(-6), ISizeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_LE] });
+        assert_eq!(ISizeSchema::default().btwn(-42, 42), ISizeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_BTWN] });
     }
 
     #[test]
@@ -133,12 +151,12 @@ mod tests {
         let validation_lt = ISizeSchema::default().lt_field(FIELD.into());
         let validation_le = ISizeSchema::default().le_field(FIELD.into());
         let validation_btwn = ISizeSchema::default().btwn_field(FIELD.into(), FIELD_B.into());
-        assert_eq!(validation_eq, ISizeSchema { required: true, operation: Some(OPERATION_FIELD_EQ.clone()) });
-        assert_eq!(validation_ne, ISizeSchema { required: true, operation: Some(OPERATION_FIELD_NE.clone()) });
-        assert_eq!(validation_gt, ISizeSchema { required: true, operation: Some(OPERATION_FIELD_GT.clone()) });
-        assert_eq!(validation_ge, ISizeSchema { required: true, operation: Some(OPERATION_FIELD_GE.clone()) });
-        assert_eq!(validation_lt, ISizeSchema { required: true, operation: Some(OPERATION_FIELD_LT.clone()) });
-        assert_eq!(validation_le, ISizeSchema { required: true, operation: Some(OPERATION_FIELD_LE.clone()) });
-        assert_eq!(validation_btwn, ISizeSchema { required: true, operation: Some(OPERATION_FIELD_BTWN.clone()) });
+        assert_eq!(validation_eq, ISizeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_EQ.clone()] });
+        assert_eq!(validation_ne, ISizeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_NE.clone()] });
+        assert_eq!(validation_gt, ISizeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_GT.clone()] });
+        assert_eq!(validation_ge, ISizeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_GE.clone()] });
+        assert_eq!(validation_lt, ISizeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_LT.clone()] });
+        assert_eq!(validation_le, ISizeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_LE.clone()] });
+        assert_eq!(validation_btwn, ISizeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_BTWN.clone()] });
     }
 }
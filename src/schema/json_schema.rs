@@ -0,0 +1,502 @@
+use serde_json::{Map, Value as JsonValue, json};
+
+use crate::operation::{Operand, OperandValue, Operation};
+
+use super::{EnumValues, ObjSchema, Pattern, Schema, UnknownKeys};
+
+const DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+fn operand_value_json(value: &OperandValue) -> JsonValue {
+    match value {
+        OperandValue::U64(value) => json!(value),
+        OperandValue::I64(value) => json!(value),
+        OperandValue::F64(value) => json!(value),
+        OperandValue::USize(value) => json!(value),
+        OperandValue::ISize(value) => json!(value),
+        OperandValue::Bool(value) => json!(value),
+        OperandValue::Str(value) => json!(value),
+        OperandValue::Date(value) => json!(value.to_string()),
+        OperandValue::Time(value) => json!(value.to_string()),
+        OperandValue::DateTime(value) => json!(value.to_string()),
+    }
+}
+
+fn extension(map: &mut Map<String, JsonValue>, key: &str, operation: &Operation) {
+    let operations = map.entry(key).or_insert_with(|| json!([]));
+    if let JsonValue::Array(operations) = operations {
+        operations.push(serde_json::to_value(operation).unwrap_or(JsonValue::Null));
+    }
+}
+
+fn set_keywords(map: &mut Map<String, JsonValue>, keywords: Vec<(&str, JsonValue)>) -> bool {
+    if keywords.is_empty() || keywords.iter().any(|(keyword, _)| map.contains_key(*keyword)) {
+        return false;
+    }
+    for (keyword, value) in keywords {
+        map.insert(keyword.into(), value);
+    }
+    true
+}
+
+fn apply_operations(map: &mut Map<String, JsonValue>, operations: &[Operation], ordered: bool) {
+    for operation in operations {
+        let keywords = match (operation, ordered) {
+            (Operation::Eq(Operand::Value(value)), _) => vec![("const", operand_value_json(value))],
+            (Operation::Ne(Operand::Value(value)), _) => vec![("not", json!({ "const": operand_value_json(value) }))],
+            (Operation::Gt(Operand::Value(value)), true) => vec![("exclusiveMinimum", operand_value_json(value))],
+            (Operation::Ge(Operand::Value(value)), true) => vec![("minimum", operand_value_json(value))],
+            (Operation::Lt(Operand::Value(value)), true) => vec![("exclusiveMaximum", operand_value_json(value))],
+            (Operation::Le(Operand::Value(value)), true) => vec![("maximum", operand_value_json(value))],
+            (Operation::Btwn(Operand::Value(value_a), Operand::Value(value_b)), true) => {
+                vec![("minimum", operand_value_json(value_a)), ("maximum", operand_value_json(value_b))]
+            }
+            _ => vec![],
+        };
+        if !set_keywords(map, keywords) {
+            extension(map, "x-araucaria-operations", operation);
+        }
+    }
+}
+
+fn len_value(operand: &Operand) -> Option<usize> {
+    match operand {
+        Operand::Value(OperandValue::USize(value)) => Some(*value),
+        _ => None,
+    }
+}
+
+fn apply_len(map: &mut Map<String, JsonValue>, operations: &[Operation], min: &str, max: &str, key: &str) {
+    for operation in operations {
+        let keywords = match operation {
+            Operation::Eq(operand) => len_value(operand).map(|len| vec![(min, json!(len)), (max, json!(len))]),
+            Operation::Gt(operand) => len_value(operand).map(|len| vec![(min, json!(len + 1))]),
+            Operation::Ge(operand) => len_value(operand).map(|len| vec![(min, json!(len))]),
+            Operation::Lt(operand) => len_value(operand).and_then(|len| len.checked_sub(1)).map(|len| vec![(max, json!(len))]),
+            Operation::Le(operand) => len_value(operand).map(|len| vec![(max, json!(len))]),
+            Operation::Btwn(operand_a, operand_b) => {
+                len_value(operand_a).zip(len_value(operand_b)).map(|(len_a, len_b)| vec![(min, json!(len_a)), (max, json!(len_b))])
+            }
+            Operation::Ne(_) => None,
+        };
+        if !set_keywords(map, keywords.unwrap_or_default()) {
+            extension(map, key, operation);
+        }
+    }
+}
+
+fn apply_extension(map: &mut Map<String, JsonValue>, operations: &[Operation], key: &str) {
+    for operation in operations {
+        extension(map, key, operation);
+    }
+}
+
+fn obj_json_schema(schema: &ObjSchema) -> Map<String, JsonValue> {
+    let mut map = Map::new();
+    map.insert("type".into(), json!("object"));
+    let properties: Map<String, JsonValue> =
+        schema.validation.iter().map(|(key, item)| (key.clone(), JsonValue::Object(json_schema(item)))).collect();
+    let required: Vec<&String> = schema.validation.iter().filter(|(_, item)| item.is_required()).map(|(key, _)| key).collect();
+    map.insert("properties".into(), JsonValue::Object(properties));
+    if !required.is_empty() {
+        map.insert("required".into(), json!(required));
+    }
+    if schema.unknown_keys == UnknownKeys::Reject {
+        map.insert("additionalProperties".into(), json!(false));
+    }
+    map
+}
+
+fn json_schema(schema: &Schema) -> Map<String, JsonValue> {
+    let mut map = Map::new();
+    match schema {
+        Schema::U64(schema) => {
+            map.insert("type".into(), json!("integer"));
+            apply_operations(&mut map, &schema.operations, true);
+        }
+        Schema::I64(schema) => {
+            map.insert("type".into(), json!("integer"));
+            apply_operations(&mut map, &schema.operations, true);
+        }
+        Schema::F64(schema) => {
+            map.insert("type".into(), json!("number"));
+            apply_operations(&mut map, &schema.operations, true);
+        }
+        Schema::USize(schema) => {
+            map.insert("type".into(), json!("integer"));
+            apply_operations(&mut map, &schema.operations, true);
+        }
+        Schema::ISize(schema) => {
+            map.insert("type".into(), json!("integer"));
+            apply_operations(&mut map, &schema.operations, true);
+        }
+        Schema::Bool(schema) => {
+            map.insert("type".into(), json!("boolean"));
+            apply_operations(&mut map, &schema.operations, false);
+        }
+        Schema::Str(schema) => {
+            map.insert("type".into(), json!("string"));
+            apply_operations(&mut map, &schema.operations, false);
+            apply_len(&mut map, &schema.chars_len, "minLength", "maxLength", "x-araucaria-chars-len");
+            apply_extension(&mut map, &schema.bytes_len, "x-araucaria-bytes-len");
+            apply_extension(&mut map, &schema.graphemes_len, "x-araucaria-graphemes-len");
+            apply_extension(&mut map, &schema.lowercase_len, "x-araucaria-lowercase-len");
+            apply_extension(&mut map, &schema.uppercase_len, "x-araucaria-uppercase-len");
+            apply_extension(&mut map, &schema.numbers_len, "x-araucaria-numbers-len");
+            apply_extension(&mut map, &schema.symbols_len, "x-araucaria-symbols-len");
+            if let Some((pattern, rest)) = schema.patterns.split_first() {
+                map.insert("pattern".into(), json!(pattern.as_str()));
+                if !rest.is_empty() {
+                    map.insert("x-araucaria-patterns".into(), json!(rest.iter().map(Pattern::as_str).collect::<Vec<&str>>()));
+                }
+            }
+            if !schema.predicates.is_empty() {
+                map.insert("x-araucaria-predicates".into(), serde_json::to_value(&schema.predicates).unwrap_or(JsonValue::Null));
+            }
+            if let Some(normalization) = schema.len_normalization {
+                map.insert("x-araucaria-len-normalization".into(), serde_json::to_value(normalization).unwrap_or(JsonValue::Null));
+            }
+        }
+        Schema::Email(_) => {
+            map.insert("type".into(), json!("string"));
+            map.insert("format".into(), json!("email"));
+        }
+        Schema::Date(schema) => {
+            map.insert("type".into(), json!("string"));
+            map.insert("format".into(), json!("date"));
+            apply_operations(&mut map, &schema.operations, false);
+        }
+        Schema::Time(schema) => {
+            map.insert("type".into(), json!("string"));
+            map.insert("format".into(), json!("time"));
+            apply_operations(&mut map, &schema.operations, false);
+        }
+        Schema::DateTime(schema) => {
+            map.insert("type".into(), json!("string"));
+            map.insert("format".into(), json!("date-time"));
+            apply_operations(&mut map, &schema.operations, false);
+        }
+        Schema::Arr(schema) => {
+            map.insert("type".into(), json!("array"));
+            map.insert("items".into(), JsonValue::Object(json_schema(&schema.item)));
+            apply_len(&mut map, &schema.len, "minItems", "maxItems", "x-araucaria-len");
+        }
+        Schema::Obj(schema) => map = obj_json_schema(schema),
+        Schema::Map(schema) => {
+            map.insert("type".into(), json!("object"));
+            map.insert("propertyNames".into(), JsonValue::Object(json_schema(&schema.key)));
+            map.insert("additionalProperties".into(), JsonValue::Object(json_schema(&schema.value)));
+            apply_len(&mut map, &schema.len, "minProperties", "maxProperties", "x-araucaria-len");
+        }
+        Schema::Enum(schema) => {
+            let values = match &schema.values {
+                EnumValues::USize(values) => json!(values),
+                EnumValues::ISize(values) => json!(values),
+                EnumValues::Str(values) => json!(values),
+            };
+            map.insert("enum".into(), values);
+        }
+        Schema::Union(schema) => {
+            map.insert("anyOf".into(), JsonValue::Array(schema.variants.iter().map(|variant| JsonValue::Object(json_schema(variant))).collect()));
+        }
+        Schema::Tagged(schema) => {
+            let variants = schema
+                .variants
+                .iter()
+                .map(|(tag_value, variant)| {
+                    let mut variant_map = obj_json_schema(variant);
+                    if let Some(JsonValue::Object(properties)) = variant_map.get_mut("properties") {
+                        properties.insert(schema.tag.clone(), json!({ "const": tag_value }));
+                    }
+                    let mut required: Vec<JsonValue> = vec![json!(schema.tag)];
+                    if let Some(JsonValue::Array(variant_required)) = variant_map.remove("required") {
+                        required.extend(variant_required.into_iter().filter(|key| key != &json!(schema.tag)));
+                    }
+                    variant_map.insert("required".into(), JsonValue::Array(required));
+                    JsonValue::Object(variant_map)
+                })
+                .collect();
+            map.insert("oneOf".into(), JsonValue::Array(variants));
+        }
+        Schema::Custom(schema) => {
+            map.extend(json_schema(&schema.schema));
+            let codes: Vec<&String> = schema.validators.iter().map(|validator| &validator.code).collect();
+            #[cfg(feature = "async")]
+            let codes: Vec<&String> = codes.into_iter().chain(schema.async_validators.iter().map(|validator| &validator.code)).collect();
+            map.insert("x-araucaria-custom".into(), json!(codes));
+        }
+    }
+    if let Some(label) = schema.get_label() {
+        map.insert("title".into(), json!(label));
+    }
+    if let Some(default) = schema.get_default() {
+        map.insert("default".into(), serde_json::to_value(default).unwrap_or(JsonValue::Null));
+    }
+    map
+}
+
+impl Schema {
+    pub fn to_json_schema(&self) -> JsonValue {
+        let mut map = Map::new();
+        map.insert("$schema".into(), json!(DRAFT));
+        map.extend(json_schema(self));
+        JsonValue::Object(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde_json::json;
+
+    use crate::{
+        schema::{
+            ArrSchema, BoolSchema, Charset, DateSchema, EmailSchema, EnumSchema, MapSchema, Normalization, ObjSchema, Pattern, Schema, StrSchema,
+            TaggedSchema, U64Schema, UnionSchema, UnknownKeys,
+        },
+        value::Value,
+    };
+
+    #[test]
+    fn json_schema_primitives() {
+        assert_eq!(
+            Schema::from(U64Schema::default().btwn(1, 9)).to_json_schema(),
+            json!({ "$schema": "https://json-schema.org/draft/2020-12/schema", "type": "integer", "minimum": 1, "maximum": 9 })
+        );
+        assert_eq!(
+            Schema::from(U64Schema::default().gt(1).le(9).ne(5)).to_json_schema(),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "integer",
+                "exclusiveMinimum": 1,
+                "maximum": 9,
+                "not": { "const": 5 }
+            })
+        );
+        assert_eq!(
+            Schema::from(StrSchema::default().chars_len_btwn(1, 64)).to_json_schema(),
+            json!({ "$schema": "https://json-schema.org/draft/2020-12/schema", "type": "string", "minLength": 1, "maxLength": 64 })
+        );
+        assert_eq!(
+            Schema::from(StrSchema::default().chars_len_gt(1).chars_len_lt(64)).to_json_schema(),
+            json!({ "$schema": "https://json-schema.org/draft/2020-12/schema", "type": "string", "minLength": 2, "maxLength": 63 })
+        );
+        assert_eq!(
+            Schema::from(EmailSchema::default()).to_json_schema(),
+            json!({ "$schema": "https://json-schema.org/draft/2020-12/schema", "type": "string", "format": "email" })
+        );
+        assert_eq!(
+            Schema::from(DateSchema::default()).to_json_schema(),
+            json!({ "$schema": "https://json-schema.org/draft/2020-12/schema", "type": "string", "format": "date" })
+        );
+        assert_eq!(
+            Schema::from(EnumSchema::from(["ADMIN", "USER"])).to_json_schema(),
+            json!({ "$schema": "https://json-schema.org/draft/2020-12/schema", "enum": ["ADMIN", "USER"] })
+        );
+    }
+
+    #[test]
+    fn json_schema_extensions() {
+        assert_eq!(
+            Schema::from(StrSchema::default().graphemes_len_le(10).chars_len_ne(3)).to_json_schema(),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "string",
+                "x-araucaria-chars-len": [{ "ne": { "value": { "u_size": 3 } } }],
+                "x-araucaria-graphemes-len": [{ "le": { "value": { "u_size": 10 } } }]
+            })
+        );
+        assert_eq!(
+            Schema::from(U64Schema::default().gt_field("min".into()).ge(1).ge(2)).to_json_schema(),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "integer",
+                "minimum": 1,
+                "x-araucaria-operations": [{ "gt": { "field_path": "min" } }, { "ge": { "value": { "u64": 2 } } }]
+            })
+        );
+        assert_eq!(
+            Schema::from(DateSchema::default().unix_epoch()).to_json_schema(),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "string",
+                "format": "date",
+                "x-araucaria-operations": [{ "ge": { "value": { "date": "1970-01-01" } } }]
+            })
+        );
+    }
+
+    #[test]
+    fn json_schema_pattern() {
+        assert_eq!(
+            Schema::from(StrSchema::default().pattern(Pattern::new("^[a-z0-9-]+$").unwrap())).to_json_schema(),
+            json!({ "$schema": "https://json-schema.org/draft/2020-12/schema", "type": "string", "pattern": "^[a-z0-9-]+$" })
+        );
+        assert_eq!(
+            Schema::from(StrSchema::default().pattern(Pattern::new("^[A-Z]{3}").unwrap()).pattern(Pattern::new("[0-9]{4}$").unwrap()))
+                .to_json_schema(),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "string",
+                "pattern": "^[A-Z]{3}",
+                "x-araucaria-patterns": ["[0-9]{4}$"]
+            })
+        );
+    }
+
+    #[test]
+    fn json_schema_predicates() {
+        assert_eq!(
+            Schema::from(StrSchema::default().starts_with("sk_").not_contains(" ").only_chars(Charset::Base64Url)).to_json_schema(),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "string",
+                "x-araucaria-predicates": [{ "starts_with": "sk_" }, { "not_contains": " " }, { "only_chars": "base64_url" }]
+            })
+        );
+        assert_eq!(
+            Schema::from(StrSchema::default().trimmed().normalized(Normalization::Nfc).normalize_len(Normalization::Nfkc)).to_json_schema(),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "string",
+                "x-araucaria-predicates": ["trimmed", { "normalized": "nfc" }],
+                "x-araucaria-len-normalization": "nfkc"
+            })
+        );
+    }
+
+    #[test]
+    fn json_schema_custom() {
+        let schema = Schema::from(StrSchema::default().label("Username"))
+            .custom("username_not_reserved", |_, _| Ok(()))
+            .custom("username_available", |_, _| Ok(()));
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "string",
+                "title": "Username",
+                "x-araucaria-custom": ["username_not_reserved", "username_available"]
+            })
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn json_schema_custom_async() {
+        let schema = Schema::from(ObjSchema::from([(
+            "email".into(),
+            Schema::from(EmailSchema::default()).custom("email_domain", |_, _| Ok(())).custom_async("email_unique", |_, _| async { Ok(()) }),
+        )]));
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "object",
+                "properties": {
+                    "email": { "type": "string", "format": "email", "x-araucaria-custom": ["email_domain", "email_unique"] }
+                },
+                "required": ["email"]
+            })
+        );
+    }
+
+    #[test]
+    fn json_schema_obj() {
+        let schema = Schema::from(
+            ObjSchema::from([
+                ("name".into(), Schema::from(StrSchema::default().label("Name"))),
+                ("nickname".into(), Schema::from(StrSchema::default().optional())),
+                ("tags".into(), Schema::from(ArrSchema::from(Schema::from(StrSchema::default())).len_le(3))),
+            ])
+            .unknown_keys(UnknownKeys::Reject),
+        );
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "object",
+                "properties": {
+                    "name": { "type": "string", "title": "Name" },
+                    "nickname": { "type": "string" },
+                    "tags": { "type": "array", "items": { "type": "string" }, "maxItems": 3 }
+                },
+                "required": ["name", "tags"],
+                "additionalProperties": false
+            })
+        );
+        assert_eq!(
+            Schema::from(MapSchema::from(Schema::from(U64Schema::default())).len_ge(1)).to_json_schema(),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "object",
+                "propertyNames": { "type": "string" },
+                "additionalProperties": { "type": "integer" },
+                "minProperties": 1
+            })
+        );
+    }
+
+    #[test]
+    fn json_schema_default() {
+        let schema = Schema::from(ObjSchema::from([
+            ("port".into(), Schema::from(U64Schema::default().optional().default_value(8080))),
+            ("tls".into(), Schema::from(BoolSchema::default().optional().default_value(false))),
+            ("host".into(), Schema::from(StrSchema::default().optional().default_value("localhost"))),
+            ("level".into(), Schema::from(EnumSchema::from(["debug", "info"]).optional().default_value("info"))),
+            (
+                "limits".into(),
+                Schema::from(
+                    ObjSchema::from([("rps".into(), Schema::from(U64Schema::default()))])
+                        .optional()
+                        .default_value(BTreeMap::from([("rps".into(), Value::U64(100))])),
+                ),
+            ),
+        ]));
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "object",
+                "properties": {
+                    "host": { "type": "string", "default": "localhost" },
+                    "level": { "enum": ["debug", "info"], "default": "info" },
+                    "limits": { "type": "object", "properties": { "rps": { "type": "integer" } }, "required": ["rps"], "default": { "rps": 100 } },
+                    "port": { "type": "integer", "default": 8080 },
+                    "tls": { "type": "boolean", "default": false }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn json_schema_union_tagged() {
+        assert_eq!(
+            Schema::from(UnionSchema::from([Schema::from(StrSchema::default()), Schema::from(U64Schema::default())])).to_json_schema(),
+            json!({ "$schema": "https://json-schema.org/draft/2020-12/schema", "anyOf": [{ "type": "string" }, { "type": "integer" }] })
+        );
+        let schema = Schema::from(
+            TaggedSchema::from(String::from("kind"))
+                .variant("circle".into(), ObjSchema::from([("radius".into(), Schema::from(U64Schema::default()))]))
+                .variant("square".into(), ObjSchema::from([("side".into(), Schema::from(U64Schema::default()))])),
+        );
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "oneOf": [
+                    {
+                        "type": "object",
+                        "properties": { "kind": { "const": "circle" }, "radius": { "type": "integer" } },
+                        "required": ["kind", "radius"]
+                    },
+                    {
+                        "type": "object",
+                        "properties": { "kind": { "const": "square" }, "side": { "type": "integer" } },
+                        "required": ["kind", "side"]
+                    }
+                ]
+            })
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use crate::operation::{Operand, OperandValue, Operation};
+
+use super::{Schema, StrSchema};
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MapSchema {
+    pub required: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub label: Option<String>,
+    pub key: Box<Schema>,
+    pub value: Box<Schema>,
+    pub len: Vec<Operation>,
+}
+
+impl From<Schema> for MapSchema {
+    fn from(value: Schema) -> Self {
+        MapSchema { required: true, label: None, key: Box::new(Schema::from(StrSchema::default())), value: Box::new(value), len: vec![] }
+    }
+}
+
+impl MapSchema {
+    pub fn optional(self) -> Self {
+        MapSchema { required: false, ..self }
+    }
+
+    pub fn label(self, label: &str) -> Self {
+        MapSchema { label: Some(label.into()), ..self }
+    }
+
+    pub fn key(self, key: Schema) -> Self {
+        MapSchema { key: Box::new(key), ..self }
+    }
+
+    pub fn len_eq(mut self, len: usize) -> Self {
+        self.len.push(Operation::Eq(Operand::Value(OperandValue::USize(len))));
+        self
+    }
+
+    pub fn len_ne(mut self, len: usize) -> Self {
+        self.len.push(Operation::Ne(Operand::Value(OperandValue::USize(len))));
+        self
+    }
+
+    pub fn len_gt(mut self, len: usize) -> Self {
+        self.len.push(Operation::Gt(Operand::Value(OperandValue::USize(len))));
+        self
+    }
+
+    pub fn len_ge(mut self, len: usize) -> Self {
+        self.len.push(Operation::Ge(Operand::Value(OperandValue::USize(len))));
+        self
+    }
+
+    pub fn len_lt(mut self, len: usize) -> Self {
+        self.len.push(Operation::Lt(Operand::Value(OperandValue::USize(len))));
+        self
+    }
+
+    pub fn len_le(mut self, len: usize) -> Self {
+        self.len.push(Operation::Le(Operand::Value(OperandValue::USize(len))));
+        self
+    }
+
+    pub fn len_btwn(mut self, len_a: usize, len_b: usize) -> Self {
+        self.len.push(Operation::Btwn(Operand::Value(OperandValue::USize(len_a)), Operand::Value(OperandValue::USize(len_b))));
+        self
+    }
+
+    pub fn len_eq_field(mut self, field: String) -> Self {
+        self.len.push(Operation::Eq(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn len_ne_field(mut self, field: String) -> Self {
+        self.len.push(Operation::Ne(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn len_gt_field(mut self, field: String) -> Self {
+        self.len.push(Operation::Gt(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn len_ge_field(mut self, field: String) -> Self {
+        self.len.push(Operation::Ge(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn len_lt_field(mut self, field: String) -> Self {
+        self.len.push(Operation::Lt(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn len_le_field(mut self, field: String) -> Self {
+        self.len.push(Operation::Le(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn len_btwn_field(mut self, field_a: String, field_b: String) -> Self {
+        self.len.push(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b)));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::LazyLock;
+
+    use crate::{
+        operation::{Operand, OperandValue, Operation},
+        schema::{EnumSchema, Schema, StrSchema, U64Schema},
+    };
+
+    use super::MapSchema;
+
+    static KEY: LazyLock<Box<Schema>> = LazyLock::new(|| Box::new(Schema::from(StrSchema::default())));
+    static VALUE: LazyLock<Box<Schema>> = LazyLock::new(|| Box::new(Schema::from(U64Schema::default())));
+
+    const OPERATION_VALUE_EQ: Operation = Operation::Eq(Operand::Value(OperandValue::USize(1)));
+    const OPERATION_VALUE_NE: Operation = Operation::Ne(Operand::Value(OperandValue::USize(2)));
+    const OPERATION_VALUE_GT: Operation = Operation::Gt(Operand::Value(OperandValue::USize(3)));
+    const OPERATION_VALUE_GE: Operation = Operation::Ge(Operand::Value(OperandValue::USize(4)));
+    const OPERATION_VALUE_LT: Operation = Operation::Lt(Operand::Value(OperandValue::USize(5)));
+    const OPERATION_VALUE_LE: Operation = Operation::Le(Operand::Value(OperandValue::USize(6)));
+    const OPERATION_VALUE_BTWN: Operation = Operation::Btwn(Operand::Value(OperandValue::USize(1)), Operand::Value(OperandValue::USize(9)));
+
+    const FIELD: &str = "settings.flags.min";
+    const FIELD_B: &str = "settings.flags.max";
+
+    #[test]
+    fn map_schema() {
+        let schema = MapSchema::from(Schema::from(U64Schema::default()));
+        assert_eq!(schema, MapSchema { required: true, label: None, key: KEY.clone(), value: VALUE.clone(), len: vec![] });
+        assert_eq!(schema.clone().optional(), MapSchema { required: false, label: None, key: KEY.clone(), value: VALUE.clone(), len: vec![] });
+        assert_eq!(
+            schema.key(Schema::from(EnumSchema::from(["en", "pt"]))),
+            MapSchema { required: true, label: None, key: Box::new(Schema::from(EnumSchema::from(["en", "pt"]))), value: VALUE.clone(), len: vec![] }
+        );
+    }
+
+    #[test]
+    fn map_schema_len_value() {
+        let schema = MapSchema::from(Schema::from(U64Schema::default()));
+        assert_eq!(
+            schema.clone().len_eq(1),
+            MapSchema { required: true, label: None, key: KEY.clone(), value: VALUE.clone(), len: vec![OPERATION_VALUE_EQ] }
+        );
+        assert_eq!(
+            schema.clone().len_ne(2),
+            MapSchema { required: true, label: None, key: KEY.clone(), value: VALUE.clone(), len: vec![OPERATION_VALUE_NE] }
+        );
+        assert_eq!(
+            schema.clone().len_gt(3),
+            MapSchema { required: true, label: None, key: KEY.clone(), value: VALUE.clone(), len: vec![OPERATION_VALUE_GT] }
+        );
+        assert_eq!(
+            schema.clone().len_ge(4),
+            MapSchema { required: true, label: None, key: KEY.clone(), value: VALUE.clone(), len: vec![OPERATION_VALUE_GE] }
+        );
+        assert_eq!(
+            schema.clone().len_lt(5),
+            MapSchema { required: true, label: None, key: KEY.clone(), value: VALUE.clone(), len: vec![OPERATION_VALUE_LT] }
+        );
+        assert_eq!(
+            schema.clone().len_le(6),
+            MapSchema { required: true, label: None, key: KEY.clone(), value: VALUE.clone(), len: vec![OPERATION_VALUE_LE] }
+        );
+        assert_eq!(
+            schema.len_btwn(1, 9),
+            MapSchema { required: true, label: None, key: KEY.clone(), value: VALUE.clone(), len: vec![OPERATION_VALUE_BTWN] }
+        );
+    }
+
+    #[test]
+    fn map_schema_len_field() {
+        let schema = MapSchema::from(Schema::from(U64Schema::default()));
+        let field = Operand::FieldPath(FIELD.into());
+        let field_b = Operand::FieldPath(FIELD_B.into());
+        assert_eq!(schema.clone().len_eq_field(FIELD.into()).len, vec![Operation::Eq(field.clone())]);
+        assert_eq!(schema.clone().len_ne_field(FIELD.into()).len, vec![Operation::Ne(field.clone())]);
+        assert_eq!(schema.clone().len_gt_field(FIELD.into()).len, vec![Operation::Gt(field.clone())]);
+        assert_eq!(schema.clone().len_ge_field(FIELD.into()).len, vec![Operation::Ge(field.clone())]);
+        assert_eq!(schema.clone().len_lt_field(FIELD.into()).len, vec![Operation::Lt(field.clone())]);
+        assert_eq!(schema.clone().len_le_field(FIELD.into()).len, vec![Operation::Le(field.clone())]);
+        assert_eq!(schema.len_btwn_field(FIELD.into(), FIELD_B.into()).len, vec![Operation::Btwn(field, field_b)]);
+    }
+}
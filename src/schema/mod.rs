@@ -1,56 +1,144 @@
 use std::collections::BTreeMap;
 
+use crate::value::Value;
+
+pub use arr_schema::ArrSchema;
 pub use bool_schema::BoolSchema;
+#[cfg(feature = "async")]
+pub use custom_schema::{AsyncCustomFn, AsyncCustomValidator};
+pub use custom_schema::{CustomFn, CustomParams, CustomSchema, CustomValidator};
 pub use date_schema::DateSchema;
 pub use date_time_schema::DateTimeSchema;
 pub use email_schema::EmailSchema;
-pub use enum_schema::{EnumSchema, EnumValues};
+pub use enum_schema::{EnumSchema, EnumValue, EnumValues};
 pub use f64_schema::F64Schema;
+#[cfg(feature = "serde")]
+pub use from_json_schema::{JsonSchemaErr, JsonSchemaWarning};
 pub use i64_schema::I64Schema;
 pub use isize_schema::ISizeSchema;
-pub use str_schema::StrSchema;
+pub use map_schema::MapSchema;
+pub use str_schema::{Charset, Normalization, Pattern, StrPredicate, StrSchema, StrTransform};
+pub use tagged_schema::TaggedSchema;
 pub use time_schema::TimeSchema;
 pub use u64_schema::U64Schema;
+pub use union_schema::UnionSchema;
 pub use usize_schema::USizeSchema;
 
+mod arr_schema;
 mod bool_schema;
+mod custom_schema;
 mod date_schema;
 mod date_time_schema;
 mod email_schema;
 mod enum_schema;
 mod f64_schema;
+#[cfg(feature = "serde")]
+mod from_json_schema;
 mod i64_schema;
 mod isize_schema;
+#[cfg(feature = "serde")]
+mod json_schema;
+mod map_schema;
 mod str_schema;
+mod tagged_schema;
 mod time_schema;
 mod u64_schema;
+mod union_schema;
 mod usize_schema;
 
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum UnknownKeys {
+    Strip,
+    Reject,
+    Passthrough,
+}
+
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjSchema {
     pub required: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub label: Option<String>,
     pub validation: BTreeMap<String, Schema>,
+    pub unknown_keys: UnknownKeys,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub default: Option<BTreeMap<String, Value>>,
 }
 
 impl From<BTreeMap<String, Schema>> for ObjSchema {
     fn from(validation: BTreeMap<String, Schema>) -> Self {
-        ObjSchema { required: true, validation }
+        ObjSchema { required: true, label: None, validation, unknown_keys: UnknownKeys::Strip, default: None }
     }
 }
 
 impl<const N: usize> From<[(String, Schema); N]> for ObjSchema {
     fn from(value: [(String, Schema); N]) -> Self {
-        ObjSchema { required: true, validation: BTreeMap::from(value) }
+        ObjSchema { required: true, label: None, validation: BTreeMap::from(value), unknown_keys: UnknownKeys::Strip, default: None }
     }
 }
 
 impl ObjSchema {
     pub fn optional(self) -> Self {
-        ObjSchema { required: false, validation: self.validation }
+        ObjSchema { required: false, ..self }
+    }
+
+    pub fn label(self, label: &str) -> Self {
+        ObjSchema { label: Some(label.into()), ..self }
+    }
+
+    pub fn unknown_keys(self, unknown_keys: UnknownKeys) -> Self {
+        ObjSchema { unknown_keys, ..self }
+    }
+
+    pub fn default_value(self, value: BTreeMap<String, Value>) -> Self {
+        ObjSchema { default: Some(value), ..self }
+    }
+
+    pub fn pick(self, keys: &[&str]) -> Self {
+        let validation = self.validation.into_iter().filter(|(key, _)| keys.contains(&key.as_str())).collect();
+        ObjSchema { validation, ..self }
+    }
+
+    pub fn omit(self, keys: &[&str]) -> Self {
+        let validation = self.validation.into_iter().filter(|(key, _)| !keys.contains(&key.as_str())).collect();
+        ObjSchema { validation, ..self }
+    }
+
+    pub fn extend(mut self, validation: BTreeMap<String, Schema>) -> Self {
+        self.validation.extend(validation);
+        self
+    }
+
+    pub fn merge(mut self, other: ObjSchema) -> Self {
+        self.validation.extend(other.validation);
+        let default = match (self.default, other.default) {
+            (Some(mut default), Some(other_default)) => {
+                default.extend(other_default);
+                Some(default)
+            }
+            (default, other_default) => other_default.or(default),
+        };
+        ObjSchema {
+            required: other.required,
+            label: other.label.or(self.label),
+            validation: self.validation,
+            unknown_keys: other.unknown_keys,
+            default,
+        }
+    }
+
+    pub fn partial(self) -> Self {
+        let validation = self.validation.into_iter().map(|(key, schema)| (key, schema.partial_nested().optional())).collect();
+        ObjSchema { validation, ..self }
     }
 }
 
+// StrSchema is much larger than the other variants, but schemas are built once and then only
+// borrowed; boxing it would change the public `Schema::Str(StrSchema)` variant callers match on.
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
 pub enum Schema {
     U64(U64Schema),
     I64(I64Schema),
@@ -63,8 +151,170 @@ pub enum Schema {
     Date(DateSchema),
     Time(TimeSchema),
     DateTime(DateTimeSchema),
+    Arr(ArrSchema),
     Obj(ObjSchema),
+    Map(MapSchema),
     Enum(EnumSchema),
+    Union(UnionSchema),
+    Tagged(TaggedSchema),
+    #[cfg_attr(feature = "serde", serde(untagged, skip_deserializing))]
+    Custom(CustomSchema),
+}
+
+impl Schema {
+    pub fn is_required(&self) -> bool {
+        match self {
+            Schema::U64(schema) => schema.required,
+            Schema::I64(schema) => schema.required,
+            Schema::F64(schema) => schema.required,
+            Schema::USize(schema) => schema.required,
+            Schema::ISize(schema) => schema.required,
+            Schema::Bool(schema) => schema.required,
+            Schema::Str(schema) => schema.required,
+            Schema::Email(schema) => schema.required,
+            Schema::Date(schema) => schema.required,
+            Schema::Time(schema) => schema.required,
+            Schema::DateTime(schema) => schema.required,
+            Schema::Arr(schema) => schema.required,
+            Schema::Obj(schema) => schema.required,
+            Schema::Map(schema) => schema.required,
+            Schema::Enum(schema) => schema.required,
+            Schema::Union(schema) => schema.required,
+            Schema::Tagged(schema) => schema.required,
+            Schema::Custom(schema) => schema.schema.is_required(),
+        }
+    }
+
+    pub fn get_label(&self) -> Option<&str> {
+        match self {
+            Schema::U64(schema) => schema.label.as_deref(),
+            Schema::I64(schema) => schema.label.as_deref(),
+            Schema::F64(schema) => schema.label.as_deref(),
+            Schema::USize(schema) => schema.label.as_deref(),
+            Schema::ISize(schema) => schema.label.as_deref(),
+            Schema::Bool(schema) => schema.label.as_deref(),
+            Schema::Str(schema) => schema.label.as_deref(),
+            Schema::Email(schema) => schema.label.as_deref(),
+            Schema::Date(schema) => schema.label.as_deref(),
+            Schema::Time(schema) => schema.label.as_deref(),
+            Schema::DateTime(schema) => schema.label.as_deref(),
+            Schema::Arr(schema) => schema.label.as_deref(),
+            Schema::Obj(schema) => schema.label.as_deref(),
+            Schema::Map(schema) => schema.label.as_deref(),
+            Schema::Enum(schema) => schema.label.as_deref(),
+            Schema::Union(schema) => schema.label.as_deref(),
+            Schema::Tagged(schema) => schema.label.as_deref(),
+            Schema::Custom(schema) => schema.schema.get_label(),
+        }
+    }
+
+    pub fn get_default(&self) -> Option<Value> {
+        match self {
+            Schema::U64(schema) => schema.default.map(Value::U64),
+            Schema::Bool(schema) => schema.default.map(Value::Bool),
+            Schema::Str(schema) => schema.default.clone().map(Value::Str),
+            Schema::Obj(schema) => schema.default.clone().map(Value::Obj),
+            Schema::Enum(schema) => schema.default.clone().map(Value::from),
+            Schema::Custom(schema) => schema.schema.get_default(),
+            Schema::I64(_)
+            | Schema::F64(_)
+            | Schema::USize(_)
+            | Schema::ISize(_)
+            | Schema::Email(_)
+            | Schema::Date(_)
+            | Schema::Time(_)
+            | Schema::DateTime(_)
+            | Schema::Arr(_)
+            | Schema::Map(_)
+            | Schema::Union(_)
+            | Schema::Tagged(_) => None,
+        }
+    }
+
+    pub fn optional(self) -> Self {
+        match self {
+            Schema::U64(schema) => Schema::U64(schema.optional()),
+            Schema::I64(schema) => Schema::I64(schema.optional()),
+            Schema::F64(schema) => Schema::F64(schema.optional()),
+            Schema::USize(schema) => Schema::USize(schema.optional()),
+            Schema::ISize(schema) => Schema::ISize(schema.optional()),
+            Schema::Bool(schema) => Schema::Bool(schema.optional()),
+            Schema::Str(schema) => Schema::Str(schema.optional()),
+            Schema::Email(schema) => Schema::Email(schema.optional()),
+            Schema::Date(schema) => Schema::Date(schema.optional()),
+            Schema::Time(schema) => Schema::Time(schema.optional()),
+            Schema::DateTime(schema) => Schema::DateTime(schema.optional()),
+            Schema::Arr(schema) => Schema::Arr(schema.optional()),
+            Schema::Obj(schema) => Schema::Obj(schema.optional()),
+            Schema::Map(schema) => Schema::Map(schema.optional()),
+            Schema::Enum(schema) => Schema::Enum(schema.optional()),
+            Schema::Union(schema) => Schema::Union(schema.optional()),
+            Schema::Tagged(schema) => Schema::Tagged(schema.optional()),
+            Schema::Custom(schema) => Schema::Custom(schema.optional()),
+        }
+    }
+
+    fn partial_nested(self) -> Self {
+        match self {
+            Schema::Arr(schema) => Schema::Arr(ArrSchema { item: Box::new(schema.item.partial_nested()), ..schema }),
+            Schema::Obj(schema) => Schema::Obj(schema.partial()),
+            Schema::Map(schema) => Schema::Map(MapSchema { value: Box::new(schema.value.partial_nested()), ..schema }),
+            Schema::Union(schema) => {
+                Schema::Union(UnionSchema { variants: schema.variants.into_iter().map(Schema::partial_nested).collect(), ..schema })
+            }
+            Schema::Tagged(schema) => Schema::Tagged(TaggedSchema {
+                variants: schema.variants.into_iter().map(|(tag_value, variant)| (tag_value, variant.partial())).collect(),
+                ..schema
+            }),
+            Schema::Custom(schema) => Schema::Custom(CustomSchema { schema: Box::new(schema.schema.partial_nested()), ..schema }),
+            schema => schema,
+        }
+    }
+
+    pub fn label(self, label: &str) -> Self {
+        match self {
+            Schema::U64(schema) => Schema::U64(schema.label(label)),
+            Schema::I64(schema) => Schema::I64(schema.label(label)),
+            Schema::F64(schema) => Schema::F64(schema.label(label)),
+            Schema::USize(schema) => Schema::USize(schema.label(label)),
+            Schema::ISize(schema) => Schema::ISize(schema.label(label)),
+            Schema::Bool(schema) => Schema::Bool(schema.label(label)),
+            Schema::Str(schema) => Schema::Str(schema.label(label)),
+            Schema::Email(schema) => Schema::Email(schema.label(label)),
+            Schema::Date(schema) => Schema::Date(schema.label(label)),
+            Schema::Time(schema) => Schema::Time(schema.label(label)),
+            Schema::DateTime(schema) => Schema::DateTime(schema.label(label)),
+            Schema::Arr(schema) => Schema::Arr(schema.label(label)),
+            Schema::Obj(schema) => Schema::Obj(schema.label(label)),
+            Schema::Map(schema) => Schema::Map(schema.label(label)),
+            Schema::Enum(schema) => Schema::Enum(schema.label(label)),
+            Schema::Union(schema) => Schema::Union(schema.label(label)),
+            Schema::Tagged(schema) => Schema::Tagged(schema.label(label)),
+            Schema::Custom(schema) => Schema::Custom(schema.label(label)),
+        }
+    }
+
+    pub fn custom<F>(self, code: &str, validate: F) -> Self
+    where
+        F: Fn(&Value, &Value) -> Result<(), CustomParams> + Send + Sync + 'static,
+    {
+        match self {
+            Schema::Custom(schema) => Schema::Custom(schema.validator(code, validate)),
+            schema => Schema::Custom(CustomSchema::from(schema).validator(code, validate)),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn custom_async<F, Fut>(self, code: &str, validate: F) -> Self
+    where
+        F: Fn(Value, Value) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = Result<(), CustomParams>> + Send + 'static,
+    {
+        match self {
+            Schema::Custom(schema) => Schema::Custom(schema.async_validator(code, validate)),
+            schema => Schema::Custom(CustomSchema::from(schema).async_validator(code, validate)),
+        }
+    }
 }
 
 impl From<U64Schema> for Schema {
@@ -133,78 +383,433 @@ impl From<DateTimeSchema> for Schema {
     }
 }
 
+impl From<ArrSchema> for Schema {
+    fn from(validation: ArrSchema) -> Self {
+        Schema::Arr(validation)
+    }
+}
+
 impl From<ObjSchema> for Schema {
     fn from(validation: ObjSchema) -> Self {
         Schema::Obj(validation)
     }
 }
 
+impl From<MapSchema> for Schema {
+    fn from(validation: MapSchema) -> Self {
+        Schema::Map(validation)
+    }
+}
+
 impl From<EnumSchema> for Schema {
     fn from(validation: EnumSchema) -> Self {
         Schema::Enum(validation)
     }
 }
 
+impl From<UnionSchema> for Schema {
+    fn from(validation: UnionSchema) -> Self {
+        Schema::Union(validation)
+    }
+}
+
+impl From<TaggedSchema> for Schema {
+    fn from(validation: TaggedSchema) -> Self {
+        Schema::Tagged(validation)
+    }
+}
+
+impl From<CustomSchema> for Schema {
+    fn from(validation: CustomSchema) -> Self {
+        Schema::Custom(validation)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
 
+    #[cfg(feature = "serde")]
+    use crate::operation::{parse_date, parse_time};
+    use crate::value::Value;
+
+    #[cfg(feature = "serde")]
+    use super::Pattern;
+
     use super::{
-        BoolSchema, DateSchema, DateTimeSchema, EmailSchema, EnumSchema, EnumValues, F64Schema, I64Schema, ISizeSchema, ObjSchema, Schema, StrSchema,
-        TimeSchema, U64Schema, USizeSchema,
+        ArrSchema, BoolSchema, DateSchema, DateTimeSchema, EmailSchema, EnumSchema, EnumValues, F64Schema, I64Schema, ISizeSchema, MapSchema,
+        ObjSchema, Schema, StrSchema, TaggedSchema, TimeSchema, U64Schema, USizeSchema, UnionSchema, UnknownKeys,
     };
 
     #[test]
     fn obj_validation() {
         assert_eq!(
             ObjSchema::from(BTreeMap::from([("is".into(), Schema::Bool(BoolSchema::default().eq(false)))])),
-            ObjSchema { required: true, validation: BTreeMap::from([("is".into(), Schema::Bool(BoolSchema::default().eq(false)))]) }
+            ObjSchema {
+                required: true,
+                label: None,
+                validation: BTreeMap::from([("is".into(), Schema::Bool(BoolSchema::default().eq(false)))]),
+                unknown_keys: UnknownKeys::Strip,
+                default: None
+            }
         );
         assert_eq!(
             ObjSchema::from(BTreeMap::from([("is".into(), Schema::Bool(BoolSchema::default().eq(false)))])).optional(),
-            ObjSchema { required: false, validation: BTreeMap::from([("is".into(), Schema::Bool(BoolSchema::default().eq(false)))]) }
+            ObjSchema {
+                required: false,
+                label: None,
+                validation: BTreeMap::from([("is".into(), Schema::Bool(BoolSchema::default().eq(false)))]),
+                unknown_keys: UnknownKeys::Strip,
+                default: None
+            }
         );
         assert_eq!(
             ObjSchema::from([("is".into(), Schema::Bool(BoolSchema::default().eq(false)))]),
-            ObjSchema { required: true, validation: BTreeMap::from([("is".into(), Schema::Bool(BoolSchema::default().eq(false)))]) }
+            ObjSchema {
+                required: true,
+                label: None,
+                validation: BTreeMap::from([("is".into(), Schema::Bool(BoolSchema::default().eq(false)))]),
+                unknown_keys: UnknownKeys::Strip,
+                default: None
+            }
         );
         assert_eq!(
             ObjSchema::from([("is".into(), Schema::Bool(BoolSchema::default().eq(false)))]).optional(),
-            ObjSchema { required: false, validation: BTreeMap::from([("is".into(), Schema::Bool(BoolSchema::default().eq(false)))]) }
+            ObjSchema {
+                required: false,
+                label: None,
+                validation: BTreeMap::from([("is".into(), Schema::Bool(BoolSchema::default().eq(false)))]),
+                unknown_keys: UnknownKeys::Strip,
+                default: None
+            }
+        );
+        assert_eq!(
+            ObjSchema::from([("is".into(), Schema::Bool(BoolSchema::default().eq(false)))]).unknown_keys(UnknownKeys::Reject),
+            ObjSchema {
+                required: true,
+                label: None,
+                validation: BTreeMap::from([("is".into(), Schema::Bool(BoolSchema::default().eq(false)))]),
+                unknown_keys: UnknownKeys::Reject,
+                default: None
+            }
+        );
+        assert_eq!(
+            ObjSchema::from(BTreeMap::new()).unknown_keys(UnknownKeys::Passthrough).optional(),
+            ObjSchema { required: false, label: None, validation: BTreeMap::new(), unknown_keys: UnknownKeys::Passthrough, default: None }
+        );
+    }
+
+    #[test]
+    fn obj_composition() {
+        let schema = ObjSchema::from([
+            ("id".into(), Schema::from(U64Schema::default())),
+            ("name".into(), Schema::from(StrSchema::default())),
+            ("email".into(), Schema::from(EmailSchema::default())),
+        ]);
+        assert_eq!(
+            schema.clone().pick(&["name", "email", "age"]),
+            ObjSchema::from([("name".into(), Schema::from(StrSchema::default())), ("email".into(), Schema::from(EmailSchema::default()))])
         );
+        assert_eq!(schema.clone().omit(&["name", "email"]), ObjSchema::from([("id".into(), Schema::from(U64Schema::default()))]));
+        assert_eq!(
+            schema.clone().omit(&["name", "email"]).extend(BTreeMap::from([("id".into(), Schema::from(StrSchema::default()))])),
+            ObjSchema::from([("id".into(), Schema::from(StrSchema::default()))])
+        );
+        assert_eq!(
+            schema
+                .clone()
+                .pick(&["id"])
+                .unknown_keys(UnknownKeys::Reject)
+                .merge(ObjSchema::from([("alive".into(), Schema::from(BoolSchema::default()))]).unknown_keys(UnknownKeys::Passthrough)),
+            ObjSchema::from([("id".into(), Schema::from(U64Schema::default())), ("alive".into(), Schema::from(BoolSchema::default()))])
+                .unknown_keys(UnknownKeys::Passthrough)
+        );
+        assert_eq!(
+            schema.partial(),
+            ObjSchema::from([
+                ("id".into(), Schema::from(U64Schema::default().optional())),
+                ("name".into(), Schema::from(StrSchema::default().optional())),
+                ("email".into(), Schema::from(EmailSchema::default().optional())),
+            ])
+        );
+    }
+
+    #[test]
+    fn obj_merge() {
+        let base = ObjSchema::from([("id".into(), Schema::from(U64Schema::default())), ("name".into(), Schema::from(StrSchema::default()))])
+            .label("User")
+            .unknown_keys(UnknownKeys::Reject)
+            .default_value(BTreeMap::from([("id".into(), Value::U64(1)), ("name".into(), Value::from("Paul"))]));
+        let other = ObjSchema::from([("name".into(), Schema::from(EmailSchema::default()))]);
+        let merged = base.clone().merge(other.clone());
+        assert_eq!(
+            merged.validation,
+            BTreeMap::from([("id".into(), Schema::from(U64Schema::default())), ("name".into(), Schema::from(EmailSchema::default()))])
+        );
+        assert!(merged.required);
+        assert_eq!(merged.label, Some("User".into()));
+        assert_eq!(merged.unknown_keys, UnknownKeys::Strip);
+        assert_eq!(merged.default, Some(BTreeMap::from([("id".into(), Value::U64(1)), ("name".into(), Value::from("Paul"))])));
+        let other = other
+            .optional()
+            .label("Account")
+            .unknown_keys(UnknownKeys::Passthrough)
+            .default_value(BTreeMap::from([("name".into(), Value::from("paul@gmail.com"))]));
+        let merged = base.clone().merge(other);
+        assert!(!merged.required);
+        assert_eq!(merged.label, Some("Account".into()));
+        assert_eq!(merged.unknown_keys, UnknownKeys::Passthrough);
+        assert_eq!(merged.default, Some(BTreeMap::from([("id".into(), Value::U64(1)), ("name".into(), Value::from("paul@gmail.com"))])));
+        assert_eq!(ObjSchema::from(BTreeMap::new()).merge(base.clone()).default, base.default);
+    }
+
+    #[test]
+    fn obj_partial_nested() {
+        let address = ObjSchema::from([("street".into(), Schema::from(StrSchema::default()))]);
+        let schema = ObjSchema::from([
+            ("address".into(), Schema::from(address.clone())),
+            ("addresses".into(), Schema::from(ArrSchema::from(Schema::from(address.clone())))),
+            ("by_name".into(), Schema::from(MapSchema::from(Schema::from(address.clone())))),
+            ("tags".into(), Schema::from(ArrSchema::from(Schema::from(StrSchema::default())))),
+        ]);
+        let partial_address = ObjSchema::from([("street".into(), Schema::from(StrSchema::default().optional()))]);
+        assert_eq!(
+            schema.partial(),
+            ObjSchema::from([
+                ("address".into(), Schema::from(partial_address.clone().optional())),
+                ("addresses".into(), Schema::from(ArrSchema::from(Schema::from(partial_address.clone())).optional())),
+                ("by_name".into(), Schema::from(MapSchema::from(Schema::from(partial_address.clone())).optional())),
+                ("tags".into(), Schema::from(ArrSchema::from(Schema::from(StrSchema::default())).optional())),
+            ])
+        );
+        let schema = ObjSchema::from([
+            ("payment".into(), Schema::from(UnionSchema::from([Schema::from(address.clone()), Schema::from(U64Schema::default())]))),
+            ("event".into(), Schema::from(TaggedSchema::from("type".to_string()).variant("move".into(), address.clone()))),
+            ("checked".into(), Schema::from(address).custom("ok", |_, _| Ok(()))),
+        ]);
+        let partial = schema.partial();
+        assert_eq!(
+            partial.validation["payment"],
+            Schema::from(UnionSchema::from([Schema::from(partial_address.clone()), Schema::from(U64Schema::default())]).optional())
+        );
+        assert_eq!(
+            partial.validation["event"],
+            Schema::from(TaggedSchema::from("type".to_string()).variant("move".into(), partial_address.clone()).optional())
+        );
+        let Schema::Custom(checked) = &partial.validation["checked"] else { panic!("expected a custom schema") };
+        assert_eq!(*checked.schema, Schema::from(partial_address.optional()));
+        assert_eq!(checked.validators.len(), 1);
+    }
+
+    #[test]
+    fn schema_optional() {
+        assert_eq!(Schema::from(U64Schema::default()).optional(), Schema::from(U64Schema::default().optional()));
+        assert_eq!(Schema::from(DateSchema::default()).optional(), Schema::from(DateSchema::default().optional()));
+        assert_eq!(
+            Schema::from(ArrSchema::from(Schema::from(BoolSchema::default()))).optional(),
+            Schema::from(ArrSchema::from(Schema::from(BoolSchema::default())).optional())
+        );
+        assert_eq!(Schema::from(ObjSchema::from(BTreeMap::new())).optional(), Schema::from(ObjSchema::from(BTreeMap::new()).optional()));
+        assert_eq!(Schema::from(EnumSchema::from(["A", "B"])).optional(), Schema::from(EnumSchema::from(["A", "B"]).optional()));
+    }
+
+    #[test]
+    fn schema_label() {
+        assert_eq!(Schema::from(U64Schema::default()).get_label(), None);
+        assert_eq!(Schema::from(U64Schema::default()).label("Age"), Schema::from(U64Schema::default().label("Age")));
+        assert_eq!(Schema::from(U64Schema::default()).label("Age").get_label(), Some("Age"));
+        assert_eq!(Schema::from(EmailSchema::default()).label("E-mail").get_label(), Some("E-mail"));
+        assert_eq!(Schema::from(ObjSchema::from(BTreeMap::new())).label("User"), Schema::from(ObjSchema::from(BTreeMap::new()).label("User")));
+        assert_eq!(Schema::from(EnumSchema::from(["A", "B"]).optional()).label("Letter").get_label(), Some("Letter"));
+    }
+
+    #[test]
+    fn schema_default_value() {
+        let server = BTreeMap::from([("port".into(), Value::U64(8080))]);
+        assert_eq!(ObjSchema::from(BTreeMap::new()).default_value(server.clone()).default, Some(server.clone()));
+        assert_eq!(Schema::from(U64Schema::default()).get_default(), None);
+        assert_eq!(Schema::from(U64Schema::default().default_value(8080)).get_default(), Some(Value::U64(8080)));
+        assert_eq!(Schema::from(BoolSchema::default().default_value(false)).get_default(), Some(Value::Bool(false)));
+        assert_eq!(Schema::from(StrSchema::default().default_value("info")).get_default(), Some(Value::from("info")));
+        assert_eq!(Schema::from(EnumSchema::from(["ADMIN", "USER"]).default_value("USER")).get_default(), Some(Value::from("USER")));
+        assert_eq!(Schema::from(ObjSchema::from(BTreeMap::new()).default_value(server.clone())).get_default(), Some(Value::Obj(server)));
+        assert_eq!(Schema::from(U64Schema::default().default_value(1)).custom("a", |_, _| Ok(())).get_default(), Some(Value::U64(1)));
+        assert_eq!(Schema::from(I64Schema::default()).get_default(), None);
+    }
+
+    #[test]
+    fn schema_custom() {
+        let schema = Schema::from(StrSchema::default()).custom("a", |_, _| Ok(())).custom("b", |_, _| Ok(()));
+        let Schema::Custom(custom) = &schema else { panic!("expected Schema::Custom") };
+        assert_eq!(custom.schema, Box::new(Schema::from(StrSchema::default())));
+        assert_eq!(custom.validators.iter().map(|validator| validator.code.as_str()).collect::<Vec<&str>>(), vec!["a", "b"]);
+        assert!(schema.is_required());
+        assert!(!schema.clone().optional().is_required());
+        assert_eq!(schema.clone().label("Username").get_label(), Some("Username"));
     }
 
     #[test]
     fn validation_from() {
         let enum_usize: Vec<usize> = vec![1, 2, 3];
-        assert_eq!(Schema::from(U64Schema::default()), Schema::U64(U64Schema { required: true, operation: None }));
-        assert_eq!(Schema::from(I64Schema::default()), Schema::I64(I64Schema { required: true, operation: None }));
-        assert_eq!(Schema::from(F64Schema::default()), Schema::F64(F64Schema { required: true, operation: None }));
-        assert_eq!(Schema::from(USizeSchema::default()), Schema::USize(USizeSchema { required: true, operation: None }));
-        assert_eq!(Schema::from(ISizeSchema::default()), Schema::ISize(ISizeSchema { required: true, operation: None }));
-        assert_eq!(Schema::from(BoolSchema::default()), Schema::Bool(BoolSchema { required: true, operation: None }));
+        assert_eq!(
+            Schema::from(U64Schema::default()),
+            Schema::U64(U64Schema { required: true, label: None, operations: vec![], coerce: false, default: None })
+        );
+        assert_eq!(Schema::from(I64Schema::default()), Schema::I64(I64Schema { required: true, label: None, operations: vec![] }));
+        assert_eq!(Schema::from(F64Schema::default()), Schema::F64(F64Schema { required: true, label: None, operations: vec![] }));
+        assert_eq!(Schema::from(USizeSchema::default()), Schema::USize(USizeSchema { required: true, label: None, operations: vec![] }));
+        assert_eq!(Schema::from(ISizeSchema::default()), Schema::ISize(ISizeSchema { required: true, label: None, operations: vec![] }));
+        assert_eq!(Schema::from(BoolSchema::default()), Schema::Bool(BoolSchema { required: true, label: None, operations: vec![], default: None }));
         assert_eq!(
             Schema::from(StrSchema::default()),
             Schema::Str(StrSchema {
                 required: true,
-                operation: None,
-                bytes_len: None,
-                chars_len: None,
-                graphemes_len: None,
-                lowercase_len: None,
-                uppercase_len: None,
-                numbers_len: None,
-                symbols_len: None,
+                label: None,
+                operations: vec![],
+                bytes_len: vec![],
+                chars_len: vec![],
+                graphemes_len: vec![],
+                lowercase_len: vec![],
+                uppercase_len: vec![],
+                numbers_len: vec![],
+                symbols_len: vec![],
+                patterns: vec![],
+                predicates: vec![],
+                len_normalization: None,
+                transforms: vec![],
+                default: None,
+            })
+        );
+        assert_eq!(Schema::from(EmailSchema::default()), Schema::Email(EmailSchema { required: true, label: None }));
+        assert_eq!(Schema::from(DateSchema::default()), Schema::Date(DateSchema { required: true, label: None, operations: vec![] }));
+        assert_eq!(Schema::from(TimeSchema::default()), Schema::Time(TimeSchema { required: true, label: None, operations: vec![] }));
+        assert_eq!(Schema::from(DateTimeSchema::default()), Schema::DateTime(DateTimeSchema { required: true, label: None, operations: vec![] }));
+        assert_eq!(
+            Schema::from(ArrSchema::from(Schema::from(BoolSchema::default()))),
+            Schema::Arr(ArrSchema {
+                required: true,
+                label: None,
+                item: Box::new(Schema::Bool(BoolSchema { required: true, label: None, operations: vec![], default: None })),
+                len: vec![]
+            })
+        );
+        assert_eq!(
+            Schema::from(ObjSchema::from(BTreeMap::new())),
+            Schema::Obj(ObjSchema { required: true, label: None, validation: BTreeMap::new(), unknown_keys: UnknownKeys::Strip, default: None })
+        );
+        assert_eq!(
+            Schema::from(MapSchema::from(Schema::from(BoolSchema::default()))),
+            Schema::Map(MapSchema {
+                required: true,
+                label: None,
+                key: Box::new(Schema::Str(StrSchema::default())),
+                value: Box::new(Schema::Bool(BoolSchema { required: true, label: None, operations: vec![], default: None })),
+                len: vec![]
             })
         );
-        assert_eq!(Schema::from(EmailSchema::default()), Schema::Email(EmailSchema { required: true }));
-        assert_eq!(Schema::from(DateSchema::default()), Schema::Date(DateSchema { required: true, operation: None }));
-        assert_eq!(Schema::from(TimeSchema::default()), Schema::Time(TimeSchema { required: true, operation: None }));
-        assert_eq!(Schema::from(DateTimeSchema::default()), Schema::DateTime(DateTimeSchema { required: true, operation: None }));
-        assert_eq!(Schema::from(ObjSchema::from(BTreeMap::new())), Schema::Obj(ObjSchema { required: true, validation: BTreeMap::new() }));
         assert_eq!(
             Schema::from(EnumSchema::from(enum_usize.clone())),
-            Schema::Enum(EnumSchema { required: true, values: EnumValues::USize(enum_usize) })
+            Schema::Enum(EnumSchema { required: true, label: None, values: EnumValues::USize(enum_usize), default: None })
+        );
+        assert_eq!(
+            Schema::from(UnionSchema::from([Schema::from(BoolSchema::default())])),
+            Schema::Union(UnionSchema {
+                required: true,
+                label: None,
+                variants: vec![Schema::Bool(BoolSchema { required: true, label: None, operations: vec![], default: None })]
+            })
         );
+        assert_eq!(
+            Schema::from(TaggedSchema::from("type".to_string())),
+            Schema::Tagged(TaggedSchema { required: true, label: None, tag: "type".into(), variants: BTreeMap::new() })
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn schema_serde_json() {
+        let schema = Schema::from(ObjSchema::from([
+            (
+                "name".into(),
+                Schema::from(StrSchema::default().chars_len_btwn(1, 64).ne_field("user.name".into()).pattern(Pattern::new("^[A-Z]").unwrap())),
+            ),
+            ("age".into(), Schema::from(U64Schema::default().ge(18).optional().default_value(18))),
+            ("birthdate".into(), Schema::from(DateSchema::default().unix_epoch().age_ge(18))),
+            ("start".into(), Schema::from(TimeSchema::default().ge(parse_time("08:00:30.5").unwrap()))),
+            ("created_at".into(), Schema::from(DateTimeSchema::default().le_now())),
+            ("role".into(), Schema::from(EnumSchema::from(["ADMIN", "USER"]).default_value("USER"))),
+            ("tags".into(), Schema::from(ArrSchema::from(Schema::from(StrSchema::default())).len_le(3))),
+            ("flags".into(), Schema::from(MapSchema::from(Schema::from(BoolSchema::default())))),
+            (
+                "settings".into(),
+                Schema::from(
+                    ObjSchema::from([("theme".into(), Schema::from(StrSchema::default().default_value("dark")))])
+                        .optional()
+                        .default_value(BTreeMap::from([("theme".into(), Value::from("light"))])),
+                ),
+            ),
+            ("id".into(), Schema::from(UnionSchema::from([Schema::from(USizeSchema::default()), Schema::from(EmailSchema::default())]))),
+            ("event".into(), Schema::from(TaggedSchema::from("type".to_string()).variant("click".into(), ObjSchema::from(BTreeMap::new())))),
+        ]));
+        let json = serde_json::to_string(&schema).unwrap();
+        assert_eq!(serde_json::from_str::<Schema>(&json).unwrap(), schema);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn schema_serde_json_format() {
+        let schema = Schema::from(
+            ObjSchema::from([
+                ("min".into(), Schema::from(DateSchema::default().ge(parse_date("2026-10-18").unwrap()))),
+                ("max".into(), Schema::from(U64Schema::default().gt_field("min".into()).optional())),
+            ])
+            .unknown_keys(UnknownKeys::Reject),
+        );
+        let json = serde_json::json!({
+            "obj": {
+                "required": true,
+                "validation": {
+                    "max": { "u64": { "required": false, "operations": [{ "gt": { "field_path": "min" } }] } },
+                    "min": { "date": { "required": true, "operations": [{ "ge": { "value": { "date": "2026-10-18" } } }] } }
+                },
+                "unknown_keys": "reject"
+            }
+        });
+        assert_eq!(serde_json::to_value(&schema).unwrap(), json);
+        assert_eq!(serde_json::from_value::<Schema>(json).unwrap(), schema);
+        assert!(
+            serde_json::from_value::<Schema>(
+                serde_json::json!({ "date": { "required": true, "operations": [{ "eq": { "value": { "date": "2026-13-01" } } }] } })
+            )
+            .is_err()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn schema_serde_json_custom() {
+        let username = Schema::from(StrSchema::default().chars_len_btwn(1, 64)).custom("username_not_reserved", |_, _| Ok(()));
+        let schema = Schema::from(ObjSchema::from([("username".into(), username), ("age".into(), Schema::from(U64Schema::default()))]));
+        let plain = Schema::from(ObjSchema::from([
+            ("username".into(), Schema::from(StrSchema::default().chars_len_btwn(1, 64))),
+            ("age".into(), Schema::from(U64Schema::default())),
+        ]));
+        let json = serde_json::to_value(&schema).unwrap();
+        assert_eq!(json, serde_json::to_value(&plain).unwrap());
+        let loaded = serde_json::from_value::<Schema>(json).unwrap();
+        assert_eq!(loaded, plain);
+        let Schema::Obj(mut loaded) = loaded else { panic!("expected Schema::Obj") };
+        let username = loaded.validation.remove("username").unwrap().custom("username_not_reserved", |_, _| Ok(()));
+        loaded.validation.insert("username".into(), username);
+        assert_eq!(Schema::Obj(loaded), schema);
+    }
+
+    #[cfg(all(feature = "serde", feature = "async"))]
+    #[test]
+    fn schema_serde_json_custom_async() {
+        let email = Schema::from(EmailSchema::default()).custom_async("email_unique", |_, _| async { Ok(()) });
+        let json = serde_json::to_value(&email).unwrap();
+        assert_eq!(json, serde_json::to_value(Schema::from(EmailSchema::default())).unwrap());
+        assert_eq!(serde_json::from_value::<Schema>(json).unwrap(), Schema::from(EmailSchema::default()));
     }
 }
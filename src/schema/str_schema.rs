@@ -1,30 +1,210 @@
+use std::fmt;
+
+use regex::Regex;
+use unicode_normalization::{UnicodeNormalization, is_nfc, is_nfkc};
+
 use crate::operation::{Operand, OperandValue, Operation};
 
+#[derive(Clone)]
+pub struct Pattern(Regex);
+
+impl Pattern {
+    pub fn new(source: &str) -> Result<Self, regex::Error> {
+        Regex::new(source).map(Pattern)
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    pub fn is_match(&self, value: &str) -> bool {
+        self.0.is_match(value)
+    }
+}
+
+impl From<Regex> for Pattern {
+    fn from(regex: Regex) -> Self {
+        Pattern(regex)
+    }
+}
+
+impl fmt::Debug for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Pattern({:?})", self.as_str())
+    }
+}
+
+impl PartialEq for Pattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Pattern {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Pattern {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let source = String::deserialize(deserializer)?;
+        Pattern::new(&source).map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum Charset {
+    AsciiAlphanumeric,
+    Hex,
+    Base64Url,
+    Custom(String),
+}
+
+impl Charset {
+    pub fn contains(&self, c: char) -> bool {
+        match self {
+            Charset::AsciiAlphanumeric => c.is_ascii_alphanumeric(),
+            Charset::Hex => c.is_ascii_hexdigit(),
+            Charset::Base64Url => c.is_ascii_alphanumeric() || c == '-' || c == '_',
+            Charset::Custom(chars) => chars.contains(c),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum Normalization {
+    Nfc,
+    Nfkc,
+}
+
+impl Normalization {
+    pub fn is_normalized(&self, value: &str) -> bool {
+        match self {
+            Normalization::Nfc => is_nfc(value),
+            Normalization::Nfkc => is_nfkc(value),
+        }
+    }
+
+    pub fn normalize(&self, value: &str) -> String {
+        match self {
+            Normalization::Nfc => value.nfc().collect(),
+            Normalization::Nfkc => value.nfkc().collect(),
+        }
+    }
+}
+
+impl fmt::Display for Normalization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Normalization::Nfc => write!(f, "NFC"),
+            Normalization::Nfkc => write!(f, "NFKC"),
+        }
+    }
+}
+
+fn is_invisible(c: char) -> bool {
+    c.is_control()
+        || matches!(
+            c,
+            '\u{00AD}' | '\u{180E}' | '\u{200B}'..='\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2060}'..='\u{2064}' | '\u{2066}'..='\u{206F}' | '\u{FEFF}'
+        )
+}
+
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum StrPredicate {
+    StartsWith(String),
+    EndsWith(String),
+    Contains(String),
+    NotContains(String),
+    OnlyChars(Charset),
+    Trimmed,
+    NoControlChars,
+    Normalized(Normalization),
+}
+
+impl StrPredicate {
+    pub fn test(&self, value: &str) -> bool {
+        match self {
+            StrPredicate::StartsWith(prefix) => value.starts_with(prefix.as_str()),
+            StrPredicate::EndsWith(suffix) => value.ends_with(suffix.as_str()),
+            StrPredicate::Contains(part) => value.contains(part.as_str()),
+            StrPredicate::NotContains(part) => !value.contains(part.as_str()),
+            StrPredicate::OnlyChars(charset) => value.chars().all(|c| charset.contains(c)),
+            StrPredicate::Trimmed => value.trim() == value,
+            StrPredicate::NoControlChars => !value.chars().any(is_invisible),
+            StrPredicate::Normalized(normalization) => normalization.is_normalized(value),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum StrTransform {
+    Trim,
+    Lowercase,
+    CollapseWhitespace,
+}
+
+impl StrTransform {
+    pub fn apply(&self, value: &str) -> String {
+        match self {
+            StrTransform::Trim => value.trim().into(),
+            StrTransform::Lowercase => value.to_lowercase(),
+            StrTransform::CollapseWhitespace => value.split_whitespace().collect::<Vec<&str>>().join(" "),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StrSchema {
     pub required: bool,
-    pub operation: Option<Operation>,
-    pub bytes_len: Option<Operation>,
-    pub chars_len: Option<Operation>,
-    pub graphemes_len: Option<Operation>,
-    pub lowercase_len: Option<Operation>,
-    pub uppercase_len: Option<Operation>,
-    pub numbers_len: Option<Operation>,
-    pub symbols_len: Option<Operation>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub label: Option<String>,
+    pub operations: Vec<Operation>,
+    pub bytes_len: Vec<Operation>,
+    pub chars_len: Vec<Operation>,
+    pub graphemes_len: Vec<Operation>,
+    pub lowercase_len: Vec<Operation>,
+    pub uppercase_len: Vec<Operation>,
+    pub numbers_len: Vec<Operation>,
+    pub symbols_len: Vec<Operation>,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub patterns: Vec<Pattern>,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub predicates: Vec<StrPredicate>,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub len_normalization: Option<Normalization>,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub transforms: Vec<StrTransform>,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub default: Option<String>,
 }
 
 impl Default for StrSchema {
     fn default() -> Self {
         StrSchema {
             required: true,
-            operation: None,
-            bytes_len: None,
-            chars_len: None,
-            graphemes_len: None,
-            lowercase_len: None,
-            uppercase_len: None,
-            numbers_len: None,
-            symbols_len: None,
+            label: None,
+            operations: vec![],
+            bytes_len: vec![],
+            chars_len: vec![],
+            graphemes_len: vec![],
+            lowercase_len: vec![],
+            uppercase_len: vec![],
+            numbers_len: vec![],
+            symbols_len: vec![],
+            patterns: vec![],
+            predicates: vec![],
+            len_normalization: None,
+            transforms: vec![],
+            default: None,
         }
     }
 }
@@ -34,467 +214,626 @@ impl StrSchema {
         StrSchema { required: false, ..self }
     }
 
-    pub fn eq(self, value: String) -> Self {
-        StrSchema { operation: Some(Operation::Eq(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn label(self, label: &str) -> Self {
+        StrSchema { label: Some(label.into()), ..self }
     }
 
-    pub fn ne(self, value: String) -> Self {
-        StrSchema { operation: Some(Operation::Ne(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn pattern(mut self, pattern: Pattern) -> Self {
+        self.patterns.push(pattern);
+        self
     }
 
-    pub fn gt(self, value: String) -> Self {
-        StrSchema { operation: Some(Operation::Gt(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn starts_with(mut self, prefix: &str) -> Self {
+        self.predicates.push(StrPredicate::StartsWith(prefix.into()));
+        self
     }
 
-    pub fn ge(self, value: String) -> Self {
-        StrSchema { operation: Some(Operation::Ge(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn ends_with(mut self, suffix: &str) -> Self {
+        self.predicates.push(StrPredicate::EndsWith(suffix.into()));
+        self
     }
 
-    pub fn lt(self, value: String) -> Self {
-        StrSchema { operation: Some(Operation::Lt(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn contains(mut self, part: &str) -> Self {
+        self.predicates.push(StrPredicate::Contains(part.into()));
+        self
     }
 
-    pub fn le(self, value: String) -> Self {
-        StrSchema { operation: Some(Operation::Le(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn not_contains(mut self, part: &str) -> Self {
+        self.predicates.push(StrPredicate::NotContains(part.into()));
+        self
     }
 
-    pub fn btwn(self, value_a: String, value_b: String) -> Self {
-        StrSchema { operation: Some(Operation::Btwn(Operand::Value(OperandValue::Str(value_a)), Operand::Value(OperandValue::Str(value_b)))), ..self }
+    pub fn only_chars(mut self, charset: Charset) -> Self {
+        self.predicates.push(StrPredicate::OnlyChars(charset));
+        self
     }
 
-    pub fn bytes_len_eq(self, len: usize) -> Self {
-        StrSchema { bytes_len: Some(Operation::Eq(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn trimmed(mut self) -> Self {
+        self.predicates.push(StrPredicate::Trimmed);
+        self
     }
 
-    pub fn bytes_len_ne(self, len: usize) -> Self {
-        StrSchema { bytes_len: Some(Operation::Ne(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn no_control_chars(mut self) -> Self {
+        self.predicates.push(StrPredicate::NoControlChars);
+        self
     }
 
-    pub fn bytes_len_gt(self, len: usize) -> Self {
-        StrSchema { bytes_len: Some(Operation::Gt(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn normalized(mut self, normalization: Normalization) -> Self {
+        self.predicates.push(StrPredicate::Normalized(normalization));
+        self
     }
 
-    pub fn bytes_len_ge(self, len: usize) -> Self {
-        StrSchema { bytes_len: Some(Operation::Ge(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn normalize_len(self, normalization: Normalization) -> Self {
+        StrSchema { len_normalization: Some(normalization), ..self }
     }
 
-    pub fn bytes_len_lt(self, len: usize) -> Self {
-        StrSchema { bytes_len: Some(Operation::Lt(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn default_value(self, value: &str) -> Self {
+        StrSchema { default: Some(value.into()), ..self }
     }
 
-    pub fn bytes_len_le(self, len: usize) -> Self {
-        StrSchema { bytes_len: Some(Operation::Le(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn transform(mut self, transform: StrTransform) -> Self {
+        self.transforms.push(transform);
+        self
     }
 
-    pub fn bytes_len_btwn(self, len_a: usize, len_b: usize) -> Self {
-        StrSchema { bytes_len: Some(Operation::Btwn(Operand::Value(OperandValue::USize(len_a)), Operand::Value(OperandValue::USize(len_b)))), ..self }
+    pub fn eq(mut self, value: String) -> Self {
+        self.operations.push(Operation::Eq(Operand::Value(OperandValue::Str(value))));
+        self
     }
 
-    pub fn chars_len_eq(self, len: usize) -> Self {
-        StrSchema { chars_len: Some(Operation::Eq(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn ne(mut self, value: String) -> Self {
+        self.operations.push(Operation::Ne(Operand::Value(OperandValue::Str(value))));
+        self
     }
 
-    pub fn chars_len_ne(self, len: usize) -> Self {
-        StrSchema { chars_len: Some(Operation::Ne(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn gt(mut self, value: String) -> Self {
+        self.operations.push(Operation::Gt(Operand::Value(OperandValue::Str(value))));
+        self
     }
 
-    pub fn chars_len_gt(self, len: usize) -> Self {
-        StrSchema { chars_len: Some(Operation::Gt(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn ge(mut self, value: String) -> Self {
+        self.operations.push(Operation::Ge(Operand::Value(OperandValue::Str(value))));
+        self
     }
 
-    pub fn chars_len_ge(self, len: usize) -> Self {
-        StrSchema { chars_len: Some(Operation::Ge(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn lt(mut self, value: String) -> Self {
+        self.operations.push(Operation::Lt(Operand::Value(OperandValue::Str(value))));
+        self
     }
 
-    pub fn chars_len_lt(self, len: usize) -> Self {
-        StrSchema { chars_len: Some(Operation::Lt(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn le(mut self, value: String) -> Self {
+        self.operations.push(Operation::Le(Operand::Value(OperandValue::Str(value))));
+        self
     }
 
-    pub fn chars_len_le(self, len: usize) -> Self {
-        StrSchema { chars_len: Some(Operation::Le(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn btwn(mut self, value_a: String, value_b: String) -> Self {
+        self.operations.push(Operation::Btwn(Operand::Value(OperandValue::Str(value_a)), Operand::Value(OperandValue::Str(value_b))));
+        self
     }
 
-    pub fn chars_len_btwn(self, len_a: usize, len_b: usize) -> Self {
-        StrSchema { chars_len: Some(Operation::Btwn(Operand::Value(OperandValue::USize(len_a)), Operand::Value(OperandValue::USize(len_b)))), ..self }
+    pub fn bytes_len_eq(mut self, len: usize) -> Self {
+        self.bytes_len.push(Operation::Eq(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn graphemes_len_eq(self, len: usize) -> Self {
-        StrSchema { graphemes_len: Some(Operation::Eq(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn bytes_len_ne(mut self, len: usize) -> Self {
+        self.bytes_len.push(Operation::Ne(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn graphemes_len_ne(self, len: usize) -> Self {
-        StrSchema { graphemes_len: Some(Operation::Ne(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn bytes_len_gt(mut self, len: usize) -> Self {
+        self.bytes_len.push(Operation::Gt(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn graphemes_len_gt(self, len: usize) -> Self {
-        StrSchema { graphemes_len: Some(Operation::Gt(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn bytes_len_ge(mut self, len: usize) -> Self {
+        self.bytes_len.push(Operation::Ge(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn graphemes_len_ge(self, len: usize) -> Self {
-        StrSchema { graphemes_len: Some(Operation::Ge(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn bytes_len_lt(mut self, len: usize) -> Self {
+        self.bytes_len.push(Operation::Lt(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn graphemes_len_lt(self, len: usize) -> Self {
-        StrSchema { graphemes_len: Some(Operation::Lt(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn bytes_len_le(mut self, len: usize) -> Self {
+        self.bytes_len.push(Operation::Le(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn graphemes_len_le(self, len: usize) -> Self {
-        StrSchema { graphemes_len: Some(Operation::Le(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn bytes_len_btwn(mut self, len_a: usize, len_b: usize) -> Self {
+        self.bytes_len.push(Operation::Btwn(Operand::Value(OperandValue::USize(len_a)), Operand::Value(OperandValue::USize(len_b))));
+        self
     }
 
-    pub fn graphemes_len_btwn(self, len_a: usize, len_b: usize) -> Self {
-        StrSchema {
-            graphemes_len: Some(Operation::Btwn(Operand::Value(OperandValue::USize(len_a)), Operand::Value(OperandValue::USize(len_b)))),
-            ..self
-        }
+    pub fn chars_len_eq(mut self, len: usize) -> Self {
+        self.chars_len.push(Operation::Eq(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn lowercase_len_eq(self, len: usize) -> Self {
-        StrSchema { lowercase_len: Some(Operation::Eq(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn chars_len_ne(mut self, len: usize) -> Self {
+        self.chars_len.push(Operation::Ne(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn lowercase_len_ne(self, len: usize) -> Self {
-        StrSchema { lowercase_len: Some(Operation::Ne(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn chars_len_gt(mut self, len: usize) -> Self {
+        self.chars_len.push(Operation::Gt(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn lowercase_len_gt(self, len: usize) -> Self {
-        StrSchema { lowercase_len: Some(Operation::Gt(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn chars_len_ge(mut self, len: usize) -> Self {
+        self.chars_len.push(Operation::Ge(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn lowercase_len_ge(self, len: usize) -> Self {
-        StrSchema { lowercase_len: Some(Operation::Ge(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn chars_len_lt(mut self, len: usize) -> Self {
+        self.chars_len.push(Operation::Lt(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn lowercase_len_lt(self, len: usize) -> Self {
-        StrSchema { lowercase_len: Some(Operation::Lt(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn chars_len_le(mut self, len: usize) -> Self {
+        self.chars_len.push(Operation::Le(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn lowercase_len_le(self, len: usize) -> Self {
-        StrSchema { lowercase_len: Some(Operation::Le(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn chars_len_btwn(mut self, len_a: usize, len_b: usize) -> Self {
+        self.chars_len.push(Operation::Btwn(Operand::Value(OperandValue::USize(len_a)), Operand::Value(OperandValue::USize(len_b))));
+        self
     }
 
-    pub fn lowercase_len_btwn(self, len_a: usize, len_b: usize) -> Self {
-        StrSchema {
-            lowercase_len: Some(Operation::Btwn(Operand::Value(OperandValue::USize(len_a)), Operand::Value(OperandValue::USize(len_b)))),
-            ..self
-        }
+    pub fn graphemes_len_eq(mut self, len: usize) -> Self {
+        self.graphemes_len.push(Operation::Eq(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn uppercase_len_eq(self, len: usize) -> Self {
-        StrSchema { uppercase_len: Some(Operation::Eq(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn graphemes_len_ne(mut self, len: usize) -> Self {
+        self.graphemes_len.push(Operation::Ne(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn uppercase_len_ne(self, len: usize) -> Self {
-        StrSchema { uppercase_len: Some(Operation::Ne(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn graphemes_len_gt(mut self, len: usize) -> Self {
+        self.graphemes_len.push(Operation::Gt(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn uppercase_len_gt(self, len: usize) -> Self {
-        StrSchema { uppercase_len: Some(Operation::Gt(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn graphemes_len_ge(mut self, len: usize) -> Self {
+        self.graphemes_len.push(Operation::Ge(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn uppercase_len_ge(self, len: usize) -> Self {
-        StrSchema { uppercase_len: Some(Operation::Ge(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn graphemes_len_lt(mut self, len: usize) -> Self {
+        self.graphemes_len.push(Operation::Lt(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn uppercase_len_lt(self, len: usize) -> Self {
-        StrSchema { uppercase_len: Some(Operation::Lt(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn graphemes_len_le(mut self, len: usize) -> Self {
+        self.graphemes_len.push(Operation::Le(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn uppercase_len_le(self, len: usize) -> Self {
-        StrSchema { uppercase_len: Some(Operation::Le(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn graphemes_len_btwn(mut self, len_a: usize, len_b: usize) -> Self {
+        self.graphemes_len.push(Operation::Btwn(Operand::Value(OperandValue::USize(len_a)), Operand::Value(OperandValue::USize(len_b))));
+        self
     }
 
-    pub fn uppercase_len_btwn(self, len_a: usize, len_b: usize) -> Self {
-        StrSchema {
-            uppercase_len: Some(Operation::Btwn(Operand::Value(OperandValue::USize(len_a)), Operand::Value(OperandValue::USize(len_b)))),
-            ..self
-        }
+    pub fn lowercase_len_eq(mut self, len: usize) -> Self {
+        self.lowercase_len.push(Operation::Eq(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn numbers_len_eq(self, len: usize) -> Self {
-        StrSchema { numbers_len: Some(Operation::Eq(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn lowercase_len_ne(mut self, len: usize) -> Self {
+        self.lowercase_len.push(Operation::Ne(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn numbers_len_ne(self, len: usize) -> Self {
-        StrSchema { numbers_len: Some(Operation::Ne(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn lowercase_len_gt(mut self, len: usize) -> Self {
+        self.lowercase_len.push(Operation::Gt(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn numbers_len_gt(self, len: usize) -> Self {
-        StrSchema { numbers_len: Some(Operation::Gt(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn lowercase_len_ge(mut self, len: usize) -> Self {
+        self.lowercase_len.push(Operation::Ge(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn numbers_len_ge(self, len: usize) -> Self {
-        StrSchema { numbers_len: Some(Operation::Ge(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn lowercase_len_lt(mut self, len: usize) -> Self {
+        self.lowercase_len.push(Operation::Lt(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn numbers_len_lt(self, len: usize) -> Self {
-        StrSchema { numbers_len: Some(Operation::Lt(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn lowercase_len_le(mut self, len: usize) -> Self {
+        self.lowercase_len.push(Operation::Le(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn numbers_len_le(self, len: usize) -> Self {
-        StrSchema { numbers_len: Some(Operation::Le(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn lowercase_len_btwn(mut self, len_a: usize, len_b: usize) -> Self {
+        self.lowercase_len.push(Operation::Btwn(Operand::Value(OperandValue::USize(len_a)), Operand::Value(OperandValue::USize(len_b))));
+        self
     }
 
-    pub fn numbers_len_btwn(self, len_a: usize, len_b: usize) -> Self {
-        StrSchema {
-            numbers_len: Some(Operation::Btwn(Operand::Value(OperandValue::USize(len_a)), Operand::Value(OperandValue::USize(len_b)))),
-            ..self
-        }
+    pub fn uppercase_len_eq(mut self, len: usize) -> Self {
+        self.uppercase_len.push(Operation::Eq(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn symbols_len_eq(self, len: usize) -> Self {
-        StrSchema { symbols_len: Some(Operation::Eq(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn uppercase_len_ne(mut self, len: usize) -> Self {
+        self.uppercase_len.push(Operation::Ne(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn symbols_len_ne(self, len: usize) -> Self {
-        StrSchema { symbols_len: Some(Operation::Ne(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn uppercase_len_gt(mut self, len: usize) -> Self {
+        self.uppercase_len.push(Operation::Gt(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn symbols_len_gt(self, len: usize) -> Self {
-        StrSchema { symbols_len: Some(Operation::Gt(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn uppercase_len_ge(mut self, len: usize) -> Self {
+        self.uppercase_len.push(Operation::Ge(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn symbols_len_ge(self, len: usize) -> Self {
-        StrSchema { symbols_len: Some(Operation::Ge(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn uppercase_len_lt(mut self, len: usize) -> Self {
+        self.uppercase_len.push(Operation::Lt(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn symbols_len_lt(self, len: usize) -> Self {
-        StrSchema { symbols_len: Some(Operation::Lt(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn uppercase_len_le(mut self, len: usize) -> Self {
+        self.uppercase_len.push(Operation::Le(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn symbols_len_le(self, len: usize) -> Self {
-        StrSchema { symbols_len: Some(Operation::Le(Operand::Value(OperandValue::USize(len)))), ..self }
+    pub fn uppercase_len_btwn(mut self, len_a: usize, len_b: usize) -> Self {
+        self.uppercase_len.push(Operation::Btwn(Operand::Value(OperandValue::USize(len_a)), Operand::Value(OperandValue::USize(len_b))));
+        self
     }
 
-    pub fn symbols_len_btwn(self, len_a: usize, len_b: usize) -> Self {
-        StrSchema {
-            symbols_len: Some(Operation::Btwn(Operand::Value(OperandValue::USize(len_a)), Operand::Value(OperandValue::USize(len_b)))),
-            ..self
-        }
+    pub fn numbers_len_eq(mut self, len: usize) -> Self {
+        self.numbers_len.push(Operation::Eq(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn eq_field(self, field: String) -> Self {
-        StrSchema { operation: Some(Operation::Eq(Operand::FieldPath(field))), ..self }
+    pub fn numbers_len_ne(mut self, len: usize) -> Self {
+        self.numbers_len.push(Operation::Ne(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn ne_field(self, field: String) -> Self {
-        StrSchema { operation: Some(Operation::Ne(Operand::FieldPath(field))), ..self }
+    pub fn numbers_len_gt(mut self, len: usize) -> Self {
+        self.numbers_len.push(Operation::Gt(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn gt_field(self, field: String) -> Self {
-        StrSchema { operation: Some(Operation::Gt(Operand::FieldPath(field))), ..self }
+    pub fn numbers_len_ge(mut self, len: usize) -> Self {
+        self.numbers_len.push(Operation::Ge(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn ge_field(self, field: String) -> Self {
-        StrSchema { operation: Some(Operation::Ge(Operand::FieldPath(field))), ..self }
+    pub fn numbers_len_lt(mut self, len: usize) -> Self {
+        self.numbers_len.push(Operation::Lt(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn lt_field(self, field: String) -> Self {
-        StrSchema { operation: Some(Operation::Lt(Operand::FieldPath(field))), ..self }
+    pub fn numbers_len_le(mut self, len: usize) -> Self {
+        self.numbers_len.push(Operation::Le(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn le_field(self, field: String) -> Self {
-        StrSchema { operation: Some(Operation::Le(Operand::FieldPath(field))), ..self }
+    pub fn numbers_len_btwn(mut self, len_a: usize, len_b: usize) -> Self {
+        self.numbers_len.push(Operation::Btwn(Operand::Value(OperandValue::USize(len_a)), Operand::Value(OperandValue::USize(len_b))));
+        self
     }
 
-    pub fn btwn_field(self, field_a: String, field_b: String) -> Self {
-        StrSchema { operation: Some(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b))), ..self }
+    pub fn symbols_len_eq(mut self, len: usize) -> Self {
+        self.symbols_len.push(Operation::Eq(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn bytes_len_eq_field(self, field: String) -> Self {
-        StrSchema { bytes_len: Some(Operation::Eq(Operand::FieldPath(field))), ..self }
+    pub fn symbols_len_ne(mut self, len: usize) -> Self {
+        self.symbols_len.push(Operation::Ne(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn bytes_len_ne_field(self, field: String) -> Self {
-        StrSchema { bytes_len: Some(Operation::Ne(Operand::FieldPath(field))), ..self }
+    pub fn symbols_len_gt(mut self, len: usize) -> Self {
+        self.symbols_len.push(Operation::Gt(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn bytes_len_gt_field(self, field: String) -> Self {
-        StrSchema { bytes_len: Some(Operation::Gt(Operand::FieldPath(field))), ..self }
+    pub fn symbols_len_ge(mut self, len: usize) -> Self {
+        self.symbols_len.push(Operation::Ge(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn bytes_len_ge_field(self, field: String) -> Self {
-        StrSchema { bytes_len: Some(Operation::Ge(Operand::FieldPath(field))), ..self }
+    pub fn symbols_len_lt(mut self, len: usize) -> Self {
+        self.symbols_len.push(Operation::Lt(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn bytes_len_lt_field(self, field: String) -> Self {
-        StrSchema { bytes_len: Some(Operation::Lt(Operand::FieldPath(field))), ..self }
+    pub fn symbols_len_le(mut self, len: usize) -> Self {
+        self.symbols_len.push(Operation::Le(Operand::Value(OperandValue::USize(len))));
+        self
     }
 
-    pub fn bytes_len_le_field(self, field: String) -> Self {
-        StrSchema { bytes_len: Some(Operation::Le(Operand::FieldPath(field))), ..self }
+    pub fn symbols_len_btwn(mut self, len_a: usize, len_b: usize) -> Self {
+        self.symbols_len.push(Operation::Btwn(Operand::Value(OperandValue::USize(len_a)), Operand::Value(OperandValue::USize(len_b))));
+        self
     }
 
-    pub fn bytes_len_btwn_field(self, field_a: String, field_b: String) -> Self {
-        StrSchema { bytes_len: Some(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b))), ..self }
+    pub fn eq_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Eq(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn chars_len_eq_field(self, field: String) -> Self {
-        StrSchema { chars_len: Some(Operation::Eq(Operand::FieldPath(field))), ..self }
+    pub fn ne_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Ne(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn chars_len_ne_field(self, field: String) -> Self {
-        StrSchema { chars_len: Some(Operation::Ne(Operand::FieldPath(field))), ..self }
+    pub fn gt_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Gt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn chars_len_gt_field(self, field: String) -> Self {
-        StrSchema { chars_len: Some(Operation::Gt(Operand::FieldPath(field))), ..self }
+    pub fn ge_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Ge(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn chars_len_ge_field(self, field: String) -> Self {
-        StrSchema { chars_len: Some(Operation::Ge(Operand::FieldPath(field))), ..self }
+    pub fn lt_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Lt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn chars_len_lt_field(self, field: String) -> Self {
-        StrSchema { chars_len: Some(Operation::Lt(Operand::FieldPath(field))), ..self }
+    pub fn le_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Le(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn chars_len_le_field(self, field: String) -> Self {
-        StrSchema { chars_len: Some(Operation::Le(Operand::FieldPath(field))), ..self }
+    pub fn btwn_field(mut self, field_a: String, field_b: String) -> Self {
+        self.operations.push(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b)));
+        self
     }
 
-    pub fn chars_len_btwn_field(self, field_a: String, field_b: String) -> Self {
-        StrSchema { chars_len: Some(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b))), ..self }
+    pub fn bytes_len_eq_field(mut self, field: String) -> Self {
+        self.bytes_len.push(Operation::Eq(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn graphemes_len_eq_field(self, field: String) -> Self {
-        StrSchema { graphemes_len: Some(Operation::Eq(Operand::FieldPath(field))), ..self }
+    pub fn bytes_len_ne_field(mut self, field: String) -> Self {
+        self.bytes_len.push(Operation::Ne(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn graphemes_len_ne_field(self, field: String) -> Self {
-        StrSchema { graphemes_len: Some(Operation::Ne(Operand::FieldPath(field))), ..self }
+    pub fn bytes_len_gt_field(mut self, field: String) -> Self {
+        self.bytes_len.push(Operation::Gt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn graphemes_len_gt_field(self, field: String) -> Self {
-        StrSchema { graphemes_len: Some(Operation::Gt(Operand::FieldPath(field))), ..self }
+    pub fn bytes_len_ge_field(mut self, field: String) -> Self {
+        self.bytes_len.push(Operation::Ge(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn graphemes_len_ge_field(self, field: String) -> Self {
-        StrSchema { graphemes_len: Some(Operation::Ge(Operand::FieldPath(field))), ..self }
+    pub fn bytes_len_lt_field(mut self, field: String) -> Self {
+        self.bytes_len.push(Operation::Lt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn graphemes_len_lt_field(self, field: String) -> Self {
-        StrSchema { graphemes_len: Some(Operation::Lt(Operand::FieldPath(field))), ..self }
+    pub fn bytes_len_le_field(mut self, field: String) -> Self {
+        self.bytes_len.push(Operation::Le(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn graphemes_len_le_field(self, field: String) -> Self {
-        StrSchema { graphemes_len: Some(Operation::Le(Operand::FieldPath(field))), ..self }
+    pub fn bytes_len_btwn_field(mut self, field_a: String, field_b: String) -> Self {
+        self.bytes_len.push(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b)));
+        self
     }
 
-    pub fn graphemes_len_btwn_field(self, field_a: String, field_b: String) -> Self {
-        StrSchema { graphemes_len: Some(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b))), ..self }
+    pub fn chars_len_eq_field(mut self, field: String) -> Self {
+        self.chars_len.push(Operation::Eq(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn lowercase_len_eq_field(self, field: String) -> Self {
-        StrSchema { lowercase_len: Some(Operation::Eq(Operand::FieldPath(field))), ..self }
+    pub fn chars_len_ne_field(mut self, field: String) -> Self {
+        self.chars_len.push(Operation::Ne(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn lowercase_len_ne_field(self, field: String) -> Self {
-        StrSchema { lowercase_len: Some(Operation::Ne(Operand::FieldPath(field))), ..self }
+    pub fn chars_len_gt_field(mut self, field: String) -> Self {
+        self.chars_len.push(Operation::Gt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn lowercase_len_gt_field(self, field: String) -> Self {
-        StrSchema { lowercase_len: Some(Operation::Gt(Operand::FieldPath(field))), ..self }
+    pub fn chars_len_ge_field(mut self, field: String) -> Self {
+        self.chars_len.push(Operation::Ge(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn lowercase_len_ge_field(self, field: String) -> Self {
-        StrSchema { lowercase_len: Some(Operation::Ge(Operand::FieldPath(field))), ..self }
+    pub fn chars_len_lt_field(mut self, field: String) -> Self {
+        self.chars_len.push(Operation::Lt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn lowercase_len_lt_field(self, field: String) -> Self {
-        StrSchema { lowercase_len: Some(Operation::Lt(Operand::FieldPath(field))), ..self }
+    pub fn chars_len_le_field(mut self, field: String) -> Self {
+        self.chars_len.push(Operation::Le(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn lowercase_len_le_field(self, field: String) -> Self {
-        StrSchema { lowercase_len: Some(Operation::Le(Operand::FieldPath(field))), ..self }
+    pub fn chars_len_btwn_field(mut self, field_a: String, field_b: String) -> Self {
+        self.chars_len.push(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b)));
+        self
     }
 
-    pub fn lowercase_len_btwn_field(self, field_a: String, field_b: String) -> Self {
-        StrSchema { lowercase_len: Some(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b))), ..self }
+    pub fn graphemes_len_eq_field(mut self, field: String) -> Self {
+        self.graphemes_len.push(Operation::Eq(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn uppercase_len_eq_field(self, field: String) -> Self {
-        StrSchema { uppercase_len: Some(Operation::Eq(Operand::FieldPath(field))), ..self }
+    pub fn graphemes_len_ne_field(mut self, field: String) -> Self {
+        self.graphemes_len.push(Operation::Ne(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn uppercase_len_ne_field(self, field: String) -> Self {
-        StrSchema { uppercase_len: Some(Operation::Ne(Operand::FieldPath(field))), ..self }
+    pub fn graphemes_len_gt_field(mut self, field: String) -> Self {
+        self.graphemes_len.push(Operation::Gt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn uppercase_len_gt_field(self, field: String) -> Self {
-        StrSchema { uppercase_len: Some(Operation::Gt(Operand::FieldPath(field))), ..self }
+    pub fn graphemes_len_ge_field(mut self, field: String) -> Self {
+        self.graphemes_len.push(Operation::Ge(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn uppercase_len_ge_field(self, field: String) -> Self {
-        StrSchema { uppercase_len: Some(Operation::Ge(Operand::FieldPath(field))), ..self }
+    pub fn graphemes_len_lt_field(mut self, field: String) -> Self {
+        self.graphemes_len.push(Operation::Lt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn uppercase_len_lt_field(self, field: String) -> Self {
-        StrSchema { uppercase_len: Some(Operation::Lt(Operand::FieldPath(field))), ..self }
+    pub fn graphemes_len_le_field(mut self, field: String) -> Self {
+        self.graphemes_len.push(Operation::Le(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn uppercase_len_le_field(self, field: String) -> Self {
-        StrSchema { uppercase_len: Some(Operation::Le(Operand::FieldPath(field))), ..self }
+    pub fn graphemes_len_btwn_field(mut self, field_a: String, field_b: String) -> Self {
+        self.graphemes_len.push(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b)));
+        self
     }
 
-    pub fn uppercase_len_btwn_field(self, field_a: String, field_b: String) -> Self {
-        StrSchema { uppercase_len: Some(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b))), ..self }
+    pub fn lowercase_len_eq_field(mut self, field: String) -> Self {
+        self.lowercase_len.push(Operation::Eq(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn numbers_len_eq_field(self, field: String) -> Self {
-        StrSchema { numbers_len: Some(Operation::Eq(Operand::FieldPath(field))), ..self }
+    pub fn lowercase_len_ne_field(mut self, field: String) -> Self {
+        self.lowercase_len.push(Operation::Ne(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn numbers_len_ne_field(self, field: String) -> Self {
-        StrSchema { numbers_len: Some(Operation::Ne(Operand::FieldPath(field))), ..self }
+    pub fn lowercase_len_gt_field(mut self, field: String) -> Self {
+        self.lowercase_len.push(Operation::Gt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn numbers_len_gt_field(self, field: String) -> Self {
-        StrSchema { numbers_len: Some(Operation::Gt(Operand::FieldPath(field))), ..self }
+    pub fn lowercase_len_ge_field(mut self, field: String) -> Self {
+        self.lowercase_len.push(Operation::Ge(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn numbers_len_ge_field(self, field: String) -> Self {
-        StrSchema { numbers_len: Some(Operation::Ge(Operand::FieldPath(field))), ..self }
+    pub fn lowercase_len_lt_field(mut self, field: String) -> Self {
+        self.lowercase_len.push(Operation::Lt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn numbers_len_lt_field(self, field: String) -> Self {
-        StrSchema { numbers_len: Some(Operation::Lt(Operand::FieldPath(field))), ..self }
+    pub fn lowercase_len_le_field(mut self, field: String) -> Self {
+        self.lowercase_len.push(Operation::Le(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn numbers_len_le_field(self, field: String) -> Self {
-        StrSchema { numbers_len: Some(Operation::Le(Operand::FieldPath(field))), ..self }
+    pub fn lowercase_len_btwn_field(mut self, field_a: String, field_b: String) -> Self {
+        self.lowercase_len.push(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b)));
+        self
     }
 
-    pub fn numbers_len_btwn_field(self, field_a: String, field_b: String) -> Self {
-        StrSchema { numbers_len: Some(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b))), ..self }
+    pub fn uppercase_len_eq_field(mut self, field: String) -> Self {
+        self.uppercase_len.push(Operation::Eq(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn symbols_len_eq_field(self, field: String) -> Self {
-        StrSchema { symbols_len: Some(Operation::Eq(Operand::FieldPath(field))), ..self }
+    pub fn uppercase_len_ne_field(mut self, field: String) -> Self {
+        self.uppercase_len.push(Operation::Ne(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn symbols_len_ne_field(self, field: String) -> Self {
-        StrSchema { symbols_len: Some(Operation::Ne(Operand::FieldPath(field))), ..self }
+    pub fn uppercase_len_gt_field(mut self, field: String) -> Self {
+        self.uppercase_len.push(Operation::Gt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn symbols_len_gt_field(self, field: String) -> Self {
-        StrSchema { symbols_len: Some(Operation::Gt(Operand::FieldPath(field))), ..self }
+    pub fn uppercase_len_ge_field(mut self, field: String) -> Self {
+        self.uppercase_len.push(Operation::Ge(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn symbols_len_ge_field(self, field: String) -> Self {
-        StrSchema { symbols_len: Some(Operation::Ge(Operand::FieldPath(field))), ..self }
+    pub fn uppercase_len_lt_field(mut self, field: String) -> Self {
+        self.uppercase_len.push(Operation::Lt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn symbols_len_lt_field(self, field: String) -> Self {
-        StrSchema { symbols_len: Some(Operation::Lt(Operand::FieldPath(field))), ..self }
+    pub fn uppercase_len_le_field(mut self, field: String) -> Self {
+        self.uppercase_len.push(Operation::Le(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn symbols_len_le_field(self, field: String) -> Self {
-        StrSchema { symbols_len: Some(Operation::Le(Operand::FieldPath(field))), ..self }
+    pub fn uppercase_len_btwn_field(mut self, field_a: String, field_b: String) -> Self {
+        self.uppercase_len.push(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b)));
+        self
     }
 
-    pub fn symbols_len_btwn_field(self, field_a: String, field_b: String) -> Self {
-        StrSchema { symbols_len: Some(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b))), ..self }
+    pub fn numbers_len_eq_field(mut self, field: String) -> Self {
+        self.numbers_len.push(Operation::Eq(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn numbers_len_ne_field(mut self, field: String) -> Self {
+        self.numbers_len.push(Operation::Ne(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn numbers_len_gt_field(mut self, field: String) -> Self {
+        self.numbers_len.push(Operation::Gt(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn numbers_len_ge_field(mut self, field: String) -> Self {
+        self.numbers_len.push(Operation::Ge(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn numbers_len_lt_field(mut self, field: String) -> Self {
+        self.numbers_len.push(Operation::Lt(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn numbers_len_le_field(mut self, field: String) -> Self {
+        self.numbers_len.push(Operation::Le(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn numbers_len_btwn_field(mut self, field_a: String, field_b: String) -> Self {
+        self.numbers_len.push(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b)));
+        self
+    }
+
+    pub fn symbols_len_eq_field(mut self, field: String) -> Self {
+        self.symbols_len.push(Operation::Eq(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn symbols_len_ne_field(mut self, field: String) -> Self {
+        self.symbols_len.push(Operation::Ne(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn symbols_len_gt_field(mut self, field: String) -> Self {
+        self.symbols_len.push(Operation::Gt(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn symbols_len_ge_field(mut self, field: String) -> Self {
+        self.symbols_len.push(Operation::Ge(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn symbols_len_lt_field(mut self, field: String) -> Self {
+        self.symbols_len.push(Operation::Lt(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn symbols_len_le_field(mut self, field: String) -> Self {
+        self.symbols_len.push(Operation::Le(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn symbols_len_btwn_field(mut self, field_a: String, field_b: String) -> Self {
+        self.symbols_len.push(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b)));
+        self
     }
 }
 
@@ -504,7 +843,7 @@ mod tests {
 
     use crate::operation::{Operand, OperandValue, Operation};
 
-    use super::StrSchema;
+    use super::{Charset, Normalization, Pattern, StrPredicate, StrSchema};
 
     const VALUE: &str = "Avalon";
     const VALUE_B: &str = "Mu";
@@ -568,28 +907,40 @@ mod tests {
             StrSchema::default(),
             StrSchema {
                 required: true,
-                operation: None,
-                bytes_len: None,
-                chars_len: None,
-                graphemes_len: None,
-                lowercase_len: None,
-                uppercase_len: None,
-                numbers_len: None,
-                symbols_len: None
+                label: None,
+                operations: vec![],
+                bytes_len: vec![],
+                chars_len: vec![],
+                graphemes_len: vec![],
+                lowercase_len: vec![],
+                uppercase_len: vec![],
+                numbers_len: vec![],
+                symbols_len: vec![],
+                patterns: vec![],
+                predicates: vec![],
+                len_normalization: None,
+                transforms: vec![],
+                default: None
             }
         );
         assert_eq!(
             StrSchema::default().optional(),
             StrSchema {
                 required: false,
-                operation: None,
-                bytes_len: None,
-                chars_len: None,
-                graphemes_len: None,
-                lowercase_len: None,
-                uppercase_len: None,
-                numbers_len: None,
-                symbols_len: None
+                label: None,
+                operations: vec![],
+                bytes_len: vec![],
+                chars_len: vec![],
+                graphemes_len: vec![],
+                lowercase_len: vec![],
+                uppercase_len: vec![],
+                numbers_len: vec![],
+                symbols_len: vec![],
+                patterns: vec![],
+                predicates: vec![],
+                len_normalization: None,
+                transforms: vec![],
+                default: None
             }
         );
     }
@@ -603,13 +954,13 @@ mod tests {
         let validation_lt = StrSchema::default().lt(VALUE.into());
         let validation_le = StrSchema::default().le(VALUE.into());
         let validation_btwn = StrSchema::default().btwn(VALUE.into(), VALUE_B.into());
-        assert_eq!(validation_eq, StrSchema { operation: Some(OPERATION_VALUE_EQ.clone()), ..Default::default() });
-        assert_eq!(validation_ne, StrSchema { operation: Some(OPERATION_VALUE_NE.clone()), ..Default::default() });
-        assert_eq!(validation_gt, StrSchema { operation: Some(OPERATION_VALUE_GT.clone()), ..Default::default() });
-        assert_eq!(validation_ge, StrSchema { operation: Some(OPERATION_VALUE_GE.clone()), ..Default::default() });
-        assert_eq!(validation_lt, StrSchema { operation: Some(OPERATION_VALUE_LT.clone()), ..Default::default() });
-        assert_eq!(validation_le, StrSchema { operation: Some(OPERATION_VALUE_LE.clone()), ..Default::default() });
-        assert_eq!(validation_btwn, StrSchema { operation: Some(OPERATION_VALUE_BTWN.clone()), ..Default::default() });
+        assert_eq!(validation_eq, StrSchema { operations: vec![OPERATION_VALUE_EQ.clone()], ..Default::default() });
+        assert_eq!(validation_ne, StrSchema { operations: vec![OPERATION_VALUE_NE.clone()], ..Default::default() });
+        assert_eq!(validation_gt, StrSchema { operations: vec![OPERATION_VALUE_GT.clone()], ..Default::default() });
+        assert_eq!(validation_ge, StrSchema { operations: vec![OPERATION_VALUE_GE.clone()], ..Default::default() });
+        assert_eq!(validation_lt, StrSchema { operations: vec![OPERATION_VALUE_LT.clone()], ..Default::default() });
+        assert_eq!(validation_le, StrSchema { operations: vec![OPERATION_VALUE_LE.clone()], ..Default::default() });
+        assert_eq!(validation_btwn, StrSchema { operations: vec![OPERATION_VALUE_BTWN.clone()], ..Default::default() });
     }
 
     #[test]
@@ -621,13 +972,13 @@ mod tests {
         let validation_lt = StrSchema::default().lt_field(FIELD.into());
         let validation_le = StrSchema::default().le_field(FIELD.into());
         let validation_btwn = StrSchema::default().btwn_field(FIELD.into(), FIELD_B.into());
-        assert_eq!(validation_eq, StrSchema { operation: Some(OPERATION_FIELD_EQ.clone()), ..Default::default() });
-        assert_eq!(validation_ne, StrSchema { operation: Some(OPERATION_FIELD_NE.clone()), ..Default::default() });
-        assert_eq!(validation_gt, StrSchema { operation: Some(OPERATION_FIELD_GT.clone()), ..Default::default() });
-        assert_eq!(validation_ge, StrSchema { operation: Some(OPERATION_FIELD_GE.clone()), ..Default::default() });
-        assert_eq!(validation_lt, StrSchema { operation: Some(OPERATION_FIELD_LT.clone()), ..Default::default() });
-        assert_eq!(validation_le, StrSchema { operation: Some(OPERATION_FIELD_LE.clone()), ..Default::default() });
-        assert_eq!(validation_btwn, StrSchema { operation: Some(OPERATION_FIELD_BTWN.clone()), ..Default::default() });
+        assert_eq!(validation_eq, StrSchema { operations: vec![OPERATION_FIELD_EQ.clone()], ..Default::default() });
+        assert_eq!(validation_ne, StrSchema { operations: vec![OPERATION_FIELD_NE.clone()], ..Default::default() });
+        assert_eq!(validation_gt, StrSchema { operations: vec![OPERATION_FIELD_GT.clone()], ..Default::default() });
+        assert_eq!(validation_ge, StrSchema { operations: vec![OPERATION_FIELD_GE.clone()], ..Default::default() });
+        assert_eq!(validation_lt, StrSchema { operations: vec![OPERATION_FIELD_LT.clone()], ..Default::default() });
+        assert_eq!(validation_le, StrSchema { operations: vec![OPERATION_FIELD_LE.clone()], ..Default::default() });
+        assert_eq!(validation_btwn, StrSchema { operations: vec![OPERATION_FIELD_BTWN.clone()], ..Default::default() });
     }
 
     #[test]
@@ -639,13 +990,13 @@ mod tests {
         let validation_lt = StrSchema::default().bytes_len_lt(VALUE_LEN);
         let validation_le = StrSchema::default().bytes_len_le(VALUE_LEN);
         let validation_btwn = StrSchema::default().bytes_len_btwn(VALUE_LEN, VALUE_LEN_B);
-        assert_eq!(validation_eq, StrSchema { bytes_len: Some(OPERATION_VALUE_LEN_EQ.clone()), ..Default::default() });
-        assert_eq!(validation_ne, StrSchema { bytes_len: Some(OPERATION_VALUE_LEN_NE.clone()), ..Default::default() });
-        assert_eq!(validation_gt, StrSchema { bytes_len: Some(OPERATION_VALUE_LEN_GT.clone()), ..Default::default() });
-        assert_eq!(validation_ge, StrSchema { bytes_len: Some(OPERATION_VALUE_LEN_GE.clone()), ..Default::default() });
-        assert_eq!(validation_lt, StrSchema { bytes_len: Some(OPERATION_VALUE_LEN_LT.clone()), ..Default::default() });
-        assert_eq!(validation_le, StrSchema { bytes_len: Some(OPERATION_VALUE_LEN_LE.clone()), ..Default::default() });
-        assert_eq!(validation_btwn, StrSchema { bytes_len: Some(OPERATION_VALUE_LEN_BTWN.clone()), ..Default::default() });
+        assert_eq!(validation_eq, StrSchema { bytes_len: vec![OPERATION_VALUE_LEN_EQ.clone()], ..Default::default() });
+        assert_eq!(validation_ne, StrSchema { bytes_len: vec![OPERATION_VALUE_LEN_NE.clone()], ..Default::default() });
+        assert_eq!(validation_gt, StrSchema { bytes_len: vec![OPERATION_VALUE_LEN_GT.clone()], ..Default::default() });
+        assert_eq!(validation_ge, StrSchema { bytes_len: vec![OPERATION_VALUE_LEN_GE.clone()], ..Default::default() });
+        assert_eq!(validation_lt, StrSchema { bytes_len: vec![OPERATION_VALUE_LEN_LT.clone()], ..Default::default() });
+        assert_eq!(validation_le, StrSchema { bytes_len: vec![OPERATION_VALUE_LEN_LE.clone()], ..Default::default() });
+        assert_eq!(validation_btwn, StrSchema { bytes_len: vec![OPERATION_VALUE_LEN_BTWN.clone()], ..Default::default() });
     }
 
     #[test]
@@ -657,13 +1008,13 @@ mod tests {
         let validation_lt = StrSchema::default().chars_len_lt(VALUE_LEN);
         let validation_le = StrSchema::default().chars_len_le(VALUE_LEN);
         let validation_btwn = StrSchema::default().chars_len_btwn(VALUE_LEN, VALUE_LEN_B);
-        assert_eq!(validation_eq, StrSchema { chars_len: Some(OPERATION_VALUE_LEN_EQ.clone()), ..Default::default() });
-        assert_eq!(validation_ne, StrSchema { chars_len: Some(OPERATION_VALUE_LEN_NE.clone()), ..Default::default() });
-        assert_eq!(validation_gt, StrSchema { chars_len: Some(OPERATION_VALUE_LEN_GT.clone()), ..Default::default() });
-        assert_eq!(validation_ge, StrSchema { chars_len: Some(OPERATION_VALUE_LEN_GE.clone()), ..Default::default() });
-        assert_eq!(validation_lt, StrSchema { chars_len: Some(OPERATION_VALUE_LEN_LT.clone()), ..Default::default() });
-        assert_eq!(validation_le, StrSchema { chars_len: Some(OPERATION_VALUE_LEN_LE.clone()), ..Default::default() });
-        assert_eq!(validation_btwn, StrSchema { chars_len: Some(OPERATION_VALUE_LEN_BTWN.clone()), ..Default::default() });
+        assert_eq!(validation_eq, StrSchema { chars_len: vec![OPERATION_VALUE_LEN_EQ.clone()], ..Default::default() });
+        assert_eq!(validation_ne, StrSchema { chars_len: vec![OPERATION_VALUE_LEN_NE.clone()], ..Default::default() });
+        assert_eq!(validation_gt, StrSchema { chars_len: vec![OPERATION_VALUE_LEN_GT.clone()], ..Default::default() });
+        assert_eq!(validation_ge, StrSchema { chars_len: vec![OPERATION_VALUE_LEN_GE.clone()], ..Default::default() });
+        assert_eq!(validation_lt, StrSchema { chars_len: vec![OPERATION_VALUE_LEN_LT.clone()], ..Default::default() });
+        assert_eq!(validation_le, StrSchema { chars_len: vec![OPERATION_VALUE_LEN_LE.clone()], ..Default::default() });
+        assert_eq!(validation_btwn, StrSchema { chars_len: vec![OPERATION_VALUE_LEN_BTWN.clone()], ..Default::default() });
     }
 
     #[test]
@@ -675,13 +1026,13 @@ mod tests {
         let validation_lt = StrSchema::default().graphemes_len_lt(VALUE_LEN);
         let validation_le = StrSchema::default().graphemes_len_le(VALUE_LEN);
         let validation_btwn = StrSchema::default().graphemes_len_btwn(VALUE_LEN, VALUE_LEN_B);
-        assert_eq!(validation_eq, StrSchema { graphemes_len: Some(OPERATION_VALUE_LEN_EQ.clone()), ..Default::default() });
-        assert_eq!(validation_ne, StrSchema { graphemes_len: Some(OPERATION_VALUE_LEN_NE.clone()), ..Default::default() });
-        assert_eq!(validation_gt, StrSchema { graphemes_len: Some(OPERATION_VALUE_LEN_GT.clone()), ..Default::default() });
-        assert_eq!(validation_ge, StrSchema { graphemes_len: Some(OPERATION_VALUE_LEN_GE.clone()), ..Default::default() });
-        assert_eq!(validation_lt, StrSchema { graphemes_len: Some(OPERATION_VALUE_LEN_LT.clone()), ..Default::default() });
-        assert_eq!(validation_le, StrSchema { graphemes_len: Some(OPERATION_VALUE_LEN_LE.clone()), ..Default::default() });
-        assert_eq!(validation_btwn, StrSchema { graphemes_len: Some(OPERATION_VALUE_LEN_BTWN.clone()), ..Default::default() });
+        assert_eq!(validation_eq, StrSchema { graphemes_len: vec![OPERATION_VALUE_LEN_EQ.clone()], ..Default::default() });
+        assert_eq!(validation_ne, StrSchema { graphemes_len: vec![OPERATION_VALUE_LEN_NE.clone()], ..Default::default() });
+        assert_eq!(validation_gt, StrSchema { graphemes_len: vec![OPERATION_VALUE_LEN_GT.clone()], ..Default::default() });
+        assert_eq!(validation_ge, StrSchema { graphemes_len: vec![OPERATION_VALUE_LEN_GE.clone()], ..Default::default() });
+        assert_eq!(validation_lt, StrSchema { graphemes_len: vec![OPERATION_VALUE_LEN_LT.clone()], ..Default::default() });
+        assert_eq!(validation_le, StrSchema { graphemes_len: vec![OPERATION_VALUE_LEN_LE.clone()], ..Default::default() });
+        assert_eq!(validation_btwn, StrSchema { graphemes_len: vec![OPERATION_VALUE_LEN_BTWN.clone()], ..Default::default() });
     }
 
     #[test]
@@ -693,13 +1044,13 @@ mod tests {
         let validation_lt = StrSchema::default().lowercase_len_lt(VALUE_LEN);
         let validation_le = StrSchema::default().lowercase_len_le(VALUE_LEN);
         let validation_btwn = StrSchema::default().lowercase_len_btwn(VALUE_LEN, VALUE_LEN_B);
-        assert_eq!(validation_eq, StrSchema { lowercase_len: Some(OPERATION_VALUE_LEN_EQ.clone()), ..Default::default() });
-        assert_eq!(validation_ne, StrSchema { lowercase_len: Some(OPERATION_VALUE_LEN_NE.clone()), ..Default::default() });
-        assert_eq!(validation_gt, StrSchema { lowercase_len: Some(OPERATION_VALUE_LEN_GT.clone()), ..Default::default() });
-        assert_eq!(validation_ge, StrSchema { lowercase_len: Some(OPERATION_VALUE_LEN_GE.clone()), ..Default::default() });
-        assert_eq!(validation_lt, StrSchema { lowercase_len: Some(OPERATION_VALUE_LEN_LT.clone()), ..Default::default() });
-        assert_eq!(validation_le, StrSchema { lowercase_len: Some(OPERATION_VALUE_LEN_LE.clone()), ..Default::default() });
-        assert_eq!(validation_btwn, StrSchema { lowercase_len: Some(OPERATION_VALUE_LEN_BTWN.clone()), ..Default::default() });
+        assert_eq!(validation_eq, StrSchema { lowercase_len: vec![OPERATION_VALUE_LEN_EQ.clone()], ..Default::default() });
+        assert_eq!(validation_ne, StrSchema { lowercase_len: vec![OPERATION_VALUE_LEN_NE.clone()], ..Default::default() });
+        assert_eq!(validation_gt, StrSchema { lowercase_len: vec![OPERATION_VALUE_LEN_GT.clone()], ..Default::default() });
+        assert_eq!(validation_ge, StrSchema { lowercase_len: vec![OPERATION_VALUE_LEN_GE.clone()], ..Default::default() });
+        assert_eq!(validation_lt, StrSchema { lowercase_len: vec![OPERATION_VALUE_LEN_LT.clone()], ..Default::default() });
+        assert_eq!(validation_le, StrSchema { lowercase_len: vec![OPERATION_VALUE_LEN_LE.clone()], ..Default::default() });
+        assert_eq!(validation_btwn, StrSchema { lowercase_len: vec![OPERATION_VALUE_LEN_BTWN.clone()], ..Default::default() });
     }
 
     #[test]
@@ -711,13 +1062,13 @@ mod tests {
         let validation_lt = StrSchema::default().uppercase_len_lt(VALUE_LEN);
         let validation_le = StrSchema::default().uppercase_len_le(VALUE_LEN);
         let validation_btwn = StrSchema::default().uppercase_len_btwn(VALUE_LEN, VALUE_LEN_B);
-        assert_eq!(validation_eq, StrSchema { uppercase_len: Some(OPERATION_VALUE_LEN_EQ.clone()), ..Default::default() });
-        assert_eq!(validation_ne, StrSchema { uppercase_len: Some(OPERATION_VALUE_LEN_NE.clone()), ..Default::default() });
-        assert_eq!(validation_gt, StrSchema { uppercase_len: Some(OPERATION_VALUE_LEN_GT.clone()), ..Default::default() });
-        assert_eq!(validation_ge, StrSchema { uppercase_len: Some(OPERATION_VALUE_LEN_GE.clone()), ..Default::default() });
-        assert_eq!(validation_lt, StrSchema { uppercase_len: Some(OPERATION_VALUE_LEN_LT.clone()), ..Default::default() });
-        assert_eq!(validation_le, StrSchema { uppercase_len: Some(OPERATION_VALUE_LEN_LE.clone()), ..Default::default() });
-        assert_eq!(validation_btwn, StrSchema { uppercase_len: Some(OPERATION_VALUE_LEN_BTWN.clone()), ..Default::default() });
+        assert_eq!(validation_eq, StrSchema { uppercase_len: vec![OPERATION_VALUE_LEN_EQ.clone()], ..Default::default() });
+        assert_eq!(validation_ne, StrSchema { uppercase_len: vec![OPERATION_VALUE_LEN_NE.clone()], ..Default::default() });
+        assert_eq!(validation_gt, StrSchema { uppercase_len: vec![OPERATION_VALUE_LEN_GT.clone()], ..Default::default() });
+        assert_eq!(validation_ge, StrSchema { uppercase_len: vec![OPERATION_VALUE_LEN_GE.clone()], ..Default::default() });
+        assert_eq!(validation_lt, StrSchema { uppercase_len: vec![OPERATION_VALUE_LEN_LT.clone()], ..Default::default() });
+        assert_eq!(validation_le, StrSchema { uppercase_len: vec![OPERATION_VALUE_LEN_LE.clone()], ..Default::default() });
+        assert_eq!(validation_btwn, StrSchema { uppercase_len: vec![OPERATION_VALUE_LEN_BTWN.clone()], ..Default::default() });
     }
 
     #[test]
@@ -729,13 +1080,13 @@ mod tests {
         let validation_lt = StrSchema::default().numbers_len_lt(VALUE_LEN);
         let validation_le = StrSchema::default().numbers_len_le(VALUE_LEN);
         let validation_btwn = StrSchema::default().numbers_len_btwn(VALUE_LEN, VALUE_LEN_B);
-        assert_eq!(validation_eq, StrSchema { numbers_len: Some(OPERATION_VALUE_LEN_EQ.clone()), ..Default::default() });
-        assert_eq!(validation_ne, StrSchema { numbers_len: Some(OPERATION_VALUE_LEN_NE.clone()), ..Default::default() });
-        assert_eq!(validation_gt, StrSchema { numbers_len: Some(OPERATION_VALUE_LEN_GT.clone()), ..Default::default() });
-        assert_eq!(validation_ge, StrSchema { numbers_len: Some(OPERATION_VALUE_LEN_GE.clone()), ..Default::default() });
-        assert_eq!(validation_lt, StrSchema { numbers_len: Some(OPERATION_VALUE_LEN_LT.clone()), ..Default::default() });
-        assert_eq!(validation_le, StrSchema { numbers_len: Some(OPERATION_VALUE_LEN_LE.clone()), ..Default::default() });
-        assert_eq!(validation_btwn, StrSchema { numbers_len: Some(OPERATION_VALUE_LEN_BTWN.clone()), ..Default::default() });
+        assert_eq!(validation_eq, StrSchema { numbers_len: vec![OPERATION_VALUE_LEN_EQ.clone()], ..Default::default() });
+        assert_eq!(validation_ne, StrSchema { numbers_len: vec![OPERATION_VALUE_LEN_NE.clone()], ..Default::default() });
+        assert_eq!(validation_gt, StrSchema { numbers_len: vec![OPERATION_VALUE_LEN_GT.clone()], ..Default::default() });
+        assert_eq!(validation_ge, StrSchema { numbers_len: vec![OPERATION_VALUE_LEN_GE.clone()], ..Default::default() });
+        assert_eq!(validation_lt, StrSchema { numbers_len: vec![OPERATION_VALUE_LEN_LT.clone()], ..Default::default() });
+        assert_eq!(validation_le, StrSchema { numbers_len: vec![OPERATION_VALUE_LEN_LE.clone()], ..Default::default() });
+        assert_eq!(validation_btwn, StrSchema { numbers_len: vec![OPERATION_VALUE_LEN_BTWN.clone()], ..Default::default() });
     }
 
     #[test]
@@ -747,13 +1098,13 @@ mod tests {
         let validation_lt = StrSchema::default().symbols_len_lt(VALUE_LEN);
         let validation_le = StrSchema::default().symbols_len_le(VALUE_LEN);
         let validation_btwn = StrSchema::default().symbols_len_btwn(VALUE_LEN, VALUE_LEN_B);
-        assert_eq!(validation_eq, StrSchema { symbols_len: Some(OPERATION_VALUE_LEN_EQ.clone()), ..Default::default() });
-        assert_eq!(validation_ne, StrSchema { symbols_len: Some(OPERATION_VALUE_LEN_NE.clone()), ..Default::default() });
-        assert_eq!(validation_gt, StrSchema { symbols_len: Some(OPERATION_VALUE_LEN_GT.clone()), ..Default::default() });
-        assert_eq!(validation_ge, StrSchema { symbols_len: Some(OPERATION_VALUE_LEN_GE.clone()), ..Default::default() });
-        assert_eq!(validation_lt, StrSchema { symbols_len: Some(OPERATION_VALUE_LEN_LT.clone()), ..Default::default() });
-        assert_eq!(validation_le, StrSchema { symbols_len: Some(OPERATION_VALUE_LEN_LE.clone()), ..Default::default() });
-        assert_eq!(validation_btwn, StrSchema { symbols_len: Some(OPERATION_VALUE_LEN_BTWN.clone()), ..Default::default() });
+        assert_eq!(validation_eq, StrSchema { symbols_len: vec![OPERATION_VALUE_LEN_EQ.clone()], ..Default::default() });
+        assert_eq!(validation_ne, StrSchema { symbols_len: vec![OPERATION_VALUE_LEN_NE.clone()], ..Default::default() });
+        assert_eq!(validation_gt, StrSchema { symbols_len: vec![OPERATION_VALUE_LEN_GT.clone()], ..Default::default() });
+        assert_eq!(validation_ge, StrSchema { symbols_len: vec![OPERATION_VALUE_LEN_GE.clone()], ..Default::default() });
+        assert_eq!(validation_lt, StrSchema { symbols_len: vec![OPERATION_VALUE_LEN_LT.clone()], ..Default::default() });
+        assert_eq!(validation_le, StrSchema { symbols_len: vec![OPERATION_VALUE_LEN_LE.clone()], ..Default::default() });
+        assert_eq!(validation_btwn, StrSchema { symbols_len: vec![OPERATION_VALUE_LEN_BTWN.clone()], ..Default::default() });
     }
 
     #[test]
@@ -765,13 +1116,13 @@ mod tests {
         let validation_lt = StrSchema::default().bytes_len_lt_field(FIELD_LEN.into());
         let validation_le = StrSchema::default().bytes_len_le_field(FIELD_LEN.into());
         let validation_btwn = StrSchema::default().bytes_len_btwn_field(FIELD_LEN.into(), FIELD_LEN_B.into());
-        assert_eq!(validation_eq, StrSchema { bytes_len: Some(OPERATION_FIELD_LEN_EQ.clone()), ..Default::default() });
-        assert_eq!(validation_ne, StrSchema { bytes_len: Some(OPERATION_FIELD_LEN_NE.clone()), ..Default::default() });
-        assert_eq!(validation_gt, StrSchema { bytes_len: Some(OPERATION_FIELD_LEN_GT.clone()), ..Default::default() });
-        assert_eq!(validation_ge, StrSchema { bytes_len: Some(OPERATION_FIELD_LEN_GE.clone()), ..Default::default() });
-        assert_eq!(validation_lt, StrSchema { bytes_len: Some(OPERATION_FIELD_LEN_LT.clone()), ..Default::default() });
-        assert_eq!(validation_le, StrSchema { bytes_len: Some(OPERATION_FIELD_LEN_LE.clone()), ..Default::default() });
-        assert_eq!(validation_btwn, StrSchema { bytes_len: Some(OPERATION_FIELD_LEN_BTWN.clone()), ..Default::default() });
+        assert_eq!(validation_eq, StrSchema { bytes_len: vec![OPERATION_FIELD_LEN_EQ.clone()], ..Default::default() });
+        assert_eq!(validation_ne, StrSchema { bytes_len: vec![OPERATION_FIELD_LEN_NE.clone()], ..Default::default() });
+        assert_eq!(validation_gt, StrSchema { bytes_len: vec![OPERATION_FIELD_LEN_GT.clone()], ..Default::default() });
+        assert_eq!(validation_ge, StrSchema { bytes_len: vec![OPERATION_FIELD_LEN_GE.clone()], ..Default::default() });
+        assert_eq!(validation_lt, StrSchema { bytes_len: vec![OPERATION_FIELD_LEN_LT.clone()], ..Default::default() });
+        assert_eq!(validation_le, StrSchema { bytes_len: vec![OPERATION_FIELD_LEN_LE.clone()], ..Default::default() });
+        assert_eq!(validation_btwn, StrSchema { bytes_len: vec![OPERATION_FIELD_LEN_BTWN.clone()], ..Default::default() });
     }
 
     #[test]
@@ -783,13 +1134,13 @@ mod tests {
         let validation_lt = StrSchema::default().chars_len_lt_field(FIELD_LEN.into());
         let validation_le = StrSchema::default().chars_len_le_field(FIELD_LEN.into());
         let validation_btwn = StrSchema::default().chars_len_btwn_field(FIELD_LEN.into(), FIELD_LEN_B.into());
-        assert_eq!(validation_eq, StrSchema { chars_len: Some(OPERATION_FIELD_LEN_EQ.clone()), ..Default::default() });
-        assert_eq!(validation_ne, StrSchema { chars_len: Some(OPERATION_FIELD_LEN_NE.clone()), ..Default::default() });
-        assert_eq!(validation_gt, StrSchema { chars_len: Some(OPERATION_FIELD_LEN_GT.clone()), ..Default::default() });
-        assert_eq!(validation_ge, StrSchema { chars_len: Some(OPERATION_FIELD_LEN_GE.clone()), ..Default::default() });
-        assert_eq!(validation_lt, StrSchema { chars_len: Some(OPERATION_FIELD_LEN_LT.clone()), ..Default::default() });
-        assert_eq!(validation_le, StrSchema { chars_len: Some(OPERATION_FIELD_LEN_LE.clone()), ..Default::default() });
-        assert_eq!(validation_btwn, StrSchema { chars_len: Some(OPERATION_FIELD_LEN_BTWN.clone()), ..Default::default() });
+        assert_eq!(validation_eq, StrSchema { chars_len: vec![OPERATION_FIELD_LEN_EQ.clone()], ..Default::default() });
+        assert_eq!(validation_ne, StrSchema { chars_len: vec![OPERATION_FIELD_LEN_NE.clone()], ..Default::default() });
+        assert_eq!(validation_gt, StrSchema { chars_len: vec![OPERATION_FIELD_LEN_GT.clone()], ..Default::default() });
+        assert_eq!(validation_ge, StrSchema { chars_len: vec![OPERATION_FIELD_LEN_GE.clone()], ..Default::default() });
+        assert_eq!(validation_lt, StrSchema { chars_len: vec![OPERATION_FIELD_LEN_LT.clone()], ..Default::default() });
+        assert_eq!(validation_le, StrSchema { chars_len: vec![OPERATION_FIELD_LEN_LE.clone()], ..Default::default() });
+        assert_eq!(validation_btwn, StrSchema { chars_len: vec![OPERATION_FIELD_LEN_BTWN.clone()], ..Default::default() });
     }
 
     #[test]
@@ -801,13 +1152,13 @@ mod tests {
         let validation_lt = StrSchema::default().graphemes_len_lt_field(FIELD_LEN.into());
         let validation_le = StrSchema::default().graphemes_len_le_field(FIELD_LEN.into());
         let validation_btwn = StrSchema::default().graphemes_len_btwn_field(FIELD_LEN.into(), FIELD_LEN_B.into());
-        assert_eq!(validation_eq, StrSchema { graphemes_len: Some(OPERATION_FIELD_LEN_EQ.clone()), ..Default::default() });
-        assert_eq!(validation_ne, StrSchema { graphemes_len: Some(OPERATION_FIELD_LEN_NE.clone()), ..Default::default() });
-        assert_eq!(validation_gt, StrSchema { graphemes_len: Some(OPERATION_FIELD_LEN_GT.clone()), ..Default::default() });
-        assert_eq!(validation_ge, StrSchema { graphemes_len: Some(OPERATION_FIELD_LEN_GE.clone()), ..Default::default() });
-        assert_eq!(validation_lt, StrSchema { graphemes_len: Some(OPERATION_FIELD_LEN_LT.clone()), ..Default::default() });
-        assert_eq!(validation_le, StrSchema { graphemes_len: Some(OPERATION_FIELD_LEN_LE.clone()), ..Default::default() });
-        assert_eq!(validation_btwn, StrSchema { graphemes_len: Some(OPERATION_FIELD_LEN_BTWN.clone()), ..Default::default() });
+        assert_eq!(validation_eq, StrSchema { graphemes_len: vec![OPERATION_FIELD_LEN_EQ.clone()], ..Default::default() });
+        assert_eq!(validation_ne, StrSchema { graphemes_len: vec![OPERATION_FIELD_LEN_NE.clone()], ..Default::default() });
+        assert_eq!(validation_gt, StrSchema { graphemes_len: vec![OPERATION_FIELD_LEN_GT.clone()], ..Default::default() });
+        assert_eq!(validation_ge, StrSchema { graphemes_len: vec![OPERATION_FIELD_LEN_GE.clone()], ..Default::default() });
+        assert_eq!(validation_lt, StrSchema { graphemes_len: vec![OPERATION_FIELD_LEN_LT.clone()], ..Default::default() });
+        assert_eq!(validation_le, StrSchema { graphemes_len: vec![OPERATION_FIELD_LEN_LE.clone()], ..Default::default() });
+        assert_eq!(validation_btwn, StrSchema { graphemes_len: vec![OPERATION_FIELD_LEN_BTWN.clone()], ..Default::default() });
     }
 
     #[test]
@@ -819,13 +1170,13 @@ mod tests {
         let validation_lt = StrSchema::default().lowercase_len_lt_field(FIELD_LEN.into());
         let validation_le = StrSchema::default().lowercase_len_le_field(FIELD_LEN.into());
         let validation_btwn = StrSchema::default().lowercase_len_btwn_field(FIELD_LEN.into(), FIELD_LEN_B.into());
-        assert_eq!(validation_eq, StrSchema { lowercase_len: Some(OPERATION_FIELD_LEN_EQ.clone()), ..Default::default() });
-        assert_eq!(validation_ne, StrSchema { lowercase_len: Some(OPERATION_FIELD_LEN_NE.clone()), ..Default::default() });
-        assert_eq!(validation_gt, StrSchema { lowercase_len: Some(OPERATION_FIELD_LEN_GT.clone()), ..Default::default() });
-        assert_eq!(validation_ge, StrSchema { lowercase_len: Some(OPERATION_FIELD_LEN_GE.clone()), ..Default::default() });
-        assert_eq!(validation_lt, StrSchema { lowercase_len: Some(OPERATION_FIELD_LEN_LT.clone()), ..Default::default() });
-        assert_eq!(validation_le, StrSchema { lowercase_len: Some(OPERATION_FIELD_LEN_LE.clone()), ..Default::default() });
-        assert_eq!(validation_btwn, StrSchema { lowercase_len: Some(OPERATION_FIELD_LEN_BTWN.clone()), ..Default::default() });
+        assert_eq!(validation_eq, StrSchema { lowercase_len: vec![OPERATION_FIELD_LEN_EQ.clone()], ..Default::default() });
+        assert_eq!(validation_ne, StrSchema { lowercase_len: vec![OPERATION_FIELD_LEN_NE.clone()], ..Default::default() });
+        assert_eq!(validation_gt, StrSchema { lowercase_len: vec![OPERATION_FIELD_LEN_GT.clone()], ..Default::default() });
+        assert_eq!(validation_ge, StrSchema { lowercase_len: vec![OPERATION_FIELD_LEN_GE.clone()], ..Default::default() });
+        assert_eq!(validation_lt, StrSchema { lowercase_len: vec![OPERATION_FIELD_LEN_LT.clone()], ..Default::default() });
+        assert_eq!(validation_le, StrSchema { lowercase_len: vec![OPERATION_FIELD_LEN_LE.clone()], ..Default::default() });
+        assert_eq!(validation_btwn, StrSchema { lowercase_len: vec![OPERATION_FIELD_LEN_BTWN.clone()], ..Default::default() });
     }
 
     #[test]
@@ -837,13 +1188,13 @@ mod tests {
         let validation_lt = StrSchema::default().uppercase_len_lt_field(FIELD_LEN.into());
         let validation_le = StrSchema::default().uppercase_len_le_field(FIELD_LEN.into());
         let validation_btwn = StrSchema::default().uppercase_len_btwn_field(FIELD_LEN.into(), FIELD_LEN_B.into());
-        assert_eq!(validation_eq, StrSchema { uppercase_len: Some(OPERATION_FIELD_LEN_EQ.clone()), ..Default::default() });
-        assert_eq!(validation_ne, StrSchema { uppercase_len: Some(OPERATION_FIELD_LEN_NE.clone()), ..Default::default() });
-        assert_eq!(validation_gt, StrSchema { uppercase_len: Some(OPERATION_FIELD_LEN_GT.clone()), ..Default::default() });
-        assert_eq!(validation_ge, StrSchema { uppercase_len: Some(OPERATION_FIELD_LEN_GE.clone()), ..Default::default() });
-        assert_eq!(validation_lt, StrSchema { uppercase_len: Some(OPERATION_FIELD_LEN_LT.clone()), ..Default::default() });
-        assert_eq!(validation_le, StrSchema { uppercase_len: Some(OPERATION_FIELD_LEN_LE.clone()), ..Default::default() });
-        assert_eq!(validation_btwn, StrSchema { uppercase_len: Some(OPERATION_FIELD_LEN_BTWN.clone()), ..Default::default() });
+        assert_eq!(validation_eq, StrSchema { uppercase_len: vec![OPERATION_FIELD_LEN_EQ.clone()], ..Default::default() });
+        assert_eq!(validation_ne, StrSchema { uppercase_len: vec![OPERATION_FIELD_LEN_NE.clone()], ..Default::default() });
+        assert_eq!(validation_gt, StrSchema { uppercase_len: vec![OPERATION_FIELD_LEN_GT.clone()], ..Default::default() });
+        assert_eq!(validation_ge, StrSchema { uppercase_len: vec![OPERATION_FIELD_LEN_GE.clone()], ..Default::default() });
+        assert_eq!(validation_lt, StrSchema { uppercase_len: vec![OPERATION_FIELD_LEN_LT.clone()], ..Default::default() });
+        assert_eq!(validation_le, StrSchema { uppercase_len: vec![OPERATION_FIELD_LEN_LE.clone()], ..Default::default() });
+        assert_eq!(validation_btwn, StrSchema { uppercase_len: vec![OPERATION_FIELD_LEN_BTWN.clone()], ..Default::default() });
     }
 
     #[test]
@@ -855,13 +1206,13 @@ mod tests {
         let validation_lt = StrSchema::default().numbers_len_lt_field(FIELD_LEN.into());
         let validation_le = StrSchema::default().numbers_len_le_field(FIELD_LEN.into());
         let validation_btwn = StrSchema::default().numbers_len_btwn_field(FIELD_LEN.into(), FIELD_LEN_B.into());
-        assert_eq!(validation_eq, StrSchema { numbers_len: Some(OPERATION_FIELD_LEN_EQ.clone()), ..Default::default() });
-        assert_eq!(validation_ne, StrSchema { numbers_len: Some(OPERATION_FIELD_LEN_NE.clone()), ..Default::default() });
-        assert_eq!(validation_gt, StrSchema { numbers_len: Some(OPERATION_FIELD_LEN_GT.clone()), ..Default::default() });
-        assert_eq!(validation_ge, StrSchema { numbers_len: Some(OPERATION_FIELD_LEN_GE.clone()), ..Default::default() });
-        assert_eq!(validation_lt, StrSchema { numbers_len: Some(OPERATION_FIELD_LEN_LT.clone()), ..Default::default() });
-        assert_eq!(validation_le, StrSchema { numbers_len: Some(OPERATION_FIELD_LEN_LE.clone()), ..Default::default() });
-        assert_eq!(validation_btwn, StrSchema { numbers_len: Some(OPERATION_FIELD_LEN_BTWN.clone()), ..Default::default() });
+        assert_eq!(validation_eq, StrSchema { numbers_len: vec![OPERATION_FIELD_LEN_EQ.clone()], ..Default::default() });
+        assert_eq!(validation_ne, StrSchema { numbers_len: vec![OPERATION_FIELD_LEN_NE.clone()], ..Default::default() });
+        assert_eq!(validation_gt, StrSchema { numbers_len: vec![OPERATION_FIELD_LEN_GT.clone()], ..Default::default() });
+        assert_eq!(validation_ge, StrSchema { numbers_len: vec![OPERATION_FIELD_LEN_GE.clone()], ..Default::default() });
+        assert_eq!(validation_lt, StrSchema { numbers_len: vec![OPERATION_FIELD_LEN_LT.clone()], ..Default::default() });
+        assert_eq!(validation_le, StrSchema { numbers_len: vec![OPERATION_FIELD_LEN_LE.clone()], ..Default::default() });
+        assert_eq!(validation_btwn, StrSchema { numbers_len: vec![OPERATION_FIELD_LEN_BTWN.clone()], ..Default::default() });
     }
 
     #[test]
@@ -873,12 +1224,113 @@ mod tests {
         let validation_lt = StrSchema::default().symbols_len_lt_field(FIELD_LEN.into());
         let validation_le = StrSchema::default().symbols_len_le_field(FIELD_LEN.into());
         let validation_btwn = StrSchema::default().symbols_len_btwn_field(FIELD_LEN.into(), FIELD_LEN_B.into());
-        assert_eq!(validation_eq, StrSchema { symbols_len: Some(OPERATION_FIELD_LEN_EQ.clone()), ..Default::default() });
-        assert_eq!(validation_ne, StrSchema { symbols_len: Some(OPERATION_FIELD_LEN_NE.clone()), ..Default::default() });
-        assert_eq!(validation_gt, StrSchema { symbols_len: Some(OPERATION_FIELD_LEN_GT.clone()), ..Default::default() });
-        assert_eq!(validation_ge, StrSchema { symbols_len: Some(OPERATION_FIELD_LEN_GE.clone()), ..Default::default() });
-        assert_eq!(validation_lt, StrSchema { symbols_len: Some(OPERATION_FIELD_LEN_LT.clone()), ..Default::default() });
-        assert_eq!(validation_le, StrSchema { symbols_len: Some(OPERATION_FIELD_LEN_LE.clone()), ..Default::default() });
-        assert_eq!(validation_btwn, StrSchema { symbols_len: Some(OPERATION_FIELD_LEN_BTWN.clone()), ..Default::default() });
+        assert_eq!(validation_eq, StrSchema { symbols_len: vec![OPERATION_FIELD_LEN_EQ.clone()], ..Default::default() });
+        assert_eq!(validation_ne, StrSchema { symbols_len: vec![OPERATION_FIELD_LEN_NE.clone()], ..Default::default() });
+        assert_eq!(validation_gt, StrSchema { symbols_len: vec![OPERATION_FIELD_LEN_GT.clone()], ..Default::default() });
+        assert_eq!(validation_ge, StrSchema { symbols_len: vec![OPERATION_FIELD_LEN_GE.clone()], ..Default::default() });
+        assert_eq!(validation_lt, StrSchema { symbols_len: vec![OPERATION_FIELD_LEN_LT.clone()], ..Default::default() });
+        assert_eq!(validation_le, StrSchema { symbols_len: vec![OPERATION_FIELD_LEN_LE.clone()], ..Default::default() });
+        assert_eq!(validation_btwn, StrSchema { symbols_len: vec![OPERATION_FIELD_LEN_BTWN.clone()], ..Default::default() });
+    }
+
+    #[test]
+    fn str_schema_pattern() {
+        let schema = StrSchema::default().pattern(Pattern::new("^[A-Z]{3}-[0-9]{4}$").unwrap()).pattern(Pattern::new("^ABC").unwrap());
+        assert_eq!(
+            schema,
+            StrSchema { patterns: vec![Pattern::new("^[A-Z]{3}-[0-9]{4}$").unwrap(), Pattern::new("^ABC").unwrap()], ..Default::default() }
+        );
+        assert_eq!(schema.patterns[0].as_str(), "^[A-Z]{3}-[0-9]{4}$");
+        assert!(schema.patterns[0].is_match("ABC-1234"));
+        assert!(!schema.patterns[0].is_match("ABC-12345"));
+        assert_eq!(format!("{:?}", schema.patterns[1]), r#"Pattern("^ABC")"#);
+        assert!(Pattern::new("[a-z").is_err());
+    }
+
+    #[test]
+    fn str_schema_predicates() {
+        assert_eq!(
+            StrSchema::default().starts_with("sk_").ends_with("_live").contains("abc").not_contains("..").only_chars(Charset::Base64Url),
+            StrSchema {
+                predicates: vec![
+                    StrPredicate::StartsWith("sk_".into()),
+                    StrPredicate::EndsWith("_live".into()),
+                    StrPredicate::Contains("abc".into()),
+                    StrPredicate::NotContains("..".into()),
+                    StrPredicate::OnlyChars(Charset::Base64Url),
+                ],
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn str_predicate_test() {
+        assert!(StrPredicate::StartsWith("sk_".into()).test("sk_123"));
+        assert!(!StrPredicate::StartsWith("sk_".into()).test("pk_123"));
+        assert!(StrPredicate::EndsWith(".json".into()).test("data.json"));
+        assert!(!StrPredicate::EndsWith(".json".into()).test("data.yaml"));
+        assert!(StrPredicate::Contains("@".into()).test("paul@gmail.com"));
+        assert!(!StrPredicate::Contains("@".into()).test("paul"));
+        assert!(StrPredicate::NotContains("..".into()).test("a.b"));
+        assert!(!StrPredicate::NotContains("..".into()).test("a..b"));
+        assert!(StrPredicate::OnlyChars(Charset::Hex).test(""));
+    }
+
+    #[test]
+    fn charset_contains() {
+        assert!("Avalon42".chars().all(|c| Charset::AsciiAlphanumeric.contains(c)));
+        assert!(!"Avalon-42".chars().all(|c| Charset::AsciiAlphanumeric.contains(c)));
+        assert!("0123456789abcdefABCDEF".chars().all(|c| Charset::Hex.contains(c)));
+        assert!(!Charset::Hex.contains('g'));
+        assert!("aZ09-_".chars().all(|c| Charset::Base64Url.contains(c)));
+        assert!(!Charset::Base64Url.contains('+'));
+        assert!(!Charset::Base64Url.contains('='));
+        assert!(!Charset::AsciiAlphanumeric.contains('ã'));
+        assert!(Charset::Custom("01".into()).contains('1'));
+        assert!(!Charset::Custom("01".into()).contains('2'));
+    }
+
+    #[test]
+    fn str_schema_unicode() {
+        assert_eq!(
+            StrSchema::default().trimmed().no_control_chars().normalized(Normalization::Nfc).normalize_len(Normalization::Nfkc),
+            StrSchema {
+                predicates: vec![StrPredicate::Trimmed, StrPredicate::NoControlChars, StrPredicate::Normalized(Normalization::Nfc)],
+                len_normalization: Some(Normalization::Nfkc),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn str_predicate_unicode() {
+        assert!(StrPredicate::Trimmed.test("Paul McCartney"));
+        assert!(!StrPredicate::Trimmed.test(" Paul"));
+        assert!(!StrPredicate::Trimmed.test("Paul\u{3000}"));
+        assert!(StrPredicate::NoControlChars.test("Paul"));
+        assert!(!StrPredicate::NoControlChars.test("Pa\u{200B}ul"));
+        assert!(!StrPredicate::NoControlChars.test("Paul\u{202E}"));
+        assert!(!StrPredicate::NoControlChars.test("Paul\n"));
+        assert!(StrPredicate::Normalized(Normalization::Nfc).test("Jos\u{00E9}"));
+        assert!(!StrPredicate::Normalized(Normalization::Nfc).test("Jose\u{0301}"));
+        assert!(StrPredicate::Normalized(Normalization::Nfc).test("\u{FB01}le"));
+        assert!(!StrPredicate::Normalized(Normalization::Nfkc).test("\u{FB01}le"));
+    }
+
+    #[test]
+    fn normalization() {
+        assert_eq!(Normalization::Nfc.normalize("Jose\u{0301}"), "Jos\u{00E9}");
+        assert_eq!(Normalization::Nfkc.normalize("\u{FB01}le"), "file");
+        assert_eq!(Normalization::Nfc.to_string(), "NFC");
+        assert_eq!(Normalization::Nfkc.to_string(), "NFKC");
+    }
+
+    #[test]
+    fn str_schema_default_value() {
+        assert_eq!(
+            StrSchema::default().optional().default_value("info"),
+            StrSchema { required: false, default: Some("info".into()), ..Default::default() }
+        );
     }
 }
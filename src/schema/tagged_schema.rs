@@ -0,0 +1,62 @@
+use std::collections::BTreeMap;
+
+use super::ObjSchema;
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TaggedSchema {
+    pub required: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub label: Option<String>,
+    pub tag: String,
+    pub variants: BTreeMap<String, ObjSchema>,
+}
+
+impl From<String> for TaggedSchema {
+    fn from(tag: String) -> Self {
+        TaggedSchema { required: true, label: None, tag, variants: BTreeMap::new() }
+    }
+}
+
+impl TaggedSchema {
+    pub fn optional(self) -> Self {
+        TaggedSchema { required: false, ..self }
+    }
+
+    pub fn label(self, label: &str) -> Self {
+        TaggedSchema { label: Some(label.into()), ..self }
+    }
+
+    pub fn variant(mut self, tag_value: String, schema: ObjSchema) -> Self {
+        self.variants.insert(tag_value, schema);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::schema::{ObjSchema, Schema, StrSchema, U64Schema};
+
+    use super::TaggedSchema;
+
+    #[test]
+    fn tagged_schema() {
+        let click = ObjSchema::from([("x".into(), Schema::from(U64Schema::default())), ("y".into(), Schema::from(U64Schema::default()))]);
+        let key = ObjSchema::from([("code".into(), Schema::from(StrSchema::default()))]);
+        assert_eq!(
+            TaggedSchema::from("type".to_string()),
+            TaggedSchema { required: true, label: None, tag: "type".into(), variants: BTreeMap::new() }
+        );
+        assert_eq!(
+            TaggedSchema::from("type".to_string()).variant("click".into(), click.clone()).variant("key".into(), key.clone()).optional(),
+            TaggedSchema {
+                required: false,
+                label: None,
+                tag: "type".into(),
+                variants: BTreeMap::from([("click".into(), click), ("key".into(), key)])
+            }
+        );
+    }
+}
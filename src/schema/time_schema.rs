@@ -1,14 +1,17 @@
-use crate::operation::{Operand, OperandValue, Operation};
+use crate::operation::{Operand, OperandValue, Operation, Time};
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TimeSchema {
     pub required: bool,
-    pub operation: Option<Operation>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub label: Option<String>,
+    pub operations: Vec<Operation>,
 }
 
 impl Default for TimeSchema {
     fn default() -> Self {
-        TimeSchema { required: true, operation: None }
+        TimeSchema { required: true, label: None, operations: vec![] }
     }
 }
 
@@ -17,63 +20,78 @@ impl TimeSchema {
         TimeSchema { required: false, ..self }
     }
 
-    pub fn eq(self, value: String) -> Self {
-        TimeSchema { operation: Some(Operation::Eq(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn label(self, label: &str) -> Self {
+        TimeSchema { label: Some(label.into()), ..self }
     }
 
-    pub fn ne(self, value: String) -> Self {
-        TimeSchema { operation: Some(Operation::Ne(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn eq(mut self, value: Time) -> Self {
+        self.operations.push(Operation::Eq(Operand::Value(OperandValue::Time(value))));
+        self
     }
 
-    pub fn gt(self, value: String) -> Self {
-        TimeSchema { operation: Some(Operation::Gt(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn ne(mut self, value: Time) -> Self {
+        self.operations.push(Operation::Ne(Operand::Value(OperandValue::Time(value))));
+        self
     }
 
-    pub fn ge(self, value: String) -> Self {
-        TimeSchema { operation: Some(Operation::Ge(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn gt(mut self, value: Time) -> Self {
+        self.operations.push(Operation::Gt(Operand::Value(OperandValue::Time(value))));
+        self
     }
 
-    pub fn lt(self, value: String) -> Self {
-        TimeSchema { operation: Some(Operation::Lt(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn ge(mut self, value: Time) -> Self {
+        self.operations.push(Operation::Ge(Operand::Value(OperandValue::Time(value))));
+        self
     }
 
-    pub fn le(self, value: String) -> Self {
-        TimeSchema { operation: Some(Operation::Le(Operand::Value(OperandValue::Str(value)))), ..self }
+    pub fn lt(mut self, value: Time) -> Self {
+        self.operations.push(Operation::Lt(Operand::Value(OperandValue::Time(value))));
+        self
     }
 
-    pub fn btwn(self, value_a: String, value_b: String) -> Self {
-        TimeSchema {
-            operation: Some(Operation::Btwn(Operand::Value(OperandValue::Str(value_a)), Operand::Value(OperandValue::Str(value_b)))),
-            ..self
-        }
+    pub fn le(mut self, value: Time) -> Self {
+        self.operations.push(Operation::Le(Operand::Value(OperandValue::Time(value))));
+        self
     }
 
-    pub fn eq_field(self, field: String) -> Self {
-        TimeSchema { operation: Some(Operation::Eq(Operand::FieldPath(field))), ..self }
+    pub fn btwn(mut self, value_a: Time, value_b: Time) -> Self {
+        self.operations.push(Operation::Btwn(Operand::Value(OperandValue::Time(value_a)), Operand::Value(OperandValue::Time(value_b))));
+        self
     }
 
-    pub fn ne_field(self, field: String) -> Self {
-        TimeSchema { operation: Some(Operation::Ne(Operand::FieldPath(field))), ..self }
+    pub fn eq_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Eq(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn gt_field(self, field: String) -> Self {
-        TimeSchema { operation: Some(Operation::Gt(Operand::FieldPath(field))), ..self }
+    pub fn ne_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Ne(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn ge_field(self, field: String) -> Self {
-        TimeSchema { operation: Some(Operation::Ge(Operand::FieldPath(field))), ..self }
+    pub fn gt_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Gt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn lt_field(self, field: String) -> Self {
-        TimeSchema { operation: Some(Operation::Lt(Operand::FieldPath(field))), ..self }
+    pub fn ge_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Ge(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn le_field(self, field: String) -> Self {
-        TimeSchema { operation: Some(Operation::Le(Operand::FieldPath(field))), ..self }
+    pub fn lt_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Lt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn btwn_field(self, field_a: String, field_b: String) -> Self {
-        TimeSchema { operation: Some(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b))), ..self }
+    pub fn le_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Le(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn btwn_field(mut self, field_a: String, field_b: String) -> Self {
+        self.operations.push(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b)));
+        self
     }
 }
 
@@ -81,15 +99,15 @@ impl TimeSchema {
 mod tests {
     use std::sync::LazyLock;
 
-    use crate::operation::{Operand, OperandValue, Operation};
+    use crate::operation::{Operand, OperandValue, Operation, parse_time};
 
     use super::TimeSchema;
 
     const VALUE: &str = "10:27";
     const VALUE_B: &str = "19:41";
 
-    static OPERAND_VALUE: LazyLock<Operand> = LazyLock::new(|| Operand::Value(OperandValue::from(VALUE)));
-    static OPERAND_VALUE_B: LazyLock<Operand> = LazyLock::new(|| Operand::Value(OperandValue::from(VALUE_B)));
+    static OPERAND_VALUE: LazyLock<Operand> = LazyLock::new(|| Operand::Value(OperandValue::Time(parse_time(VALUE).unwrap())));
+    static OPERAND_VALUE_B: LazyLock<Operand> = LazyLock::new(|| Operand::Value(OperandValue::Time(parse_time(VALUE_B).unwrap())));
 
     static OPERATION_VALUE_EQ: LazyLock<Operation> = LazyLock::new(|| Operation::Eq(OPERAND_VALUE.clone()));
     static OPERATION_VALUE_NE: LazyLock<Operation> = LazyLock::new(|| Operation::Ne(OPERAND_VALUE.clone()));
@@ -115,26 +133,26 @@ mod tests {
 
     #[test]
     fn time_schema() {
-        assert_eq!(TimeSchema::default(), TimeSchema { required: true, operation: None });
-        assert_eq!(TimeSchema::default().optional(), TimeSchema { required: false, operation: None });
+        assert_eq!(TimeSchema::default(), TimeSchema { required: true, label: None, operations: vec![] });
+        assert_eq!(TimeSchema::default().optional(), TimeSchema { required: false, label: None, operations: vec![] });
     }
 
     #[test]
     fn time_schema_operation_value() {
-        let validation_eq = TimeSchema::default().eq(VALUE.into());
-        let validation_ne = TimeSchema::default().ne(VALUE.into());
-        let validation_gt = TimeSchema::default().gt(VALUE.into());
-        let validation_ge = TimeSchema::default().ge(VALUE.into());
-        let validation_lt = TimeSchema::default().lt(VALUE.into());
-        let validation_le = TimeSchema::default().le(VALUE.into());
-        let validation_btwn = TimeSchema::default().btwn(VALUE.into(), VALUE_B.into());
-        assert_eq!(validation_eq, TimeSchema { required: true, operation: Some(OPERATION_VALUE_EQ.clone()) });
-        assert_eq!(validation_ne, TimeSchema { required: true, operation: Some(OPERATION_VALUE_NE.clone()) });
-        assert_eq!(validation_gt, TimeSchema { required: true, operation: Some(OPERATION_VALUE_GT.clone()) });
-        assert_eq!(validation_ge, TimeSchema { required: true, operation: Some(OPERATION_VALUE_GE.clone()) });
-        assert_eq!(validation_lt, TimeSchema { required: true, operation: Some(OPERATION_VALUE_LT.clone()) });
-        assert_eq!(validation_le, TimeSchema { required: true, operation: Some(OPERATION_VALUE_LE.clone()) });
-        assert_eq!(validation_btwn, TimeSchema { required: true, operation: Some(OPERATION_VALUE_BTWN.clone()) });
+        let validation_eq = TimeSchema::default().eq(parse_time(VALUE).unwrap());
+        let validation_ne = TimeSchema::default().ne(parse_time(VALUE).unwrap());
+        let validation_gt = TimeSchema::default().gt(parse_time(VALUE).unwrap());
+        let validation_ge = TimeSchema::default().ge(parse_time(VALUE).unwrap());
+        let validation_lt = TimeSchema::default().lt(parse_time(VALUE).unwrap());
+        let validation_le = TimeSchema::default().le(parse_time(VALUE).unwrap());
+        let validation_btwn = TimeSchema::default().btwn(parse_time(VALUE).unwrap(), parse_time(VALUE_B).unwrap());
+        assert_eq!(validation_eq, TimeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_EQ.clone()] });
+        assert_eq!(validation_ne, TimeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_NE.clone()] });
+        assert_eq!(validation_gt, TimeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_GT.clone()] });
+        assert_eq!(validation_ge, TimeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_GE.clone()] });
+        assert_eq!(validation_lt, TimeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_LT.clone()] });
+        assert_eq!(validation_le, TimeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_LE.clone()] });
+        assert_eq!(validation_btwn, TimeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_BTWN.clone()] });
     }
 
     #[test]
@@ -146,12 +164,12 @@ mod tests {
         let validation_lt = TimeSchema::default().lt_field(FIELD.into());
         let validation_le = TimeSchema::default().le_field(FIELD.into());
         let validation_btwn = TimeSchema::default().btwn_field(FIELD.into(), FIELD_B.into());
-        assert_eq!(validation_eq, TimeSchema { required: true, operation: Some(OPERATION_FIELD_EQ.clone()) });
-        assert_eq!(validation_ne, TimeSchema { required: true, operation: Some(OPERATION_FIELD_NE.clone()) });
-        assert_eq!(validation_gt, TimeSchema { required: true, operation: Some(OPERATION_FIELD_GT.clone()) });
-        assert_eq!(validation_ge, TimeSchema { required: true, operation: Some(OPERATION_FIELD_GE.clone()) });
-        assert_eq!(validation_lt, TimeSchema { required: true, operation: Some(OPERATION_FIELD_LT.clone()) });
-        assert_eq!(validation_le, TimeSchema { required: true, operation: Some(OPERATION_FIELD_LE.clone()) });
-        assert_eq!(validation_btwn, TimeSchema { required: true, operation: Some(OPERATION_FIELD_BTWN.clone()) });
+        assert_eq!(validation_eq, TimeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_EQ.clone()] });
+        assert_eq!(validation_ne, TimeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_NE.clone()] });
+        assert_eq!(validation_gt, TimeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_GT.clone()] });
+        assert_eq!(validation_ge, TimeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_GE.clone()] });
+        assert_eq!(validation_lt, TimeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_LT.clone()] });
+        assert_eq!(validation_le, TimeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_LE.clone()] });
+        assert_eq!(validation_btwn, TimeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_BTWN.clone()] });
     }
 }
@@ -1,14 +1,21 @@
 use crate::operation::{Operand, OperandValue, Operation};
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct U64Schema {
     pub required: bool,
-    pub operation: Option<Operation>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub label: Option<String>,
+    pub operations: Vec<Operation>,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "std::ops::Not::not"))]
+    pub coerce: bool,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub default: Option<u64>,
 }
 
 impl Default for U64Schema {
     fn default() -> Self {
-        U64Schema { required: true, operation: None }
+        U64Schema { required: true, label: None, operations: vec![], coerce: false, default: None }
     }
 }
 
@@ -17,60 +24,86 @@ impl U64Schema {
         U64Schema { required: false, ..self }
     }
 
-    pub fn eq(self, value: u64) -> Self {
-        U64Schema { operation: Some(Operation::Eq(Operand::Value(OperandValue::U64(value)))), ..self }
+    pub fn label(self, label: &str) -> Self {
+        U64Schema { label: Some(label.into()), ..self }
     }
 
-    pub fn ne(self, value: u64) -> Self {
-        U64Schema { operation: Some(Operation::Ne(Operand::Value(OperandValue::U64(value)))), ..self }
+    pub fn default_value(self, value: u64) -> Self {
+        U64Schema { default: Some(value), ..self }
     }
 
-    pub fn gt(self, value: u64) -> Self {
-        U64Schema { operation: Some(Operation::Gt(Operand::Value(OperandValue::U64(value)))), ..self }
+    pub fn coerce(self) -> Self {
+        U64Schema { coerce: true, ..self }
     }
 
-    pub fn ge(self, value: u64) -> Self {
-        U64Schema { operation: Some(Operation::Ge(Operand::Value(OperandValue::U64(value)))), ..self }
+    pub fn eq(mut self, value: u64) -> Self {
+        self.operations.push(Operation::Eq(Operand::Value(OperandValue::U64(value))));
+        self
     }
 
-    pub fn lt(self, value: u64) -> Self {
-        U64Schema { operation: Some(Operation::Lt(Operand::Value(OperandValue::U64(value)))), ..self }
+    pub fn ne(mut self, value: u64) -> Self {
+        self.operations.push(Operation::Ne(Operand::Value(OperandValue::U64(value))));
+        self
     }
 
-    pub fn le(self, value: u64) -> Self {
-        U64Schema { operation: Some(Operation::Le(Operand::Value(OperandValue::U64(value)))), ..self }
+    pub fn gt(mut self, value: u64) -> Self {
+        self.operations.push(Operation::Gt(Operand::Value(OperandValue::U64(value))));
+        self
     }
 
-    pub fn btwn(self, value_a: u64, value_b: u64) -> Self {
-        U64Schema { operation: Some(Operation::Btwn(Operand::Value(OperandValue::U64(value_a)), Operand::Value(OperandValue::U64(value_b)))), ..self }
+    pub fn ge(mut self, value: u64) -> Self {
+        self.operations.push(Operation::Ge(Operand::Value(OperandValue::U64(value))));
+        self
     }
 
-    pub fn eq_field(self, field: String) -> Self {
-        U64Schema { operation: Some(Operation::Eq(Operand::FieldPath(field))), ..self }
+    pub fn lt(mut self, value: u64) -> Self {
+        self.operations.push(Operation::Lt(Operand::Value(OperandValue::U64(value))));
+        self
     }
 
-    pub fn ne_field(self, field: String) -> Self {
-        U64Schema { operation: Some(Operation::Ne(Operand::FieldPath(field))), ..self }
+    pub fn le(mut self, value: u64) -> Self {
+        self.operations.push(Operation::Le(Operand::Value(OperandValue::U64(value))));
+        self
     }
 
-    pub fn gt_field(self, field: String) -> Self {
-        U64Schema { operation: Some(Operation::Gt(Operand::FieldPath(field))), ..self }
+    pub fn btwn(mut self, value_a: u64, value_b: u64) -> Self {
+        self.operations.push(Operation::Btwn(Operand::Value(OperandValue::U64(value_a)), Operand::Value(OperandValue::U64(value_b))));
+        self
     }
 
-    pub fn ge_field(self, field: String) -> Self {
-        U64Schema { operation: Some(Operation::Ge(Operand::FieldPath(field))), ..self }
+    pub fn eq_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Eq(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn lt_field(self, field: String) -> Self {
-        U64Schema { operation: Some(Operation::Lt(Operand::FieldPath(field))), ..self }
+    pub fn ne_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Ne(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn le_field(self, field: String) -> Self {
-        U64Schema { operation: Some(Operation::Le(Operand::FieldPath(field))), ..self }
+    pub fn gt_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Gt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn btwn_field(self, field_a: String, field_b: String) -> Self {
-        U64Schema { operation: Some(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b))), ..self }
+    pub fn ge_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Ge(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn lt_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Lt(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn le_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Le(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn btwn_field(mut self, field_a: String, field_b: String) -> Self {
+        self.operations.push(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b)));
+        self
     }
 }
 
@@ -106,19 +139,42 @@ mod tests {
 
     #[test]
     fn u64_schema() {
-        assert_eq!(U64Schema::default(), U64Schema { required: true, operation: None });
-        assert_eq!(U64Schema::default().optional(), U64Schema { required: false, operation: None });
+        assert_eq!(U64Schema::default(), U64Schema { required: true, label: None, operations: vec![], coerce: false, default: None });
+        assert_eq!(U64Schema::default().optional(), U64Schema { required: false, label: None, operations: vec![], coerce: false, default: None });
+        assert_eq!(U64Schema::default().coerce(), U64Schema { required: true, label: None, operations: vec![], coerce: true, default: None });
+        assert_eq!(U64Schema::default().optional().default_value(8080).default, Some(8080));
     }
 
     #[test]
     fn u64_schema_operation_value() {
-        assert_eq!(U64Schema::default().eq(1), U64Schema { required: true, operation: Some(OPERATION_VALUE_EQ) });
-        assert_eq!(U64Schema::default().ne(2), U64Schema { required: true, operation: Some(OPERATION_VALUE_NE) });
-        assert_eq!(U64Schema::default().gt(3), U64Schema { required: true, operation: Some(OPERATION_VALUE_GT) });
-        assert_eq!(U64Schema::default().ge(4), U64Schema { required: true, operation: Some(OPERATION_VALUE_GE) });
-        assert_eq!(U64Schema::default().lt(5), U64Schema { required: true, operation: Some(OPERATION_VALUE_LT) });
-        assert_eq!(U64Schema::default().le(6), U64Schema { required: true, operation: Some(OPERATION_VALUE_LE) });
-        assert_eq!(U64Schema::default().btwn(1, 9), U64Schema { required: true, operation: Some(OPERATION_VALUE_BTWN) });
+        assert_eq!(
+            U64Schema::default().eq(1),
+            U64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_EQ], coerce: false, default: None }
+        );
+        assert_eq!(
+            U64Schema::default().ne(2),
+            U64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_NE], coerce: false, default: None }
+        );
+        assert_eq!(
+            U64Schema::default().gt(3),
+            U64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_GT], coerce: false, default: None }
+        );
+        assert_eq!(
+            U64Schema::default().ge(4),
+            U64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_GE], coerce: false, default: None }
+        );
+        assert_eq!(
+            U64Schema::default().lt(5),
+            U64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_LT], coerce: false, default: None }
+        );
+        assert_eq!(
+            U64Schema::default().le(6),
+            U64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_LE], coerce: false, default: None }
+        );
+        assert_eq!(
+            U64Schema::default().btwn(1, 9),
+            U64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_BTWN], coerce: false, default: None }
+        );
     }
 
     #[test]
@@ -130,12 +186,51 @@ mod tests {
         let validation_lt = U64Schema::default().lt_field(FIELD.into());
         let validation_le = U64Schema::default().le_field(FIELD.into());
         let validation_btwn = U64Schema::default().btwn_field(FIELD.into(), FIELD_B.into());
-        assert_eq!(validation_eq, U64Schema { required: true, operation: Some(OPERATION_FIELD_EQ.clone()) });
-        assert_eq!(validation_ne, U64Schema { required: true, operation: Some(OPERATION_FIELD_NE.clone()) });
-        assert_eq!(validation_gt, U64Schema { required: true, operation: Some(OPERATION_FIELD_GT.clone()) });
-        assert_eq!(validation_ge, U64Schema { required: true, operation: Some(OPERATION_FIELD_GE.clone()) });
-        assert_eq!(validation_lt, U64Schema { required: true, operation: Some(OPERATION_FIELD_LT.clone()) });
-        assert_eq!(validation_le, U64Schema { required: true, operation: Some(OPERATION_FIELD_LE.clone()) });
-        assert_eq!(validation_btwn, U64Schema { required: true, operation: Some(OPERATION_FIELD_BTWN.clone()) });
+        assert_eq!(
+            validation_eq,
+            U64Schema { required: true, label: None, operations: vec![OPERATION_FIELD_EQ.clone()], coerce: false, default: None }
+        );
+        assert_eq!(
+            validation_ne,
+            U64Schema { required: true, label: None, operations: vec![OPERATION_FIELD_NE.clone()], coerce: false, default: None }
+        );
+        assert_eq!(
+            validation_gt,
+            U64Schema { required: true, label: None, operations: vec![OPERATION_FIELD_GT.clone()], coerce: false, default: None }
+        );
+        assert_eq!(
+            validation_ge,
+            U64Schema { required: true, label: None, operations: vec![OPERATION_FIELD_GE.clone()], coerce: false, default: None }
+        );
+        assert_eq!(
+            validation_lt,
+            U64Schema { required: true, label: None, operations: vec![OPERATION_FIELD_LT.clone()], coerce: false, default: None }
+        );
+        assert_eq!(
+            validation_le,
+            U64Schema { required: true, label: None, operations: vec![OPERATION_FIELD_LE.clone()], coerce: false, default: None }
+        );
+        assert_eq!(
+            validation_btwn,
+            U64Schema { required: true, label: None, operations: vec![OPERATION_FIELD_BTWN.clone()], coerce: false, default: None }
+        );
+    }
+
+    #[test]
+    fn u64_schema_operations() {
+        assert_eq!(
+            U64Schema::default().gt(3).ne(2),
+            U64Schema { required: true, label: None, operations: vec![OPERATION_VALUE_GT, OPERATION_VALUE_NE], coerce: false, default: None }
+        );
+        assert_eq!(
+            U64Schema::default().btwn(1, 9).ne_field(FIELD.into()),
+            U64Schema {
+                required: true,
+                label: None,
+                operations: vec![OPERATION_VALUE_BTWN, OPERATION_FIELD_NE.clone()],
+                coerce: false,
+                default: None
+            }
+        );
     }
 }
@@ -0,0 +1,50 @@
+use super::Schema;
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnionSchema {
+    pub required: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub label: Option<String>,
+    pub variants: Vec<Schema>,
+}
+
+impl From<Vec<Schema>> for UnionSchema {
+    fn from(variants: Vec<Schema>) -> Self {
+        UnionSchema { required: true, label: None, variants }
+    }
+}
+
+impl<const N: usize> From<[Schema; N]> for UnionSchema {
+    fn from(variants: [Schema; N]) -> Self {
+        UnionSchema { required: true, label: None, variants: variants.to_vec() }
+    }
+}
+
+impl UnionSchema {
+    pub fn optional(self) -> Self {
+        UnionSchema { required: false, ..self }
+    }
+
+    pub fn label(self, label: &str) -> Self {
+        UnionSchema { label: Some(label.into()), ..self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{Schema, StrSchema, U64Schema};
+
+    use super::UnionSchema;
+
+    #[test]
+    fn union_schema() {
+        let variants = vec![Schema::from(StrSchema::default()), Schema::from(U64Schema::default())];
+        assert_eq!(UnionSchema::from(variants.clone()), UnionSchema { required: true, label: None, variants: variants.clone() });
+        assert_eq!(
+            UnionSchema::from([Schema::from(StrSchema::default()), Schema::from(U64Schema::default())]),
+            UnionSchema { required: true, label: None, variants: variants.clone() }
+        );
+        assert_eq!(UnionSchema::from(variants.clone()).optional(), UnionSchema { required: false, label: None, variants });
+    }
+}
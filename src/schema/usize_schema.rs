@@ -1,14 +1,17 @@
 use crate::operation::{Operand, OperandValue, Operation};
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct USizeSchema {
     pub required: bool,
-    pub operation: Option<Operation>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub label: Option<String>,
+    pub operations: Vec<Operation>,
 }
 
 impl Default for USizeSchema {
     fn default() -> Self {
-        USizeSchema { required: true, operation: None }
+        USizeSchema { required: true, label: None, operations: vec![] }
     }
 }
 
@@ -17,63 +20,78 @@ impl USizeSchema {
         USizeSchema { required: false, ..self }
     }
 
-    pub fn eq(self, value: usize) -> Self {
-        USizeSchema { operation: Some(Operation::Eq(Operand::Value(OperandValue::USize(value)))), ..self }
+    pub fn label(self, label: &str) -> Self {
+        USizeSchema { label: Some(label.into()), ..self }
     }
 
-    pub fn ne(self, value: usize) -> Self {
-        USizeSchema { operation: Some(Operation::Ne(Operand::Value(OperandValue::USize(value)))), ..self }
+    pub fn eq(mut self, value: usize) -> Self {
+        self.operations.push(Operation::Eq(Operand::Value(OperandValue::USize(value))));
+        self
     }
 
-    pub fn gt(self, value: usize) -> Self {
-        USizeSchema { operation: Some(Operation::Gt(Operand::Value(OperandValue::USize(value)))), ..self }
+    pub fn ne(mut self, value: usize) -> Self {
+        self.operations.push(Operation::Ne(Operand::Value(OperandValue::USize(value))));
+        self
     }
 
-    pub fn ge(self, value: usize) -> Self {
-        USizeSchema { operation: Some(Operation::Ge(Operand::Value(OperandValue::USize(value)))), ..self }
+    pub fn gt(mut self, value: usize) -> Self {
+        self.operations.push(Operation::Gt(Operand::Value(OperandValue::USize(value))));
+        self
     }
 
-    pub fn lt(self, value: usize) -> Self {
-        USizeSchema { operation: Some(Operation::Lt(Operand::Value(OperandValue::USize(value)))), ..self }
+    pub fn ge(mut self, value: usize) -> Self {
+        self.operations.push(Operation::Ge(Operand::Value(OperandValue::USize(value))));
+        self
     }
 
-    pub fn le(self, value: usize) -> Self {
-        USizeSchema { operation: Some(Operation::Le(Operand::Value(OperandValue::USize(value)))), ..self }
+    pub fn lt(mut self, value: usize) -> Self {
+        self.operations.push(Operation::Lt(Operand::Value(OperandValue::USize(value))));
+        self
     }
 
-    pub fn btwn(self, value_a: usize, value_b: usize) -> Self {
-        USizeSchema {
-            operation: Some(Operation::Btwn(Operand::Value(OperandValue::USize(value_a)), Operand::Value(OperandValue::USize(value_b)))),
-            ..self
-        }
+    pub fn le(mut self, value: usize) -> Self {
+        self.operations.push(Operation::Le(Operand::Value(OperandValue::USize(value))));
+        self
     }
 
-    pub fn eq_field(self, field: String) -> Self {
-        USizeSchema { operation: Some(Operation::Eq(Operand::FieldPath(field))), ..self }
+    pub fn btwn(mut self, value_a: usize, value_b: usize) -> Self {
+        self.operations.push(Operation::Btwn(Operand::Value(OperandValue::USize(value_a)), Operand::Value(OperandValue::USize(value_b))));
+        self
     }
 
-    pub fn ne_field(self, field: String) -> Self {
-        USizeSchema { operation: Some(Operation::Ne(Operand::FieldPath(field))), ..self }
+    pub fn eq_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Eq(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn gt_field(self, field: String) -> Self {
-        USizeSchema { operation: Some(Operation::Gt(Operand::FieldPath(field))), ..self }
+    pub fn ne_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Ne(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn ge_field(self, field: String) -> Self {
-        USizeSchema { operation: Some(Operation::Ge(Operand::FieldPath(field))), ..self }
+    pub fn gt_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Gt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn lt_field(self, field: String) -> Self {
-        USizeSchema { operation: Some(Operation::Lt(Operand::FieldPath(field))), ..self }
+    pub fn ge_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Ge(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn le_field(self, field: String) -> Self {
-        USizeSchema { operation: Some(Operation::Le(Operand::FieldPath(field))), ..self }
+    pub fn lt_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Lt(Operand::FieldPath(field)));
+        self
     }
 
-    pub fn btwn_field(self, field_a: String, field_b: String) -> Self {
-        USizeSchema { operation: Some(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b))), ..self }
+    pub fn le_field(mut self, field: String) -> Self {
+        self.operations.push(Operation::Le(Operand::FieldPath(field)));
+        self
+    }
+
+    pub fn btwn_field(mut self, field_a: String, field_b: String) -> Self {
+        self.operations.push(Operation::Btwn(Operand::FieldPath(field_a), Operand::FieldPath(field_b)));
+        self
     }
 }
 
@@ -109,19 +127,19 @@ mod tests {
 
     #[test]
     fn usize_schema() {
-        assert_eq!(USizeSchema::default(), USizeSchema { required: true, operation: None });
-        assert_eq!(USizeSchema::default().optional(), USizeSchema { required: false, operation: None });
+        assert_eq!(USizeSchema::default(), USizeSchema { required: true, label: None, operations: vec![] });
+        assert_eq!(USizeSchema::default().optional(), USizeSchema { required: false, label: None, operations: vec![] });
     }
 
     #[test]
     fn usize_schema_operation_value() {
-        assert_eq!(USizeSchema::default().eq(1), USizeSchema { required: true, operation: Some(OPERATION_VALUE_EQ) });
-        assert_eq!(USizeSchema::default().ne(2), USizeSchema { required: true, operation: Some(OPERATION_VALUE_NE) });
-        assert_eq!(USizeSchema::default().gt(3), USizeSchema { required: true, operation: Some(OPERATION_VALUE_GT) });
-        assert_eq!(USizeSchema::default().ge(4), USizeSchema { required: true, operation: Some(OPERATION_VALUE_GE) });
-        assert_eq!(USizeSchema::default().lt(5), USizeSchema { required: true, operation: Some(OPERATION_VALUE_LT) });
-        assert_eq!(USizeSchema::default().le(6), USizeSchema { required: true, operation: Some(OPERATION_VALUE_LE) });
-        assert_eq!(USizeSchema::default().btwn(1, 9), USizeSchema { required: true, operation: Some(OPERATION_VALUE_BTWN) });
+        assert_eq!(USizeSchema::default().eq(1), USizeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_EQ] });
+        assert_eq!(USizeSchema::default().ne(2), USizeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_NE] });
+        assert_eq!(USizeSchema::default().gt(3), USizeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_GT] });
+        assert_eq!(USizeSchema::default().ge(4), USizeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_GE] });
+        assert_eq!(USizeSchema::default().lt(5), USizeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_LT] });
+        assert_eq!(USizeSchema::default().le(6), USizeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_LE] });
+        assert_eq!(USizeSchema::default().btwn(1, 9), USizeSchema { required: true, label: None, operations: vec![OPERATION_VALUE_BTWN] });
     }
 
     #[test]
@@ -133,12 +151,12 @@ mod tests {
         let validation_lt = USizeSchema::default().lt_field(FIELD.into());
         let validation_le = USizeSchema::default().le_field(FIELD.into());
         let validation_btwn = USizeSchema::default().btwn_field(FIELD.into(), FIELD_B.into());
-        assert_eq!(validation_eq, USizeSchema { required: true, operation: Some(OPERATION_FIELD_EQ.clone()) });
-        assert_eq!(validation_ne, USizeSchema { required: true, operation: Some(OPERATION_FIELD_NE.clone()) });
-        assert_eq!(validation_gt, USizeSchema { required: true, operation: Some(OPERATION_FIELD_GT.clone()) });
-        assert_eq!(validation_ge, USizeSchema { required: true, operation: Some(OPERATION_FIELD_GE.clone()) });
-        assert_eq!(validation_lt, USizeSchema { required: true, operation: Some(OPERATION_FIELD_LT.clone()) });
-        assert_eq!(validation_le, USizeSchema { required: true, operation: Some(OPERATION_FIELD_LE.clone()) });
-        assert_eq!(validation_btwn, USizeSchema { required: true, operation: Some(OPERATION_FIELD_BTWN.clone()) });
+        assert_eq!(validation_eq, USizeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_EQ.clone()] });
+        assert_eq!(validation_ne, USizeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_NE.clone()] });
+        assert_eq!(validation_gt, USizeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_GT.clone()] });
+        assert_eq!(validation_ge, USizeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_GE.clone()] });
+        assert_eq!(validation_lt, USizeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_LT.clone()] });
+        assert_eq!(validation_le, USizeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_LE.clone()] });
+        assert_eq!(validation_btwn, USizeSchema { required: true, label: None, operations: vec![OPERATION_FIELD_BTWN.clone()] });
     }
 }
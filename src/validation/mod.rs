@@ -0,0 +1,288 @@
+use crate::{
+    error::SchemaErr,
+    operation::{Clock, SystemClock},
+    schema::Schema,
+    value::Value,
+};
+
+use strip_unknown_keys::strip_unknown_keys;
+use transform_value::transform_value;
+#[cfg(feature = "async")]
+pub use validate_async::validate_async_with_clock;
+
+use validate_arr::validate_arr;
+use validate_bool::validate_bool;
+use validate_custom::validate_custom;
+use validate_date::validate_date;
+use validate_date_time::validate_date_time;
+use validate_email::validate_email;
+use validate_enum::validate_enum;
+use validate_f64::validate_f64;
+use validate_i64::validate_i64;
+use validate_isize::validate_isize;
+use validate_map::validate_map;
+use validate_obj::validate_obj;
+use validate_str::validate_str;
+use validate_tagged::validate_tagged;
+use validate_time::validate_time;
+use validate_u64::validate_u64;
+use validate_union::validate_union;
+use validate_usize::validate_usize;
+
+mod strip_unknown_keys;
+mod transform_value;
+mod validate_arr;
+#[cfg(feature = "async")]
+mod validate_async;
+mod validate_bool;
+mod validate_custom;
+mod validate_date;
+mod validate_date_time;
+mod validate_email;
+mod validate_enum;
+mod validate_f64;
+mod validate_i64;
+mod validate_isize;
+mod validate_map;
+mod validate_obj;
+mod validate_str;
+mod validate_tagged;
+mod validate_time;
+mod validate_u64;
+mod validate_union;
+mod validate_usize;
+
+pub fn validate(schema: &Schema, value: &Value) -> Result<(), SchemaErr> {
+    validate_with_clock(schema, value, &SystemClock)
+}
+
+pub fn validate_with_clock(schema: &Schema, value: &Value, clock: &dyn Clock) -> Result<(), SchemaErr> {
+    validate_schema(schema, value, value, clock)
+}
+
+#[cfg(feature = "async")]
+pub async fn validate_async(schema: &Schema, value: &Value) -> Result<(), SchemaErr> {
+    validate_async_with_clock(schema, value, &SystemClock).await
+}
+
+pub fn parse(schema: &Schema, value: Value) -> Result<Value, SchemaErr> {
+    parse_with_clock(schema, value, &SystemClock)
+}
+
+pub fn parse_with_clock(schema: &Schema, value: Value, clock: &dyn Clock) -> Result<Value, SchemaErr> {
+    let (value, result) = transform_with_clock(schema, value, clock);
+    result.map(|()| value)
+}
+
+pub fn transform(schema: &Schema, value: Value) -> (Value, Result<(), SchemaErr>) {
+    transform_with_clock(schema, value, &SystemClock)
+}
+
+pub fn transform_with_clock(schema: &Schema, value: Value, clock: &dyn Clock) -> (Value, Result<(), SchemaErr>) {
+    let root = value.clone();
+    let value = transform_value(schema, value, &root, clock);
+    match validate_with_clock(schema, &value, clock) {
+        Ok(()) => {
+            let root = value.clone();
+            (strip_unknown_keys(schema, value, &root, clock), Ok(()))
+        }
+        Err(err) => (value, Err(err)),
+    }
+}
+
+fn validate_schema(schema: &Schema, value: &Value, root: &Value, clock: &dyn Clock) -> Result<(), SchemaErr> {
+    match schema {
+        Schema::U64(schema) => validate_u64(schema, value, root),
+        Schema::I64(schema) => validate_i64(schema, value, root),
+        Schema::F64(schema) => validate_f64(schema, value, root),
+        Schema::USize(schema) => validate_usize(schema, value, root),
+        Schema::ISize(schema) => validate_isize(schema, value, root),
+        Schema::Bool(schema) => validate_bool(schema, value, root),
+        Schema::Str(schema) => validate_str(schema, value, root),
+        Schema::Email(schema) => validate_email(schema, value),
+        Schema::Date(schema) => validate_date(schema, value, root, clock),
+        Schema::Time(schema) => validate_time(schema, value, root),
+        Schema::DateTime(schema) => validate_date_time(schema, value, root, clock),
+        Schema::Arr(schema) => validate_arr(schema, value, root, clock),
+        Schema::Obj(schema) => validate_obj(schema, value, root, clock),
+        Schema::Map(schema) => validate_map(schema, value, root, clock),
+        Schema::Enum(schema) => validate_enum(schema, value),
+        Schema::Union(schema) => validate_union(schema, value, root, clock),
+        Schema::Tagged(schema) => validate_tagged(schema, value, root, clock),
+        Schema::Custom(schema) => validate_custom(schema, value, root, clock),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, sync::LazyLock};
+
+    use crate::{
+        error::{SchemaErr, ValidationErr},
+        operation::{Operand, OperandValue, Operation, parse_date},
+        schema::{BoolSchema, DateSchema, EmailSchema, EnumSchema, ObjSchema, Schema, StrSchema, StrTransform, U64Schema, UnknownKeys},
+        value::Value,
+    };
+
+    use super::{parse, transform, validate};
+
+    static USER_SCHEMA: LazyLock<Schema> = LazyLock::new(|| {
+        Schema::from(ObjSchema::from([
+            ("name".into(), Schema::from(StrSchema::default().chars_len_btwn(1, 64))),
+            ("email".into(), Schema::from(EmailSchema::default())),
+            ("birthdate".into(), Schema::from(DateSchema::default().unix_epoch())),
+            ("role".into(), Schema::from(EnumSchema::from(["ADMIN", "USER"]))),
+            ("active".into(), Schema::from(BoolSchema::default().optional())),
+            (
+                "score".into(),
+                Schema::from(ObjSchema::from([
+                    ("min".into(), Schema::from(U64Schema::default())),
+                    ("max".into(), Schema::from(U64Schema::default().gt_field("score.min".into()))),
+                ])),
+            ),
+        ]))
+    });
+
+    #[test]
+    fn validate_ok() {
+        let value = Value::from([
+            ("name".into(), Value::from("Paul McCartney")),
+            ("email".into(), Value::from("paul@gmail.com")),
+            ("birthdate".into(), Value::from("2000-08-22")),
+            ("role".into(), Value::from("USER")),
+            ("score".into(), Value::from([("min".into(), Value::U64(10)), ("max".into(), Value::U64(20))])),
+        ]);
+        assert_eq!(validate(&USER_SCHEMA, &value), Ok(()));
+    }
+
+    #[test]
+    fn validate_err() {
+        let value = Value::from([
+            ("name".into(), Value::from("")),
+            ("email".into(), Value::from("paul")),
+            ("birthdate".into(), Value::from("1942-13-18")),
+            ("role".into(), Value::from("OWNER")),
+            ("active".into(), Value::U64(1)),
+            ("score".into(), Value::from([("min".into(), Value::U64(20)), ("max".into(), Value::U64(10))])),
+        ]);
+        assert_eq!(
+            validate(&USER_SCHEMA, &value),
+            Err(SchemaErr::Obj(BTreeMap::from([
+                (
+                    "name".into(),
+                    SchemaErr::from([ValidationErr::CharsLen(Operation::Btwn(
+                        Operand::Value(OperandValue::USize(1)),
+                        Operand::Value(OperandValue::USize(64))
+                    ))])
+                ),
+                ("email".into(), SchemaErr::from([ValidationErr::Email])),
+                (
+                    "birthdate".into(),
+                    SchemaErr::from([
+                        ValidationErr::Date,
+                        ValidationErr::Operation(Operation::Ge(Operand::Value(OperandValue::Date(parse_date("1970-01-01").unwrap()))))
+                    ])
+                ),
+                ("role".into(), SchemaErr::from([ValidationErr::Enumerated(["ADMIN", "USER"].into())])),
+                ("active".into(), SchemaErr::from([ValidationErr::Bool])),
+                (
+                    "score".into(),
+                    SchemaErr::from([(
+                        "max".into(),
+                        SchemaErr::from([ValidationErr::Operation(Operation::Gt(Operand::FieldPath("score.min".into())))])
+                    )])
+                ),
+            ])))
+        );
+    }
+
+    #[test]
+    fn validate_none() {
+        assert_eq!(validate(&USER_SCHEMA, &Value::None), Err(SchemaErr::from([ValidationErr::Required, ValidationErr::Obj])));
+        assert_eq!(validate(&Schema::from(StrSchema::default().optional()), &Value::None), Ok(()));
+    }
+
+    #[test]
+    fn parse_unknown_keys() {
+        let schema = Schema::from(ObjSchema::from([("name".into(), Schema::from(StrSchema::default()))]));
+        let value = Value::from([("name".into(), Value::from("Paul McCartney")), ("admin".into(), Value::Bool(true))]);
+        assert_eq!(parse(&schema, value.clone()), Ok(Value::from([("name".into(), Value::from("Paul McCartney"))])));
+        assert_eq!(
+            parse(
+                &Schema::from(ObjSchema::from([("name".into(), Schema::from(StrSchema::default()))]).unknown_keys(UnknownKeys::Reject)),
+                value.clone()
+            ),
+            Err(SchemaErr::from([("admin".into(), SchemaErr::from([ValidationErr::UnknownKey]))]))
+        );
+        assert_eq!(
+            parse(
+                &Schema::from(ObjSchema::from([("name".into(), Schema::from(StrSchema::default()))]).unknown_keys(UnknownKeys::Passthrough)),
+                value.clone()
+            ),
+            Ok(value)
+        );
+        assert_eq!(parse(&schema, Value::None), Err(SchemaErr::from([ValidationErr::Required, ValidationErr::Obj])));
+    }
+
+    #[test]
+    fn transform_query() {
+        let schema = Schema::from(ObjSchema::from([
+            ("q".into(), Schema::from(StrSchema::default().transform(StrTransform::CollapseWhitespace).chars_len_ge(1))),
+            ("email".into(), Schema::from(EmailSchema::default())),
+            ("page".into(), Schema::from(U64Schema::default().coerce().ge(1))),
+        ]));
+        let value = Value::from([
+            ("q".into(), Value::from("  rust   wasm ")),
+            ("email".into(), Value::from("paul@gmail.com")),
+            ("page".into(), Value::from("2")),
+            ("debug".into(), Value::from("1")),
+        ]);
+        let parsed =
+            Value::from([("q".into(), Value::from("rust wasm")), ("email".into(), Value::from("paul@gmail.com")), ("page".into(), Value::U64(2))]);
+        assert_eq!(transform(&schema, value.clone()), (parsed.clone(), Ok(())));
+        assert_eq!(parse(&schema, value), Ok(parsed));
+    }
+
+    #[test]
+    fn transform_err() {
+        let schema = Schema::from(ObjSchema::from([
+            ("q".into(), Schema::from(StrSchema::default().transform(StrTransform::Trim).chars_len_ge(1))),
+            ("page".into(), Schema::from(U64Schema::default().coerce().ge(1))),
+        ]));
+        let value = Value::from([("q".into(), Value::from("   ")), ("page".into(), Value::from("0")), ("debug".into(), Value::from("1"))]);
+        let err = SchemaErr::from([
+            ("page".into(), SchemaErr::from([ValidationErr::Operation(Operation::Ge(Operand::Value(OperandValue::U64(1))))])),
+            ("q".into(), SchemaErr::from([ValidationErr::CharsLen(Operation::Ge(Operand::Value(OperandValue::USize(1))))])),
+        ]);
+        assert_eq!(
+            transform(&schema, value.clone()),
+            (Value::from([("q".into(), Value::from("")), ("page".into(), Value::U64(0)), ("debug".into(), Value::from("1"))]), Err(err.clone()))
+        );
+        assert_eq!(parse(&schema, value), Err(err));
+        assert_eq!(
+            transform(&Schema::from(U64Schema::default().coerce()), Value::from("two")),
+            (Value::from("two"), Err(SchemaErr::from([ValidationErr::U64])))
+        );
+    }
+
+    #[test]
+    fn parse_default() {
+        let schema = Schema::from(ObjSchema::from([
+            ("workers".into(), Schema::from(U64Schema::default().optional().default_value(4).ge(1))),
+            ("log_level".into(), Schema::from(EnumSchema::from(["debug", "info", "warn"]).optional().default_value("info"))),
+            ("verbose".into(), Schema::from(BoolSchema::default().optional().default_value(false))),
+        ]));
+        assert_eq!(
+            parse(&schema, Value::from([("verbose".into(), Value::Bool(true))])),
+            Ok(Value::from([("log_level".into(), Value::from("info")), ("verbose".into(), Value::Bool(true)), ("workers".into(), Value::U64(4))]))
+        );
+        assert_eq!(
+            parse(&schema, Value::from([("workers".into(), Value::U64(0))])),
+            Err(SchemaErr::from([(
+                "workers".into(),
+                SchemaErr::from([ValidationErr::Operation(Operation::Ge(Operand::Value(OperandValue::U64(1))))])
+            )]))
+        );
+        assert_eq!(validate(&schema, &Value::Obj(BTreeMap::new())), Ok(()));
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    operation::Clock,
+    schema::{ObjSchema, Schema, UnknownKeys},
+    value::Value,
+};
+
+use super::validate_schema;
+
+fn strip_obj(schema: &ObjSchema, obj: BTreeMap<String, Value>, tag: Option<&str>, root: &Value, clock: &dyn Clock) -> Value {
+    Value::Obj(
+        obj.into_iter()
+            .filter_map(|(key, item)| match schema.validation.get(&key) {
+                Some(item_schema) => Some((key, strip_unknown_keys(item_schema, item, root, clock))),
+                None if schema.unknown_keys == UnknownKeys::Passthrough || tag == Some(key.as_str()) => Some((key, item)),
+                None => None,
+            })
+            .collect(),
+    )
+}
+
+pub fn strip_unknown_keys(schema: &Schema, value: Value, root: &Value, clock: &dyn Clock) -> Value {
+    match (schema, value) {
+        (Schema::Obj(schema), Value::Obj(obj)) => strip_obj(schema, obj, None, root, clock),
+        (Schema::Map(schema), Value::Obj(obj)) => {
+            Value::Obj(obj.into_iter().map(|(key, item)| (key, strip_unknown_keys(&schema.value, item, root, clock))).collect())
+        }
+        (Schema::Arr(schema), Value::Arr(arr)) => {
+            Value::Arr(arr.into_iter().map(|item| strip_unknown_keys(&schema.item, item, root, clock)).collect())
+        }
+        (Schema::Union(schema), value) => match schema.variants.iter().find(|variant| validate_schema(variant, &value, root, clock).is_ok()) {
+            Some(variant) => strip_unknown_keys(variant, value, root, clock),
+            None => value,
+        },
+        (Schema::Tagged(schema), Value::Obj(obj)) => {
+            let variant = match obj.get(&schema.tag) {
+                Some(Value::Str(tag_value)) => schema.variants.get(tag_value),
+                _ => None,
+            };
+            match variant {
+                Some(variant) => strip_obj(variant, obj, Some(&schema.tag), root, clock),
+                None => Value::Obj(obj),
+            }
+        }
+        (Schema::Custom(schema), value) => strip_unknown_keys(&schema.schema, value, root, clock),
+        (_, value) => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        operation::SystemClock,
+        schema::{ArrSchema, BoolSchema, MapSchema, ObjSchema, Schema, StrSchema, TaggedSchema, U64Schema, UnionSchema, UnknownKeys},
+        value::Value,
+    };
+
+    use super::strip_unknown_keys;
+
+    #[test]
+    fn strip_unknown_keys_obj() {
+        let schema = ObjSchema::from([("id".into(), Schema::from(U64Schema::default()))]);
+        let value = Value::from([("id".into(), Value::U64(1)), ("admin".into(), Value::Bool(true))]);
+        assert_eq!(
+            strip_unknown_keys(&Schema::from(schema.clone()), value.clone(), &Value::None, &SystemClock),
+            Value::from([("id".into(), Value::U64(1))])
+        );
+        assert_eq!(
+            strip_unknown_keys(&Schema::from(schema.clone().unknown_keys(UnknownKeys::Reject)), value.clone(), &Value::None, &SystemClock),
+            Value::from([("id".into(), Value::U64(1))])
+        );
+        assert_eq!(
+            strip_unknown_keys(&Schema::from(schema.unknown_keys(UnknownKeys::Passthrough)), value.clone(), &Value::None, &SystemClock),
+            value
+        );
+    }
+
+    #[test]
+    fn strip_unknown_keys_nested() {
+        let schema = Schema::from(ArrSchema::from(Schema::from(ObjSchema::from([(
+            "details".into(),
+            Schema::from(ObjSchema::from([("alive".into(), Schema::from(BoolSchema::default()))])),
+        )]))));
+        let value = Value::Arr(vec![
+            Value::from([("details".into(), Value::from([("alive".into(), Value::Bool(true)), ("admin".into(), Value::Bool(true))]))]),
+            Value::U64(1),
+        ]);
+        assert_eq!(
+            strip_unknown_keys(&schema, value, &Value::None, &SystemClock),
+            Value::Arr(vec![Value::from([("details".into(), Value::from([("alive".into(), Value::Bool(true))]))]), Value::U64(1)])
+        );
+    }
+
+    #[test]
+    fn strip_unknown_keys_map() {
+        let schema = Schema::from(MapSchema::from(Schema::from(ObjSchema::from([("alive".into(), Schema::from(BoolSchema::default()))]))));
+        let value = Value::from([("paul".into(), Value::from([("alive".into(), Value::Bool(true)), ("admin".into(), Value::Bool(true))]))]);
+        assert_eq!(
+            strip_unknown_keys(&schema, value, &Value::None, &SystemClock),
+            Value::from([("paul".into(), Value::from([("alive".into(), Value::Bool(true))]))])
+        );
+    }
+
+    #[test]
+    fn strip_unknown_keys_union() {
+        let schema = Schema::from(UnionSchema::from([
+            Schema::from(ObjSchema::from([("id".into(), Schema::from(StrSchema::default()))])),
+            Schema::from(ObjSchema::from([("id".into(), Schema::from(U64Schema::default()))]).unknown_keys(UnknownKeys::Passthrough)),
+        ]));
+        let value_str = Value::from([("id".into(), Value::from("a")), ("admin".into(), Value::Bool(true))]);
+        let value_u64 = Value::from([("id".into(), Value::U64(1)), ("admin".into(), Value::Bool(true))]);
+        assert_eq!(strip_unknown_keys(&schema, value_str, &Value::None, &SystemClock), Value::from([("id".into(), Value::from("a"))]));
+        assert_eq!(strip_unknown_keys(&schema, value_u64.clone(), &Value::None, &SystemClock), value_u64);
+    }
+
+    #[test]
+    fn strip_unknown_keys_tagged() {
+        let schema = Schema::from(
+            TaggedSchema::from("type".to_string()).variant("key".into(), ObjSchema::from([("code".into(), Schema::from(StrSchema::default()))])),
+        );
+        let value = Value::from([("type".into(), Value::from("key")), ("code".into(), Value::from("Enter")), ("admin".into(), Value::Bool(true))]);
+        assert_eq!(
+            strip_unknown_keys(&schema, value, &Value::None, &SystemClock),
+            Value::from([("type".into(), Value::from("key")), ("code".into(), Value::from("Enter"))])
+        );
+    }
+}
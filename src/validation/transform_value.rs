@@ -0,0 +1,232 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    operation::Clock,
+    schema::{ObjSchema, Schema, StrSchema, U64Schema},
+    value::Value,
+};
+
+use super::validate_schema;
+
+fn transform_str(schema: &StrSchema, value: Value) -> Value {
+    match value {
+        Value::Str(str_value) => Value::Str(schema.transforms.iter().fold(str_value, |str_value, transform| transform.apply(&str_value))),
+        value => value,
+    }
+}
+
+fn transform_u64(schema: &U64Schema, value: Value) -> Value {
+    match value {
+        Value::Str(str_value) if schema.coerce => match str_value.parse::<u64>() {
+            Ok(u64_value) => Value::U64(u64_value),
+            Err(_) => Value::Str(str_value),
+        },
+        value => value,
+    }
+}
+
+fn transform_obj(schema: &ObjSchema, mut obj: BTreeMap<String, Value>, root: &Value, clock: &dyn Clock) -> Value {
+    for (key, item_schema) in &schema.validation {
+        if !obj.contains_key(key)
+            && let Some(default) = item_schema.get_default()
+        {
+            obj.insert(key.clone(), default);
+        }
+    }
+    Value::Obj(
+        obj.into_iter()
+            .map(|(key, item)| match schema.validation.get(&key) {
+                Some(item_schema) => (key, transform_value(item_schema, item, root, clock)),
+                None => (key, item),
+            })
+            .collect(),
+    )
+}
+
+pub fn transform_value(schema: &Schema, value: Value, root: &Value, clock: &dyn Clock) -> Value {
+    let value = match (value, schema.get_default()) {
+        (Value::None, Some(default)) => default,
+        (value, _) => value,
+    };
+    match (schema, value) {
+        (Schema::Str(schema), value) => transform_str(schema, value),
+        (Schema::U64(schema), value) => transform_u64(schema, value),
+        (Schema::Obj(schema), Value::Obj(obj)) => transform_obj(schema, obj, root, clock),
+        (Schema::Map(schema), Value::Obj(obj)) => {
+            Value::Obj(obj.into_iter().map(|(key, item)| (key, transform_value(&schema.value, item, root, clock))).collect())
+        }
+        (Schema::Arr(schema), Value::Arr(arr)) => Value::Arr(arr.into_iter().map(|item| transform_value(&schema.item, item, root, clock)).collect()),
+        (Schema::Union(schema), value) => {
+            let transformed = schema.variants.iter().find_map(|variant| {
+                let candidate = transform_value(variant, value.clone(), root, clock);
+                validate_schema(variant, &candidate, root, clock).is_ok().then_some(candidate)
+            });
+            transformed.unwrap_or(value)
+        }
+        (Schema::Tagged(schema), Value::Obj(obj)) => {
+            let variant = match obj.get(&schema.tag) {
+                Some(Value::Str(tag_value)) => schema.variants.get(tag_value),
+                _ => None,
+            };
+            match variant {
+                Some(variant) => transform_obj(variant, obj, root, clock),
+                None => Value::Obj(obj),
+            }
+        }
+        (Schema::Custom(schema), value) => transform_value(&schema.schema, value, root, clock),
+        (_, value) => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        operation::SystemClock,
+        schema::{ArrSchema, BoolSchema, EnumSchema, MapSchema, ObjSchema, Schema, StrSchema, StrTransform, TaggedSchema, U64Schema, UnionSchema},
+        value::Value,
+    };
+
+    use super::transform_value;
+
+    #[test]
+    fn transform_value_str() {
+        let schema = Schema::from(StrSchema::default().transform(StrTransform::Trim).transform(StrTransform::Lowercase));
+        assert_eq!(transform_value(&schema, Value::from("  Paul@Gmail.com "), &Value::None, &SystemClock), Value::from("paul@gmail.com"));
+        let schema = Schema::from(StrSchema::default().transform(StrTransform::CollapseWhitespace));
+        assert_eq!(transform_value(&schema, Value::from(" Paul \t  McCartney\n"), &Value::None, &SystemClock), Value::from("Paul McCartney"));
+        assert_eq!(transform_value(&schema, Value::U64(42), &Value::None, &SystemClock), Value::U64(42));
+        assert_eq!(transform_value(&Schema::from(StrSchema::default()), Value::from(" Paul "), &Value::None, &SystemClock), Value::from(" Paul "));
+    }
+
+    #[test]
+    fn transform_value_u64() {
+        let schema = Schema::from(U64Schema::default().coerce());
+        assert_eq!(transform_value(&schema, Value::from("42"), &Value::None, &SystemClock), Value::U64(42));
+        assert_eq!(transform_value(&schema, Value::U64(42), &Value::None, &SystemClock), Value::U64(42));
+        assert_eq!(transform_value(&schema, Value::from("-42"), &Value::None, &SystemClock), Value::from("-42"));
+        assert_eq!(transform_value(&schema, Value::from("4.2"), &Value::None, &SystemClock), Value::from("4.2"));
+        assert_eq!(transform_value(&schema, Value::from(""), &Value::None, &SystemClock), Value::from(""));
+        assert_eq!(transform_value(&Schema::from(U64Schema::default()), Value::from("42"), &Value::None, &SystemClock), Value::from("42"));
+    }
+
+    #[test]
+    fn transform_value_containers() {
+        let tag = Schema::from(StrSchema::default().transform(StrTransform::Trim).transform(StrTransform::Lowercase));
+        let page = Schema::from(U64Schema::default().coerce());
+        let obj = Schema::from(ObjSchema::from([("tags".into(), Schema::from(ArrSchema::from(tag.clone()))), ("page".into(), page.clone())]));
+        assert_eq!(
+            transform_value(
+                &obj,
+                Value::from([
+                    ("tags".into(), Value::from([Value::from(" Rust"), Value::from("WASM ")])),
+                    ("page".into(), Value::from("2")),
+                    ("sort".into(), Value::from(" ASC ")),
+                ]),
+                &Value::None,
+                &SystemClock
+            ),
+            Value::from([
+                ("tags".into(), Value::from([Value::from("rust"), Value::from("wasm")])),
+                ("page".into(), Value::U64(2)),
+                ("sort".into(), Value::from(" ASC ")),
+            ])
+        );
+        assert_eq!(
+            transform_value(
+                &Schema::from(MapSchema::from(page.clone())),
+                Value::from([("a".into(), Value::from("1")), ("b".into(), Value::from("x"))]),
+                &Value::None,
+                &SystemClock
+            ),
+            Value::from([("a".into(), Value::U64(1)), ("b".into(), Value::from("x"))])
+        );
+        assert_eq!(transform_value(&page.custom("even", |_, _| Ok(())), Value::from("8"), &Value::None, &SystemClock), Value::U64(8));
+    }
+
+    #[test]
+    fn transform_value_union() {
+        let schema = Schema::from(UnionSchema::from([
+            Schema::from(U64Schema::default().coerce()),
+            Schema::from(StrSchema::default().transform(StrTransform::Lowercase)),
+        ]));
+        assert_eq!(transform_value(&schema, Value::from("7"), &Value::None, &SystemClock), Value::U64(7));
+        assert_eq!(transform_value(&schema, Value::from("ALL"), &Value::None, &SystemClock), Value::from("all"));
+        assert_eq!(transform_value(&schema, Value::Bool(true), &Value::None, &SystemClock), Value::Bool(true));
+    }
+
+    #[test]
+    fn transform_value_tagged() {
+        let schema = Schema::from(TaggedSchema::from("kind".to_string()).variant(
+            "search".into(),
+            ObjSchema::from([
+                ("kind".into(), Schema::from(StrSchema::default())),
+                ("query".into(), Schema::from(StrSchema::default().transform(StrTransform::Trim))),
+            ]),
+        ));
+        assert_eq!(
+            transform_value(
+                &schema,
+                Value::from([("kind".into(), Value::from("search")), ("query".into(), Value::from(" rust "))]),
+                &Value::None,
+                &SystemClock
+            ),
+            Value::from([("kind".into(), Value::from("search")), ("query".into(), Value::from("rust"))])
+        );
+        assert_eq!(
+            transform_value(
+                &schema,
+                Value::from([("kind".into(), Value::from("other")), ("query".into(), Value::from(" rust "))]),
+                &Value::None,
+                &SystemClock
+            ),
+            Value::from([("kind".into(), Value::from("other")), ("query".into(), Value::from(" rust "))])
+        );
+    }
+
+    #[test]
+    fn transform_value_default() {
+        let schema = Schema::from(ObjSchema::from([
+            ("port".into(), Schema::from(U64Schema::default().optional().default_value(8080))),
+            ("debug".into(), Schema::from(BoolSchema::default().optional().default_value(false))),
+            ("host".into(), Schema::from(StrSchema::default().optional().default_value("localhost"))),
+            ("level".into(), Schema::from(EnumSchema::from(["debug", "info"]).optional().default_value("info"))),
+            ("name".into(), Schema::from(StrSchema::default().optional())),
+        ]));
+        assert_eq!(
+            transform_value(&schema, Value::from([("port".into(), Value::U64(3000)), ("debug".into(), Value::None)]), &Value::None, &SystemClock),
+            Value::from([
+                ("port".into(), Value::U64(3000)),
+                ("debug".into(), Value::Bool(false)),
+                ("host".into(), Value::from("localhost")),
+                ("level".into(), Value::from("info")),
+            ])
+        );
+        assert_eq!(transform_value(&Schema::from(U64Schema::default().default_value(1)), Value::None, &Value::None, &SystemClock), Value::U64(1));
+        assert_eq!(transform_value(&Schema::from(U64Schema::default()), Value::None, &Value::None, &SystemClock), Value::None);
+    }
+
+    #[test]
+    fn transform_value_default_nested() {
+        let server = ObjSchema::from([
+            ("port".into(), Schema::from(U64Schema::default().optional().default_value(8080))),
+            ("host".into(), Schema::from(StrSchema::default().optional().default_value("localhost"))),
+        ]);
+        let schema = Schema::from(ObjSchema::from([
+            ("server".into(), Schema::from(server.clone().optional().default_value(BTreeMap::new()))),
+            ("admin".into(), Schema::from(server.optional())),
+        ]));
+        assert_eq!(
+            transform_value(&schema, Value::Obj(BTreeMap::new()), &Value::None, &SystemClock),
+            Value::from([("server".into(), Value::from([("host".into(), Value::from("localhost")), ("port".into(), Value::U64(8080))]))])
+        );
+        assert_eq!(
+            transform_value(&schema, Value::from([("admin".into(), Value::from([("port".into(), Value::U64(9090))]))]), &Value::None, &SystemClock),
+            Value::from([
+                ("admin".into(), Value::from([("host".into(), Value::from("localhost")), ("port".into(), Value::U64(9090))])),
+                ("server".into(), Value::from([("host".into(), Value::from("localhost")), ("port".into(), Value::U64(8080))])),
+            ])
+        );
+    }
+}
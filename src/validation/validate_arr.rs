@@ -0,0 +1,167 @@
+use crate::{
+    error::{SchemaErr, ValidationErr},
+    operation::{Clock, OperandValue, Operation, compare_with_clock},
+    schema::ArrSchema,
+    value::Value,
+};
+
+use super::validate_schema;
+
+pub fn len_errs(len: &[Operation], value_len: usize, root: &Value, clock: &dyn Clock) -> Vec<ValidationErr> {
+    len.iter()
+        .filter(|len| matches!(compare_with_clock(len, &OperandValue::USize(value_len), root, clock), Some(Err(()))))
+        .map(|len| ValidationErr::Len(len.clone()))
+        .collect()
+}
+
+pub fn collection_err(errors: Vec<ValidationErr>, items: Option<SchemaErr>) -> Result<(), SchemaErr> {
+    match (errors.is_empty(), items) {
+        (true, None) => Ok(()),
+        (false, None) => Err(SchemaErr::Validation(errors)),
+        (true, Some(items)) => Err(items),
+        (false, Some(items)) => Err(SchemaErr::Collection { errors, items: Box::new(items) }),
+    }
+}
+
+pub fn arr_items_err(result: Vec<Result<(), SchemaErr>>) -> Option<SchemaErr> {
+    if result.iter().any(|item| item.is_err()) {
+        Some(SchemaErr::Arr(result.into_iter().map(|item| item.err().unwrap_or(SchemaErr::Validation(vec![]))).collect()))
+    } else {
+        None
+    }
+}
+
+pub fn validate_arr(schema: &ArrSchema, value: &Value, root: &Value, clock: &dyn Clock) -> Result<(), SchemaErr> {
+    let mut base = vec![];
+    match value {
+        Value::Arr(arr) => {
+            let result: Vec<Result<(), SchemaErr>> = arr.iter().map(|item| validate_schema(&schema.item, item, root, clock)).collect();
+            return collection_err(len_errs(&schema.len, arr.len(), root, clock), arr_items_err(result));
+        }
+        Value::None => {
+            if schema.required {
+                base.push(ValidationErr::Required);
+                base.push(ValidationErr::Arr);
+                for len in &schema.len {
+                    base.push(ValidationErr::Len(len.clone()));
+                }
+            }
+        }
+        _ => {
+            base.push(ValidationErr::Arr);
+            for len in &schema.len {
+                base.push(ValidationErr::Len(len.clone()));
+            }
+        }
+    }
+    if base.is_empty() { Ok(()) } else { Err(SchemaErr::Validation(base)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        error::{FlatErr, SchemaErr, ValidationErr},
+        operation::{Operand, OperandValue, Operation, SystemClock},
+        schema::{ArrSchema, ObjSchema, Schema, StrSchema, U64Schema},
+        value::Value,
+    };
+
+    use super::validate_arr;
+
+    const REQUIRED: ValidationErr = ValidationErr::Required;
+    const ARR: ValidationErr = ValidationErr::Arr;
+    const U64: ValidationErr = ValidationErr::U64;
+    const LEN_BTWN: ValidationErr =
+        ValidationErr::Len(Operation::Btwn(Operand::Value(OperandValue::USize(1)), Operand::Value(OperandValue::USize(3))));
+    const OPERATION_GT: ValidationErr = ValidationErr::Operation(Operation::Gt(Operand::Value(OperandValue::U64(10))));
+
+    #[test]
+    fn validate_arr_default() {
+        let schema = ArrSchema::from(Schema::from(U64Schema::default()));
+        assert_eq!(validate_arr(&schema, &Value::from([1_u64, 2, 3]), &Value::None, &SystemClock), Ok(()));
+        assert_eq!(validate_arr(&schema, &Value::Arr(vec![]), &Value::None, &SystemClock), Ok(()));
+        assert_eq!(validate_arr(&schema, &Value::None, &Value::None, &SystemClock), Err(SchemaErr::from([REQUIRED, ARR])));
+        assert_eq!(validate_arr(&schema, &Value::U64(1), &Value::None, &SystemClock), Err(SchemaErr::from([ARR])));
+        assert_eq!(validate_arr(&schema, &Value::from([("id".into(), Value::U64(1))]), &Value::None, &SystemClock), Err(SchemaErr::from([ARR])));
+    }
+
+    #[test]
+    fn validate_arr_optional() {
+        let schema = ArrSchema::from(Schema::from(U64Schema::default())).optional();
+        assert_eq!(validate_arr(&schema, &Value::from([1_u64, 2, 3]), &Value::None, &SystemClock), Ok(()));
+        assert_eq!(validate_arr(&schema, &Value::None, &Value::None, &SystemClock), Ok(()));
+        assert_eq!(validate_arr(&schema, &Value::U64(1), &Value::None, &SystemClock), Err(SchemaErr::from([ARR])));
+    }
+
+    #[test]
+    fn validate_arr_item() {
+        let schema = ArrSchema::from(Schema::from(U64Schema::default().gt(10)));
+        assert_eq!(validate_arr(&schema, &Value::from([11_u64, 12]), &Value::None, &SystemClock), Ok(()));
+        assert_eq!(
+            validate_arr(&schema, &Value::Arr(vec![Value::U64(11), Value::U64(10), Value::None]), &Value::None, &SystemClock),
+            Err(SchemaErr::Arr(vec![SchemaErr::Validation(vec![]), SchemaErr::from([OPERATION_GT]), SchemaErr::from([REQUIRED, U64, OPERATION_GT])]))
+        );
+        let err = validate_arr(&schema, &Value::Arr(vec![Value::U64(11), Value::U64(10), Value::U64(12)]), &Value::None, &SystemClock).unwrap_err();
+        assert_eq!(err.flatten(), vec![FlatErr { pointer: "/1".into(), path: "1".into(), key: false, errors: vec![OPERATION_GT] }]);
+    }
+
+    #[test]
+    fn validate_arr_item_obj() {
+        let schema = ArrSchema::from(Schema::from(ObjSchema::from([("name".into(), Schema::from(StrSchema::default()))])));
+        let value = Value::Arr(vec![Value::from([("name".into(), Value::from("Paul"))]), Value::from([("name".into(), Value::U64(1))])]);
+        assert_eq!(
+            validate_arr(&schema, &value, &value, &SystemClock),
+            Err(SchemaErr::Arr(vec![
+                SchemaErr::Validation(vec![]),
+                SchemaErr::Obj(BTreeMap::from([("name".into(), SchemaErr::from([ValidationErr::Str]))]))
+            ]))
+        );
+    }
+
+    #[test]
+    fn validate_arr_len_value() {
+        let schema = ArrSchema::from(Schema::from(U64Schema::default().gt(10))).len_btwn(1, 3);
+        assert_eq!(validate_arr(&schema, &Value::from([11_u64, 12, 13]), &Value::None, &SystemClock), Ok(()));
+        assert_eq!(validate_arr(&schema, &Value::Arr(vec![]), &Value::None, &SystemClock), Err(SchemaErr::from([LEN_BTWN])));
+        assert_eq!(validate_arr(&schema, &Value::from([11_u64, 12, 13, 14]), &Value::None, &SystemClock), Err(SchemaErr::from([LEN_BTWN])));
+        assert_eq!(validate_arr(&schema, &Value::None, &Value::None, &SystemClock), Err(SchemaErr::from([REQUIRED, ARR, LEN_BTWN])));
+        assert_eq!(validate_arr(&schema, &Value::Bool(true), &Value::None, &SystemClock), Err(SchemaErr::from([ARR, LEN_BTWN])));
+    }
+
+    #[test]
+    fn validate_arr_len_and_items() {
+        let schema = ArrSchema::from(Schema::from(U64Schema::default().gt(10))).len_btwn(1, 3);
+        let err = validate_arr(&schema, &Value::from([11_u64, 5, 12, 13]), &Value::None, &SystemClock).unwrap_err();
+        assert_eq!(
+            err,
+            SchemaErr::Collection {
+                errors: vec![LEN_BTWN],
+                items: Box::new(SchemaErr::Arr(vec![
+                    SchemaErr::Validation(vec![]),
+                    SchemaErr::from([OPERATION_GT]),
+                    SchemaErr::Validation(vec![]),
+                    SchemaErr::Validation(vec![])
+                ]))
+            }
+        );
+        assert_eq!(
+            err.flatten(),
+            vec![
+                FlatErr { pointer: "".into(), path: "".into(), key: false, errors: vec![LEN_BTWN] },
+                FlatErr { pointer: "/1".into(), path: "1".into(), key: false, errors: vec![OPERATION_GT] },
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_arr_len_field() {
+        let schema = ArrSchema::from(Schema::from(StrSchema::default())).len_le_field("max".into());
+        let root = Value::from([("max".into(), Value::USize(2))]);
+        let len = ValidationErr::Len(Operation::Le(Operand::FieldPath("max".into())));
+        assert_eq!(validate_arr(&schema, &Value::from(["a", "b"]), &root, &SystemClock), Ok(()));
+        assert_eq!(validate_arr(&schema, &Value::from(["a", "b", "c"]), &root, &SystemClock), Err(SchemaErr::from([len])));
+        assert_eq!(validate_arr(&schema, &Value::from(["a", "b", "c"]), &Value::None, &SystemClock), Ok(()));
+    }
+}
@@ -0,0 +1,364 @@
+use std::collections::BTreeMap;
+
+use futures_util::future::{BoxFuture, join_all};
+
+use crate::{
+    error::{SchemaErr, ValidationErr},
+    operation::Clock,
+    schema::{ArrSchema, CustomSchema, MapSchema, ObjSchema, Schema, TaggedSchema, UnionSchema, UnknownKeys},
+    value::Value,
+};
+
+use super::{
+    validate_arr::{arr_items_err, collection_err, len_errs},
+    validate_map::map_entry_err,
+    validate_schema, validate_tagged,
+};
+
+fn has_async(schema: &Schema) -> bool {
+    match schema {
+        Schema::Arr(schema) => has_async(&schema.item),
+        Schema::Obj(schema) => schema.validation.values().any(has_async),
+        Schema::Map(schema) => has_async(&schema.key) || has_async(&schema.value),
+        Schema::Union(schema) => schema.variants.iter().any(has_async),
+        Schema::Tagged(schema) => schema.variants.values().any(|variant| variant.validation.values().any(has_async)),
+        Schema::Custom(schema) => !schema.async_validators.is_empty() || has_async(&schema.schema),
+        _ => false,
+    }
+}
+
+async fn validate_arr_async(schema: &ArrSchema, arr: &[Value], root: &Value, clock: &(dyn Clock + Sync)) -> Result<(), SchemaErr> {
+    let result = join_all(arr.iter().map(|item| validate_schema_async(&schema.item, item, root, clock))).await;
+    collection_err(len_errs(&schema.len, arr.len(), root, clock), arr_items_err(result))
+}
+
+async fn validate_obj_async(schema: &ObjSchema, obj: &BTreeMap<String, Value>, root: &Value, clock: &(dyn Clock + Sync)) -> Result<(), SchemaErr> {
+    let results = join_all(
+        schema.validation.iter().map(|(key, item_schema)| validate_schema_async(item_schema, obj.get(key).unwrap_or(&Value::None), root, clock)),
+    )
+    .await;
+    let mut result: BTreeMap<String, SchemaErr> = BTreeMap::new();
+    for (key, item_result) in schema.validation.keys().zip(results) {
+        if let Err(err) = item_result {
+            result.insert(key.clone(), err);
+        }
+    }
+    if schema.unknown_keys == UnknownKeys::Reject {
+        for key in obj.keys().filter(|key| !schema.validation.contains_key(*key)) {
+            result.insert(key.clone(), SchemaErr::from([ValidationErr::UnknownKey]));
+        }
+    }
+    if result.is_empty() { Ok(()) } else { Err(SchemaErr::Obj(result)) }
+}
+
+async fn validate_map_async(schema: &MapSchema, obj: &BTreeMap<String, Value>, root: &Value, clock: &(dyn Clock + Sync)) -> Result<(), SchemaErr> {
+    let results = join_all(obj.iter().map(|(key, item)| async move {
+        let key_value = Value::Str(key.clone());
+        let key_result = validate_schema_async(&schema.key, &key_value, root, clock).await;
+        map_entry_err(key_result, validate_schema_async(&schema.value, item, root, clock).await)
+    }))
+    .await;
+    let mut result: BTreeMap<String, SchemaErr> = BTreeMap::new();
+    for (key, item_result) in obj.keys().zip(results) {
+        if let Err(err) = item_result {
+            result.insert(key.clone(), err);
+        }
+    }
+    let items = if result.is_empty() { None } else { Some(SchemaErr::Obj(result)) };
+    collection_err(len_errs(&schema.len, obj.len(), root, clock), items)
+}
+
+async fn validate_union_async(schema: &UnionSchema, value: &Value, root: &Value, clock: &(dyn Clock + Sync)) -> Result<(), SchemaErr> {
+    let results = join_all(schema.variants.iter().map(|variant| validate_schema_async(variant, value, root, clock))).await;
+    let mut result: Vec<SchemaErr> = Vec::new();
+    for variant_result in results {
+        match variant_result {
+            Ok(()) => return Ok(()),
+            Err(err) => result.push(err),
+        }
+    }
+    Err(SchemaErr::Union(result))
+}
+
+async fn validate_tagged_async(
+    schema: &TaggedSchema,
+    obj: &BTreeMap<String, Value>,
+    value: &Value,
+    root: &Value,
+    clock: &(dyn Clock + Sync),
+) -> Result<(), SchemaErr> {
+    let variant = match obj.get(&schema.tag) {
+        Some(Value::Str(tag_value)) => schema.variants.get_key_value(tag_value),
+        _ => None,
+    };
+    let Some((tag_value, variant)) = variant else {
+        return validate_tagged(schema, value, root, clock);
+    };
+    let result = if variant.validation.contains_key(&schema.tag) {
+        validate_obj_async(variant, obj, root, clock).await
+    } else {
+        let mut untagged = obj.clone();
+        untagged.remove(&schema.tag);
+        validate_obj_async(variant, &untagged, root, clock).await
+    };
+    result.map_err(|err| SchemaErr::Tagged(tag_value.clone(), Box::new(err)))
+}
+
+async fn validate_custom_async(schema: &CustomSchema, value: &Value, root: &Value, clock: &(dyn Clock + Sync)) -> Result<(), SchemaErr> {
+    validate_schema_async(&schema.schema, value, root, clock).await?;
+    if let Value::None = value {
+        return Ok(());
+    }
+    let mut base = vec![];
+    for validator in &schema.validators {
+        if let Err(params) = (validator.validate)(value, root) {
+            base.push(ValidationErr::Custom { code: validator.code.clone(), params });
+        }
+    }
+    let results = join_all(schema.async_validators.iter().map(|validator| (validator.validate)(value.clone(), root.clone()))).await;
+    for (validator, result) in schema.async_validators.iter().zip(results) {
+        if let Err(params) = result {
+            base.push(ValidationErr::Custom { code: validator.code.clone(), params });
+        }
+    }
+    if base.is_empty() { Ok(()) } else { Err(SchemaErr::Validation(base)) }
+}
+
+fn validate_schema_async<'a>(
+    schema: &'a Schema,
+    value: &'a Value,
+    root: &'a Value,
+    clock: &'a (dyn Clock + Sync),
+) -> BoxFuture<'a, Result<(), SchemaErr>> {
+    Box::pin(async move {
+        if !has_async(schema) {
+            return validate_schema(schema, value, root, clock);
+        }
+        match (schema, value) {
+            (Schema::Arr(schema), Value::Arr(arr)) => validate_arr_async(schema, arr, root, clock).await,
+            (Schema::Obj(schema), Value::Obj(obj)) => validate_obj_async(schema, obj, root, clock).await,
+            (Schema::Map(schema), Value::Obj(obj)) => validate_map_async(schema, obj, root, clock).await,
+            (Schema::Union(schema), value) if *value != Value::None => validate_union_async(schema, value, root, clock).await,
+            (Schema::Tagged(schema), Value::Obj(obj)) => validate_tagged_async(schema, obj, value, root, clock).await,
+            (Schema::Custom(schema), value) => validate_custom_async(schema, value, root, clock).await,
+            (schema, value) => validate_schema(schema, value, root, clock),
+        }
+    })
+}
+
+pub async fn validate_async_with_clock(schema: &Schema, value: &Value, clock: &(dyn Clock + Sync)) -> Result<(), SchemaErr> {
+    validate_schema_async(schema, value, value, clock).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::BTreeMap,
+        future::Future,
+        pin::Pin,
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        task::{Context, Poll},
+    };
+
+    use futures_executor::block_on;
+
+    use crate::{
+        error::{SchemaErr, ValidationErr},
+        operation::{OperandValue, SystemClock},
+        schema::{ArrSchema, EmailSchema, EnumSchema, MapSchema, ObjSchema, Schema, StrSchema, TaggedSchema, U64Schema, UnionSchema, UnknownKeys},
+        validation::validate,
+        value::Value,
+    };
+
+    use super::validate_async_with_clock;
+
+    struct UserRepository {
+        emails: Vec<String>,
+        ids: Vec<u64>,
+    }
+
+    impl UserRepository {
+        async fn email_exists(&self, email: &str) -> bool {
+            self.emails.iter().any(|item| item == email)
+        }
+
+        async fn id_exists(&self, id: u64) -> bool {
+            self.ids.contains(&id)
+        }
+    }
+
+    fn repository() -> Arc<UserRepository> {
+        Arc::new(UserRepository { emails: vec!["paul@gmail.com".into()], ids: vec![1, 2] })
+    }
+
+    fn user_schema(repository: Arc<UserRepository>) -> Schema {
+        let emails = repository.clone();
+        let ids = repository;
+        Schema::from(ObjSchema::from([
+            (
+                "email".into(),
+                Schema::from(EmailSchema::default()).custom_async("email_unique", move |value, _| {
+                    let emails = emails.clone();
+                    async move {
+                        match value {
+                            Value::Str(email) if emails.email_exists(&email).await => {
+                                Err(BTreeMap::from([("email".into(), OperandValue::from(email.as_str()))]))
+                            }
+                            _ => Ok(()),
+                        }
+                    }
+                }),
+            ),
+            (
+                "manager_id".into(),
+                Schema::from(U64Schema::default().optional()).custom_async("user_exists", move |value, _| {
+                    let ids = ids.clone();
+                    async move {
+                        match value {
+                            Value::U64(id) if !ids.id_exists(id).await => Err(BTreeMap::new()),
+                            _ => Ok(()),
+                        }
+                    }
+                }),
+            ),
+            ("name".into(), Schema::from(StrSchema::default().chars_len_ge(1))),
+        ]))
+    }
+
+    fn assert_send<T: Send>(_: &T) {}
+
+    #[test]
+    fn validate_async_send() {
+        let schema = user_schema(repository());
+        let value = Value::None;
+        let future = validate_async_with_clock(&schema, &value, &SystemClock);
+        assert_send(&future);
+        assert!(block_on(future).is_err());
+    }
+
+    #[test]
+    fn validate_async_ok() {
+        let value = Value::from([
+            ("email".into(), Value::from("john@gmail.com")),
+            ("manager_id".into(), Value::U64(1)),
+            ("name".into(), Value::from("John")),
+        ]);
+        assert_eq!(block_on(validate_async_with_clock(&user_schema(repository()), &value, &SystemClock)), Ok(()));
+        let value = Value::from([("email".into(), Value::from("john@gmail.com")), ("name".into(), Value::from("John"))]);
+        assert_eq!(block_on(validate_async_with_clock(&user_schema(repository()), &value, &SystemClock)), Ok(()));
+    }
+
+    #[test]
+    fn validate_async_err() {
+        let value =
+            Value::from([("email".into(), Value::from("paul@gmail.com")), ("manager_id".into(), Value::U64(7)), ("name".into(), Value::from(""))]);
+        assert_eq!(
+            block_on(validate_async_with_clock(&user_schema(repository()), &value, &SystemClock)),
+            Err(SchemaErr::from([
+                (
+                    "email".into(),
+                    SchemaErr::from([ValidationErr::Custom {
+                        code: "email_unique".into(),
+                        params: BTreeMap::from([("email".into(), OperandValue::from("paul@gmail.com"))])
+                    }])
+                ),
+                ("manager_id".into(), SchemaErr::from([ValidationErr::Custom { code: "user_exists".into(), params: BTreeMap::new() }])),
+                ("name".into(), validate(&Schema::from(StrSchema::default().chars_len_ge(1)), &Value::from("")).unwrap_err()),
+            ]))
+        );
+    }
+
+    #[test]
+    fn validate_async_same_as_sync() {
+        let schemas = |leaf: fn(Schema) -> Schema| {
+            vec![
+                Schema::from(ArrSchema::from(leaf(Schema::from(U64Schema::default().ge(2)))).len_le(2)),
+                Schema::from(MapSchema::from(leaf(Schema::from(U64Schema::default().ge(2))))),
+                Schema::from(MapSchema::from(leaf(Schema::from(U64Schema::default().ge(2)))).key(Schema::from(EnumSchema::from(["a", "c"])))),
+                Schema::from(UnionSchema::from([leaf(Schema::from(U64Schema::default())), leaf(Schema::from(StrSchema::default()))])),
+                Schema::from(
+                    TaggedSchema::from("type".to_string())
+                        .variant("key".into(), ObjSchema::from([("code".into(), leaf(Schema::from(StrSchema::default())))])),
+                ),
+                Schema::from(ObjSchema::from([("code".into(), leaf(Schema::from(StrSchema::default())))]).unknown_keys(UnknownKeys::Reject)),
+            ]
+        };
+        let values = [
+            Value::None,
+            Value::Bool(true),
+            Value::from([1_u64, 2, 3]),
+            Value::from([1_u64, 2]),
+            Value::from([5_u64]),
+            Value::from([("a".into(), Value::U64(1)), ("b".into(), Value::U64(3))]),
+            Value::from([("type".into(), Value::from("key")), ("code".into(), Value::U64(1))]),
+            Value::from([("type".into(), Value::from("key")), ("code".into(), Value::from("Enter"))]),
+            Value::from([("type".into(), Value::from("click"))]),
+        ];
+        let async_schemas = schemas(|schema| schema.custom_async("always_ok", |_, _| async { Ok(()) }));
+        let sync_schemas = schemas(|schema| schema);
+        for (async_schema, sync_schema) in async_schemas.iter().zip(sync_schemas.iter()) {
+            for value in &values {
+                assert_eq!(block_on(validate_async_with_clock(async_schema, value, &SystemClock)), validate(sync_schema, value));
+            }
+        }
+    }
+
+    #[test]
+    fn validate_async_nested() {
+        let schema = Schema::from(ArrSchema::from(Schema::from(U64Schema::default()).custom_async("even", |value, _| async move {
+            match value {
+                Value::U64(number) if number % 2 != 0 => Err(BTreeMap::from([("value".into(), OperandValue::U64(number))])),
+                _ => Ok(()),
+            }
+        })));
+        assert_eq!(
+            block_on(validate_async_with_clock(&schema, &Value::from([2_u64, 3, 4]), &SystemClock)),
+            Err(SchemaErr::from([
+                SchemaErr::Validation(vec![]),
+                SchemaErr::from([ValidationErr::Custom { code: "even".into(), params: BTreeMap::from([("value".into(), OperandValue::U64(3))]) }]),
+                SchemaErr::Validation(vec![]),
+            ]))
+        );
+        assert_eq!(block_on(validate_async_with_clock(&schema, &Value::from([2_u64, 4]), &SystemClock)), Ok(()));
+    }
+
+    struct Rendezvous {
+        arrived: Arc<AtomicUsize>,
+        expected: usize,
+    }
+
+    impl Future for Rendezvous {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.arrived.load(Ordering::SeqCst) >= self.expected {
+                Poll::Ready(())
+            } else {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    #[test]
+    fn validate_async_concurrent() {
+        let arrived = Arc::new(AtomicUsize::new(0));
+        let field = |arrived: Arc<AtomicUsize>| {
+            Schema::from(U64Schema::default()).custom_async("rendezvous", move |_, _| {
+                let arrived = arrived.clone();
+                async move {
+                    arrived.fetch_add(1, Ordering::SeqCst);
+                    Rendezvous { arrived, expected: 2 }.await;
+                    Ok(())
+                }
+            })
+        };
+        let schema = Schema::from(ObjSchema::from([("a".into(), field(arrived.clone())), ("b".into(), field(arrived.clone()))]));
+        let value = Value::from([("a".into(), Value::U64(1)), ("b".into(), Value::U64(2))]);
+        assert_eq!(block_on(validate_async_with_clock(&schema, &value, &SystemClock)), Ok(()));
+        assert_eq!(arrived.load(Ordering::SeqCst), 2);
+    }
+}
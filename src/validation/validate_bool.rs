@@ -0,0 +1,89 @@
+use crate::{
+    error::{SchemaErr, ValidationErr},
+    operation::{OperandValue, compare},
+    schema::BoolSchema,
+    value::Value,
+};
+
+pub fn validate_bool(schema: &BoolSchema, value: &Value, root: &Value) -> Result<(), SchemaErr> {
+    let mut base = vec![];
+    match value {
+        Value::Bool(bool_value) => {
+            for operation in &schema.operations {
+                if let Some(Err(())) = compare(operation, &OperandValue::Bool(*bool_value), root) {
+                    base.push(ValidationErr::Operation(operation.clone()));
+                }
+            }
+        }
+        Value::None => {
+            if schema.required {
+                base.push(ValidationErr::Required);
+                base.push(ValidationErr::Bool);
+                for operation in &schema.operations {
+                    base.push(ValidationErr::Operation(operation.clone()));
+                }
+            }
+        }
+        _ => {
+            base.push(ValidationErr::Bool);
+            for operation in &schema.operations {
+                base.push(ValidationErr::Operation(operation.clone()));
+            }
+        }
+    }
+    if base.is_empty() { Ok(()) } else { Err(SchemaErr::Validation(base)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        error::{SchemaErr, ValidationErr},
+        operation::{Operand, OperandValue, Operation},
+        schema::BoolSchema,
+        value::Value,
+    };
+
+    use super::validate_bool;
+
+    const REQUIRED: ValidationErr = ValidationErr::Required;
+    const BOOL: ValidationErr = ValidationErr::Bool;
+    const OPERATION_GT: ValidationErr = ValidationErr::Operation(Operation::Gt(Operand::Value(OperandValue::Bool(false))));
+
+    #[test]
+    fn validate_bool_default() {
+        let schema = BoolSchema::default();
+        assert_eq!(validate_bool(&schema, &Value::Bool(false), &Value::None), Ok(()));
+        assert_eq!(validate_bool(&schema, &Value::None, &Value::None), Err(SchemaErr::from([REQUIRED, BOOL])));
+        assert_eq!(validate_bool(&schema, &Value::U64(1), &Value::None), Err(SchemaErr::from([BOOL])));
+        assert_eq!(validate_bool(&schema, &Value::from("false"), &Value::None), Err(SchemaErr::from([BOOL])));
+    }
+
+    #[test]
+    fn validate_bool_optional() {
+        let schema = BoolSchema::default().optional();
+        assert_eq!(validate_bool(&schema, &Value::Bool(false), &Value::None), Ok(()));
+        assert_eq!(validate_bool(&schema, &Value::None, &Value::None), Ok(()));
+        assert_eq!(validate_bool(&schema, &Value::U64(1), &Value::None), Err(SchemaErr::from([BOOL])));
+    }
+
+    #[test]
+    fn validate_bool_operation_value() {
+        let schema = BoolSchema::default().gt(false);
+        assert_eq!(validate_bool(&schema, &Value::Bool(true), &Value::None), Ok(()));
+        assert_eq!(validate_bool(&schema, &Value::Bool(false), &Value::None), Err(SchemaErr::from([OPERATION_GT])));
+        assert_eq!(validate_bool(&schema, &Value::None, &Value::None), Err(SchemaErr::from([REQUIRED, BOOL, OPERATION_GT])));
+        assert_eq!(validate_bool(&schema, &Value::U64(1), &Value::None), Err(SchemaErr::from([BOOL, OPERATION_GT])));
+    }
+
+    #[test]
+    fn validate_bool_operation_field() {
+        let schema = BoolSchema::default().gt_field("min".into());
+        let root = Value::Obj(BTreeMap::from([("min".into(), Value::Bool(false))]));
+        let operation = ValidationErr::Operation(Operation::Gt(Operand::FieldPath("min".into())));
+        assert_eq!(validate_bool(&schema, &Value::Bool(true), &root), Ok(()));
+        assert_eq!(validate_bool(&schema, &Value::Bool(false), &root), Err(SchemaErr::from([operation])));
+        assert_eq!(validate_bool(&schema, &Value::Bool(false), &Value::None), Ok(()));
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{
+    error::{SchemaErr, ValidationErr},
+    operation::Clock,
+    schema::CustomSchema,
+    value::Value,
+};
+
+use super::validate_schema;
+
+pub fn validate_custom(schema: &CustomSchema, value: &Value, root: &Value, clock: &dyn Clock) -> Result<(), SchemaErr> {
+    validate_schema(&schema.schema, value, root, clock)?;
+    if let Value::None = value {
+        return Ok(());
+    }
+    let mut base = vec![];
+    for validator in &schema.validators {
+        if let Err(params) = (validator.validate)(value, root) {
+            base.push(ValidationErr::Custom { code: validator.code.clone(), params });
+        }
+    }
+    if base.is_empty() { Ok(()) } else { Err(SchemaErr::Validation(base)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        error::{SchemaErr, ValidationErr},
+        operation::{Operand, OperandValue, Operation, SystemClock},
+        schema::{CustomSchema, Schema, StrSchema, U64Schema},
+        value::{Value, path::resolve_path},
+    };
+
+    use super::validate_custom;
+
+    fn username_schema() -> CustomSchema {
+        CustomSchema::from(Schema::from(StrSchema::default().chars_len_ge(3))).validator("username_not_reserved", |value, _| match value {
+            Value::Str(username) if ["admin", "root"].contains(&username.as_str()) => {
+                Err(BTreeMap::from([("username".into(), OperandValue::from(username.as_str()))]))
+            }
+            _ => Ok(()),
+        })
+    }
+
+    #[test]
+    fn validate_custom_ok() {
+        assert_eq!(validate_custom(&username_schema(), &Value::from("paul"), &Value::None, &SystemClock), Ok(()));
+        assert_eq!(validate_custom(&username_schema().optional(), &Value::None, &Value::None, &SystemClock), Ok(()));
+    }
+
+    #[test]
+    fn validate_custom_err() {
+        assert_eq!(
+            validate_custom(&username_schema(), &Value::from("root"), &Value::None, &SystemClock),
+            Err(SchemaErr::from([ValidationErr::Custom {
+                code: "username_not_reserved".into(),
+                params: BTreeMap::from([("username".into(), OperandValue::from("root"))])
+            }]))
+        );
+    }
+
+    #[test]
+    fn validate_custom_inner_err() {
+        assert_eq!(
+            validate_custom(&username_schema(), &Value::from("ro"), &Value::None, &SystemClock),
+            Err(SchemaErr::from([ValidationErr::CharsLen(Operation::Ge(Operand::Value(OperandValue::USize(3))))]))
+        );
+        assert_eq!(
+            validate_custom(&username_schema(), &Value::None, &Value::None, &SystemClock),
+            Err(SchemaErr::from([
+                ValidationErr::Required,
+                ValidationErr::Str,
+                ValidationErr::CharsLen(Operation::Ge(Operand::Value(OperandValue::USize(3))))
+            ]))
+        );
+    }
+
+    #[test]
+    fn validate_custom_root() {
+        let schema =
+            CustomSchema::from(Schema::from(U64Schema::default())).validator("lt_stock", |value, root| match (value, resolve_path(root, "stock")) {
+                (Value::U64(amount), Some(Value::U64(stock))) if *amount > stock => Err(BTreeMap::from([("stock".into(), OperandValue::U64(stock))])),
+                _ => Ok(()),
+            });
+        let root = Value::from([("stock".into(), Value::U64(3)), ("amount".into(), Value::U64(5))]);
+        assert_eq!(
+            validate_custom(&schema, &Value::U64(5), &root, &SystemClock),
+            Err(SchemaErr::from([ValidationErr::Custom {
+                code: "lt_stock".into(),
+                params: BTreeMap::from([("stock".into(), OperandValue::U64(3))])
+            }]))
+        );
+        assert_eq!(validate_custom(&schema, &Value::U64(2), &root, &SystemClock), Ok(()));
+    }
+}
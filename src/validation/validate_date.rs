@@ -0,0 +1,120 @@
+use crate::{
+    error::{SchemaErr, ValidationErr},
+    operation::{Clock, OperandValue, compare_with_clock, parse_date},
+    schema::DateSchema,
+    value::Value,
+};
+
+pub fn validate_date(schema: &DateSchema, value: &Value, root: &Value, clock: &dyn Clock) -> Result<(), SchemaErr> {
+    let mut base = vec![];
+    let date_value = if let Value::Str(str_value) = value { parse_date(str_value) } else { None };
+    match (date_value, value) {
+        (Some(date_value), _) => {
+            for operation in &schema.operations {
+                if let Some(Err(())) = compare_with_clock(operation, &OperandValue::Date(date_value), root, clock) {
+                    base.push(ValidationErr::Operation(operation.clone()));
+                }
+            }
+        }
+        (None, Value::None) => {
+            if schema.required {
+                base.push(ValidationErr::Required);
+                base.push(ValidationErr::Date);
+                for operation in &schema.operations {
+                    base.push(ValidationErr::Operation(operation.clone()));
+                }
+            }
+        }
+        _ => {
+            base.push(ValidationErr::Date);
+            for operation in &schema.operations {
+                base.push(ValidationErr::Operation(operation.clone()));
+            }
+        }
+    }
+    if base.is_empty() { Ok(()) } else { Err(SchemaErr::Validation(base)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        error::{SchemaErr, ValidationErr},
+        operation::{FixedClock, Operand, OperandValue, Operation, Relative, SystemClock, parse_date, parse_date_time},
+        schema::DateSchema,
+        value::Value,
+    };
+
+    use super::validate_date;
+
+    const REQUIRED: ValidationErr = ValidationErr::Required;
+    const DATE: ValidationErr = ValidationErr::Date;
+
+    #[test]
+    fn validate_date_default() {
+        let schema = DateSchema::default();
+        assert_eq!(validate_date(&schema, &Value::from("2026-10-18"), &Value::None, &SystemClock), Ok(()));
+        assert_eq!(validate_date(&schema, &Value::None, &Value::None, &SystemClock), Err(SchemaErr::from([REQUIRED, DATE])));
+        assert_eq!(validate_date(&schema, &Value::from("2026-10-32"), &Value::None, &SystemClock), Err(SchemaErr::from([DATE])));
+        assert_eq!(validate_date(&schema, &Value::U64(20261018), &Value::None, &SystemClock), Err(SchemaErr::from([DATE])));
+    }
+
+    #[test]
+    fn validate_date_optional() {
+        let schema = DateSchema::default().optional();
+        assert_eq!(validate_date(&schema, &Value::from("2026-10-18"), &Value::None, &SystemClock), Ok(()));
+        assert_eq!(validate_date(&schema, &Value::None, &Value::None, &SystemClock), Ok(()));
+        assert_eq!(validate_date(&schema, &Value::from("18/10/2026"), &Value::None, &SystemClock), Err(SchemaErr::from([DATE])));
+    }
+
+    #[test]
+    fn validate_date_operation_value() {
+        let schema = DateSchema::default().unix_epoch();
+        let operation = ValidationErr::Operation(Operation::Ge(Operand::Value(OperandValue::Date(parse_date("1970-01-01").unwrap()))));
+        assert_eq!(validate_date(&schema, &Value::from("1970-01-01"), &Value::None, &SystemClock), Ok(()));
+        assert_eq!(validate_date(&schema, &Value::from("1969-12-31"), &Value::None, &SystemClock), Err(SchemaErr::from([operation.clone()])));
+        assert_eq!(validate_date(&schema, &Value::None, &Value::None, &SystemClock), Err(SchemaErr::from([REQUIRED, DATE, operation.clone()])));
+        assert_eq!(validate_date(&schema, &Value::Bool(false), &Value::None, &SystemClock), Err(SchemaErr::from([DATE, operation])));
+    }
+
+    #[test]
+    fn validate_date_operation_field() {
+        let schema = DateSchema::default().lt_field("end".into());
+        let root = Value::Obj(BTreeMap::from([("end".into(), Value::from("2026-10-18"))]));
+        let operation = ValidationErr::Operation(Operation::Lt(Operand::FieldPath("end".into())));
+        assert_eq!(validate_date(&schema, &Value::from("2026-10-17"), &root, &SystemClock), Ok(()));
+        assert_eq!(validate_date(&schema, &Value::from("2026-10-18"), &root, &SystemClock), Err(SchemaErr::from([operation])));
+        assert_eq!(validate_date(&schema, &Value::from("2026-10-18"), &Value::None, &SystemClock), Ok(()));
+    }
+
+    #[test]
+    fn validate_date_operations() {
+        let schema = DateSchema::default().gt(parse_date("2000-01-01").unwrap()).lt_field("end".into());
+        let root = Value::Obj(BTreeMap::from([("end".into(), Value::from("2026-10-18"))]));
+        let gt = ValidationErr::Operation(Operation::Gt(Operand::Value(OperandValue::Date(parse_date("2000-01-01").unwrap()))));
+        let lt = ValidationErr::Operation(Operation::Lt(Operand::FieldPath("end".into())));
+        assert_eq!(validate_date(&schema, &Value::from("2026-10-17"), &root, &SystemClock), Ok(()));
+        assert_eq!(validate_date(&schema, &Value::from("1999-12-31"), &root, &SystemClock), Err(SchemaErr::from([gt])));
+        assert_eq!(validate_date(&schema, &Value::from("2026-10-19"), &root, &SystemClock), Err(SchemaErr::from([lt])));
+    }
+
+    #[test]
+    fn validate_date_relative() {
+        let clock = FixedClock(parse_date_time("2026-10-18T10:27Z").unwrap());
+        let schema = DateSchema::default().age_ge(18);
+        let operation = ValidationErr::Operation(Operation::Le(Operand::Now(Relative::Years(-18))));
+        assert_eq!(validate_date(&schema, &Value::from("2008-10-18"), &Value::None, &clock), Ok(()));
+        assert_eq!(validate_date(&schema, &Value::from("2008-10-19"), &Value::None, &clock), Err(SchemaErr::from([operation.clone()])));
+        assert_eq!(validate_date(&schema, &Value::None, &Value::None, &clock), Err(SchemaErr::from([REQUIRED, DATE, operation])));
+        let schema = DateSchema::default().le_today();
+        let operation = ValidationErr::Operation(Operation::Le(Operand::Now(Relative::Days(0))));
+        assert_eq!(validate_date(&schema, &Value::from("2026-10-18"), &Value::None, &clock), Ok(()));
+        assert_eq!(validate_date(&schema, &Value::from("2026-10-19"), &Value::None, &clock), Err(SchemaErr::from([operation])));
+        let schema = DateSchema::default().within_days(30);
+        let operation = ValidationErr::Operation(Operation::Btwn(Operand::Now(Relative::Days(0)), Operand::Now(Relative::Days(30))));
+        assert_eq!(validate_date(&schema, &Value::from("2026-11-17"), &Value::None, &clock), Ok(()));
+        assert_eq!(validate_date(&schema, &Value::from("2026-11-18"), &Value::None, &clock), Err(SchemaErr::from([operation.clone()])));
+        assert_eq!(validate_date(&schema, &Value::from("2026-10-17"), &Value::None, &clock), Err(SchemaErr::from([operation])));
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{
+    error::{SchemaErr, ValidationErr},
+    operation::{Clock, OperandValue, compare_with_clock, parse_date_time},
+    schema::DateTimeSchema,
+    value::Value,
+};
+
+pub fn validate_date_time(schema: &DateTimeSchema, value: &Value, root: &Value, clock: &dyn Clock) -> Result<(), SchemaErr> {
+    let mut base = vec![];
+    let date_time_value = if let Value::Str(str_value) = value { parse_date_time(str_value) } else { None };
+    match (date_time_value, value) {
+        (Some(date_time_value), _) => {
+            for operation in &schema.operations {
+                if let Some(Err(())) = compare_with_clock(operation, &OperandValue::DateTime(date_time_value), root, clock) {
+                    base.push(ValidationErr::Operation(operation.clone()));
+                }
+            }
+        }
+        (None, Value::None) => {
+            if schema.required {
+                base.push(ValidationErr::Required);
+                base.push(ValidationErr::DateTime);
+                for operation in &schema.operations {
+                    base.push(ValidationErr::Operation(operation.clone()));
+                }
+            }
+        }
+        _ => {
+            base.push(ValidationErr::DateTime);
+            for operation in &schema.operations {
+                base.push(ValidationErr::Operation(operation.clone()));
+            }
+        }
+    }
+    if base.is_empty() { Ok(()) } else { Err(SchemaErr::Validation(base)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        error::{SchemaErr, ValidationErr},
+        operation::{FixedClock, Operand, OperandValue, Operation, Relative, SystemClock, parse_date_time},
+        schema::DateTimeSchema,
+        value::Value,
+    };
+
+    use super::validate_date_time;
+
+    const REQUIRED: ValidationErr = ValidationErr::Required;
+    const DATE_TIME: ValidationErr = ValidationErr::DateTime;
+
+    #[test]
+    fn validate_date_time_default() {
+        let schema = DateTimeSchema::default();
+        assert_eq!(validate_date_time(&schema, &Value::from("2026-10-18T10:27Z"), &Value::None, &SystemClock), Ok(()));
+        assert_eq!(validate_date_time(&schema, &Value::None, &Value::None, &SystemClock), Err(SchemaErr::from([REQUIRED, DATE_TIME])));
+        assert_eq!(validate_date_time(&schema, &Value::from("2026-10-18"), &Value::None, &SystemClock), Err(SchemaErr::from([DATE_TIME])));
+        assert_eq!(validate_date_time(&schema, &Value::U64(1760783220), &Value::None, &SystemClock), Err(SchemaErr::from([DATE_TIME])));
+    }
+
+    #[test]
+    fn validate_date_time_optional() {
+        let schema = DateTimeSchema::default().optional();
+        assert_eq!(validate_date_time(&schema, &Value::from("2026-10-18T10:27Z"), &Value::None, &SystemClock), Ok(()));
+        assert_eq!(validate_date_time(&schema, &Value::None, &Value::None, &SystemClock), Ok(()));
+        assert_eq!(validate_date_time(&schema, &Value::from("10:27"), &Value::None, &SystemClock), Err(SchemaErr::from([DATE_TIME])));
+    }
+
+    #[test]
+    fn validate_date_time_operation_value() {
+        let schema = DateTimeSchema::default().unix_epoch();
+        let operation =
+            ValidationErr::Operation(Operation::Ge(Operand::Value(OperandValue::DateTime(parse_date_time("1970-01-01T00:00Z").unwrap()))));
+        assert_eq!(validate_date_time(&schema, &Value::from("1970-01-01T00:00Z"), &Value::None, &SystemClock), Ok(()));
+        assert_eq!(
+            validate_date_time(&schema, &Value::from("1969-12-31T23:59Z"), &Value::None, &SystemClock),
+            Err(SchemaErr::from([operation.clone()]))
+        );
+        assert_eq!(
+            validate_date_time(&schema, &Value::None, &Value::None, &SystemClock),
+            Err(SchemaErr::from([REQUIRED, DATE_TIME, operation.clone()]))
+        );
+        assert_eq!(validate_date_time(&schema, &Value::Bool(false), &Value::None, &SystemClock), Err(SchemaErr::from([DATE_TIME, operation])));
+    }
+
+    #[test]
+    fn validate_date_time_operation_field() {
+        let schema = DateTimeSchema::default().lt_field("end".into());
+        let root = Value::Obj(BTreeMap::from([("end".into(), Value::from("2026-10-18T18:00Z"))]));
+        let operation = ValidationErr::Operation(Operation::Lt(Operand::FieldPath("end".into())));
+        assert_eq!(validate_date_time(&schema, &Value::from("2026-10-18T17:59Z"), &root, &SystemClock), Ok(()));
+        assert_eq!(validate_date_time(&schema, &Value::from("2026-10-18T18:00Z"), &root, &SystemClock), Err(SchemaErr::from([operation])));
+        assert_eq!(validate_date_time(&schema, &Value::from("2026-10-18T18:00Z"), &Value::None, &SystemClock), Ok(()));
+    }
+
+    #[test]
+    fn validate_date_time_offset() {
+        let schema = DateTimeSchema::default().lt(parse_date_time("2026-10-18T18:00Z").unwrap());
+        let operation =
+            ValidationErr::Operation(Operation::Lt(Operand::Value(OperandValue::DateTime(parse_date_time("2026-10-18T18:00Z").unwrap()))));
+        assert_eq!(validate_date_time(&schema, &Value::from("2026-10-18T14:59-03:00"), &Value::None, &SystemClock), Ok(()));
+        assert_eq!(
+            validate_date_time(&schema, &Value::from("2026-10-18T15:00-03:00"), &Value::None, &SystemClock),
+            Err(SchemaErr::from([operation.clone()]))
+        );
+        assert_eq!(
+            validate_date_time(&schema, &Value::from("2026-10-19T00:30+05:30"), &Value::None, &SystemClock),
+            Err(SchemaErr::from([operation]))
+        );
+        let field_schema = DateTimeSchema::default().gt_field("start".into());
+        let root = Value::Obj(BTreeMap::from([("start".into(), Value::from("2026-10-18T10:00-03:00"))]));
+        assert_eq!(
+            validate_date_time(&field_schema, &Value::from("2026-10-18T12:00Z"), &root, &SystemClock),
+            Err(SchemaErr::from([ValidationErr::Operation(Operation::Gt(Operand::FieldPath("start".into())))]))
+        );
+        assert_eq!(validate_date_time(&field_schema, &Value::from("2026-10-18T13:01Z"), &root, &SystemClock), Ok(()));
+    }
+
+    #[test]
+    fn validate_date_time_multibyte() {
+        let schema = DateTimeSchema::default();
+        assert_eq!(validate_date_time(&schema, &Value::from("2026-10-1éT10:27Z"), &Value::None, &SystemClock), Err(SchemaErr::from([DATE_TIME])));
+        assert_eq!(validate_date_time(&schema, &Value::from("2026-10-18T10:2éZ"), &Value::None, &SystemClock), Err(SchemaErr::from([DATE_TIME])));
+        assert_eq!(
+            validate_date_time(&schema, &Value::from("2026-10-18T10:27:00é-03:00"), &Value::None, &SystemClock),
+            Err(SchemaErr::from([DATE_TIME]))
+        );
+    }
+
+    #[test]
+    fn validate_date_time_relative() {
+        let clock = FixedClock(parse_date_time("2026-10-18T10:27Z").unwrap());
+        let schema = DateTimeSchema::default().le_now();
+        let operation = ValidationErr::Operation(Operation::Le(Operand::Now(Relative::Days(0))));
+        assert_eq!(validate_date_time(&schema, &Value::from("2026-10-18T10:27Z"), &Value::None, &clock), Ok(()));
+        assert_eq!(validate_date_time(&schema, &Value::from("2026-10-18T07:27-03:00"), &Value::None, &clock), Ok(()));
+        assert_eq!(validate_date_time(&schema, &Value::from("2026-10-18T10:28Z"), &Value::None, &clock), Err(SchemaErr::from([operation])));
+        let schema = DateTimeSchema::default().within_days(1);
+        let operation = ValidationErr::Operation(Operation::Btwn(Operand::Now(Relative::Days(0)), Operand::Now(Relative::Days(1))));
+        assert_eq!(validate_date_time(&schema, &Value::from("2026-10-19T10:27Z"), &Value::None, &clock), Ok(()));
+        assert_eq!(validate_date_time(&schema, &Value::from("2026-10-19T10:28Z"), &Value::None, &clock), Err(SchemaErr::from([operation])));
+    }
+}
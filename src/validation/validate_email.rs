@@ -0,0 +1,48 @@
+use email_address::EmailAddress;
+
+use crate::{
+    error::{SchemaErr, ValidationErr},
+    schema::EmailSchema,
+    value::Value,
+};
+
+pub fn validate_email(schema: &EmailSchema, value: &Value) -> Result<(), SchemaErr> {
+    match value {
+        Value::Str(str_value) if EmailAddress::is_valid(str_value) => Ok(()),
+        Value::None if !schema.required => Ok(()),
+        Value::None => Err(SchemaErr::from([ValidationErr::Required, ValidationErr::Email])),
+        _ => Err(SchemaErr::from([ValidationErr::Email])),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{SchemaErr, ValidationErr},
+        schema::EmailSchema,
+        value::Value,
+    };
+
+    use super::validate_email;
+
+    const REQUIRED: ValidationErr = ValidationErr::Required;
+    const EMAIL: ValidationErr = ValidationErr::Email;
+
+    #[test]
+    fn validate_email_default() {
+        let schema = EmailSchema::default();
+        assert_eq!(validate_email(&schema, &Value::from("john@gmail.com")), Ok(()));
+        assert_eq!(validate_email(&schema, &Value::None), Err(SchemaErr::from([REQUIRED, EMAIL])));
+        assert_eq!(validate_email(&schema, &Value::from("john")), Err(SchemaErr::from([EMAIL])));
+        assert_eq!(validate_email(&schema, &Value::from("john@")), Err(SchemaErr::from([EMAIL])));
+        assert_eq!(validate_email(&schema, &Value::U64(42)), Err(SchemaErr::from([EMAIL])));
+    }
+
+    #[test]
+    fn validate_email_optional() {
+        let schema = EmailSchema::default().optional();
+        assert_eq!(validate_email(&schema, &Value::from("john@gmail.com")), Ok(()));
+        assert_eq!(validate_email(&schema, &Value::None), Ok(()));
+        assert_eq!(validate_email(&schema, &Value::from("john")), Err(SchemaErr::from([EMAIL])));
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{
+    error::{SchemaErr, ValidationErr},
+    schema::{EnumSchema, EnumValues},
+    value::Value,
+};
+
+pub fn validate_enum(schema: &EnumSchema, value: &Value) -> Result<(), SchemaErr> {
+    match (value, &schema.values) {
+        (Value::USize(usize_value), EnumValues::USize(values)) if values.contains(usize_value) => Ok(()),
+        (Value::ISize(isize_value), EnumValues::ISize(values)) if values.contains(isize_value) => Ok(()),
+        (Value::Str(str_value), EnumValues::Str(values)) if values.contains(str_value) => Ok(()),
+        (Value::None, _) if !schema.required => Ok(()),
+        (Value::None, _) => Err(SchemaErr::from([ValidationErr::Required, ValidationErr::Enumerated(schema.values.clone())])),
+        _ => Err(SchemaErr::from([ValidationErr::Enumerated(schema.values.clone())])),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::LazyLock;
+
+    use crate::{
+        error::{SchemaErr, ValidationErr},
+        schema::{EnumSchema, EnumValues},
+        value::Value,
+    };
+
+    use super::validate_enum;
+
+    const REQUIRED: ValidationErr = ValidationErr::Required;
+
+    const USIZE_VALUES: [usize; 3] = [0, 1, 2];
+    const ISIZE_VALUES: [isize; 3] = [-1, 0, 1];
+
+    static ENUM_USIZE: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Enumerated(EnumValues::from(USIZE_VALUES)));
+    static ENUM_ISIZE: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Enumerated(EnumValues::from(ISIZE_VALUES)));
+    static ENUM_STR: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Enumerated(EnumValues::from(["APPLE", "GRAPE", "PEAR"])));
+
+    #[test]
+    fn validate_enum_usize() {
+        let schema = EnumSchema::from(USIZE_VALUES);
+        assert_eq!(validate_enum(&schema, &Value::USize(1)), Ok(()));
+        assert_eq!(validate_enum(&schema, &Value::USize(3)), Err(SchemaErr::from([ENUM_USIZE.clone()])));
+        assert_eq!(validate_enum(&schema, &Value::U64(1)), Err(SchemaErr::from([ENUM_USIZE.clone()])));
+        assert_eq!(validate_enum(&schema, &Value::None), Err(SchemaErr::from([REQUIRED, ENUM_USIZE.clone()])));
+    }
+
+    #[test]
+    fn validate_enum_isize() {
+        let schema = EnumSchema::from(ISIZE_VALUES);
+        assert_eq!(validate_enum(&schema, &Value::ISize(-1)), Ok(()));
+        assert_eq!(validate_enum(&schema, &Value::ISize(-2)), Err(SchemaErr::from([ENUM_ISIZE.clone()])));
+        assert_eq!(validate_enum(&schema, &Value::I64(-1)), Err(SchemaErr::from([ENUM_ISIZE.clone()])));
+        assert_eq!(validate_enum(&schema, &Value::None), Err(SchemaErr::from([REQUIRED, ENUM_ISIZE.clone()])));
+    }
+
+    #[test]
+    fn validate_enum_str() {
+        let schema = EnumSchema::from(["APPLE", "GRAPE", "PEAR"]);
+        assert_eq!(validate_enum(&schema, &Value::from("GRAPE")), Ok(()));
+        assert_eq!(validate_enum(&schema, &Value::from("grape")), Err(SchemaErr::from([ENUM_STR.clone()])));
+        assert_eq!(validate_enum(&schema, &Value::Bool(false)), Err(SchemaErr::from([ENUM_STR.clone()])));
+        assert_eq!(validate_enum(&schema, &Value::None), Err(SchemaErr::from([REQUIRED, ENUM_STR.clone()])));
+    }
+
+    #[test]
+    fn validate_enum_optional() {
+        let schema = EnumSchema::from(["APPLE", "GRAPE", "PEAR"]).optional();
+        assert_eq!(validate_enum(&schema, &Value::from("PEAR")), Ok(()));
+        assert_eq!(validate_enum(&schema, &Value::None), Ok(()));
+        assert_eq!(validate_enum(&schema, &Value::from("BANANA")), Err(SchemaErr::from([ENUM_STR.clone()])));
+    }
+}
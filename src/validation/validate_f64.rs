@@ -0,0 +1,89 @@
+use crate::{
+    error::{SchemaErr, ValidationErr},
+    operation::{OperandValue, compare},
+    schema::F64Schema,
+    value::Value,
+};
+
+pub fn validate_f64(schema: &F64Schema, value: &Value, root: &Value) -> Result<(), SchemaErr> {
+    let mut base = vec![];
+    match value {
+        Value::F64(f64_value) => {
+            for operation in &schema.operations {
+                if let Some(Err(())) = compare(operation, &OperandValue::F64(*f64_value), root) {
+                    base.push(ValidationErr::Operation(operation.clone()));
+                }
+            }
+        }
+        Value::None => {
+            if schema.required {
+                base.push(ValidationErr::Required);
+                base.push(ValidationErr::F64);
+                for operation in &schema.operations {
+                    base.push(ValidationErr::Operation(operation.clone()));
+                }
+            }
+        }
+        _ => {
+            base.push(ValidationErr::F64);
+            for operation in &schema.operations {
+                base.push(ValidationErr::Operation(operation.clone()));
+            }
+        }
+    }
+    if base.is_empty() { Ok(()) } else { Err(SchemaErr::Validation(base)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        error::{SchemaErr, ValidationErr},
+        operation::{Operand, OperandValue, Operation},
+        schema::F64Schema,
+        value::Value,
+    };
+
+    use super::validate_f64;
+
+    const REQUIRED: ValidationErr = ValidationErr::Required;
+    const F64: ValidationErr = ValidationErr::F64;
+    const OPERATION_GT: ValidationErr = ValidationErr::Operation(Operation::Gt(Operand::Value(OperandValue::F64(-42.5))));
+
+    #[test]
+    fn validate_f64_default() {
+        let schema = F64Schema::default();
+        assert_eq!(validate_f64(&schema, &Value::F64(-42.5), &Value::None), Ok(()));
+        assert_eq!(validate_f64(&schema, &Value::None, &Value::None), Err(SchemaErr::from([REQUIRED, F64])));
+        assert_eq!(validate_f64(&schema, &Value::I64(-42), &Value::None), Err(SchemaErr::from([F64])));
+        assert_eq!(validate_f64(&schema, &Value::from("42"), &Value::None), Err(SchemaErr::from([F64])));
+    }
+
+    #[test]
+    fn validate_f64_optional() {
+        let schema = F64Schema::default().optional();
+        assert_eq!(validate_f64(&schema, &Value::F64(-42.5), &Value::None), Ok(()));
+        assert_eq!(validate_f64(&schema, &Value::None, &Value::None), Ok(()));
+        assert_eq!(validate_f64(&schema, &Value::I64(-42), &Value::None), Err(SchemaErr::from([F64])));
+    }
+
+    #[test]
+    fn validate_f64_operation_value() {
+        let schema = F64Schema::default().gt(-42.5);
+        assert_eq!(validate_f64(&schema, &Value::F64(-41.5), &Value::None), Ok(()));
+        assert_eq!(validate_f64(&schema, &Value::F64(-42.5), &Value::None), Err(SchemaErr::from([OPERATION_GT])));
+        assert_eq!(validate_f64(&schema, &Value::None, &Value::None), Err(SchemaErr::from([REQUIRED, F64, OPERATION_GT])));
+        assert_eq!(validate_f64(&schema, &Value::Bool(false), &Value::None), Err(SchemaErr::from([F64, OPERATION_GT])));
+    }
+
+    #[test]
+    fn validate_f64_operation_field() {
+        let schema = F64Schema::default().gt_field("min".into());
+        let root = Value::Obj(BTreeMap::from([("min".into(), Value::F64(-42.5))]));
+        let operation = ValidationErr::Operation(Operation::Gt(Operand::FieldPath("min".into())));
+        assert_eq!(validate_f64(&schema, &Value::F64(-41.5), &root), Ok(()));
+        assert_eq!(validate_f64(&schema, &Value::F64(-42.5), &root), Err(SchemaErr::from([operation])));
+        assert_eq!(validate_f64(&schema, &Value::F64(-42.5), &Value::None), Ok(()));
+    }
+}
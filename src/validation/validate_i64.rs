@@ -0,0 +1,89 @@
+use crate::{
+    error::{SchemaErr, ValidationErr},
+    operation::{OperandValue, compare},
+    schema::I64Schema,
+    value::Value,
+};
+
+pub fn validate_i64(schema: &I64Schema, value: &Value, root: &Value) -> Result<(), SchemaErr> {
+    let mut base = vec![];
+    match value {
+        Value::I64(i64_value) => {
+            for operation in &schema.operations {
+                if let Some(Err(())) = compare(operation, &OperandValue::I64(*i64_value), root) {
+                    base.push(ValidationErr::Operation(operation.clone()));
+                }
+            }
+        }
+        Value::None => {
+            if schema.required {
+                base.push(ValidationErr::Required);
+                base.push(ValidationErr::I64);
+                for operation in &schema.operations {
+                    base.push(ValidationErr::Operation(operation.clone()));
+                }
+            }
+        }
+        _ => {
+            base.push(ValidationErr::I64);
+            for operation in &schema.operations {
+                base.push(ValidationErr::Operation(operation.clone()));
+            }
+        }
+    }
+    if base.is_empty() { Ok(()) } else { Err(SchemaErr::Validation(base)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        error::{SchemaErr, ValidationErr},
+        operation::{Operand, OperandValue, Operation},
+        schema::I64Schema,
+        value::Value,
+    };
+
+    use super::validate_i64;
+
+    const REQUIRED: ValidationErr = ValidationErr::Required;
+    const I64: ValidationErr = ValidationErr::I64;
+    const OPERATION_GT: ValidationErr = ValidationErr::Operation(Operation::Gt(Operand::Value(OperandValue::I64(-42))));
+
+    #[test]
+    fn validate_i64_default() {
+        let schema = I64Schema::default();
+        assert_eq!(validate_i64(&schema, &Value::I64(-42), &Value::None), Ok(()));
+        assert_eq!(validate_i64(&schema, &Value::None, &Value::None), Err(SchemaErr::from([REQUIRED, I64])));
+        assert_eq!(validate_i64(&schema, &Value::U64(42), &Value::None), Err(SchemaErr::from([I64])));
+        assert_eq!(validate_i64(&schema, &Value::from("42"), &Value::None), Err(SchemaErr::from([I64])));
+    }
+
+    #[test]
+    fn validate_i64_optional() {
+        let schema = I64Schema::default().optional();
+        assert_eq!(validate_i64(&schema, &Value::I64(-42), &Value::None), Ok(()));
+        assert_eq!(validate_i64(&schema, &Value::None, &Value::None), Ok(()));
+        assert_eq!(validate_i64(&schema, &Value::U64(42), &Value::None), Err(SchemaErr::from([I64])));
+    }
+
+    #[test]
+    fn validate_i64_operation_value() {
+        let schema = I64Schema::default().gt(-42);
+        assert_eq!(validate_i64(&schema, &Value::I64(-41), &Value::None), Ok(()));
+        assert_eq!(validate_i64(&schema, &Value::I64(-42), &Value::None), Err(SchemaErr::from([OPERATION_GT])));
+        assert_eq!(validate_i64(&schema, &Value::None, &Value::None), Err(SchemaErr::from([REQUIRED, I64, OPERATION_GT])));
+        assert_eq!(validate_i64(&schema, &Value::Bool(false), &Value::None), Err(SchemaErr::from([I64, OPERATION_GT])));
+    }
+
+    #[test]
+    fn validate_i64_operation_field() {
+        let schema = I64Schema::default().gt_field("min".into());
+        let root = Value::Obj(BTreeMap::from([("min".into(), Value::I64(-42))]));
+        let operation = ValidationErr::Operation(Operation::Gt(Operand::FieldPath("min".into())));
+        assert_eq!(validate_i64(&schema, &Value::I64(-41), &root), Ok(()));
+        assert_eq!(validate_i64(&schema, &Value::I64(-42), &root), Err(SchemaErr::from([operation])));
+        assert_eq!(validate_i64(&schema, &Value::I64(-42), &Value::None), Ok(()));
+    }
+}
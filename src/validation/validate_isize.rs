@@ -0,0 +1,89 @@
+use crate::{
+    error::{SchemaErr, ValidationErr},
+    operation::{OperandValue, compare},
+    schema::ISizeSchema,
+    value::Value,
+};
+
+pub fn validate_isize(schema: &ISizeSchema, value: &Value, root: &Value) -> Result<(), SchemaErr> {
+    let mut base = vec![];
+    match value {
+        Value::ISize(isize_value) => {
+            for operation in &schema.operations {
+                if let Some(Err(())) = compare(operation, &OperandValue::ISize(*isize_value), root) {
+                    base.push(ValidationErr::Operation(operation.clone()));
+                }
+            }
+        }
+        Value::None => {
+            if schema.required {
+                base.push(ValidationErr::Required);
+                base.push(ValidationErr::ISize);
+                for operation in &schema.operations {
+                    base.push(ValidationErr::Operation(operation.clone()));
+                }
+            }
+        }
+        _ => {
+            base.push(ValidationErr::ISize);
+            for operation in &schema.operations {
+                base.push(ValidationErr::Operation(operation.clone()));
+            }
+        }
+    }
+    if base.is_empty() { Ok(()) } else { Err(SchemaErr::Validation(base)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        error::{SchemaErr, ValidationErr},
+        operation::{Operand, OperandValue, Operation},
+        schema::ISizeSchema,
+        value::Value,
+    };
+
+    use super::validate_isize;
+
+    const REQUIRED: ValidationErr = ValidationErr::Required;
+    const ISIZE: ValidationErr = ValidationErr::ISize;
+    const OPERATION_GT: ValidationErr = ValidationErr::Operation(Operation::Gt(Operand::Value(OperandValue::ISize(-42))));
+
+    #[test]
+    fn validate_isize_default() {
+        let schema = ISizeSchema::default();
+        assert_eq!(validate_isize(&schema, &Value::ISize(-42), &Value::None), Ok(()));
+        assert_eq!(validate_isize(&schema, &Value::None, &Value::None), Err(SchemaErr::from([REQUIRED, ISIZE])));
+        assert_eq!(validate_isize(&schema, &Value::I64(-42), &Value::None), Err(SchemaErr::from([ISIZE])));
+        assert_eq!(validate_isize(&schema, &Value::from("42"), &Value::None), Err(SchemaErr::from([ISIZE])));
+    }
+
+    #[test]
+    fn validate_isize_optional() {
+        let schema = ISizeSchema::default().optional();
+        assert_eq!(validate_isize(&schema, &Value::ISize(-42), &Value::None), Ok(()));
+        assert_eq!(validate_isize(&schema, &Value::None, &Value::None), Ok(()));
+        assert_eq!(validate_isize(&schema, &Value::I64(-42), &Value::None), Err(SchemaErr::from([ISIZE])));
+    }
+
+    #[test]
+    fn validate_isize_operation_value() {
+        let schema = ISizeSchema::default().gt(-42);
+        assert_eq!(validate_isize(&schema, &Value::ISize(-41), &Value::None), Ok(()));
+        assert_eq!(validate_isize(&schema, &Value::ISize(-42), &Value::None), Err(SchemaErr::from([OPERATION_GT])));
+        assert_eq!(validate_isize(&schema, &Value::None, &Value::None), Err(SchemaErr::from([REQUIRED, ISIZE, OPERATION_GT])));
+        assert_eq!(validate_isize(&schema, &Value::Bool(false), &Value::None), Err(SchemaErr::from([ISIZE, OPERATION_GT])));
+    }
+
+    #[test]
+    fn validate_isize_operation_field() {
+        let schema = ISizeSchema::default().gt_field("min".into());
+        let root = Value::Obj(BTreeMap::from([("min".into(), Value::ISize(-42))]));
+        let operation = ValidationErr::Operation(Operation::Gt(Operand::FieldPath("min".into())));
+        assert_eq!(validate_isize(&schema, &Value::ISize(-41), &root), Ok(()));
+        assert_eq!(validate_isize(&schema, &Value::ISize(-42), &root), Err(SchemaErr::from([operation])));
+        assert_eq!(validate_isize(&schema, &Value::ISize(-42), &Value::None), Ok(()));
+    }
+}
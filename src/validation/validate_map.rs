@@ -0,0 +1,182 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    error::{SchemaErr, ValidationErr},
+    operation::Clock,
+    schema::MapSchema,
+    value::Value,
+};
+
+use super::{
+    validate_arr::{collection_err, len_errs},
+    validate_schema,
+};
+
+pub fn map_entry_err(key_result: Result<(), SchemaErr>, value_result: Result<(), SchemaErr>) -> Result<(), SchemaErr> {
+    match key_result {
+        Err(key_err) => Err(SchemaErr::MapKey { key: Box::new(key_err), value: value_result.err().map(Box::new) }),
+        Ok(()) => value_result,
+    }
+}
+
+pub fn validate_map(schema: &MapSchema, value: &Value, root: &Value, clock: &dyn Clock) -> Result<(), SchemaErr> {
+    let mut base = vec![];
+    match value {
+        Value::Obj(obj) => {
+            let mut result: BTreeMap<String, SchemaErr> = BTreeMap::new();
+            for (key, item) in obj {
+                let item_result = map_entry_err(
+                    validate_schema(&schema.key, &Value::Str(key.clone()), root, clock),
+                    validate_schema(&schema.value, item, root, clock),
+                );
+                if let Err(err) = item_result {
+                    result.insert(key.clone(), err);
+                }
+            }
+            let items = if result.is_empty() { None } else { Some(SchemaErr::Obj(result)) };
+            return collection_err(len_errs(&schema.len, obj.len(), root, clock), items);
+        }
+        Value::None => {
+            if schema.required {
+                base.push(ValidationErr::Required);
+                base.push(ValidationErr::Map);
+                for len in &schema.len {
+                    base.push(ValidationErr::Len(len.clone()));
+                }
+            }
+        }
+        _ => {
+            base.push(ValidationErr::Map);
+            for len in &schema.len {
+                base.push(ValidationErr::Len(len.clone()));
+            }
+        }
+    }
+    if base.is_empty() { Ok(()) } else { Err(SchemaErr::Validation(base)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        error::{FlatErr, SchemaErr, ValidationErr},
+        operation::{Operand, OperandValue, Operation, SystemClock},
+        schema::{BoolSchema, EnumSchema, MapSchema, ObjSchema, Schema, StrSchema},
+        value::Value,
+    };
+
+    use super::validate_map;
+
+    const REQUIRED: ValidationErr = ValidationErr::Required;
+    const MAP: ValidationErr = ValidationErr::Map;
+    const LEN_LE: ValidationErr = ValidationErr::Len(Operation::Le(Operand::Value(OperandValue::USize(2))));
+
+    #[test]
+    fn validate_map_default() {
+        let schema = MapSchema::from(Schema::from(BoolSchema::default()));
+        let value = Value::from([("dark_mode".into(), Value::Bool(true)), ("beta".into(), Value::Bool(false))]);
+        assert_eq!(validate_map(&schema, &value, &Value::None, &SystemClock), Ok(()));
+        assert_eq!(validate_map(&schema, &Value::Obj(BTreeMap::new()), &Value::None, &SystemClock), Ok(()));
+        assert_eq!(validate_map(&schema, &Value::None, &Value::None, &SystemClock), Err(SchemaErr::from([REQUIRED, MAP])));
+        assert_eq!(validate_map(&schema, &Value::from([Value::Bool(true)]), &Value::None, &SystemClock), Err(SchemaErr::from([MAP])));
+    }
+
+    #[test]
+    fn validate_map_optional() {
+        let schema = MapSchema::from(Schema::from(BoolSchema::default())).optional();
+        assert_eq!(validate_map(&schema, &Value::None, &Value::None, &SystemClock), Ok(()));
+        assert_eq!(validate_map(&schema, &Value::U64(1), &Value::None, &SystemClock), Err(SchemaErr::from([MAP])));
+    }
+
+    #[test]
+    fn validate_map_key_value() {
+        let schema = MapSchema::from(Schema::from(StrSchema::default().chars_len_ge(1))).key(Schema::from(EnumSchema::from(["en", "pt"])));
+        let value = Value::from([("en".into(), Value::from("Hello")), ("pt".into(), Value::from("")), ("fr".into(), Value::from("Bonjour"))]);
+        let enumerated = ValidationErr::Enumerated(["en", "pt"].into());
+        let chars_len = ValidationErr::CharsLen(Operation::Ge(Operand::Value(OperandValue::USize(1))));
+        let err = validate_map(&schema, &value, &Value::None, &SystemClock).unwrap_err();
+        assert_eq!(
+            err,
+            SchemaErr::from([
+                ("fr".into(), SchemaErr::MapKey { key: Box::new(SchemaErr::from([enumerated.clone()])), value: None }),
+                ("pt".into(), SchemaErr::from([chars_len.clone()])),
+            ])
+        );
+        assert_eq!(
+            err.flatten(),
+            vec![
+                FlatErr { pointer: "/fr".into(), path: "fr".into(), key: true, errors: vec![enumerated] },
+                FlatErr { pointer: "/pt".into(), path: "pt".into(), key: false, errors: vec![chars_len] },
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_map_key_and_value() {
+        let schema = MapSchema::from(Schema::from(StrSchema::default().chars_len_ge(1))).key(Schema::from(EnumSchema::from(["en", "pt"])));
+        let value = Value::from([("fr".into(), Value::from(""))]);
+        let enumerated = ValidationErr::Enumerated(["en", "pt"].into());
+        let chars_len = ValidationErr::CharsLen(Operation::Ge(Operand::Value(OperandValue::USize(1))));
+        let err = validate_map(&schema, &value, &Value::None, &SystemClock).unwrap_err();
+        assert_eq!(
+            err,
+            SchemaErr::from([(
+                "fr".into(),
+                SchemaErr::MapKey {
+                    key: Box::new(SchemaErr::from([enumerated.clone()])),
+                    value: Some(Box::new(SchemaErr::from([chars_len.clone()])))
+                }
+            )])
+        );
+        assert_eq!(
+            err.flatten(),
+            vec![
+                FlatErr { pointer: "/fr".into(), path: "fr".into(), key: true, errors: vec![enumerated.clone()] },
+                FlatErr { pointer: "/fr".into(), path: "fr".into(), key: false, errors: vec![chars_len] },
+            ]
+        );
+        let schema = MapSchema::from(Schema::from(ObjSchema::from([("name".into(), Schema::from(StrSchema::default()))])))
+            .key(Schema::from(EnumSchema::from(["en", "pt"])));
+        let value = Value::from([("fr".into(), Value::from([("name".into(), Value::U64(1))]))]);
+        let err = validate_map(&schema, &value, &Value::None, &SystemClock).unwrap_err();
+        assert_eq!(
+            err,
+            SchemaErr::from([(
+                "fr".into(),
+                SchemaErr::MapKey {
+                    key: Box::new(SchemaErr::from([enumerated.clone()])),
+                    value: Some(Box::new(SchemaErr::from([("name".into(), SchemaErr::from([ValidationErr::Str]))])))
+                }
+            )])
+        );
+        assert_eq!(
+            err.flatten(),
+            vec![
+                FlatErr { pointer: "/fr".into(), path: "fr".into(), key: true, errors: vec![enumerated] },
+                FlatErr { pointer: "/fr/name".into(), path: "fr.name".into(), key: false, errors: vec![ValidationErr::Str] },
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_map_len() {
+        let schema = MapSchema::from(Schema::from(BoolSchema::default())).len_le(2);
+        let value = Value::from([("a".into(), Value::Bool(true)), ("b".into(), Value::Bool(true)), ("c".into(), Value::Bool(false))]);
+        assert_eq!(validate_map(&schema, &value, &Value::None, &SystemClock), Err(SchemaErr::from([LEN_LE])));
+        let value = Value::from([("a".into(), Value::Bool(true)), ("b".into(), Value::Bool(true)), ("c".into(), Value::U64(1))]);
+        let err = validate_map(&schema, &value, &Value::None, &SystemClock).unwrap_err();
+        assert_eq!(
+            err,
+            SchemaErr::Collection { errors: vec![LEN_LE], items: Box::new(SchemaErr::from([("c".into(), SchemaErr::from([ValidationErr::Bool]))])) }
+        );
+        assert_eq!(
+            err.flatten(),
+            vec![
+                FlatErr { pointer: "".into(), path: "".into(), key: false, errors: vec![LEN_LE] },
+                FlatErr { pointer: "/c".into(), path: "c".into(), key: false, errors: vec![ValidationErr::Bool] },
+            ]
+        );
+        assert_eq!(validate_map(&schema, &Value::None, &Value::None, &SystemClock), Err(SchemaErr::from([REQUIRED, MAP, LEN_LE])));
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    error::{SchemaErr, ValidationErr},
+    operation::Clock,
+    schema::{ObjSchema, UnknownKeys},
+    value::Value,
+};
+
+use super::validate_schema;
+
+pub fn validate_obj(schema: &ObjSchema, value: &Value, root: &Value, clock: &dyn Clock) -> Result<(), SchemaErr> {
+    match value {
+        Value::Obj(obj) => {
+            let mut result: BTreeMap<String, SchemaErr> = BTreeMap::new();
+            for (key, item_schema) in &schema.validation {
+                let item = obj.get(key).unwrap_or(&Value::None);
+                if let Err(err) = validate_schema(item_schema, item, root, clock) {
+                    result.insert(key.clone(), err);
+                }
+            }
+            if schema.unknown_keys == UnknownKeys::Reject {
+                for key in obj.keys().filter(|key| !schema.validation.contains_key(*key)) {
+                    result.insert(key.clone(), SchemaErr::from([ValidationErr::UnknownKey]));
+                }
+            }
+            if result.is_empty() { Ok(()) } else { Err(SchemaErr::Obj(result)) }
+        }
+        Value::None if !schema.required => Ok(()),
+        Value::None => Err(SchemaErr::from([ValidationErr::Required, ValidationErr::Obj])),
+        _ => Err(SchemaErr::from([ValidationErr::Obj])),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, sync::LazyLock};
+
+    use crate::{
+        error::{SchemaErr, ValidationErr},
+        operation::{Operand, OperandValue, Operation, SystemClock},
+        schema::{BoolSchema, ObjSchema, Schema, U64Schema, UnknownKeys},
+        value::Value,
+    };
+
+    use super::validate_obj;
+
+    const REQUIRED: ValidationErr = ValidationErr::Required;
+    const OBJ: ValidationErr = ValidationErr::Obj;
+    const OPERATION_LT: ValidationErr = ValidationErr::Operation(Operation::Lt(Operand::Value(OperandValue::U64(150))));
+
+    static SCHEMA: LazyLock<ObjSchema> = LazyLock::new(|| {
+        ObjSchema::from([
+            ("id".into(), Schema::from(U64Schema::default())),
+            ("alive".into(), Schema::from(BoolSchema::default().optional())),
+            ("details".into(), Schema::from(ObjSchema::from([("age".into(), Schema::from(U64Schema::default().lt(150)))]).optional())),
+        ])
+    });
+
+    #[test]
+    fn validate_obj_ok() {
+        let value = Value::from([
+            ("id".into(), Value::U64(1)),
+            ("alive".into(), Value::Bool(true)),
+            ("details".into(), Value::from([("age".into(), Value::U64(82))])),
+            ("unknown".into(), Value::from("unknown")),
+        ]);
+        assert_eq!(validate_obj(&SCHEMA, &value, &value, &SystemClock), Ok(()));
+        assert_eq!(validate_obj(&SCHEMA, &Value::from([("id".into(), Value::U64(1))]), &Value::None, &SystemClock), Ok(()));
+    }
+
+    #[test]
+    fn validate_obj_err() {
+        let value = Value::from([("alive".into(), Value::U64(1)), ("details".into(), Value::from([("age".into(), Value::U64(200))]))]);
+        assert_eq!(
+            validate_obj(&SCHEMA, &value, &value, &SystemClock),
+            Err(SchemaErr::Obj(BTreeMap::from([
+                ("id".into(), SchemaErr::from([REQUIRED, ValidationErr::U64])),
+                ("alive".into(), SchemaErr::from([ValidationErr::Bool])),
+                ("details".into(), SchemaErr::from([("age".into(), SchemaErr::from([OPERATION_LT]))])),
+            ])))
+        );
+    }
+
+    #[test]
+    fn validate_obj_not_obj() {
+        assert_eq!(validate_obj(&SCHEMA, &Value::None, &Value::None, &SystemClock), Err(SchemaErr::from([REQUIRED, OBJ])));
+        assert_eq!(validate_obj(&SCHEMA, &Value::U64(1), &Value::None, &SystemClock), Err(SchemaErr::from([OBJ])));
+        assert_eq!(validate_obj(&SCHEMA, &Value::from([Value::U64(1)]), &Value::None, &SystemClock), Err(SchemaErr::from([OBJ])));
+        assert_eq!(validate_obj(&SCHEMA.clone().optional(), &Value::None, &Value::None, &SystemClock), Ok(()));
+        assert_eq!(validate_obj(&SCHEMA.clone().optional(), &Value::U64(1), &Value::None, &SystemClock), Err(SchemaErr::from([OBJ])));
+    }
+
+    #[test]
+    fn validate_obj_unknown_keys() {
+        let value = Value::from([("id".into(), Value::U64(1)), ("admin".into(), Value::Bool(true)), ("role".into(), Value::from("root"))]);
+        let reject = SCHEMA.clone().unknown_keys(UnknownKeys::Reject);
+        assert_eq!(validate_obj(&SCHEMA, &value, &value, &SystemClock), Ok(()));
+        assert_eq!(validate_obj(&SCHEMA.clone().unknown_keys(UnknownKeys::Passthrough), &value, &value, &SystemClock), Ok(()));
+        assert_eq!(
+            validate_obj(&reject, &value, &value, &SystemClock),
+            Err(SchemaErr::from([
+                ("admin".into(), SchemaErr::from([ValidationErr::UnknownKey])),
+                ("role".into(), SchemaErr::from([ValidationErr::UnknownKey])),
+            ]))
+        );
+        assert_eq!(validate_obj(&reject, &Value::from([("id".into(), Value::U64(1))]), &Value::None, &SystemClock), Ok(()));
+    }
+}
@@ -0,0 +1,338 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    error::{SchemaErr, ValidationErr},
+    operation::{OperandValue, Operation, compare},
+    schema::{StrPredicate, StrSchema},
+    value::Value,
+};
+
+fn str_constraints(schema: &StrSchema) -> Vec<ValidationErr> {
+    let mut base = vec![];
+    for operation in &schema.operations {
+        base.push(ValidationErr::Operation(operation.clone()));
+    }
+    for operation in &schema.bytes_len {
+        base.push(ValidationErr::BytesLen(operation.clone()));
+    }
+    for operation in &schema.chars_len {
+        base.push(ValidationErr::CharsLen(operation.clone()));
+    }
+    for operation in &schema.graphemes_len {
+        base.push(ValidationErr::GraphemesLen(operation.clone()));
+    }
+    for operation in &schema.lowercase_len {
+        base.push(ValidationErr::LowercaseLen(operation.clone()));
+    }
+    for operation in &schema.uppercase_len {
+        base.push(ValidationErr::UppercaseLen(operation.clone()));
+    }
+    for operation in &schema.numbers_len {
+        base.push(ValidationErr::NumbersLen(operation.clone()));
+    }
+    for operation in &schema.symbols_len {
+        base.push(ValidationErr::SymbolsLen(operation.clone()));
+    }
+    for pattern in &schema.patterns {
+        base.push(ValidationErr::Pattern(pattern.as_str().into()));
+    }
+    for predicate in &schema.predicates {
+        base.push(predicate_err(predicate));
+    }
+    base
+}
+
+fn predicate_err(predicate: &StrPredicate) -> ValidationErr {
+    match predicate {
+        StrPredicate::StartsWith(prefix) => ValidationErr::StartsWith(prefix.clone()),
+        StrPredicate::EndsWith(suffix) => ValidationErr::EndsWith(suffix.clone()),
+        StrPredicate::Contains(part) => ValidationErr::Contains(part.clone()),
+        StrPredicate::NotContains(part) => ValidationErr::NotContains(part.clone()),
+        StrPredicate::OnlyChars(charset) => ValidationErr::OnlyChars(charset.clone()),
+        StrPredicate::Trimmed => ValidationErr::Trimmed,
+        StrPredicate::NoControlChars => ValidationErr::NoControlChars,
+        StrPredicate::Normalized(normalization) => ValidationErr::Normalized(*normalization),
+    }
+}
+
+fn len_fails(operations: &[Operation], len: usize, root: &Value) -> Vec<Operation> {
+    operations.iter().filter(|operation| compare(operation, &OperandValue::USize(len), root) == Some(Err(()))).cloned().collect()
+}
+
+pub fn validate_str(schema: &StrSchema, value: &Value, root: &Value) -> Result<(), SchemaErr> {
+    let mut base = vec![];
+    match value {
+        Value::Str(str_value) => {
+            for operation in &schema.operations {
+                if let Some(Err(())) = compare(operation, &OperandValue::Str(str_value.clone()), root) {
+                    base.push(ValidationErr::Operation(operation.clone()));
+                }
+            }
+            for operation in len_fails(&schema.bytes_len, str_value.len(), root) {
+                base.push(ValidationErr::BytesLen(operation));
+            }
+            let len_value = match schema.len_normalization {
+                Some(normalization) => normalization.normalize(str_value),
+                None => str_value.clone(),
+            };
+            for operation in len_fails(&schema.chars_len, len_value.chars().count(), root) {
+                base.push(ValidationErr::CharsLen(operation));
+            }
+            for operation in len_fails(&schema.graphemes_len, len_value.graphemes(true).count(), root) {
+                base.push(ValidationErr::GraphemesLen(operation));
+            }
+            for operation in len_fails(&schema.lowercase_len, str_value.chars().filter(|c| c.is_lowercase()).count(), root) {
+                base.push(ValidationErr::LowercaseLen(operation));
+            }
+            for operation in len_fails(&schema.uppercase_len, str_value.chars().filter(|c| c.is_uppercase()).count(), root) {
+                base.push(ValidationErr::UppercaseLen(operation));
+            }
+            for operation in len_fails(&schema.numbers_len, str_value.chars().filter(|c| c.is_ascii_digit()).count(), root) {
+                base.push(ValidationErr::NumbersLen(operation));
+            }
+            for operation in len_fails(&schema.symbols_len, str_value.chars().filter(|c| c.is_ascii_punctuation()).count(), root) {
+                base.push(ValidationErr::SymbolsLen(operation));
+            }
+            for pattern in schema.patterns.iter().filter(|pattern| !pattern.is_match(str_value)) {
+                base.push(ValidationErr::Pattern(pattern.as_str().into()));
+            }
+            for predicate in schema.predicates.iter().filter(|predicate| !predicate.test(str_value)) {
+                base.push(predicate_err(predicate));
+            }
+        }
+        Value::None => {
+            if schema.required {
+                base.push(ValidationErr::Required);
+                base.push(ValidationErr::Str);
+                base.append(&mut str_constraints(schema));
+            }
+        }
+        _ => {
+            base.push(ValidationErr::Str);
+            base.append(&mut str_constraints(schema));
+        }
+    }
+    if base.is_empty() { Ok(()) } else { Err(SchemaErr::Validation(base)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        error::{SchemaErr, ValidationErr},
+        operation::{Operand, OperandValue, Operation},
+        schema::{Charset, Normalization, Pattern, StrSchema},
+        value::Value,
+    };
+
+    use super::validate_str;
+
+    const REQUIRED: ValidationErr = ValidationErr::Required;
+    const STR: ValidationErr = ValidationErr::Str;
+
+    const LEN_3: Operand = Operand::Value(OperandValue::USize(3));
+
+    #[test]
+    fn validate_str_default() {
+        let schema = StrSchema::default();
+        assert_eq!(validate_str(&schema, &Value::from("Ad infinitum"), &Value::None), Ok(()));
+        assert_eq!(validate_str(&schema, &Value::from(""), &Value::None), Ok(()));
+        assert_eq!(validate_str(&schema, &Value::None, &Value::None), Err(SchemaErr::from([REQUIRED, STR])));
+        assert_eq!(validate_str(&schema, &Value::U64(42), &Value::None), Err(SchemaErr::from([STR])));
+    }
+
+    #[test]
+    fn validate_str_optional() {
+        let schema = StrSchema::default().optional();
+        assert_eq!(validate_str(&schema, &Value::from("Ad infinitum"), &Value::None), Ok(()));
+        assert_eq!(validate_str(&schema, &Value::None, &Value::None), Ok(()));
+        assert_eq!(validate_str(&schema, &Value::U64(42), &Value::None), Err(SchemaErr::from([STR])));
+    }
+
+    #[test]
+    fn validate_str_operation() {
+        let schema = StrSchema::default().eq("Ad infinitum".into());
+        let operation = ValidationErr::Operation(Operation::Eq(Operand::Value(OperandValue::from("Ad infinitum"))));
+        assert_eq!(validate_str(&schema, &Value::from("Ad infinitum"), &Value::None), Ok(()));
+        assert_eq!(validate_str(&schema, &Value::from("Ad nauseam"), &Value::None), Err(SchemaErr::from([operation.clone()])));
+        assert_eq!(validate_str(&schema, &Value::None, &Value::None), Err(SchemaErr::from([REQUIRED, STR, operation])));
+    }
+
+    #[test]
+    fn validate_str_operation_field() {
+        let schema = StrSchema::default().ne_field("username".into());
+        let root = Value::Obj(BTreeMap::from([("username".into(), Value::from("paul"))]));
+        let operation = ValidationErr::Operation(Operation::Ne(Operand::FieldPath("username".into())));
+        assert_eq!(validate_str(&schema, &Value::from("p4ssw0rd"), &root), Ok(()));
+        assert_eq!(validate_str(&schema, &Value::from("paul"), &root), Err(SchemaErr::from([operation])));
+    }
+
+    #[test]
+    fn validate_str_len() {
+        assert_eq!(
+            validate_str(&StrSchema::default().bytes_len_eq(3), &Value::from("açú"), &Value::None),
+            Err(SchemaErr::from([ValidationErr::BytesLen(Operation::Eq(LEN_3))]))
+        );
+        assert_eq!(validate_str(&StrSchema::default().bytes_len_eq(5), &Value::from("açú"), &Value::None), Ok(()));
+        assert_eq!(validate_str(&StrSchema::default().chars_len_eq(3), &Value::from("açú"), &Value::None), Ok(()));
+        assert_eq!(
+            validate_str(&StrSchema::default().chars_len_eq(3), &Value::from("👩‍👩‍👧"), &Value::None),
+            Err(SchemaErr::from([ValidationErr::CharsLen(Operation::Eq(LEN_3))]))
+        );
+        assert_eq!(
+            validate_str(&StrSchema::default().graphemes_len_eq(3), &Value::from("👩‍👩‍👧"), &Value::None),
+            Err(SchemaErr::from([ValidationErr::GraphemesLen(Operation::Eq(LEN_3))]))
+        );
+        assert_eq!(validate_str(&StrSchema::default().graphemes_len_eq(1), &Value::from("👩‍👩‍👧"), &Value::None), Ok(()));
+        assert_eq!(
+            validate_str(&StrSchema::default().lowercase_len_ge(3), &Value::from("aBcD"), &Value::None),
+            Err(SchemaErr::from([ValidationErr::LowercaseLen(Operation::Ge(LEN_3))]))
+        );
+        assert_eq!(validate_str(&StrSchema::default().lowercase_len_ge(3), &Value::from("aBcDe"), &Value::None), Ok(()));
+        assert_eq!(
+            validate_str(&StrSchema::default().uppercase_len_ge(3), &Value::from("AbCd"), &Value::None),
+            Err(SchemaErr::from([ValidationErr::UppercaseLen(Operation::Ge(LEN_3))]))
+        );
+        assert_eq!(validate_str(&StrSchema::default().uppercase_len_ge(3), &Value::from("AbCdE"), &Value::None), Ok(()));
+        assert_eq!(
+            validate_str(&StrSchema::default().numbers_len_ge(3), &Value::from("a1b2"), &Value::None),
+            Err(SchemaErr::from([ValidationErr::NumbersLen(Operation::Ge(LEN_3))]))
+        );
+        assert_eq!(validate_str(&StrSchema::default().numbers_len_ge(3), &Value::from("a1b2c3"), &Value::None), Ok(()));
+        assert_eq!(
+            validate_str(&StrSchema::default().symbols_len_ge(3), &Value::from("a!b@"), &Value::None),
+            Err(SchemaErr::from([ValidationErr::SymbolsLen(Operation::Ge(LEN_3))]))
+        );
+        assert_eq!(validate_str(&StrSchema::default().symbols_len_ge(3), &Value::from("a!b@c#"), &Value::None), Ok(()));
+    }
+
+    #[test]
+    fn validate_str_len_none() {
+        let schema = StrSchema::default().chars_len_btwn(1, 64).uppercase_len_gt(1).numbers_len_gt(1);
+        let chars_len = ValidationErr::CharsLen(Operation::Btwn(Operand::Value(OperandValue::USize(1)), Operand::Value(OperandValue::USize(64))));
+        let uppercase_len = ValidationErr::UppercaseLen(Operation::Gt(Operand::Value(OperandValue::USize(1))));
+        let numbers_len = ValidationErr::NumbersLen(Operation::Gt(Operand::Value(OperandValue::USize(1))));
+        assert_eq!(
+            validate_str(&schema, &Value::None, &Value::None),
+            Err(SchemaErr::from([REQUIRED, STR, chars_len.clone(), uppercase_len.clone(), numbers_len.clone()]))
+        );
+        assert_eq!(
+            validate_str(&schema, &Value::Bool(true), &Value::None),
+            Err(SchemaErr::from([STR, chars_len.clone(), uppercase_len.clone(), numbers_len.clone()]))
+        );
+        assert_eq!(validate_str(&schema, &Value::from("paul"), &Value::None), Err(SchemaErr::from([uppercase_len, numbers_len])));
+    }
+
+    #[test]
+    fn validate_str_len_multiple() {
+        let schema = StrSchema::default().chars_len_ge(3).chars_len_ne(4).numbers_len_ge(1).numbers_len_le(2);
+        let chars_len_ge = ValidationErr::CharsLen(Operation::Ge(LEN_3));
+        let chars_len_ne = ValidationErr::CharsLen(Operation::Ne(Operand::Value(OperandValue::USize(4))));
+        let numbers_len_ge = ValidationErr::NumbersLen(Operation::Ge(Operand::Value(OperandValue::USize(1))));
+        let numbers_len_le = ValidationErr::NumbersLen(Operation::Le(Operand::Value(OperandValue::USize(2))));
+        assert_eq!(validate_str(&schema, &Value::from("abc1"), &Value::None), Err(SchemaErr::from([chars_len_ne])));
+        assert_eq!(validate_str(&schema, &Value::from("a1"), &Value::None), Err(SchemaErr::from([chars_len_ge])));
+        assert_eq!(validate_str(&schema, &Value::from("a1234"), &Value::None), Err(SchemaErr::from([numbers_len_le])));
+        assert_eq!(validate_str(&schema, &Value::from("abcde"), &Value::None), Err(SchemaErr::from([numbers_len_ge])));
+        assert_eq!(validate_str(&schema, &Value::from("abc12"), &Value::None), Ok(()));
+    }
+
+    #[test]
+    fn validate_str_pattern() {
+        let schema = StrSchema::default().pattern(Pattern::new("^[0-9]{5}-[0-9]{3}$").unwrap()).pattern(Pattern::new("^[0-8]").unwrap());
+        let pattern_zip = ValidationErr::Pattern("^[0-9]{5}-[0-9]{3}$".into());
+        let pattern_digit = ValidationErr::Pattern("^[0-8]".into());
+        assert_eq!(validate_str(&schema, &Value::from("01310-200"), &Value::None), Ok(()));
+        assert_eq!(validate_str(&schema, &Value::from("91310-200"), &Value::None), Err(SchemaErr::from([pattern_digit.clone()])));
+        assert_eq!(validate_str(&schema, &Value::from("ABC"), &Value::None), Err(SchemaErr::from([pattern_zip.clone(), pattern_digit.clone()])));
+        assert_eq!(
+            validate_str(&schema, &Value::None, &Value::None),
+            Err(SchemaErr::from([REQUIRED, STR, pattern_zip.clone(), pattern_digit.clone()]))
+        );
+        assert_eq!(validate_str(&schema, &Value::Bool(true), &Value::None), Err(SchemaErr::from([STR, pattern_zip, pattern_digit])));
+        assert_eq!(validate_str(&schema.optional(), &Value::None, &Value::None), Ok(()));
+    }
+
+    #[test]
+    fn validate_str_predicates() {
+        let schema = StrSchema::default().starts_with("sk_").ends_with("_live").contains("0").not_contains("__");
+        let starts_with = ValidationErr::StartsWith("sk_".into());
+        let ends_with = ValidationErr::EndsWith("_live".into());
+        let contains = ValidationErr::Contains("0".into());
+        let not_contains = ValidationErr::NotContains("__".into());
+        assert_eq!(validate_str(&schema, &Value::from("sk_a0b_live"), &Value::None), Ok(()));
+        assert_eq!(validate_str(&schema, &Value::from("pk_a0b_live"), &Value::None), Err(SchemaErr::from([starts_with.clone()])));
+        assert_eq!(validate_str(&schema, &Value::from("sk_a0b_test"), &Value::None), Err(SchemaErr::from([ends_with.clone()])));
+        assert_eq!(validate_str(&schema, &Value::from("sk_ab_live"), &Value::None), Err(SchemaErr::from([contains.clone()])));
+        assert_eq!(validate_str(&schema, &Value::from("sk__0_live"), &Value::None), Err(SchemaErr::from([not_contains.clone()])));
+        assert_eq!(
+            validate_str(&schema, &Value::None, &Value::None),
+            Err(SchemaErr::from([REQUIRED, STR, starts_with.clone(), ends_with.clone(), contains.clone(), not_contains.clone()]))
+        );
+        assert_eq!(
+            validate_str(&schema, &Value::Bool(true), &Value::None),
+            Err(SchemaErr::from([STR, starts_with, ends_with, contains, not_contains]))
+        );
+        assert_eq!(validate_str(&schema.optional(), &Value::None, &Value::None), Ok(()));
+    }
+
+    #[test]
+    fn validate_str_only_chars() {
+        let hex = StrSchema::default().only_chars(Charset::Hex);
+        assert_eq!(validate_str(&hex, &Value::from("deadBEEF01"), &Value::None), Ok(()));
+        assert_eq!(validate_str(&hex, &Value::from(""), &Value::None), Ok(()));
+        assert_eq!(validate_str(&hex, &Value::from("0xff"), &Value::None), Err(SchemaErr::from([ValidationErr::OnlyChars(Charset::Hex)])));
+        let alphanumeric = StrSchema::default().only_chars(Charset::AsciiAlphanumeric);
+        assert_eq!(validate_str(&alphanumeric, &Value::from("Avalon42"), &Value::None), Ok(()));
+        assert_eq!(
+            validate_str(&alphanumeric, &Value::from("Avalon 42"), &Value::None),
+            Err(SchemaErr::from([ValidationErr::OnlyChars(Charset::AsciiAlphanumeric)]))
+        );
+        let base64_url = StrSchema::default().only_chars(Charset::Base64Url);
+        assert_eq!(validate_str(&base64_url, &Value::from("dGVzdA-_"), &Value::None), Ok(()));
+        assert_eq!(
+            validate_str(&base64_url, &Value::from("dGVzdA=="), &Value::None),
+            Err(SchemaErr::from([ValidationErr::OnlyChars(Charset::Base64Url)]))
+        );
+        let custom = StrSchema::default().only_chars(Charset::Custom("ACGT".into()));
+        assert_eq!(validate_str(&custom, &Value::from("GATTACA"), &Value::None), Ok(()));
+        assert_eq!(
+            validate_str(&custom, &Value::from("GATTACU"), &Value::None),
+            Err(SchemaErr::from([ValidationErr::OnlyChars(Charset::Custom("ACGT".into()))]))
+        );
+    }
+
+    #[test]
+    fn validate_str_unicode() {
+        let schema = StrSchema::default().trimmed().no_control_chars().normalized(Normalization::Nfc);
+        let trimmed = ValidationErr::Trimmed;
+        let no_control_chars = ValidationErr::NoControlChars;
+        let normalized = ValidationErr::Normalized(Normalization::Nfc);
+        assert_eq!(validate_str(&schema, &Value::from("Jos\u{00E9}"), &Value::None), Ok(()));
+        assert_eq!(validate_str(&schema, &Value::from(" Jos\u{00E9} "), &Value::None), Err(SchemaErr::from([trimmed.clone()])));
+        assert_eq!(validate_str(&schema, &Value::from("Jos\u{00E9}\u{200B}"), &Value::None), Err(SchemaErr::from([no_control_chars.clone()])));
+        assert_eq!(validate_str(&schema, &Value::from("Jose\u{0301}"), &Value::None), Err(SchemaErr::from([normalized.clone()])));
+        assert_eq!(
+            validate_str(&schema, &Value::None, &Value::None),
+            Err(SchemaErr::from([REQUIRED, STR, trimmed.clone(), no_control_chars.clone(), normalized.clone()]))
+        );
+        assert_eq!(validate_str(&schema, &Value::U64(1), &Value::None), Err(SchemaErr::from([STR, trimmed, no_control_chars, normalized])));
+    }
+
+    #[test]
+    fn validate_str_normalize_len() {
+        let schema = StrSchema::default().chars_len_le(4);
+        assert_eq!(
+            validate_str(&schema, &Value::from("Jose\u{0301}"), &Value::None),
+            Err(SchemaErr::from([ValidationErr::CharsLen(Operation::Le(Operand::Value(OperandValue::USize(4))))]))
+        );
+        assert_eq!(validate_str(&schema.clone().normalize_len(Normalization::Nfc), &Value::from("Jose\u{0301}"), &Value::None), Ok(()));
+        let schema = StrSchema::default().graphemes_len_eq(4).normalize_len(Normalization::Nfkc);
+        assert_eq!(validate_str(&schema, &Value::from("\u{FB01}le"), &Value::None), Ok(()));
+        assert_eq!(
+            validate_str(&StrSchema::default().graphemes_len_eq(4), &Value::from("\u{FB01}le"), &Value::None),
+            Err(SchemaErr::from([ValidationErr::GraphemesLen(Operation::Eq(Operand::Value(OperandValue::USize(4))))]))
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{
+    error::{SchemaErr, ValidationErr},
+    operation::Clock,
+    schema::{EnumValues, TaggedSchema},
+    value::Value,
+};
+
+use super::validate_obj;
+
+pub fn validate_tagged(schema: &TaggedSchema, value: &Value, root: &Value, clock: &dyn Clock) -> Result<(), SchemaErr> {
+    match value {
+        Value::Obj(obj) => {
+            let variant = match obj.get(&schema.tag) {
+                Some(Value::Str(tag_value)) => schema.variants.get_key_value(tag_value),
+                _ => None,
+            };
+            let Some((tag_value, variant)) = variant else {
+                let tag_values = EnumValues::from(schema.variants.keys().cloned().collect::<Vec<String>>());
+                return Err(SchemaErr::from([(schema.tag.clone(), SchemaErr::from([ValidationErr::Enumerated(tag_values)]))]));
+            };
+            let result = if variant.validation.contains_key(&schema.tag) {
+                validate_obj(variant, value, root, clock)
+            } else {
+                let mut untagged = obj.clone();
+                untagged.remove(&schema.tag);
+                validate_obj(variant, &Value::Obj(untagged), root, clock)
+            };
+            result.map_err(|err| SchemaErr::Tagged(tag_value.clone(), Box::new(err)))
+        }
+        Value::None if !schema.required => Ok(()),
+        Value::None => Err(SchemaErr::from([ValidationErr::Required, ValidationErr::Obj])),
+        _ => Err(SchemaErr::from([ValidationErr::Obj])),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::LazyLock;
+
+    use crate::{
+        error::{SchemaErr, ValidationErr},
+        operation::SystemClock,
+        schema::{ObjSchema, Schema, StrSchema, TaggedSchema, U64Schema, UnknownKeys},
+        value::Value,
+    };
+
+    use super::validate_tagged;
+
+    static SCHEMA: LazyLock<TaggedSchema> = LazyLock::new(|| {
+        TaggedSchema::from("type".to_string())
+            .variant(
+                "click".into(),
+                ObjSchema::from([("x".into(), Schema::from(U64Schema::default())), ("y".into(), Schema::from(U64Schema::default()))])
+                    .unknown_keys(UnknownKeys::Reject),
+            )
+            .variant("key".into(), ObjSchema::from([("code".into(), Schema::from(StrSchema::default()))]))
+    });
+
+    #[test]
+    fn validate_tagged_ok() {
+        let click = Value::from([("type".into(), Value::from("click")), ("x".into(), Value::U64(1)), ("y".into(), Value::U64(2))]);
+        let key = Value::from([("type".into(), Value::from("key")), ("code".into(), Value::from("Enter"))]);
+        assert_eq!(validate_tagged(&SCHEMA, &click, &click, &SystemClock), Ok(()));
+        assert_eq!(validate_tagged(&SCHEMA, &key, &key, &SystemClock), Ok(()));
+        assert_eq!(validate_tagged(&SCHEMA.clone().optional(), &Value::None, &Value::None, &SystemClock), Ok(()));
+    }
+
+    #[test]
+    fn validate_tagged_err() {
+        let click = Value::from([("type".into(), Value::from("click")), ("x".into(), Value::U64(1)), ("code".into(), Value::from("Enter"))]);
+        let tag = SchemaErr::from([("type".into(), SchemaErr::from([ValidationErr::Enumerated(["click", "key"].into())]))]);
+        assert_eq!(
+            validate_tagged(&SCHEMA, &click, &click, &SystemClock),
+            Err(SchemaErr::Tagged(
+                "click".into(),
+                Box::new(SchemaErr::from([
+                    ("code".into(), SchemaErr::from([ValidationErr::UnknownKey])),
+                    ("y".into(), SchemaErr::from([ValidationErr::Required, ValidationErr::U64])),
+                ]))
+            ))
+        );
+        assert_eq!(validate_tagged(&SCHEMA, &Value::from([("type".into(), Value::from("scroll"))]), &Value::None, &SystemClock), Err(tag.clone()));
+        assert_eq!(validate_tagged(&SCHEMA, &Value::from([("x".into(), Value::U64(1))]), &Value::None, &SystemClock), Err(tag));
+        assert_eq!(
+            validate_tagged(&SCHEMA, &Value::None, &Value::None, &SystemClock),
+            Err(SchemaErr::from([ValidationErr::Required, ValidationErr::Obj]))
+        );
+        assert_eq!(validate_tagged(&SCHEMA, &Value::U64(1), &Value::None, &SystemClock), Err(SchemaErr::from([ValidationErr::Obj])));
+    }
+}
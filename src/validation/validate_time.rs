@@ -0,0 +1,109 @@
+use crate::{
+    error::{SchemaErr, ValidationErr},
+    operation::{OperandValue, compare, parse_time},
+    schema::TimeSchema,
+    value::Value,
+};
+
+pub fn validate_time(schema: &TimeSchema, value: &Value, root: &Value) -> Result<(), SchemaErr> {
+    let mut base = vec![];
+    let time_value = if let Value::Str(str_value) = value { parse_time(str_value) } else { None };
+    match (time_value, value) {
+        (Some(time_value), _) => {
+            for operation in &schema.operations {
+                if let Some(Err(())) = compare(operation, &OperandValue::Time(time_value), root) {
+                    base.push(ValidationErr::Operation(operation.clone()));
+                }
+            }
+        }
+        (None, Value::None) => {
+            if schema.required {
+                base.push(ValidationErr::Required);
+                base.push(ValidationErr::Time);
+                for operation in &schema.operations {
+                    base.push(ValidationErr::Operation(operation.clone()));
+                }
+            }
+        }
+        _ => {
+            base.push(ValidationErr::Time);
+            for operation in &schema.operations {
+                base.push(ValidationErr::Operation(operation.clone()));
+            }
+        }
+    }
+    if base.is_empty() { Ok(()) } else { Err(SchemaErr::Validation(base)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        error::{SchemaErr, ValidationErr},
+        operation::{Operand, OperandValue, Operation, parse_time},
+        schema::TimeSchema,
+        value::Value,
+    };
+
+    use super::validate_time;
+
+    const REQUIRED: ValidationErr = ValidationErr::Required;
+    const TIME: ValidationErr = ValidationErr::Time;
+
+    #[test]
+    fn validate_time_default() {
+        let schema = TimeSchema::default();
+        assert_eq!(validate_time(&schema, &Value::from("10:27"), &Value::None), Ok(()));
+        assert_eq!(validate_time(&schema, &Value::None, &Value::None), Err(SchemaErr::from([REQUIRED, TIME])));
+        assert_eq!(validate_time(&schema, &Value::from("10:72"), &Value::None), Err(SchemaErr::from([TIME])));
+        assert_eq!(validate_time(&schema, &Value::U64(1027), &Value::None), Err(SchemaErr::from([TIME])));
+    }
+
+    #[test]
+    fn validate_time_optional() {
+        let schema = TimeSchema::default().optional();
+        assert_eq!(validate_time(&schema, &Value::from("10:27"), &Value::None), Ok(()));
+        assert_eq!(validate_time(&schema, &Value::None, &Value::None), Ok(()));
+        assert_eq!(validate_time(&schema, &Value::from("10h27"), &Value::None), Err(SchemaErr::from([TIME])));
+    }
+
+    #[test]
+    fn validate_time_operation_value() {
+        let schema = TimeSchema::default().ge(parse_time("08:00").unwrap());
+        let operation = ValidationErr::Operation(Operation::Ge(Operand::Value(OperandValue::Time(parse_time("08:00").unwrap()))));
+        assert_eq!(validate_time(&schema, &Value::from("08:00"), &Value::None), Ok(()));
+        assert_eq!(validate_time(&schema, &Value::from("07:59"), &Value::None), Err(SchemaErr::from([operation.clone()])));
+        assert_eq!(validate_time(&schema, &Value::None, &Value::None), Err(SchemaErr::from([REQUIRED, TIME, operation.clone()])));
+        assert_eq!(validate_time(&schema, &Value::Bool(false), &Value::None), Err(SchemaErr::from([TIME, operation])));
+    }
+
+    #[test]
+    fn validate_time_operation_field() {
+        let schema = TimeSchema::default().lt_field("end".into());
+        let root = Value::Obj(BTreeMap::from([("end".into(), Value::from("18:00"))]));
+        let operation = ValidationErr::Operation(Operation::Lt(Operand::FieldPath("end".into())));
+        assert_eq!(validate_time(&schema, &Value::from("17:59"), &root), Ok(()));
+        assert_eq!(validate_time(&schema, &Value::from("18:00"), &root), Err(SchemaErr::from([operation])));
+        assert_eq!(validate_time(&schema, &Value::from("18:00"), &Value::None), Ok(()));
+    }
+
+    #[test]
+    fn validate_time_end_of_day() {
+        let schema = TimeSchema::default().gt(parse_time("23:00").unwrap());
+        assert_eq!(validate_time(&schema, &Value::from("24:00"), &Value::None), Ok(()));
+        assert_eq!(validate_time(&schema, &Value::from("23:59:59.5"), &Value::None), Ok(()));
+        assert_eq!(
+            validate_time(&schema, &Value::from("24:01"), &Value::None),
+            Err(SchemaErr::from([TIME, ValidationErr::Operation(Operation::Gt(Operand::Value(OperandValue::Time(parse_time("23:00").unwrap()))))]))
+        );
+    }
+
+    #[test]
+    fn validate_time_multibyte() {
+        let schema = TimeSchema::default();
+        assert_eq!(validate_time(&schema, &Value::from("12:0é"), &Value::None), Err(SchemaErr::from([TIME])));
+        assert_eq!(validate_time(&schema, &Value::from("12:00:00é"), &Value::None), Err(SchemaErr::from([TIME])));
+        assert_eq!(validate_time(&schema, &Value::from("12:00:00.5é"), &Value::None), Err(SchemaErr::from([TIME])));
+    }
+}
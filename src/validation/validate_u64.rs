@@ -0,0 +1,100 @@
+use crate::{
+    error::{SchemaErr, ValidationErr},
+    operation::{OperandValue, compare},
+    schema::U64Schema,
+    value::Value,
+};
+
+pub fn validate_u64(schema: &U64Schema, value: &Value, root: &Value) -> Result<(), SchemaErr> {
+    let mut base = vec![];
+    match value {
+        Value::U64(u64_value) => {
+            for operation in &schema.operations {
+                if let Some(Err(())) = compare(operation, &OperandValue::U64(*u64_value), root) {
+                    base.push(ValidationErr::Operation(operation.clone()));
+                }
+            }
+        }
+        Value::None => {
+            if schema.required {
+                base.push(ValidationErr::Required);
+                base.push(ValidationErr::U64);
+                for operation in &schema.operations {
+                    base.push(ValidationErr::Operation(operation.clone()));
+                }
+            }
+        }
+        _ => {
+            base.push(ValidationErr::U64);
+            for operation in &schema.operations {
+                base.push(ValidationErr::Operation(operation.clone()));
+            }
+        }
+    }
+    if base.is_empty() { Ok(()) } else { Err(SchemaErr::Validation(base)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        error::{SchemaErr, ValidationErr},
+        operation::{Operand, OperandValue, Operation},
+        schema::U64Schema,
+        value::Value,
+    };
+
+    use super::validate_u64;
+
+    const REQUIRED: ValidationErr = ValidationErr::Required;
+    const U64: ValidationErr = ValidationErr::U64;
+    const OPERATION_GT: ValidationErr = ValidationErr::Operation(Operation::Gt(Operand::Value(OperandValue::U64(42))));
+
+    #[test]
+    fn validate_u64_default() {
+        let schema = U64Schema::default();
+        assert_eq!(validate_u64(&schema, &Value::U64(42), &Value::None), Ok(()));
+        assert_eq!(validate_u64(&schema, &Value::None, &Value::None), Err(SchemaErr::from([REQUIRED, U64])));
+        assert_eq!(validate_u64(&schema, &Value::I64(-42), &Value::None), Err(SchemaErr::from([U64])));
+        assert_eq!(validate_u64(&schema, &Value::from("42"), &Value::None), Err(SchemaErr::from([U64])));
+    }
+
+    #[test]
+    fn validate_u64_optional() {
+        let schema = U64Schema::default().optional();
+        assert_eq!(validate_u64(&schema, &Value::U64(42), &Value::None), Ok(()));
+        assert_eq!(validate_u64(&schema, &Value::None, &Value::None), Ok(()));
+        assert_eq!(validate_u64(&schema, &Value::I64(-42), &Value::None), Err(SchemaErr::from([U64])));
+    }
+
+    #[test]
+    fn validate_u64_operation_value() {
+        let schema = U64Schema::default().gt(42);
+        assert_eq!(validate_u64(&schema, &Value::U64(43), &Value::None), Ok(()));
+        assert_eq!(validate_u64(&schema, &Value::U64(42), &Value::None), Err(SchemaErr::from([OPERATION_GT])));
+        assert_eq!(validate_u64(&schema, &Value::None, &Value::None), Err(SchemaErr::from([REQUIRED, U64, OPERATION_GT])));
+        assert_eq!(validate_u64(&schema, &Value::Bool(false), &Value::None), Err(SchemaErr::from([U64, OPERATION_GT])));
+    }
+
+    #[test]
+    fn validate_u64_operation_field() {
+        let schema = U64Schema::default().gt_field("min".into());
+        let root = Value::Obj(BTreeMap::from([("min".into(), Value::U64(42))]));
+        let operation = ValidationErr::Operation(Operation::Gt(Operand::FieldPath("min".into())));
+        assert_eq!(validate_u64(&schema, &Value::U64(43), &root), Ok(()));
+        assert_eq!(validate_u64(&schema, &Value::U64(42), &root), Err(SchemaErr::from([operation])));
+        assert_eq!(validate_u64(&schema, &Value::U64(42), &Value::None), Ok(()));
+    }
+
+    #[test]
+    fn validate_u64_operations() {
+        let schema = U64Schema::default().btwn(1, 100).ne(13);
+        let btwn = ValidationErr::Operation(Operation::Btwn(Operand::Value(OperandValue::U64(1)), Operand::Value(OperandValue::U64(100))));
+        let ne = ValidationErr::Operation(Operation::Ne(Operand::Value(OperandValue::U64(13))));
+        assert_eq!(validate_u64(&schema, &Value::U64(12), &Value::None), Ok(()));
+        assert_eq!(validate_u64(&schema, &Value::U64(13), &Value::None), Err(SchemaErr::from([ne.clone()])));
+        assert_eq!(validate_u64(&schema, &Value::U64(101), &Value::None), Err(SchemaErr::from([btwn.clone()])));
+        assert_eq!(validate_u64(&schema, &Value::None, &Value::None), Err(SchemaErr::from([REQUIRED, U64, btwn, ne])));
+    }
+}
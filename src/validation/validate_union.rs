@@ -0,0 +1,62 @@
+use crate::{
+    error::{SchemaErr, ValidationErr},
+    operation::Clock,
+    schema::UnionSchema,
+    value::Value,
+};
+
+use super::validate_schema;
+
+pub fn validate_union(schema: &UnionSchema, value: &Value, root: &Value, clock: &dyn Clock) -> Result<(), SchemaErr> {
+    match value {
+        Value::None if !schema.required => Ok(()),
+        Value::None => Err(SchemaErr::from([ValidationErr::Required])),
+        _ => {
+            let mut result: Vec<SchemaErr> = Vec::new();
+            for variant in &schema.variants {
+                match validate_schema(variant, value, root, clock) {
+                    Ok(()) => return Ok(()),
+                    Err(err) => result.push(err),
+                }
+            }
+            Err(SchemaErr::Union(result))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{SchemaErr, ValidationErr},
+        operation::{Operand, OperandValue, Operation, SystemClock},
+        schema::{Schema, StrSchema, U64Schema, UnionSchema},
+        value::Value,
+    };
+
+    use super::validate_union;
+
+    #[test]
+    fn validate_union_default() {
+        let schema = UnionSchema::from([Schema::from(StrSchema::default()), Schema::from(U64Schema::default().gt(10))]);
+        assert_eq!(validate_union(&schema, &Value::from("ten"), &Value::None, &SystemClock), Ok(()));
+        assert_eq!(validate_union(&schema, &Value::U64(11), &Value::None, &SystemClock), Ok(()));
+        assert_eq!(validate_union(&schema, &Value::None, &Value::None, &SystemClock), Err(SchemaErr::from([ValidationErr::Required])));
+        assert_eq!(
+            validate_union(&schema, &Value::U64(10), &Value::None, &SystemClock),
+            Err(SchemaErr::Union(vec![
+                SchemaErr::from([ValidationErr::Str]),
+                SchemaErr::from([ValidationErr::Operation(Operation::Gt(Operand::Value(OperandValue::U64(10))))])
+            ]))
+        );
+    }
+
+    #[test]
+    fn validate_union_optional() {
+        let schema = UnionSchema::from([Schema::from(StrSchema::default()), Schema::from(U64Schema::default())]).optional();
+        assert_eq!(validate_union(&schema, &Value::None, &Value::None, &SystemClock), Ok(()));
+        assert_eq!(
+            validate_union(&schema, &Value::Bool(true), &Value::None, &SystemClock),
+            Err(SchemaErr::Union(vec![SchemaErr::from([ValidationErr::Str]), SchemaErr::from([ValidationErr::U64])]))
+        );
+    }
+}
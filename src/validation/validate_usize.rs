@@ -0,0 +1,89 @@
+use crate::{
+    error::{SchemaErr, ValidationErr},
+    operation::{OperandValue, compare},
+    schema::USizeSchema,
+    value::Value,
+};
+
+pub fn validate_usize(schema: &USizeSchema, value: &Value, root: &Value) -> Result<(), SchemaErr> {
+    let mut base = vec![];
+    match value {
+        Value::USize(usize_value) => {
+            for operation in &schema.operations {
+                if let Some(Err(())) = compare(operation, &OperandValue::USize(*usize_value), root) {
+                    base.push(ValidationErr::Operation(operation.clone()));
+                }
+            }
+        }
+        Value::None => {
+            if schema.required {
+                base.push(ValidationErr::Required);
+                base.push(ValidationErr::USize);
+                for operation in &schema.operations {
+                    base.push(ValidationErr::Operation(operation.clone()));
+                }
+            }
+        }
+        _ => {
+            base.push(ValidationErr::USize);
+            for operation in &schema.operations {
+                base.push(ValidationErr::Operation(operation.clone()));
+            }
+        }
+    }
+    if base.is_empty() { Ok(()) } else { Err(SchemaErr::Validation(base)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        error::{SchemaErr, ValidationErr},
+        operation::{Operand, OperandValue, Operation},
+        schema::USizeSchema,
+        value::Value,
+    };
+
+    use super::validate_usize;
+
+    const REQUIRED: ValidationErr = ValidationErr::Required;
+    const USIZE: ValidationErr = ValidationErr::USize;
+    const OPERATION_GT: ValidationErr = ValidationErr::Operation(Operation::Gt(Operand::Value(OperandValue::USize(42))));
+
+    #[test]
+    fn validate_usize_default() {
+        let schema = USizeSchema::default();
+        assert_eq!(validate_usize(&schema, &Value::USize(42), &Value::None), Ok(()));
+        assert_eq!(validate_usize(&schema, &Value::None, &Value::None), Err(SchemaErr::from([REQUIRED, USIZE])));
+        assert_eq!(validate_usize(&schema, &Value::U64(42), &Value::None), Err(SchemaErr::from([USIZE])));
+        assert_eq!(validate_usize(&schema, &Value::from("42"), &Value::None), Err(SchemaErr::from([USIZE])));
+    }
+
+    #[test]
+    fn validate_usize_optional() {
+        let schema = USizeSchema::default().optional();
+        assert_eq!(validate_usize(&schema, &Value::USize(42), &Value::None), Ok(()));
+        assert_eq!(validate_usize(&schema, &Value::None, &Value::None), Ok(()));
+        assert_eq!(validate_usize(&schema, &Value::U64(42), &Value::None), Err(SchemaErr::from([USIZE])));
+    }
+
+    #[test]
+    fn validate_usize_operation_value() {
+        let schema = USizeSchema::default().gt(42);
+        assert_eq!(validate_usize(&schema, &Value::USize(43), &Value::None), Ok(()));
+        assert_eq!(validate_usize(&schema, &Value::USize(42), &Value::None), Err(SchemaErr::from([OPERATION_GT])));
+        assert_eq!(validate_usize(&schema, &Value::None, &Value::None), Err(SchemaErr::from([REQUIRED, USIZE, OPERATION_GT])));
+        assert_eq!(validate_usize(&schema, &Value::Bool(false), &Value::None), Err(SchemaErr::from([USIZE, OPERATION_GT])));
+    }
+
+    #[test]
+    fn validate_usize_operation_field() {
+        let schema = USizeSchema::default().gt_field("min".into());
+        let root = Value::Obj(BTreeMap::from([("min".into(), Value::USize(42))]));
+        let operation = ValidationErr::Operation(Operation::Gt(Operand::FieldPath("min".into())));
+        assert_eq!(validate_usize(&schema, &Value::USize(43), &root), Ok(()));
+        assert_eq!(validate_usize(&schema, &Value::USize(42), &root), Err(SchemaErr::from([operation])));
+        assert_eq!(validate_usize(&schema, &Value::USize(42), &Value::None), Ok(()));
+    }
+}
@@ -1,6 +1,8 @@
 use std::collections::BTreeMap;
 
 pub mod path;
+#[cfg(feature = "serde")]
+mod serde_impl;
 pub mod stub;
 
 #[derive(Debug, PartialEq, Clone)]
@@ -0,0 +1,534 @@
+use std::collections::BTreeMap;
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{DeserializeOwned, MapAccess, SeqAccess, Visitor},
+    ser::{
+        Error as _, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple, SerializeTupleStruct, SerializeTupleVariant,
+    },
+};
+
+use super::Value;
+
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Value::None => serializer.serialize_none(),
+            Value::U64(value) => serializer.serialize_u64(*value),
+            Value::I64(value) => serializer.serialize_i64(*value),
+            Value::F64(value) => serializer.serialize_f64(*value),
+            Value::USize(value) => serializer.serialize_u64(*value as u64),
+            Value::ISize(value) => serializer.serialize_i64(*value as i64),
+            Value::Bool(value) => serializer.serialize_bool(*value),
+            Value::Str(value) => serializer.serialize_str(value),
+            Value::Arr(arr) => {
+                let mut seq = serializer.serialize_seq(Some(arr.len()))?;
+                for item in arr {
+                    seq.serialize_element(item)?;
+                }
+                seq.end()
+            }
+            Value::Obj(obj) => {
+                let mut map = serializer.serialize_map(Some(obj.len()))?;
+                for (key, item) in obj {
+                    map.serialize_entry(key, item)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "any valid value")
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Value, E> {
+        Ok(Value::Bool(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Value, E> {
+        Ok(Value::I64(value))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Value, E> {
+        Ok(Value::U64(value))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Value, E> {
+        Ok(Value::F64(value))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Value, E> {
+        Ok(Value::Str(value.into()))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Value, E> {
+        Ok(Value::Str(value))
+    }
+
+    fn visit_none<E>(self) -> Result<Value, E> {
+        Ok(Value::None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        Value::deserialize(deserializer)
+    }
+
+    fn visit_unit<E>(self) -> Result<Value, E> {
+        Ok(Value::None)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut arr = Vec::new();
+        while let Some(item) = seq.next_element()? {
+            arr.push(item);
+        }
+        Ok(Value::Arr(arr))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let mut obj = BTreeMap::new();
+        while let Some((key, item)) = map.next_entry()? {
+            obj.insert(key, item);
+        }
+        Ok(Value::Obj(obj))
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Value, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+impl From<serde_json::Value> for Value {
+    fn from(value: serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Null => Value::None,
+            serde_json::Value::Bool(value) => Value::Bool(value),
+            serde_json::Value::Number(number) => match (number.as_u64(), number.as_i64()) {
+                (Some(value), _) => Value::U64(value),
+                (None, Some(value)) => Value::I64(value),
+                (None, None) => number.as_f64().map(Value::F64).unwrap_or(Value::None),
+            },
+            serde_json::Value::String(value) => Value::Str(value),
+            serde_json::Value::Array(arr) => Value::Arr(arr.into_iter().map(Value::from).collect()),
+            serde_json::Value::Object(obj) => Value::Obj(obj.into_iter().map(|(key, item)| (key, Value::from(item))).collect()),
+        }
+    }
+}
+
+impl From<Value> for serde_json::Value {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::None => serde_json::Value::Null,
+            Value::U64(value) => serde_json::Value::from(value),
+            Value::I64(value) => serde_json::Value::from(value),
+            Value::F64(value) => serde_json::Value::from(value),
+            Value::USize(value) => serde_json::Value::from(value),
+            Value::ISize(value) => serde_json::Value::from(value),
+            Value::Bool(value) => serde_json::Value::Bool(value),
+            Value::Str(value) => serde_json::Value::String(value),
+            Value::Arr(arr) => serde_json::Value::Array(arr.into_iter().map(serde_json::Value::from).collect()),
+            Value::Obj(obj) => serde_json::Value::Object(obj.into_iter().map(|(key, item)| (key, serde_json::Value::from(item))).collect()),
+        }
+    }
+}
+
+struct ValueSerializer;
+
+struct SeqSerializer {
+    variant: Option<&'static str>,
+    arr: Vec<Value>,
+}
+
+struct MapSerializer {
+    variant: Option<&'static str>,
+    obj: BTreeMap<String, Value>,
+    key: Option<String>,
+}
+
+fn variant_value(variant: Option<&'static str>, value: Value) -> Value {
+    match variant {
+        Some(variant) => Value::Obj(BTreeMap::from([(variant.into(), value)])),
+        None => value,
+    }
+}
+
+fn key_string<T: Serialize + ?Sized>(key: &T) -> Result<String, serde_json::Error> {
+    match key.serialize(ValueSerializer)? {
+        Value::Str(key) => Ok(key),
+        Value::U64(key) => Ok(key.to_string()),
+        Value::I64(key) => Ok(key.to_string()),
+        Value::Bool(key) => Ok(key.to_string()),
+        _ => Err(serde_json::Error::custom("key must be a string")),
+    }
+}
+
+impl Serializer for ValueSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = SeqSerializer;
+    type SerializeTupleStruct = SeqSerializer;
+    type SerializeTupleVariant = SeqSerializer;
+    type SerializeMap = MapSerializer;
+    type SerializeStruct = MapSerializer;
+    type SerializeStructVariant = MapSerializer;
+
+    fn serialize_bool(self, value: bool) -> Result<Value, Self::Error> {
+        Ok(Value::Bool(value))
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<Value, Self::Error> {
+        Ok(Value::I64(value.into()))
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<Value, Self::Error> {
+        Ok(Value::I64(value.into()))
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<Value, Self::Error> {
+        Ok(Value::I64(value.into()))
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<Value, Self::Error> {
+        Ok(Value::I64(value))
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<Value, Self::Error> {
+        Ok(Value::U64(value.into()))
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<Value, Self::Error> {
+        Ok(Value::U64(value.into()))
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<Value, Self::Error> {
+        Ok(Value::U64(value.into()))
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<Value, Self::Error> {
+        Ok(Value::U64(value))
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<Value, Self::Error> {
+        Ok(Value::F64(value.into()))
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<Value, Self::Error> {
+        Ok(Value::F64(value))
+    }
+
+    fn serialize_char(self, value: char) -> Result<Value, Self::Error> {
+        Ok(Value::Str(value.into()))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<Value, Self::Error> {
+        Ok(Value::Str(value.into()))
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<Value, Self::Error> {
+        Ok(Value::Arr(value.iter().map(|byte| Value::U64((*byte).into())).collect()))
+    }
+
+    fn serialize_none(self) -> Result<Value, Self::Error> {
+        Ok(Value::None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Value, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value, Self::Error> {
+        Ok(Value::None)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value, Self::Error> {
+        Ok(Value::None)
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _index: u32, variant: &'static str) -> Result<Value, Self::Error> {
+        Ok(Value::Str(variant.into()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<Value, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value, Self::Error> {
+        Ok(variant_value(Some(variant), value.serialize(self)?))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqSerializer, Self::Error> {
+        Ok(SeqSerializer { variant: None, arr: Vec::with_capacity(len.unwrap_or_default()) })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqSerializer, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<SeqSerializer, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(self, _name: &'static str, _index: u32, variant: &'static str, len: usize) -> Result<SeqSerializer, Self::Error> {
+        Ok(SeqSerializer { variant: Some(variant), arr: Vec::with_capacity(len) })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<MapSerializer, Self::Error> {
+        Ok(MapSerializer { variant: None, obj: BTreeMap::new(), key: None })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<MapSerializer, Self::Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(self, _name: &'static str, _index: u32, variant: &'static str, _len: usize) -> Result<MapSerializer, Self::Error> {
+        Ok(MapSerializer { variant: Some(variant), obj: BTreeMap::new(), key: None })
+    }
+}
+
+impl SerializeSeq for SeqSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.arr.push(value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Self::Error> {
+        Ok(variant_value(self.variant, Value::Arr(self.arr)))
+    }
+}
+
+impl SerializeTuple for SeqSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Value, Self::Error> {
+        SerializeSeq::end(self)
+    }
+}
+
+impl SerializeTupleStruct for SeqSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Value, Self::Error> {
+        SerializeSeq::end(self)
+    }
+}
+
+impl SerializeTupleVariant for SeqSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Value, Self::Error> {
+        SerializeSeq::end(self)
+    }
+}
+
+impl SerializeMap for MapSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.key = Some(key_string(key)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = self.key.take().ok_or_else(|| serde_json::Error::custom("value without a key"))?;
+        self.obj.insert(key, value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Self::Error> {
+        Ok(variant_value(self.variant, Value::Obj(self.obj)))
+    }
+}
+
+impl SerializeStruct for MapSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error> {
+        self.obj.insert(key.into(), value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Self::Error> {
+        SerializeMap::end(self)
+    }
+}
+
+impl SerializeStructVariant for MapSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error> {
+        SerializeStruct::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> Result<Value, Self::Error> {
+        SerializeMap::end(self)
+    }
+}
+
+impl Value {
+    pub fn from_serialize<T: Serialize>(value: &T) -> Result<Value, serde_json::Error> {
+        value.serialize(ValueSerializer)
+    }
+
+    pub fn to_deserialize<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_value(serde_json::Value::from(self.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde::{
+        Deserialize, Serialize,
+        de::{
+            IntoDeserializer,
+            value::{Error, I64Deserializer},
+        },
+    };
+
+    use super::super::Value;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Account {
+        balance: i64,
+        limit: i32,
+        deposits: u32,
+    }
+
+    #[derive(Serialize)]
+    enum Event {
+        Closed,
+        Renamed(String),
+        Moved(i64, i64),
+        Transfer { amount: i64 },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct User {
+        name: String,
+        age: u8,
+        balance: i32,
+        score: f64,
+        alive: bool,
+        email: Option<String>,
+        bands: Vec<String>,
+    }
+
+    fn user() -> User {
+        User {
+            name: "Paul McCartney".into(),
+            age: 82,
+            balance: -10,
+            score: 9.5,
+            alive: true,
+            email: None,
+            bands: vec!["The Beatles".into(), "Wings".into()],
+        }
+    }
+
+    fn user_value() -> Value {
+        Value::from([
+            ("name".into(), Value::from("Paul McCartney")),
+            ("age".into(), Value::U64(82)),
+            ("balance".into(), Value::I64(-10)),
+            ("score".into(), Value::F64(9.5)),
+            ("alive".into(), Value::Bool(true)),
+            ("email".into(), Value::None),
+            ("bands".into(), Value::from(["The Beatles", "Wings"])),
+        ])
+    }
+
+    #[test]
+    fn value_from_serialize() {
+        assert_eq!(Value::from_serialize(&user()).unwrap(), user_value());
+        assert_eq!(Value::from_serialize(&-0.0_f64).unwrap(), Value::F64(-0.0));
+        assert_eq!(Value::from_serialize(&BTreeMap::from([("a", 1_i8)])).unwrap(), Value::from([("a".into(), Value::I64(1))]));
+        assert_eq!(Value::from_serialize(&BTreeMap::from([(7_u8, true)])).unwrap(), Value::from([("7".into(), Value::Bool(true))]));
+        assert!(Value::from_serialize(&BTreeMap::from([((1, 2), true)])).is_err());
+    }
+
+    #[test]
+    fn value_from_serialize_signed() {
+        let account = Account { balance: 10, limit: 0, deposits: 3 };
+        let value = Value::from([("balance".into(), Value::I64(10)), ("limit".into(), Value::I64(0)), ("deposits".into(), Value::U64(3))]);
+        assert_eq!(Value::from_serialize(&account).unwrap(), value);
+        assert_eq!(value.to_deserialize::<Account>().unwrap(), account);
+        assert_eq!(Value::from_serialize(&10_i64).unwrap(), Value::I64(10));
+        assert_eq!(Value::from_serialize(&10_u64).unwrap(), Value::U64(10));
+        let deserializer: I64Deserializer<Error> = 10_i64.into_deserializer();
+        assert_eq!(Value::deserialize(deserializer).unwrap(), Value::I64(10));
+    }
+
+    #[test]
+    fn value_from_serialize_enum() {
+        assert_eq!(Value::from_serialize(&Event::Closed).unwrap(), Value::from("Closed"));
+        assert_eq!(Value::from_serialize(&Event::Renamed("Wings".into())).unwrap(), Value::from([("Renamed".into(), Value::from("Wings"))]));
+        assert_eq!(
+            Value::from_serialize(&Event::Moved(1, -1)).unwrap(),
+            Value::from([("Moved".into(), Value::Arr(vec![Value::I64(1), Value::I64(-1)]))])
+        );
+        assert_eq!(
+            Value::from_serialize(&Event::Transfer { amount: 5 }).unwrap(),
+            Value::from([("Transfer".into(), Value::from([("amount".into(), Value::I64(5))]))])
+        );
+        assert_eq!(Value::from_serialize(&('a', ())).unwrap(), Value::Arr(vec![Value::from("a"), Value::None]));
+    }
+
+    #[test]
+    fn value_to_deserialize() {
+        assert_eq!(user_value().to_deserialize::<User>().unwrap(), user());
+        let mut value = user_value();
+        if let Value::Obj(obj) = &mut value {
+            obj.insert("age".into(), Value::USize(83));
+            obj.insert("balance".into(), Value::ISize(-11));
+        }
+        assert_eq!(value.to_deserialize::<User>().unwrap(), User { age: 83, balance: -11, ..user() });
+        assert!(Value::from("Paul").to_deserialize::<User>().is_err());
+    }
+
+    #[test]
+    fn value_serde_json() {
+        let json = r#"{"age":82,"alive":true,"balance":-10,"bands":["The Beatles","Wings"],"email":null,"name":"Paul McCartney","score":9.5}"#;
+        assert_eq!(serde_json::from_str::<Value>(json).unwrap(), user_value());
+        assert_eq!(serde_json::to_string(&user_value()).unwrap(), json);
+        assert_eq!(Value::from(serde_json::from_str::<serde_json::Value>(json).unwrap()), user_value());
+        assert_eq!(serde_json::Value::from(user_value()), serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+}
@@ -0,0 +1,30 @@
+#![cfg(all(feature = "derive", feature = "serde"))]
+
+use araucaria::{AraucariaSchema, validation::validate, value::Value};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, AraucariaSchema, Serialize, Deserialize)]
+struct Account {
+    #[araucaria(ge(-100))]
+    balance: i64,
+    age: i32,
+    deposits: u32,
+}
+
+fn account_value(json: &str) -> Value {
+    Value::from_serialize(&serde_json::from_str::<Account>(json).unwrap()).unwrap()
+}
+
+#[test]
+fn derive_serde_signed() {
+    let schema = Account::schema();
+    let account = Account { balance: 10, age: 30, deposits: 3 };
+    let value = Value::from_serialize(&account).unwrap();
+    assert_eq!(validate(&schema, &value), Ok(()));
+    assert_eq!(value.to_deserialize::<Account>().unwrap(), account);
+    let value = account_value(r#"{ "balance": 10, "age": 30, "deposits": 3 }"#);
+    assert_eq!(validate(&schema, &value), Ok(()));
+    assert_eq!(value.to_deserialize::<Account>().unwrap(), account);
+    assert_eq!(validate(&schema, &account_value(r#"{ "balance": -10, "age": 30, "deposits": 3 }"#)), Ok(()));
+    assert!(validate(&schema, &account_value(r#"{ "balance": -101, "age": 30, "deposits": 3 }"#)).is_err());
+}